@@ -0,0 +1,11 @@
+USE personal_website;
+
+-- Per-website settings that are not feature flags.
+-- website_id matches feature_list.website_id (the appID used by the public API)
+CREATE TABLE IF NOT EXISTS website_config (
+    website_id VARCHAR(36) PRIMARY KEY,
+
+    robots_txt TEXT,                             -- NULL: serve the default "allow all" rules
+
+    updated_at DATETIME NOT NULL
+);
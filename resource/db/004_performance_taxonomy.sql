@@ -0,0 +1,58 @@
+USE personal_website;
+
+-- 1. VISIBILITY Table
+-- The ids are referenced by the API code; only labels and order are meant to change.
+CREATE TABLE IF NOT EXISTS visibility (
+    id VARCHAR(50) PRIMARY KEY,                  -- "visibility_public", ...
+    name VARCHAR(50) NOT NULL,
+    description VARCHAR(255),
+    sort_order INT NOT NULL DEFAULT 0
+);
+
+INSERT IGNORE INTO visibility (id, name, description, sort_order) VALUES
+    ('visibility_public',   'Public',             'Listed on the site, in feeds and in the sitemap', 1),
+    ('visibility_unlisted', 'Unlisted',           'Not listed anywhere',                              2),
+    ('visibility_private',  'Private',            'Only visible to the owner',                        3),
+    ('visibility_password', 'Password protected', 'Requires a password to view',                      4);
+
+-- 2. PERFORMANCE_CATEGORY Table
+CREATE TABLE IF NOT EXISTS performance_category (
+    id VARCHAR(36) PRIMARY KEY,
+    profile_id VARCHAR(36) NOT NULL,
+
+    name VARCHAR(100) NOT NULL,
+    icon VARCHAR(100),                           -- Frontend icon identifier
+    color VARCHAR(32),                           -- "#1e88e5" or a design token
+    sort_order INT NOT NULL DEFAULT 0,
+
+    created_at DATETIME NOT NULL,
+    updated_at DATETIME,
+
+    FOREIGN KEY (profile_id) REFERENCES profile(id) ON DELETE CASCADE,
+    UNIQUE KEY unique_profile_category_name (profile_id, name),
+    INDEX idx_profile_category_order (profile_id, sort_order)
+);
+
+-- Performances written before this migration name their category by a free-form id, and two
+-- profiles may use the same one. Give every (profile, id) pair its own row, named after the id,
+-- then point the performances at it so updates pass the category check.
+INSERT INTO performance_category (id, profile_id, name, sort_order, created_at)
+SELECT UUID(), legacy.profile_id, legacy.category_id, 0, NOW()
+FROM (SELECT DISTINCT profile_id, category_id FROM performance) legacy
+WHERE NOT EXISTS (
+    SELECT 1 FROM performance_category pc
+    WHERE pc.profile_id = legacy.profile_id
+      AND (pc.id = legacy.category_id OR pc.name = legacy.category_id)
+);
+
+UPDATE performance p
+JOIN performance_category pc ON pc.profile_id = p.profile_id AND pc.name = p.category_id
+SET p.category_id = pc.id
+WHERE NOT EXISTS (
+    SELECT 1 FROM performance_category own
+    WHERE own.id = p.category_id AND own.profile_id = p.profile_id
+);
+
+-- Cascades only when the whole profile goes; the API refuses to delete a category still in use.
+ALTER TABLE performance
+    ADD CONSTRAINT fk_performance_category FOREIGN KEY (category_id) REFERENCES performance_category(id) ON DELETE CASCADE;
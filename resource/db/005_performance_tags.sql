@@ -0,0 +1,26 @@
+USE personal_website;
+
+-- 1. TAG Table
+CREATE TABLE IF NOT EXISTS tag (
+    id VARCHAR(36) PRIMARY KEY,
+    profile_id VARCHAR(36) NOT NULL,
+
+    name VARCHAR(50) NOT NULL,                   -- Display name: "C#"
+    slug VARCHAR(80) NOT NULL,                   -- URL form: "c-sharp"
+
+    created_at DATETIME NOT NULL,
+
+    FOREIGN KEY (profile_id) REFERENCES profile(id) ON DELETE CASCADE,
+    UNIQUE KEY unique_profile_tag_slug (profile_id, slug)
+);
+
+-- 2. PERFORMANCE_TAG Table (many-to-many)
+CREATE TABLE IF NOT EXISTS performance_tag (
+    performance_id VARCHAR(36) NOT NULL,
+    tag_id VARCHAR(36) NOT NULL,
+
+    PRIMARY KEY (performance_id, tag_id),
+    FOREIGN KEY (performance_id) REFERENCES performance(id) ON DELETE CASCADE,
+    FOREIGN KEY (tag_id) REFERENCES tag(id) ON DELETE CASCADE,
+    INDEX idx_performance_tag_tag (tag_id)
+);
@@ -0,0 +1,24 @@
+USE personal_website;
+
+-- PERFORMANCE_SHARE_LINK Table
+-- Grants access to a single performance whatever its visibility, until it expires or is revoked.
+CREATE TABLE IF NOT EXISTS performance_share_link (
+    id VARCHAR(36) PRIMARY KEY,
+    profile_id VARCHAR(36) NOT NULL,
+    performance_id VARCHAR(36) NOT NULL,
+
+    token_hash CHAR(64) NOT NULL,                -- SHA-256 hex of the token handed out
+    password_hash VARCHAR(255),                  -- Argon2; NULL when no password is required
+    label VARCHAR(100),
+
+    expires_at DATETIME NOT NULL,
+    access_count BIGINT NOT NULL DEFAULT 0,
+    last_accessed_at DATETIME,
+    revoked_at DATETIME,
+    created_at DATETIME NOT NULL,
+
+    FOREIGN KEY (profile_id) REFERENCES profile(id) ON DELETE CASCADE,
+    FOREIGN KEY (performance_id) REFERENCES performance(id) ON DELETE CASCADE,
+    UNIQUE KEY unique_share_token_hash (token_hash),
+    INDEX idx_share_link_performance (performance_id, created_at)
+);
@@ -0,0 +1,8 @@
+USE personal_website;
+
+-- Manual ordering of performances within a profile.
+-- Listings return pinned performances first, then by sort_order, then newest first.
+ALTER TABLE performance
+    ADD COLUMN sort_order INT NOT NULL DEFAULT 0,
+    ADD COLUMN pinned TINYINT(1) NOT NULL DEFAULT 0,
+    ADD INDEX idx_performance_profile_order (profile_id, pinned, sort_order);
@@ -0,0 +1,19 @@
+USE personal_website;
+
+-- Resized copies of an uploaded image (e.g. 320/768/1600 px wide, WebP and the original format).
+-- Stored next to the original in the bucket; rows go away with their image.
+CREATE TABLE IF NOT EXISTS image_variant (
+    id VARCHAR(36) PRIMARY KEY,
+    image_id VARCHAR(36) NOT NULL,
+    filename VARCHAR(255) NOT NULL,              -- "<image id>_w320.webp"
+    storage_url TEXT NOT NULL,
+    width INT NOT NULL,
+    height INT NOT NULL,
+    mime_type VARCHAR(50) NOT NULL,
+    file_size INT NOT NULL,
+    created_at DATETIME NOT NULL,
+
+    FOREIGN KEY (image_id) REFERENCES image(id) ON DELETE CASCADE,
+    UNIQUE KEY unique_image_variant (image_id, width, mime_type),
+    INDEX idx_image_variant_image (image_id)
+);
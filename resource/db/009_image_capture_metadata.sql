@@ -0,0 +1,16 @@
+USE personal_website;
+
+-- Uploads are re-encoded without EXIF (GPS, serial numbers). Capture date and camera are the only
+-- facts kept, and only for profiles that opted in through image_settings.
+ALTER TABLE image
+    ADD COLUMN captured_at DATETIME NULL,
+    ADD COLUMN camera_model VARCHAR(100) NULL;
+
+-- Per-profile image handling preferences; a missing row means the defaults
+CREATE TABLE IF NOT EXISTS image_settings (
+    profile_id VARCHAR(36) PRIMARY KEY,
+    keep_capture_metadata TINYINT(1) NOT NULL DEFAULT 0,
+    updated_at DATETIME NOT NULL,
+
+    FOREIGN KEY (profile_id) REFERENCES profile(id) ON DELETE CASCADE
+);
@@ -0,0 +1,8 @@
+USE personal_website;
+
+-- SHA-256 (lowercase hex) of the stored object, used to detect re-uploads of the same file.
+-- NULL for images uploaded before hashing; the duplicates job fills those in.
+-- Not unique: existing duplicates stay until they are merged.
+ALTER TABLE image
+    ADD COLUMN content_hash CHAR(64) NULL,
+    ADD INDEX idx_image_profile_hash (profile_id, content_hash);
@@ -0,0 +1,46 @@
+USE personal_website;
+
+-- 1. IMAGE_TAG: created in 002 without an owner and never used, so it is empty.
+-- Tags now belong to a profile; names are unique per profile instead of globally.
+ALTER TABLE image_tag
+    DROP INDEX name,
+    ADD COLUMN profile_id VARCHAR(36) NOT NULL AFTER id,
+    ADD COLUMN created_at DATETIME NOT NULL,
+    ADD CONSTRAINT fk_image_tag_profile FOREIGN KEY (profile_id) REFERENCES profile(id) ON DELETE CASCADE,
+    ADD UNIQUE KEY unique_profile_image_tag_name (profile_id, name);
+
+ALTER TABLE image_tag_list
+    ADD INDEX idx_image_tag_list_tag (tag_id);
+
+-- 2. ALBUM Table: a named, ordered collection of images, e.g. the gallery of a performance
+CREATE TABLE IF NOT EXISTS album (
+    id VARCHAR(36) PRIMARY KEY,
+    profile_id VARCHAR(36) NOT NULL,
+    visibility_id VARCHAR(50) NOT NULL DEFAULT 'visibility_private',
+
+    title VARCHAR(200) NOT NULL,
+    slug VARCHAR(220) NOT NULL,                  -- URL form of the title: "trip-to-chiang-mai"
+    description TEXT,
+    cover_image_id VARCHAR(36),                  -- NULL: the first image is the cover
+
+    created_at DATETIME NOT NULL,
+    updated_at DATETIME,
+
+    FOREIGN KEY (profile_id) REFERENCES profile(id) ON DELETE CASCADE,
+    FOREIGN KEY (visibility_id) REFERENCES visibility(id),
+    FOREIGN KEY (cover_image_id) REFERENCES image(id) ON DELETE SET NULL,
+    UNIQUE KEY unique_profile_album_slug (profile_id, slug)
+);
+
+-- 3. ALBUM_IMAGE Table (ordered many-to-many)
+CREATE TABLE IF NOT EXISTS album_image (
+    album_id VARCHAR(36) NOT NULL,
+    image_id VARCHAR(36) NOT NULL,
+    position INT NOT NULL,
+
+    PRIMARY KEY (album_id, image_id),
+    FOREIGN KEY (album_id) REFERENCES album(id) ON DELETE CASCADE,
+    FOREIGN KEY (image_id) REFERENCES image(id) ON DELETE CASCADE,
+    INDEX idx_album_image_order (album_id, position),
+    INDEX idx_album_image_image (image_id)
+);
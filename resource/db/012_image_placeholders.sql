@@ -0,0 +1,9 @@
+USE personal_website;
+
+-- Placeholders shown while an image loads, computed on upload.
+-- NULL for images uploaded before this migration until the placeholder backfill has run.
+ALTER TABLE image
+    ADD COLUMN blurhash VARCHAR(64) NULL,          -- BlurHash of the whole image, e.g. "LEHV6nWB2yk8pyo0adR*.7kCMdnj"
+    ADD COLUMN dominant_color CHAR(7) NULL,        -- Average color as "#rrggbb"
+    ADD COLUMN lqip TEXT NULL,                     -- Tiny WebP preview as a data: URI, a few hundred bytes
+    ADD COLUMN placeholder_failed_at DATETIME NULL; -- Original could not be decoded; the backfill stops retrying it
@@ -0,0 +1,34 @@
+USE personal_website;
+
+-- 1. IMAGE_UPLOAD_SESSION Table: a resumable upload in progress.
+-- The client sends byte ranges in order; once received_bytes reaches total_size the session is
+-- completed into an image. Sessions not completed by expires_at are removed with their chunks.
+CREATE TABLE IF NOT EXISTS image_upload_session (
+    id VARCHAR(36) PRIMARY KEY,
+    profile_id VARCHAR(36) NOT NULL,
+
+    original_filename VARCHAR(255) NOT NULL,
+    total_size BIGINT NOT NULL,                  -- Announced size of the whole file in bytes
+    received_bytes BIGINT NOT NULL DEFAULT 0,    -- Offset the next chunk has to start at
+
+    -- Image fields applied when the upload completes
+    alt_text TEXT,
+    caption TEXT,
+    on_duplicate VARCHAR(20) NOT NULL DEFAULT 'existing',
+
+    created_at DATETIME NOT NULL,
+    expires_at DATETIME NOT NULL,                -- Pushed back on every chunk received
+
+    FOREIGN KEY (profile_id) REFERENCES profile(id) ON DELETE CASCADE,
+    INDEX idx_image_upload_session_expires (expires_at)
+);
+
+-- 2. IMAGE_UPLOAD_CHUNK Table: the bytes received so far, one row per chunk
+CREATE TABLE IF NOT EXISTS image_upload_chunk (
+    session_id VARCHAR(36) NOT NULL,
+    byte_offset BIGINT NOT NULL,
+    data LONGBLOB NOT NULL,
+
+    PRIMARY KEY (session_id, byte_offset),
+    FOREIGN KEY (session_id) REFERENCES image_upload_session(id) ON DELETE CASCADE
+);
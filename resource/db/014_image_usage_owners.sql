@@ -0,0 +1,21 @@
+USE personal_website;
+
+-- IMAGE_USAGE: a usage row now belongs to any owner, not only a performance.
+--   performance -> performance.id, counted from its markdown content
+--   avatar      -> profile.id, the profile picture
+--   social      -> social.id, the social logo
+--   skill       -> skill.id, the skill logo
+--   announce    -> announce_list.id, images in the message or the link
+-- Existing rows are all performances. Without a foreign key to performance,
+-- deleting a performance removes its usage rows in the same transaction instead of by cascade;
+-- performances removed by a cascade of their own are pruned by the usage sync.
+ALTER TABLE image_usage
+    DROP FOREIGN KEY image_usage_ibfk_2,
+    DROP INDEX unique_image_performance,
+    DROP INDEX idx_performance_images;
+
+ALTER TABLE image_usage
+    CHANGE COLUMN performance_id owner_id VARCHAR(36) NOT NULL,
+    ADD COLUMN owner_type VARCHAR(20) NOT NULL DEFAULT 'performance' AFTER image_id,
+    ADD UNIQUE KEY unique_image_owner (image_id, owner_type, owner_id),
+    ADD INDEX idx_image_usage_owner (owner_type, owner_id);
@@ -1,5 +1,6 @@
 use crate::delivery::http::server::server::create_router;
 use crate::delivery::http::server::state::AppState;
+use crate::delivery::jobs::spawn_background_jobs;
 use crate::config::config::Config;
 use crate::infrastructure::infrastructure::Infrastructure;
 use crate::application::services::services::Services;
@@ -19,7 +20,10 @@ impl App {
         // 3. Setup AppState
         let state = AppState::new(services);
 
-        // 4. Build router
+        // 4. Start background jobs
+        spawn_background_jobs(&state, &config);
+
+        // 5. Build router
         Ok(create_router(state))
     }
 }
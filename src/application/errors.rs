@@ -16,6 +16,11 @@ pub enum ApplicationError {
     Conflict {
         message: String,
     },
+    /// Conflict with a known existing record the client can use instead
+    AlreadyExists {
+        resource: &'static str,
+        existing_id: String,
+    },
     Internal {
         message: String,
     },
@@ -42,6 +47,9 @@ impl Display for ApplicationError {
             ApplicationError::Conflict { message } => {
                 write!(f, "Conflict: {}", message)
             }
+            ApplicationError::AlreadyExists { resource, existing_id } => {
+                write!(f, "{} already exists: {}", resource, existing_id)
+            }
             ApplicationError::Internal { message } => {
                 write!(f, "Internal error: {}", message)
             }
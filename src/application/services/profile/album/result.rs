@@ -0,0 +1,53 @@
+use serde::Serialize;
+use axum::response::IntoResponse;
+use crate::application::services::profile::performance::result::PublicImageResult;
+use crate::domain::entities::profile::album::album::{Album, AlbumSummary};
+use crate::interface_adapters::http::v1::presenters::common::api_response::ApiResponse;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AlbumListResult {
+    pub albums: Vec<AlbumSummary>,
+}
+
+impl IntoResponse for AlbumListResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AlbumResult {
+    pub album: Album,
+    /// In album order
+    pub images: Vec<PublicImageResult>,
+}
+
+impl IntoResponse for AlbumResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AlbumDeleteResult {
+    pub message: String,
+    pub deleted_id: String,
+}
+
+impl IntoResponse for AlbumDeleteResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
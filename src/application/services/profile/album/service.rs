@@ -0,0 +1,503 @@
+use std::collections::HashSet;
+use async_trait::async_trait;
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::album::dto::input::{
+    ListAlbumsInput, GetAlbumInput, CreateAlbumInput, UpdateAlbumInput, DeleteAlbumInput,
+    SetAlbumImagesInput, GetPublicAlbumsInput, GetPublicAlbumInput
+};
+use crate::application::services::profile::image::service::{find_variants, image_sources};
+use crate::application::services::profile::performance::result::PublicImageResult;
+use crate::domain::entities::profile::album::album::Album;
+use crate::domain::entities::profile::visibility::visibility::{VISIBILITY_PRIVATE, VISIBILITY_PUBLIC, VISIBILITY_UNLISTED};
+use crate::interface_adapters::gateways::repositories::profile::album::album_repository::AlbumRepository;
+use crate::interface_adapters::gateways::repositories::profile::image::image_repository::ImageRepository;
+use super::result::{AlbumListResult, AlbumResult, AlbumDeleteResult};
+
+fn now() -> String {
+    sqlx::types::chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+fn clean_description(description: Option<String>) -> Option<String> {
+    description.map(|d| d.trim().to_string()).filter(|d| !d.is_empty())
+}
+
+/// The album with its images in album order, each with its responsive sources.
+async fn album_result<A, R>(repository: &A, image_repository: &R, album: Album) -> Result<AlbumResult, ApplicationError>
+where
+    A: AlbumRepository,
+    R: ImageRepository,
+{
+    let images = repository
+        .find_images(&album.id)
+        .await
+        .map_app_err("Failed to fetch album images")?;
+    let ids: Vec<String> = images.iter().map(|img| img.id.clone()).collect();
+    let mut variants = find_variants(image_repository, &ids).await?;
+
+    let images = images
+        .into_iter()
+        .map(|img| {
+            let (_, srcset) = image_sources(
+                &img.storage_url,
+                img.width,
+                &img.mime_type,
+                variants.remove(&img.id).unwrap_or_default(),
+            );
+            PublicImageResult {
+                id: img.id,
+                url: img.storage_url,
+                width: img.width,
+                height: img.height,
+                mime_type: img.mime_type,
+                alt_text: img.alt_text,
+                caption: img.caption,
+                captured_at: img.captured_at,
+                camera_model: img.camera_model,
+                blurhash: img.blurhash,
+                dominant_color: img.dominant_color,
+                lqip: img.lqip,
+                srcset,
+            }
+        })
+        .collect();
+
+    Ok(AlbumResult { album, images })
+}
+
+async fn find_album<A>(repository: &A, id: &str, profile_id: &str) -> Result<Album, ApplicationError>
+where
+    A: AlbumRepository,
+{
+    repository
+        .find_by_id(id, profile_id)
+        .await
+        .map_app_err("Failed to fetch album")?
+        .ok_or_else(|| ApplicationError::NotFound {
+            resource: "Album",
+            identifier: id.to_string(),
+        })
+}
+
+async fn ensure_slug_free<A>(repository: &A, profile_id: &str, slug: &str, except_id: Option<&str>) -> Result<(), ApplicationError>
+where
+    A: AlbumRepository,
+{
+    let existing = repository
+        .find_by_slug(profile_id, slug)
+        .await
+        .map_app_err("Failed to fetch album")?;
+
+    match existing {
+        Some(album) if Some(album.id.as_str()) != except_id => Err(ApplicationError::Conflict {
+            message: format!("Album '{}' already exists", album.title),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// A cover must be one of the profile's own images.
+async fn ensure_own_image<R>(image_repository: &R, profile_id: &str, image_id: Option<&str>) -> Result<(), ApplicationError>
+where
+    R: ImageRepository,
+{
+    let Some(image_id) = image_id else {
+        return Ok(());
+    };
+    image_repository
+        .find_by_id_and_profile_id(image_id, profile_id)
+        .await
+        .map_app_err("Failed to fetch image")?
+        .ok_or_else(|| ApplicationError::NotFound {
+            resource: "Image",
+            identifier: image_id.to_string(),
+        })?;
+    Ok(())
+}
+
+pub struct ListAlbumsService<A>
+where
+    A: AlbumRepository,
+{
+    repository: A,
+}
+
+impl<A> ListAlbumsService<A>
+where
+    A: AlbumRepository,
+{
+    pub fn new(repository: A) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<A> UseCase for ListAlbumsService<A>
+where
+    A: AlbumRepository + Send + Sync,
+{
+    type Input = ListAlbumsInput;
+    type Output = AlbumListResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let albums = self.repository
+            .find_by_profile_id(&input.profile_id, None)
+            .await
+            .map_app_err("Failed to fetch albums")?;
+
+        Ok(AlbumListResult { albums })
+    }
+}
+
+pub struct GetAlbumService<A, R>
+where
+    A: AlbumRepository,
+    R: ImageRepository,
+{
+    repository: A,
+    image_repository: R,
+}
+
+impl<A, R> GetAlbumService<A, R>
+where
+    A: AlbumRepository,
+    R: ImageRepository,
+{
+    pub fn new(repository: A, image_repository: R) -> Self {
+        Self { repository, image_repository }
+    }
+}
+
+#[async_trait]
+impl<A, R> UseCase for GetAlbumService<A, R>
+where
+    A: AlbumRepository + Send + Sync,
+    R: ImageRepository + Send + Sync,
+{
+    type Input = GetAlbumInput;
+    type Output = AlbumResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let album = find_album(&self.repository, &input.id, &input.profile_id).await?;
+        album_result(&self.repository, &self.image_repository, album).await
+    }
+}
+
+pub struct CreateAlbumService<A, R>
+where
+    A: AlbumRepository,
+    R: ImageRepository,
+{
+    repository: A,
+    image_repository: R,
+}
+
+impl<A, R> CreateAlbumService<A, R>
+where
+    A: AlbumRepository,
+    R: ImageRepository,
+{
+    pub fn new(repository: A, image_repository: R) -> Self {
+        Self { repository, image_repository }
+    }
+}
+
+#[async_trait]
+impl<A, R> UseCase for CreateAlbumService<A, R>
+where
+    A: AlbumRepository + Send + Sync,
+    R: ImageRepository + Send + Sync,
+{
+    type Input = CreateAlbumInput;
+    type Output = AlbumResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        ensure_own_image(&self.image_repository, &input.profile_id, input.cover_image_id.as_deref()).await?;
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let slug = Album::slug_for(&input.title, &id);
+        ensure_slug_free(&self.repository, &input.profile_id, &slug, None).await?;
+
+        let album = self.repository
+            .create(Album {
+                id,
+                profile_id: input.profile_id,
+                visibility_id: input.visibility_id.unwrap_or_else(|| VISIBILITY_PRIVATE.to_string()),
+                title: input.title.trim().to_string(),
+                slug,
+                description: clean_description(input.description),
+                cover_image_id: input.cover_image_id,
+                created_at: now(),
+                updated_at: None,
+            })
+            .await
+            .map_app_err("Failed to create album")?;
+
+        Ok(AlbumResult { album, images: vec![] })
+    }
+}
+
+pub struct UpdateAlbumService<A, R>
+where
+    A: AlbumRepository,
+    R: ImageRepository,
+{
+    repository: A,
+    image_repository: R,
+}
+
+impl<A, R> UpdateAlbumService<A, R>
+where
+    A: AlbumRepository,
+    R: ImageRepository,
+{
+    pub fn new(repository: A, image_repository: R) -> Self {
+        Self { repository, image_repository }
+    }
+}
+
+#[async_trait]
+impl<A, R> UseCase for UpdateAlbumService<A, R>
+where
+    A: AlbumRepository + Send + Sync,
+    R: ImageRepository + Send + Sync,
+{
+    type Input = UpdateAlbumInput;
+    type Output = AlbumResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let old_album = find_album(&self.repository, &input.id, &input.profile_id).await?;
+        ensure_own_image(&self.image_repository, &input.profile_id, input.cover_image_id.as_deref()).await?;
+
+        // Like tags, the slug follows the title so album URLs match what readers see
+        let slug = Album::slug_for(&input.title, &input.id);
+        ensure_slug_free(&self.repository, &input.profile_id, &slug, Some(&input.id)).await?;
+
+        let album = self.repository
+            .update(Album {
+                visibility_id: input.visibility_id,
+                title: input.title.trim().to_string(),
+                slug,
+                description: clean_description(input.description),
+                cover_image_id: input.cover_image_id,
+                updated_at: Some(now()),
+                ..old_album
+            })
+            .await
+            .map_app_err("Failed to update album")?;
+
+        album_result(&self.repository, &self.image_repository, album).await
+    }
+}
+
+pub struct DeleteAlbumService<A>
+where
+    A: AlbumRepository,
+{
+    repository: A,
+}
+
+impl<A> DeleteAlbumService<A>
+where
+    A: AlbumRepository,
+{
+    pub fn new(repository: A) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<A> UseCase for DeleteAlbumService<A>
+where
+    A: AlbumRepository + Send + Sync,
+{
+    type Input = DeleteAlbumInput;
+    type Output = AlbumDeleteResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        find_album(&self.repository, &input.id, &input.profile_id).await?;
+
+        // Only the collection goes; its images stay in the library
+        self.repository
+            .delete(&input.id, &input.profile_id)
+            .await
+            .map_app_err("Failed to delete album")?;
+
+        Ok(AlbumDeleteResult {
+            message: "Album deleted".to_string(),
+            deleted_id: input.id,
+        })
+    }
+}
+
+pub struct SetAlbumImagesService<A, R>
+where
+    A: AlbumRepository,
+    R: ImageRepository,
+{
+    repository: A,
+    image_repository: R,
+}
+
+impl<A, R> SetAlbumImagesService<A, R>
+where
+    A: AlbumRepository,
+    R: ImageRepository,
+{
+    pub fn new(repository: A, image_repository: R) -> Self {
+        Self { repository, image_repository }
+    }
+}
+
+#[async_trait]
+impl<A, R> UseCase for SetAlbumImagesService<A, R>
+where
+    A: AlbumRepository + Send + Sync,
+    R: ImageRepository + Send + Sync,
+{
+    type Input = SetAlbumImagesInput;
+    type Output = AlbumResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let album = find_album(&self.repository, &input.id, &input.profile_id).await?;
+
+        let own_ids: HashSet<String> = self.image_repository
+            .find_by_profile_id(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch images")?
+            .into_iter()
+            .map(|img| img.id)
+            .collect();
+
+        let mut image_ids: Vec<String> = Vec::with_capacity(input.image_ids.len());
+        for image_id in input.image_ids {
+            if !own_ids.contains(&image_id) {
+                return Err(ApplicationError::NotFound {
+                    resource: "Image",
+                    identifier: image_id,
+                });
+            }
+            if !image_ids.contains(&image_id) {
+                image_ids.push(image_id);
+            }
+        }
+
+        self.repository
+            .set_images(&album.id, &image_ids)
+            .await
+            .map_app_err("Failed to update album images")?;
+
+        album_result(&self.repository, &self.image_repository, album).await
+    }
+}
+
+pub struct GetPublicAlbumsService<A>
+where
+    A: AlbumRepository,
+{
+    repository: A,
+}
+
+impl<A> GetPublicAlbumsService<A>
+where
+    A: AlbumRepository,
+{
+    pub fn new(repository: A) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<A> UseCase for GetPublicAlbumsService<A>
+where
+    A: AlbumRepository + Send + Sync,
+{
+    type Input = GetPublicAlbumsInput;
+    type Output = AlbumListResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let albums = self.repository
+            .find_by_profile_id(&input.profile_id, Some(VISIBILITY_PUBLIC))
+            .await
+            .map_app_err("Failed to fetch albums")?;
+
+        Ok(AlbumListResult { albums })
+    }
+}
+
+pub struct GetPublicAlbumService<A, R>
+where
+    A: AlbumRepository,
+    R: ImageRepository,
+{
+    repository: A,
+    image_repository: R,
+}
+
+impl<A, R> GetPublicAlbumService<A, R>
+where
+    A: AlbumRepository,
+    R: ImageRepository,
+{
+    pub fn new(repository: A, image_repository: R) -> Self {
+        Self { repository, image_repository }
+    }
+}
+
+#[async_trait]
+impl<A, R> UseCase for GetPublicAlbumService<A, R>
+where
+    A: AlbumRepository + Send + Sync,
+    R: ImageRepository + Send + Sync,
+{
+    type Input = GetPublicAlbumInput;
+    type Output = AlbumResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let album = match self.repository
+            .find_by_slug(&input.profile_id, &input.slug_or_id)
+            .await
+            .map_app_err("Failed to fetch album")?
+        {
+            Some(album) => Some(album),
+            None => self.repository
+                .find_by_id(&input.slug_or_id, &input.profile_id)
+                .await
+                .map_app_err("Failed to fetch album")?,
+        };
+
+        // Unlisted albums open by link, private ones are indistinguishable from missing ones
+        let album = album
+            .filter(|a| a.visibility_id == VISIBILITY_PUBLIC || a.visibility_id == VISIBILITY_UNLISTED)
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "Album",
+                identifier: input.slug_or_id.clone(),
+            })?;
+
+        album_result(&self.repository, &self.image_repository, album).await
+    }
+}
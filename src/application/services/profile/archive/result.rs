@@ -0,0 +1,55 @@
+use serde::Serialize;
+use axum::response::IntoResponse;
+use crate::interface_adapters::http::v1::presenters::common::api_response::ApiResponse;
+
+/// A finished export, sent as a zip download.
+#[derive(Debug, Clone)]
+pub struct ProfileArchiveResult {
+    pub filename: String,
+    pub bytes: Vec<u8>,
+}
+
+impl IntoResponse for ProfileArchiveResult {
+    fn into_response(self) -> axum::response::Response {
+        use axum::http::{header, StatusCode};
+
+        (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "application/zip".to_string()),
+                (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", self.filename)),
+                (header::CACHE_CONTROL, "no-store".to_string()),
+            ],
+            self.bytes,
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RestoreProfileArchiveResult {
+    pub profile_id: String,
+    pub skills: usize,
+    pub socials: usize,
+    pub announces: usize,
+    pub categories: usize,
+    pub tags: usize,
+    pub performances: usize,
+    pub images: usize,
+    pub image_tags: usize,
+    pub albums: usize,
+    /// Storage objects the export could not read; their rows were restored without them
+    pub missing: Vec<String>,
+    /// Archive images that fail the current upload checks, as "path: reason"; restored without them
+    pub rejected_images: Vec<String>,
+}
+
+impl IntoResponse for RestoreProfileArchiveResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
@@ -0,0 +1,436 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::Semaphore;
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::services::profile::image::service::sync_owner_usage;
+use crate::application::use_cases::profile::archive::dto::input::{
+    ExportProfileArchiveInput, RestoreProfileArchiveInput, RESTORE_ARCHIVE_LIMITS
+};
+use crate::domain::entities::profile::archive::archive::{
+    ArchiveFile, ArchiveManifest, ProfileArchiveTarget, ProfileSnapshot,
+    ARCHIVE_FORMAT, ARCHIVE_VERSION, MANIFEST_PATH, MIN_ARCHIVE_VERSION, SNAPSHOT_PATH
+};
+use crate::domain::entities::profile::image::image_usage::ImageUsageOwner;
+use crate::interface_adapters::gateways::repositories::profile::archive::archive_repository::ProfileArchiveRepository;
+use crate::interface_adapters::gateways::repositories::profile::image::image_repository::ImageRepository;
+use crate::interface_adapters::gateways::repositories::profile::image::image_storage_repository::ImageStorageRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance_content::performance_content_repository::PerformanceContentRepository;
+use crate::shared::utils::hash::sha256_hex;
+use crate::shared::utils::image_upload::{extension_for, validate_upload, UploadLimits};
+use crate::shared::utils::markdown_archive::rewrite_image_links;
+use crate::shared::utils::zip_archive::{read_entries, write_entries};
+use super::result::{ProfileArchiveResult, RestoreProfileArchiveResult};
+
+fn invalid_archive(message: impl Into<String>) -> ApplicationError {
+    ApplicationError::ValidationError { message: message.into() }
+}
+
+/// Gives every id of `ids` a fresh UUID.
+fn new_ids<'a>(ids: impl Iterator<Item = &'a String>) -> HashMap<String, String> {
+    ids.map(|id| (id.clone(), uuid::Uuid::new_v4().to_string())).collect()
+}
+
+fn remap(ids: &HashMap<String, String>, id: &str) -> String {
+    ids.get(id).cloned().unwrap_or_else(|| id.to_string())
+}
+
+pub struct ExportProfileArchiveService<A, C, S>
+where
+    A: ProfileArchiveRepository,
+    C: PerformanceContentRepository,
+    S: ImageStorageRepository,
+{
+    repository: A,
+    content_repository: C,
+    storage_repository: S,
+}
+
+impl<A, C, S> ExportProfileArchiveService<A, C, S>
+where
+    A: ProfileArchiveRepository,
+    C: PerformanceContentRepository,
+    S: ImageStorageRepository,
+{
+    pub fn new(repository: A, content_repository: C, storage_repository: S) -> Self {
+        Self { repository, content_repository, storage_repository }
+    }
+}
+
+#[async_trait]
+impl<A, C, S> UseCase for ExportProfileArchiveService<A, C, S>
+where
+    A: ProfileArchiveRepository + Send + Sync,
+    C: PerformanceContentRepository + Send + Sync,
+    S: ImageStorageRepository + Send + Sync,
+{
+    type Input = ExportProfileArchiveInput;
+    type Output = ProfileArchiveResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let snapshot = self.repository
+            .load_snapshot(&input.profile_id)
+            .await
+            .map_app_err("Failed to load profile")?
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "Profile",
+                identifier: input.profile_id.clone(),
+            })?;
+
+        // A storage object that can't be read shouldn't block the rest of the export;
+        // it is listed in the manifest instead
+        let mut files: Vec<(String, Vec<u8>)> = Vec::new();
+        let mut missing = Vec::new();
+
+        for performance in snapshot.performances.iter().filter(|p| p.content_url.is_some()) {
+            let path = ProfileSnapshot::content_path(&performance.id);
+            match self.content_repository.get_content(&input.profile_id, &performance.id).await {
+                Ok(content) => files.push((path, content.into_bytes())),
+                Err(_) => missing.push(path),
+            }
+        }
+        for image in &snapshot.images {
+            let path = ProfileSnapshot::image_path(image);
+            match self.storage_repository.download_image(&input.profile_id, &image.filename).await {
+                Ok(data) => files.push((path, data)),
+                Err(_) => missing.push(path),
+            }
+        }
+
+        let snapshot_json = serde_json::to_vec_pretty(&snapshot)
+            .map_err(|e| ApplicationError::Internal { message: format!("Failed to serialize profile: {}", e) })?;
+        files.insert(0, (SNAPSHOT_PATH.to_string(), snapshot_json));
+
+        let now = Utc::now();
+        let manifest = ArchiveManifest {
+            format: ARCHIVE_FORMAT.to_string(),
+            version: ARCHIVE_VERSION,
+            exported_at: now.to_rfc3339(),
+            profile_id: input.profile_id.clone(),
+            files: files
+                .iter()
+                .map(|(path, data)| ArchiveFile {
+                    path: path.clone(),
+                    size: data.len() as u64,
+                    sha256: sha256_hex(data),
+                })
+                .collect(),
+            missing,
+        };
+        let manifest_json = serde_json::to_vec_pretty(&manifest)
+            .map_err(|e| ApplicationError::Internal { message: format!("Failed to serialize manifest: {}", e) })?;
+
+        let bytes = write_entries(
+            std::iter::once((MANIFEST_PATH, manifest_json.as_slice()))
+                .chain(files.iter().map(|(path, data)| (path.as_str(), data.as_slice()))),
+        )
+        .map_err(|message| ApplicationError::Internal { message })?;
+
+        Ok(ProfileArchiveResult {
+            filename: format!("profile-{}-{}.zip", input.profile_id, now.format("%Y%m%d")),
+            bytes,
+        })
+    }
+}
+
+/// Storage objects written during a restore, removed again if the restore fails.
+#[derive(Default)]
+struct UploadedObjects {
+    images: Vec<String>,
+    contents: Vec<String>,
+}
+
+pub struct RestoreProfileArchiveService<A, C, S, I>
+where
+    A: ProfileArchiveRepository,
+    C: PerformanceContentRepository,
+    S: ImageStorageRepository,
+    I: ImageRepository,
+{
+    repository: A,
+    content_repository: C,
+    storage_repository: S,
+    image_repository: I,
+    limits: UploadLimits,
+    /// Image checks decode on the blocking pool; this caps how many run at once
+    permits: Arc<Semaphore>,
+}
+
+impl<A, C, S, I> RestoreProfileArchiveService<A, C, S, I>
+where
+    A: ProfileArchiveRepository,
+    C: PerformanceContentRepository,
+    S: ImageStorageRepository,
+    I: ImageRepository,
+{
+    pub fn new(repository: A, content_repository: C, storage_repository: S, image_repository: I, limits: UploadLimits) -> Self {
+        let workers = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(2);
+        Self {
+            repository,
+            content_repository,
+            storage_repository,
+            image_repository,
+            limits,
+            permits: Arc::new(Semaphore::new(workers)),
+        }
+    }
+
+    /// Reads the archive and checks every listed file against its manifest entry.
+    /// Files not listed in the manifest are dropped.
+    fn open_archive(bytes: &[u8]) -> Result<(ArchiveManifest, HashMap<String, Vec<u8>>), ApplicationError> {
+        let mut entries: HashMap<String, Vec<u8>> = read_entries(bytes, RESTORE_ARCHIVE_LIMITS)
+            .map_err(invalid_archive)?
+            .into_iter()
+            .collect();
+
+        let manifest_json = entries
+            .remove(MANIFEST_PATH)
+            .ok_or_else(|| invalid_archive(format!("Archive has no {}", MANIFEST_PATH)))?;
+        let manifest: ArchiveManifest = serde_json::from_slice(&manifest_json)
+            .map_err(|e| invalid_archive(format!("Invalid {}: {}", MANIFEST_PATH, e)))?;
+
+        if manifest.format != ARCHIVE_FORMAT {
+            return Err(invalid_archive("Archive is not a profile export"));
+        }
+        if !(MIN_ARCHIVE_VERSION..=ARCHIVE_VERSION).contains(&manifest.version) {
+            return Err(invalid_archive(format!("Unsupported archive version {}", manifest.version)));
+        }
+        if !manifest.files.iter().any(|f| f.path == SNAPSHOT_PATH) {
+            return Err(invalid_archive(format!("Archive has no {}", SNAPSHOT_PATH)));
+        }
+
+        let mut files = HashMap::with_capacity(manifest.files.len());
+        for file in &manifest.files {
+            let data = entries
+                .remove(&file.path)
+                .ok_or_else(|| invalid_archive(format!("'{}' is listed in the manifest but missing", file.path)))?;
+            if data.len() as u64 != file.size || sha256_hex(&data) != file.sha256 {
+                return Err(invalid_archive(format!("Checksum mismatch for '{}'", file.path)));
+            }
+            files.insert(file.path.clone(), data);
+        }
+
+        Ok((manifest, files))
+    }
+
+    /// Moves the snapshot onto `profile_id` with fresh ids for everything the profile owns,
+    /// uploads its content and images, then writes the rows. Images that fail today's upload
+    /// checks are left out and listed in `rejected`.
+    async fn restore(
+        &self,
+        input: &RestoreProfileArchiveInput,
+        mut snapshot: ProfileSnapshot,
+        files: &HashMap<String, Vec<u8>>,
+        uploaded: &mut UploadedObjects,
+        rejected: &mut Vec<String>,
+    ) -> Result<ProfileSnapshot, ApplicationError> {
+        let profile_id = input.profile_id.as_str();
+        let category_ids = new_ids(snapshot.categories.iter().map(|c| &c.id));
+        let tag_ids = new_ids(snapshot.tags.iter().map(|t| &t.id));
+        let performance_ids = new_ids(snapshot.performances.iter().map(|p| &p.id));
+        let announce_ids = new_ids(snapshot.announces.iter().map(|a| &a.id));
+        let image_tag_ids = new_ids(snapshot.image_tags.iter().map(|t| &t.id));
+        let album_ids = new_ids(snapshot.albums.iter().map(|a| &a.id));
+        let mut image_ids = HashMap::new();
+        let mut image_urls = HashMap::new();
+
+        snapshot.profile.id = profile_id.to_string();
+        for announce in &mut snapshot.announces {
+            announce.id = remap(&announce_ids, &announce.id);
+        }
+        for category in &mut snapshot.categories {
+            category.id = remap(&category_ids, &category.id);
+        }
+        for tag in &mut snapshot.tags {
+            tag.id = remap(&tag_ids, &tag.id);
+        }
+        for tag in &mut snapshot.image_tags {
+            tag.id = remap(&image_tag_ids, &tag.id);
+        }
+
+        // Images whose binary did not make it into the archive are left out
+        let mut images = Vec::with_capacity(snapshot.images.len());
+        for mut image in std::mem::take(&mut snapshot.images) {
+            let path = ProfileSnapshot::image_path(&image);
+            let Some(data) = files.get(&path) else {
+                continue;
+            };
+            // Like an upload, the content decides the format; the archive's filename and mime type are not trusted.
+            // Limits or accepted formats may have changed since the export, so a rejected image is skipped
+            let validated = {
+                let _permit = self.permits
+                    .acquire()
+                    .await
+                    .map_err(|e| ApplicationError::Internal { message: e.to_string() })?;
+                let (bytes, limits) = (data.clone(), self.limits);
+                tokio::task::spawn_blocking(move || validate_upload(&bytes, &limits))
+                    .await
+                    .map_err(|e| ApplicationError::Internal { message: format!("Image check task failed: {}", e) })?
+            };
+            let validated = match validated {
+                Ok(validated) => validated,
+                Err(message) => {
+                    rejected.push(format!("{}: {}", path, message));
+                    continue;
+                }
+            };
+            image.mime_type = validated.format.to_mime_type().to_string();
+            image.width = Some(validated.width as i32);
+            image.height = Some(validated.height as i32);
+
+            let id = uuid::Uuid::new_v4().to_string();
+            let filename = format!("{}.{}", id, extension_for(validated.format));
+            let storage_url = self.storage_repository
+                .upload_image(profile_id, &filename, data.clone(), &image.mime_type)
+                .await
+                .map_app_err("Failed to upload image")?;
+            uploaded.images.push(filename.clone());
+
+            image_ids.insert(image.id.clone(), id.clone());
+            image_urls.insert(image.storage_url.clone(), storage_url.clone());
+            image.id = id;
+            image.filename = filename;
+            image.storage_url = storage_url;
+            image.tag_ids = image.tag_ids.iter().filter_map(|id| image_tag_ids.get(id).cloned()).collect();
+            images.push(image);
+        }
+        snapshot.images = images;
+
+        // Albums keep the images that were restored, in their order
+        for album in &mut snapshot.albums {
+            album.id = remap(&album_ids, &album.id);
+            album.image_ids = album.image_ids.iter().filter_map(|id| image_ids.get(id).cloned()).collect();
+            album.cover_image_id = album.cover_image_id.as_ref().and_then(|id| image_ids.get(id).cloned());
+        }
+
+        if let Some(avatar_url) = snapshot.profile.avatar_url.as_mut() {
+            if let Some(new_url) = image_urls.get(avatar_url.as_str()) {
+                *avatar_url = new_url.clone();
+            }
+        }
+
+        for announce in &mut snapshot.announces {
+            if let Some(message) = announce.message.as_mut() {
+                *message = rewrite_image_links(message, &image_urls);
+            }
+            if let Some(link_url) = announce.link_url.as_mut() {
+                if let Some(new_url) = image_urls.get(link_url.as_str()) {
+                    *link_url = new_url.clone();
+                }
+            }
+        }
+
+        for performance in &mut snapshot.performances {
+            let content_path = ProfileSnapshot::content_path(&performance.id);
+            performance.id = remap(&performance_ids, &performance.id);
+            performance.category_id = remap(&category_ids, &performance.category_id);
+            performance.tag_ids = performance.tag_ids.iter().filter_map(|id| tag_ids.get(id).cloned()).collect();
+
+            performance.content_url = match files.get(&content_path) {
+                Some(data) => {
+                    let content = rewrite_image_links(&String::from_utf8_lossy(data), &image_urls);
+                    let url = self.content_repository
+                        .upload_content(profile_id, &performance.id, &content)
+                        .await
+                        .map_app_err("Failed to upload performance content")?;
+                    uploaded.contents.push(performance.id.clone());
+                    Some(url)
+                }
+                None => None,
+            };
+        }
+
+        snapshot.image_usage = std::mem::take(&mut snapshot.image_usage)
+            .into_iter()
+            .filter_map(|mut usage| {
+                usage.image_id = image_ids.get(&usage.image_id)?.clone();
+                // Social and skill rows are shared and keep their logo, their usage is synced again once restored
+                usage.owner_id = match ImageUsageOwner::parse(&usage.owner_type)? {
+                    ImageUsageOwner::Performance => performance_ids.get(&usage.owner_id)?.clone(),
+                    ImageUsageOwner::Announce => announce_ids.get(&usage.owner_id)?.clone(),
+                    ImageUsageOwner::Avatar => profile_id.to_string(),
+                    ImageUsageOwner::Social | ImageUsageOwner::Skill => return None,
+                };
+                Some(usage)
+            })
+            .collect();
+
+        self.repository
+            .restore_snapshot(&input.user_id, &snapshot)
+            .await
+            .map_app_err("Failed to restore profile")?;
+
+        Ok(snapshot)
+    }
+}
+
+#[async_trait]
+impl<A, C, S, I> UseCase for RestoreProfileArchiveService<A, C, S, I>
+where
+    A: ProfileArchiveRepository + Send + Sync,
+    C: PerformanceContentRepository + Send + Sync,
+    S: ImageStorageRepository + Send + Sync,
+    I: ImageRepository + Send + Sync,
+{
+    type Input = RestoreProfileArchiveInput;
+    type Output = RestoreProfileArchiveResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let (manifest, files) = Self::open_archive(&input.archive)?;
+        let snapshot: ProfileSnapshot = serde_json::from_slice(&files[SNAPSHOT_PATH])
+            .map_err(|e| invalid_archive(format!("Invalid {}: {}", SNAPSHOT_PATH, e)))?;
+
+        let target = self.repository
+            .find_target(&input.profile_id)
+            .await
+            .map_app_err("Failed to check profile")?;
+        if target == ProfileArchiveTarget::HasData {
+            return Err(ApplicationError::Conflict {
+                message: "Profile already has data; archives can only be restored into an empty or new profile".to_string(),
+            });
+        }
+
+        let mut uploaded = UploadedObjects::default();
+        let mut rejected = Vec::new();
+        let snapshot = match self.restore(&input, snapshot, &files, &mut uploaded, &mut rejected).await {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                for filename in &uploaded.images {
+                    let _ = self.storage_repository.delete_image(&input.profile_id, filename).await;
+                }
+                for performance_id in &uploaded.contents {
+                    let _ = self.content_repository.delete_content(&input.profile_id, performance_id).await;
+                }
+                return Err(e);
+            }
+        };
+
+        // The rows are written by now; a failed sync is repeated by the next read of the images
+        if let Err(e) = sync_owner_usage(&self.image_repository, &input.profile_id).await {
+            tracing::warn!("Failed to sync image usage of restored profile {}: {:?}", input.profile_id, e);
+        }
+
+        Ok(RestoreProfileArchiveResult {
+            profile_id: input.profile_id,
+            skills: snapshot.skills.len(),
+            socials: snapshot.socials.len(),
+            announces: snapshot.announces.len(),
+            categories: snapshot.categories.len(),
+            tags: snapshot.tags.len(),
+            performances: snapshot.performances.len(),
+            images: snapshot.images.len(),
+            image_tags: snapshot.image_tags.len(),
+            albums: snapshot.albums.len(),
+            missing: manifest.missing,
+            rejected_images: rejected,
+        })
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::image::dto::input::MergeDuplicateImagesInput;
+use crate::application::services::profile::image::result::{DuplicateImageGroup, DuplicateImagesResult};
+use crate::application::services::profile::image::service::{delete_transform_objects, sync_owner_usage};
+use crate::domain::entities::profile::image::image::Image;
+use crate::domain::entities::profile::image::image_usage::{ImageUsageInfo, ImageUsageOwner};
+use crate::interface_adapters::gateways::repositories::profile::image::image_repository::ImageRepository;
+use crate::interface_adapters::gateways::repositories::profile::image::image_storage_repository::ImageStorageRepository;
+use crate::interface_adapters::gateways::repositories::profile::image::image_transform_cache_repository::ImageTransformCacheRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance_content::performance_content_repository::PerformanceContentRepository;
+use crate::shared::utils::content_analysis::own_image_id;
+use crate::shared::utils::hash::sha256_hex;
+use crate::shared::utils::markdown_archive::{image_links, rewrite_image_links};
+
+/// Finds images of a profile with identical content and merges each group into its oldest image:
+/// markdown links and usage move over, the duplicates are deleted. A duplicate an avatar, logo or
+/// announce points at is left alone, this service cannot rewrite those rows.
+pub struct MergeDuplicateImagesService<R, S, T, C>
+where
+    R: ImageRepository,
+    S: ImageStorageRepository,
+    T: ImageTransformCacheRepository,
+    C: PerformanceContentRepository,
+{
+    repository: R,
+    storage_repository: S,
+    transform_cache: T,
+    content_repository: C,
+}
+
+impl<R, S, T, C> MergeDuplicateImagesService<R, S, T, C>
+where
+    R: ImageRepository,
+    S: ImageStorageRepository,
+    T: ImageTransformCacheRepository,
+    C: PerformanceContentRepository,
+{
+    pub fn new(repository: R, storage_repository: S, transform_cache: T, content_repository: C) -> Self {
+        Self { repository, storage_repository, transform_cache, content_repository }
+    }
+
+    /// Hashes images uploaded before hashing existed. An original that can't be downloaded is
+    /// skipped, it simply takes no part in this run.
+    async fn backfill_hashes(&self, images: &mut [Image]) -> Result<i32, ApplicationError> {
+        let mut backfilled = 0;
+        for image in images.iter_mut().filter(|image| image.content_hash.is_none()) {
+            let bytes = match self.storage_repository.download_image(&image.profile_id, &image.filename).await {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    tracing::warn!("Failed to download image {} for hashing: {:?}", image.id, e);
+                    continue;
+                }
+            };
+            let hash = sha256_hex(&bytes);
+            self.repository
+                .set_content_hash(&image.id, &hash)
+                .await
+                .map_app_err("Failed to store image content hash")?;
+            image.content_hash = Some(hash);
+            backfilled += 1;
+        }
+        Ok(backfilled)
+    }
+
+    /// Points every markdown image link at `duplicate` to `kept` instead. Returns the performances changed.
+    async fn rewrite_performances(
+        &self,
+        profile_id: &str,
+        duplicate: &Image,
+        kept: &Image,
+        usage: Vec<ImageUsageInfo>,
+    ) -> Result<Vec<String>, ApplicationError> {
+        let mut rewritten = Vec::new();
+        for performance in usage {
+            let content = self.content_repository
+                .get_content(profile_id, &performance.owner_id)
+                .await
+                .map_app_err("Failed to fetch performance content")?;
+
+            let replacements: HashMap<String, String> = image_links(&content)
+                .into_iter()
+                .filter(|link| own_image_id(link).as_deref() == Some(duplicate.id.as_str()))
+                .map(|link| (link, kept.storage_url.clone()))
+                .collect();
+            if replacements.is_empty() {
+                continue;
+            }
+
+            self.content_repository
+                .update_content(profile_id, &performance.owner_id, &rewrite_image_links(&content, &replacements))
+                .await
+                .map_app_err("Failed to update performance content")?;
+            rewritten.push(performance.owner_id);
+        }
+        Ok(rewritten)
+    }
+}
+
+#[async_trait]
+impl<R, S, T, C> UseCase for MergeDuplicateImagesService<R, S, T, C>
+where
+    R: ImageRepository + Send + Sync,
+    S: ImageStorageRepository + Send + Sync,
+    T: ImageTransformCacheRepository + Send + Sync,
+    C: PerformanceContentRepository + Send + Sync,
+{
+    type Input = MergeDuplicateImagesInput;
+    type Output = DuplicateImagesResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let mut images = self.repository
+            .find_by_profile_id(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch images")?;
+
+        // Backfilled hashes are kept even on a dry run, they are facts about the stored files
+        let hashes_backfilled = self.backfill_hashes(&mut images).await?;
+
+        // Oldest first, so the first image of every group is the one kept
+        let mut by_hash: Vec<(String, Vec<Image>)> = Vec::new();
+        for image in images {
+            let Some(hash) = image.content_hash.clone() else {
+                continue;
+            };
+            match by_hash.iter_mut().find(|(h, _)| *h == hash) {
+                Some((_, group)) => group.push(image),
+                None => by_hash.push((hash, vec![image])),
+            }
+        }
+
+        if !input.dry_run {
+            sync_owner_usage(&self.repository, &input.profile_id).await?;
+        }
+
+        let mut groups = Vec::new();
+        let mut merged_count = 0;
+        for (content_hash, mut group) in by_hash.into_iter().filter(|(_, group)| group.len() > 1) {
+            let kept = group.remove(0);
+            let mut performances_rewritten: Vec<String> = Vec::new();
+            let mut skipped_ids: Vec<String> = Vec::new();
+
+            if !input.dry_run {
+                for duplicate in &group {
+                    let usage = self.repository
+                        .find_usage_by_image_id(&duplicate.id, &input.profile_id)
+                        .await
+                        .map_app_err("Failed to fetch image usage")?;
+                    if usage.iter().any(|u| u.owner_type != ImageUsageOwner::Performance) {
+                        skipped_ids.push(duplicate.id.clone());
+                        continue;
+                    }
+
+                    for performance_id in self.rewrite_performances(&input.profile_id, duplicate, &kept, usage).await? {
+                        if !performances_rewritten.contains(&performance_id) {
+                            performances_rewritten.push(performance_id);
+                        }
+                    }
+
+                    // Variant rows go with the image row, so their files are looked up first
+                    let variants = self.repository
+                        .find_variants_by_image_ids(std::slice::from_ref(&duplicate.id))
+                        .await
+                        .map_app_err("Failed to fetch image variants")?;
+
+                    self.repository
+                        .merge_duplicate(&input.profile_id, &kept.id, &duplicate.id)
+                        .await
+                        .map_app_err("Failed to merge duplicate image")?;
+
+                    // The row is gone, leftover objects would only be orphans
+                    for variant in variants {
+                        let _ = self.storage_repository.delete_image(&input.profile_id, &variant.filename).await;
+                    }
+                    delete_transform_objects(&self.transform_cache, &input.profile_id, std::slice::from_ref(&duplicate.id)).await;
+                    if let Err(e) = self.storage_repository.delete_image(&input.profile_id, &duplicate.filename).await {
+                        tracing::warn!("Failed to delete duplicate image {} from storage: {:?}", duplicate.id, e);
+                    }
+                    merged_count += 1;
+                }
+            }
+
+            groups.push(DuplicateImageGroup {
+                content_hash,
+                kept_id: kept.id,
+                duplicate_ids: group.iter().map(|image| image.id.clone()).collect(),
+                performances_rewritten,
+                skipped_ids,
+                duplicate_bytes: group.iter().map(|image| image.file_size as i64).sum(),
+            });
+        }
+
+        Ok(DuplicateImagesResult {
+            dry_run: input.dry_run,
+            hashes_backfilled,
+            groups,
+            merged_count,
+        })
+    }
+}
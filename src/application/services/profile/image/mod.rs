@@ -1,2 +1,7 @@
 pub mod service;
+pub mod transform_service;
+pub mod dedupe_service;
 pub mod result;
+pub mod tag_service;
+pub mod placeholder_service;
+pub mod upload_service;
@@ -0,0 +1,105 @@
+use async_trait::async_trait;
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::image::dto::input::BackfillImagePlaceholdersInput;
+use crate::application::services::profile::image::result::ImagePlaceholderBackfillResult;
+use crate::domain::entities::profile::image::image::Image;
+use crate::interface_adapters::gateways::repositories::profile::image::image_repository::ImageRepository;
+use crate::interface_adapters::gateways::repositories::profile::image::image_storage_repository::ImageStorageRepository;
+use crate::shared::utils::image_placeholder::{compute_placeholder, ImagePlaceholder};
+use crate::shared::utils::image_transform::decode_bounded;
+
+/// Why a placeholder could not be computed. Only a download is worth retrying on a later run.
+enum PlaceholderFailure {
+    Download(String),
+    Decode(String),
+}
+
+/// Computes BlurHash, dominant color and LQIP for images uploaded before placeholders existed.
+/// Runs in batches so a large library doesn't hold one request for minutes; call again until `remaining` is 0.
+/// Originals that can't be decoded are marked and left out of later runs.
+pub struct BackfillImagePlaceholdersService<R, S>
+where
+    R: ImageRepository,
+    S: ImageStorageRepository,
+{
+    repository: R,
+    storage_repository: S,
+}
+
+impl<R, S> BackfillImagePlaceholdersService<R, S>
+where
+    R: ImageRepository,
+    S: ImageStorageRepository,
+{
+    pub fn new(repository: R, storage_repository: S) -> Self {
+        Self { repository, storage_repository }
+    }
+
+    async fn placeholder_for(&self, image: &Image) -> Result<ImagePlaceholder, PlaceholderFailure> {
+        let bytes = self.storage_repository
+            .download_image(&image.profile_id, &image.filename)
+            .await
+            .map_err(|e| PlaceholderFailure::Download(format!("download failed: {:?}", e)))?;
+
+        tokio::task::spawn_blocking(move || compute_placeholder(&decode_bounded(&bytes)?))
+            .await
+            .map_err(|e| PlaceholderFailure::Download(format!("placeholder task failed: {}", e)))?
+            .map_err(PlaceholderFailure::Decode)
+    }
+}
+
+#[async_trait]
+impl<R, S> UseCase for BackfillImagePlaceholdersService<R, S>
+where
+    R: ImageRepository + Send + Sync,
+    S: ImageStorageRepository + Send + Sync,
+{
+    type Input = BackfillImagePlaceholdersInput;
+    type Output = ImagePlaceholderBackfillResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let missing = self.repository
+            .find_missing_placeholders(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch images")?;
+
+        let mut processed = 0;
+        let mut undecodable = 0;
+        let mut failed_ids = Vec::new();
+        // Every attempt counts against the limit, so a run stays bounded however many originals are broken
+        for image in missing.iter().take(input.get_limit()) {
+            match self.placeholder_for(image).await {
+                Ok(placeholder) => {
+                    self.repository
+                        .set_placeholder(&image.id, &placeholder.blurhash, &placeholder.dominant_color, &placeholder.lqip)
+                        .await
+                        .map_app_err("Failed to store image placeholder")?;
+                    processed += 1;
+                }
+                Err(PlaceholderFailure::Decode(e)) => {
+                    tracing::warn!("Image {} can't be decoded, no placeholder: {}", image.id, e);
+                    self.repository
+                        .mark_placeholder_failed(&image.id)
+                        .await
+                        .map_app_err("Failed to mark image placeholder as failed")?;
+                    undecodable += 1;
+                    failed_ids.push(image.id.clone());
+                }
+                Err(PlaceholderFailure::Download(e)) => {
+                    tracing::warn!("Failed to compute placeholder for image {}: {}", image.id, e);
+                    failed_ids.push(image.id.clone());
+                }
+            }
+        }
+
+        Ok(ImagePlaceholderBackfillResult {
+            processed,
+            remaining: missing.len() as i32 - processed - undecodable,
+            failed_ids,
+        })
+    }
+}
@@ -1,5 +1,9 @@
 use serde::Serialize;
+use axum::http::header;
 use axum::response::{IntoResponse, Response};
+use crate::domain::entities::profile::image::image_tag::{ImageTag, ImageTagCount};
+use crate::domain::entities::profile::image::image_upload_session::ImageUploadSession;
+use crate::domain::entities::profile::image::image_usage::ImageUsageInfo;
 use crate::interface_adapters::http::v1::presenters::common::api_response::ApiResponse;
 
 #[derive(Debug, Clone, Serialize)]
@@ -15,8 +19,36 @@ pub struct ImageResult {
     pub alt_text: Option<String>,
     pub caption: Option<String>,
     pub created_at: String,
+    /// EXIF capture date and camera, present only when the profile keeps capture metadata
+    pub captured_at: Option<String>,
+    pub camera_model: Option<String>,
+    /// Placeholders to show while the image loads; `None` until backfilled for old images
+    pub blurhash: Option<String>,
+    pub dominant_color: Option<String>,
+    pub lqip: Option<String>,
     pub usage_count: Option<i32>,
-    pub performances: Vec<PerformanceUsageInfo>,
+    pub owners: Vec<ImageOwnerUsageInfo>,
+    pub tags: Vec<ImageTag>,
+    /// Resized copies, narrowest first
+    pub variants: Vec<ImageVariantResult>,
+    /// One `srcset` per format, WebP first; the original is the widest candidate of its own format
+    pub srcset: Vec<ImageSrcset>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageVariantResult {
+    pub url: String,
+    pub width: i32,
+    pub height: i32,
+    pub mime_type: String,
+    pub file_size: i32,
+}
+
+/// Value for `<source type="{mime_type}" srcset="{srcset}">`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageSrcset {
+    pub mime_type: String,
+    pub srcset: String,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -31,18 +63,33 @@ pub struct ImageListResult {
 pub struct ImageUsageResult {
     pub image_id: String,
     pub total_usage: i32,
-    pub performances: Vec<PerformanceUsageInfo>,
+    pub owners: Vec<ImageOwnerUsageInfo>,
 }
 
+/// One performance, avatar, social, skill or announce using an image.
 #[derive(Debug, Clone, Serialize)]
-pub struct PerformanceUsageInfo {
-    pub performance_id: String,
+pub struct ImageOwnerUsageInfo {
+    pub owner_type: String,
+    pub owner_id: String,
     pub title: String,
     pub usage_count: i32,
     pub first_used_at: String,
     pub last_used_at: String,
 }
 
+impl From<ImageUsageInfo> for ImageOwnerUsageInfo {
+    fn from(usage: ImageUsageInfo) -> Self {
+        Self {
+            owner_type: usage.owner_type.as_str().to_string(),
+            owner_id: usage.owner_id,
+            title: usage.title,
+            usage_count: usage.usage_count,
+            first_used_at: usage.first_used_at,
+            last_used_at: usage.last_used_at,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct UnusedImagesResult {
     pub unused_images: Vec<ImageResult>,
@@ -121,3 +168,190 @@ impl IntoResponse for ImageUsageResult {
             .into_response()
     }
 }
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageSettingsResult {
+    pub keep_capture_metadata: bool,
+    /// `None` while the profile still uses the defaults
+    pub updated_at: Option<String>,
+}
+
+impl IntoResponse for ImageSettingsResult {
+    fn into_response(self) -> Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+/// Output of `/images/{id}/transform`. Originals never change under an id, so neither does a transform.
+#[derive(Debug, Clone)]
+pub struct TransformedImageResult {
+    pub bytes: Vec<u8>,
+    pub content_type: &'static str,
+    pub etag: String,
+    pub not_modified: bool,
+}
+
+impl IntoResponse for TransformedImageResult {
+    fn into_response(self) -> Response {
+        let headers = [
+            (header::ETAG, self.etag),
+            (header::CACHE_CONTROL, "public, max-age=31536000, immutable".to_string()),
+        ];
+        if self.not_modified {
+            (axum::http::StatusCode::NOT_MODIFIED, headers).into_response()
+        } else {
+            (
+                axum::http::StatusCode::OK,
+                headers,
+                [(header::CONTENT_TYPE, self.content_type)],
+                self.bytes,
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Images sharing one content hash. The oldest is kept, the others are merged into it.
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateImageGroup {
+    pub content_hash: String,
+    pub kept_id: String,
+    pub duplicate_ids: Vec<String>,
+    /// Performances whose markdown pointed at a duplicate and was rewritten to the kept image
+    pub performances_rewritten: Vec<String>,
+    /// Duplicates kept because an avatar, logo or announce points at them
+    pub skipped_ids: Vec<String>,
+    /// Storage the duplicates' originals take up
+    pub duplicate_bytes: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateImagesResult {
+    pub dry_run: bool,
+    /// Older images that were hashed during this run
+    pub hashes_backfilled: i32,
+    pub groups: Vec<DuplicateImageGroup>,
+    /// Duplicates merged and deleted; always 0 on a dry run
+    pub merged_count: i32,
+}
+
+impl IntoResponse for DuplicateImagesResult {
+    fn into_response(self) -> Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageTagListResult {
+    pub tags: Vec<ImageTagCount>,
+}
+
+impl IntoResponse for ImageTagListResult {
+    fn into_response(self) -> Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageTagResult {
+    pub tag: ImageTag,
+}
+
+impl IntoResponse for ImageTagResult {
+    fn into_response(self) -> Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageTagsResult {
+    pub image_id: String,
+    pub tags: Vec<ImageTag>,
+}
+
+impl IntoResponse for ImageTagsResult {
+    fn into_response(self) -> Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImagePlaceholderBackfillResult {
+    pub processed: i32,
+    /// Images whose original could not be downloaded or decoded in this run.
+    /// Undecodable ones are not tried again, failed downloads are.
+    pub failed_ids: Vec<String>,
+    /// Images still to try, failed downloads included
+    pub remaining: i32,
+}
+
+impl IntoResponse for ImagePlaceholderBackfillResult {
+    fn into_response(self) -> Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageUploadSessionResult {
+    pub id: String,
+    pub original_filename: String,
+    pub total_size: i64,
+    /// Bytes received so far, the offset the next chunk starts at
+    pub offset: i64,
+    pub complete: bool,
+    pub expires_at: String,
+}
+
+impl From<ImageUploadSession> for ImageUploadSessionResult {
+    fn from(session: ImageUploadSession) -> Self {
+        Self {
+            complete: session.received_bytes >= session.total_size,
+            id: session.id,
+            original_filename: session.original_filename,
+            total_size: session.total_size,
+            offset: session.received_bytes,
+            expires_at: session.expires_at,
+        }
+    }
+}
+
+impl IntoResponse for ImageUploadSessionResult {
+    fn into_response(self) -> Response {
+        let offset = self.offset.to_string();
+        (
+            axum::http::StatusCode::OK,
+            [("Upload-Offset", offset)],
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageUploadPurgeResult {
+    pub deleted: u64,
+}
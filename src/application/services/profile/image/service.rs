@@ -1,17 +1,212 @@
+use std::collections::HashMap;
+use std::sync::Arc;
 use async_trait::async_trait;
+use image::{DynamicImage, ImageFormat};
+use tokio::sync::Semaphore;
 use crate::application::errors::{ApplicationError, MapToApplicationError};
 use crate::application::use_cases::use_case::UseCase;
 use crate::application::use_cases::profile::image::dto::input::{
     GetImagesInput, GetImageInput, CreateImageInput, UpdateImageMetadataInput,
-    DeleteImageInput, ForceDeleteImageInput, GetUnusedImagesInput, DeleteUnusedImagesInput, TrackImageUsageInput
+    DeleteImageInput, ForceDeleteImageInput, GetUnusedImagesInput, DeleteUnusedImagesInput, TrackImageUsageInput,
+    GetImageSettingsInput, UpdateImageSettingsInput, DuplicateImagePolicy
 };
 use crate::interface_adapters::gateways::repositories::profile::image::image_repository::ImageRepository;
 use crate::interface_adapters::gateways::repositories::profile::image::image_storage_repository::ImageStorageRepository;
+use crate::interface_adapters::gateways::repositories::profile::image::image_transform_cache_repository::ImageTransformCacheRepository;
 use crate::application::services::profile::image::result::{
-    ImageListResult, ImageResult, ImageUsageResult, PerformanceUsageInfo,
-    UnusedImagesResult, DeleteUnusedImagesResult, MessageResult
+    ImageListResult, ImageResult, ImageUsageResult, ImageOwnerUsageInfo,
+    UnusedImagesResult, DeleteUnusedImagesResult, MessageResult, ImageVariantResult, ImageSrcset,
+    ImageSettingsResult
 };
 use crate::domain::entities::profile::image::image::Image;
+use crate::domain::entities::profile::image::image_settings::ImageSettings;
+use crate::domain::entities::profile::image::image_usage::{ImageOwnerReference, ImageUsageOwner};
+use crate::domain::entities::profile::image::image_variant::ImageVariant;
+use crate::shared::utils::content_analysis::{analyze_markdown, own_image_id};
+use crate::shared::utils::hash::sha256_hex;
+use crate::shared::utils::image_metadata::{sanitize_upload, CaptureMetadata};
+use crate::shared::utils::image_placeholder::{compute_placeholder, ImagePlaceholder};
+use crate::shared::utils::image_upload::{extension_for, sanitize_filename, validate_upload, UploadLimits};
+use crate::shared::utils::image_variants::{
+    encode_variant, srcset, variant_filename, variant_formats, variant_widths, EncodedVariant
+};
+
+/// Variants of the given images keyed by image id.
+pub(crate) async fn find_variants<R>(
+    repository: &R,
+    image_ids: &[String],
+) -> Result<HashMap<String, Vec<ImageVariant>>, ApplicationError>
+where
+    R: ImageRepository,
+{
+    let variants = repository
+        .find_variants_by_image_ids(image_ids)
+        .await
+        .map_app_err("Failed to fetch image variants")?;
+
+    let mut by_image: HashMap<String, Vec<ImageVariant>> = HashMap::new();
+    for variant in variants {
+        by_image.entry(variant.image_id.clone()).or_default().push(variant);
+    }
+    Ok(by_image)
+}
+
+/// Brings the usage rows of avatars, logos and announces in line with the profile as it is now.
+/// Those rows are edited outside this service, so anything reporting owners or deciding whether an image
+/// is still in use runs this first.
+pub(crate) async fn sync_owner_usage<R>(repository: &R, profile_id: &str) -> Result<(), ApplicationError>
+where
+    R: ImageRepository,
+{
+    let sources = repository
+        .find_owner_sources(profile_id)
+        .await
+        .map_app_err("Failed to fetch image owners")?;
+
+    let mut counts: HashMap<(ImageUsageOwner, String, String), i32> = HashMap::new();
+    for source in sources {
+        let mut image_ids: Vec<String> = source.url.as_deref().and_then(own_image_id).into_iter().collect();
+        if let Some(markdown) = &source.markdown {
+            image_ids.extend(analyze_markdown(markdown).image_ids);
+        }
+        for image_id in image_ids {
+            *counts.entry((source.owner_type, source.owner_id.clone(), image_id)).or_insert(0) += 1;
+        }
+    }
+
+    let references: Vec<ImageOwnerReference> = counts
+        .into_iter()
+        .map(|((owner_type, owner_id, image_id), usage_count)| ImageOwnerReference { image_id, owner_type, owner_id, usage_count })
+        .collect();
+    repository
+        .sync_owner_usage(profile_id, &references)
+        .await
+        .map_app_err("Failed to sync image usage")
+}
+
+/// Removes the variant objects of the given images from storage. Best effort: a variant that is
+/// already gone must not keep its image from being deleted.
+pub(crate) async fn delete_variant_objects<R, S>(repository: &R, storage_repository: &S, profile_id: &str, image_ids: &[String])
+where
+    R: ImageRepository,
+    S: ImageStorageRepository,
+{
+    let Ok(variants) = repository.find_variants_by_image_ids(image_ids).await else {
+        return;
+    };
+    for variant in variants {
+        let _ = storage_repository.delete_image(profile_id, &variant.filename).await;
+    }
+}
+
+/// Full `ImageResult` of one image, with usage and responsive sources.
+pub(crate) async fn load_image_result<R>(repository: &R, id: &str, profile_id: &str) -> Result<ImageResult, ApplicationError>
+where
+    R: ImageRepository,
+{
+    sync_owner_usage(repository, profile_id).await?;
+
+    let (img, usage, owners) = repository
+        .find_by_id_and_profile_id(id, profile_id)
+        .await
+        .map_app_err("Failed to fetch image")?
+        .ok_or_else(|| ApplicationError::NotFound {
+            resource: "Image",
+            identifier: id.to_string(),
+        })?;
+
+    let mut variants = find_variants(repository, std::slice::from_ref(&img.id)).await?;
+    let tags = repository
+        .find_tags_by_image_ids(std::slice::from_ref(&img.id))
+        .await
+        .map_app_err("Failed to fetch image tags")?
+        .remove(&img.id)
+        .unwrap_or_default();
+    let (variants, srcset) = image_sources(
+        &img.storage_url,
+        img.width,
+        &img.mime_type,
+        variants.remove(&img.id).unwrap_or_default(),
+    );
+
+    Ok(ImageResult {
+        id: img.id,
+        storage_url: img.storage_url,
+        filename: img.filename,
+        original_filename: img.original_filename,
+        width: img.width,
+        height: img.height,
+        file_size: img.file_size,
+        mime_type: img.mime_type,
+        alt_text: img.alt_text,
+        caption: img.caption,
+        created_at: img.created_at,
+        captured_at: img.captured_at,
+        camera_model: img.camera_model,
+        blurhash: img.blurhash,
+        dominant_color: img.dominant_color,
+        lqip: img.lqip,
+        usage_count: Some(usage),
+        owners: owners.into_iter().map(Into::into).collect(),
+        tags,
+        variants,
+        srcset,
+    })
+}
+
+/// Drops the cached `/transform` results of the given images, best effort like the variants.
+pub(crate) async fn delete_transform_objects<T>(transform_cache: &T, profile_id: &str, image_ids: &[String])
+where
+    T: ImageTransformCacheRepository,
+{
+    for image_id in image_ids {
+        if let Err(e) = transform_cache.delete_transforms(profile_id, image_id).await {
+            tracing::warn!("Failed to delete cached transforms of image {}: {:?}", image_id, e);
+        }
+    }
+}
+
+/// Variant list and per-format srcset of an image. The original joins the srcset of its own
+/// format as the widest candidate.
+pub(crate) fn image_sources(
+    storage_url: &str,
+    width: Option<i32>,
+    mime_type: &str,
+    mut variants: Vec<ImageVariant>,
+) -> (Vec<ImageVariantResult>, Vec<ImageSrcset>) {
+    variants.sort_by(|a, b| a.width.cmp(&b.width).then_with(|| a.mime_type.cmp(&b.mime_type)));
+
+    let mut mime_types: Vec<&str> = variants.iter().map(|v| v.mime_type.as_str()).collect();
+    mime_types.sort_by_key(|m| (*m != "image/webp", *m));
+    mime_types.dedup();
+
+    let srcsets = mime_types
+        .into_iter()
+        .map(|mime| {
+            let mut candidates: Vec<(&str, i32)> = variants.iter()
+                .filter(|v| v.mime_type == mime)
+                .map(|v| (v.storage_url.as_str(), v.width))
+                .collect();
+            if let Some(width) = width.filter(|_| mime == mime_type) {
+                candidates.push((storage_url, width));
+            }
+            ImageSrcset { mime_type: mime.to_string(), srcset: srcset(candidates) }
+        })
+        .collect();
+
+    let results = variants
+        .into_iter()
+        .map(|v| ImageVariantResult {
+            url: v.storage_url,
+            width: v.width,
+            height: v.height,
+            mime_type: v.mime_type,
+            file_size: v.file_size,
+        })
+        .collect();
+
+    (results, srcsets)
+}
 
 pub struct GetImagesService<R>
 where
@@ -44,34 +239,54 @@ where
         let limit = input.limit.unwrap_or(20);
         let offset = input.offset.unwrap_or(0);
 
+        sync_owner_usage(&self.repository, &input.profile_id).await?;
+
         let (images_data, total) = self
             .repository
-            .find_all_by_profile_id(&input.profile_id, input.search, limit, offset)
+            .find_all_by_profile_id(&input.profile_id, input.search, &input.tags, limit, offset)
             .await
             .map_app_err("Failed to fetch images")?;
 
+        let ids: Vec<String> = images_data.iter().map(|(img, _, _)| img.id.clone()).collect();
+        let mut variants = find_variants(&self.repository, &ids).await?;
+        let mut tags = self.repository
+            .find_tags_by_image_ids(&ids)
+            .await
+            .map_app_err("Failed to fetch image tags")?;
+
         let images = images_data
             .into_iter()
-            .map(|(img, usage, perfs)| ImageResult {
-                id: img.id,
-                storage_url: img.storage_url,
-                filename: img.filename,
-                original_filename: img.original_filename,
-                width: img.width,
-                height: img.height,
-                file_size: img.file_size,
-                mime_type: img.mime_type,
-                alt_text: img.alt_text,
-                caption: img.caption,
-                created_at: img.created_at,
-                usage_count: Some(usage),
-                performances: perfs.into_iter().map(|p| PerformanceUsageInfo {
-                    performance_id: p.performance_id,
-                    title: p.title,
-                    usage_count: p.usage_count,
-                    first_used_at: p.first_used_at,
-                    last_used_at: p.last_used_at,
-                }).collect(),
+            .map(|(img, usage, owners)| {
+                let (variants, srcset) = image_sources(
+                    &img.storage_url,
+                    img.width,
+                    &img.mime_type,
+                    variants.remove(&img.id).unwrap_or_default(),
+                );
+                let tags = tags.remove(&img.id).unwrap_or_default();
+                ImageResult {
+                    id: img.id,
+                    storage_url: img.storage_url,
+                    filename: img.filename,
+                    original_filename: img.original_filename,
+                    width: img.width,
+                    height: img.height,
+                    file_size: img.file_size,
+                    mime_type: img.mime_type,
+                    alt_text: img.alt_text,
+                    caption: img.caption,
+                    created_at: img.created_at,
+                    captured_at: img.captured_at,
+                    camera_model: img.camera_model,
+                    blurhash: img.blurhash,
+                    dominant_color: img.dominant_color,
+                    lqip: img.lqip,
+                    usage_count: Some(usage),
+                    owners: owners.into_iter().map(Into::into).collect(),
+                    tags,
+                    variants,
+                    srcset,
+                }
             })
             .collect();
 
@@ -112,37 +327,7 @@ where
     async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
         input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
 
-        let (img, usage, perfs) = self
-            .repository
-            .find_by_id_and_profile_id(&input.id, &input.profile_id)
-            .await
-            .map_app_err("Failed to fetch image")?
-            .ok_or_else(|| ApplicationError::NotFound {
-                resource: "Image",
-                identifier: input.id,
-            })?;
-
-        Ok(ImageResult {
-            id: img.id,
-            storage_url: img.storage_url,
-            filename: img.filename,
-            original_filename: img.original_filename,
-            width: img.width,
-            height: img.height,
-            file_size: img.file_size,
-            mime_type: img.mime_type,
-            alt_text: img.alt_text,
-            caption: img.caption,
-            created_at: img.created_at,
-            usage_count: Some(usage),
-            performances: perfs.into_iter().map(|p| PerformanceUsageInfo {
-                performance_id: p.performance_id,
-                title: p.title,
-                usage_count: p.usage_count,
-                first_used_at: p.first_used_at,
-                last_used_at: p.last_used_at,
-            }).collect(),
-        })
+        load_image_result(&self.repository, &input.id, &input.profile_id).await
     }
 }
 
@@ -174,35 +359,80 @@ where
     async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
         input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
 
+        sync_owner_usage(&self.repository, &input.profile_id).await?;
+
         let usages = self
             .repository
             .find_usage_by_image_id(&input.id, &input.profile_id)
             .await
             .map_app_err("Failed to fetch image usage")?;
 
-        let mut total_usage = 0;
-        let performances = usages
-            .into_iter()
-            .map(|u| {
-                total_usage += u.usage_count;
-                PerformanceUsageInfo {
-                    performance_id: u.performance_id,
-                    title: u.title,
-                    usage_count: u.usage_count,
-                    first_used_at: u.first_used_at,
-                    last_used_at: u.last_used_at,
-                }
-            })
-            .collect();
+        let total_usage = usages.iter().map(|u| u.usage_count).sum();
+        let owners: Vec<ImageOwnerUsageInfo> = usages.into_iter().map(Into::into).collect();
 
         Ok(ImageUsageResult {
             image_id: input.id,
             total_usage,
-            performances,
+            owners,
         })
     }
 }
 
+/// An upload after validation, orientation and metadata stripping.
+struct PreparedUpload {
+    format: ImageFormat,
+    image: DynamicImage,
+    bytes: Vec<u8>,
+    capture: CaptureMetadata,
+    content_hash: String,
+}
+
+fn prepare_upload(bytes: Vec<u8>, limits: &UploadLimits) -> Result<PreparedUpload, String> {
+    // The content decides the format; filename and content type are the client's word only
+    let validated = validate_upload(&bytes, limits)?;
+
+    // Turn upright and drop EXIF/XMP before anything is stored
+    let upload = sanitize_upload(&bytes)?;
+    let bytes = upload.bytes.unwrap_or(bytes);
+    // Re-encoding can grow a file past the limit it was checked against, a turned WebP most of all
+    if bytes.len() > limits.max_bytes {
+        return Err(format!(
+            "Image cannot be larger than {} bytes once its metadata is removed and it is turned upright",
+            limits.max_bytes
+        ));
+    }
+    let content_hash = sha256_hex(&bytes);
+
+    Ok(PreparedUpload {
+        format: validated.format,
+        image: upload.image,
+        bytes,
+        capture: upload.capture,
+        content_hash,
+    })
+}
+
+/// Resized copies for every width and format, plus the placeholder, which is `None` when it
+/// can't be computed.
+fn encode_derivatives(
+    image: &DynamicImage,
+    widths: &[u32],
+    mime_type: &str,
+) -> Result<(Vec<EncodedVariant>, Option<ImagePlaceholder>), String> {
+    let mut encoded = Vec::new();
+    for width in variant_widths(image.width(), widths) {
+        for format in variant_formats(mime_type) {
+            encoded.push(encode_variant(image, width, format)?);
+        }
+    }
+
+    let placeholder = compute_placeholder(image)
+        .inspect_err(|e| tracing::warn!("Failed to compute placeholder for upload: {}", e))
+        .ok();
+
+    Ok((encoded, placeholder))
+}
+
 pub struct CreateImageService<R, S>
 where
     R: ImageRepository,
@@ -210,6 +440,10 @@ where
 {
     repository: R,
     storage_repository: S,
+    variant_widths: Vec<u32>,
+    limits: UploadLimits,
+    /// Decoding and encoding run on the blocking pool; this caps how many uploads do so at once
+    permits: Arc<Semaphore>,
 }
 
 impl<R, S> CreateImageService<R, S>
@@ -217,8 +451,61 @@ where
     R: ImageRepository,
     S: ImageStorageRepository,
 {
-    pub fn new(repository: R, storage_repository: S) -> Self {
-        Self { repository, storage_repository }
+    pub fn new(repository: R, storage_repository: S, variant_widths: Vec<u32>, limits: UploadLimits) -> Self {
+        let workers = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(2);
+        Self {
+            repository,
+            storage_repository,
+            variant_widths,
+            limits,
+            permits: Arc::new(Semaphore::new(workers)),
+        }
+    }
+
+    /// Largest upload accepted, for callers that read the file from a stream.
+    pub fn max_upload_bytes(&self) -> usize {
+        self.limits.max_bytes
+    }
+
+    /// Uploads every encoded variant. On failure the variants uploaded so far are removed again.
+    async fn upload_variants(
+        &self,
+        profile_id: &str,
+        image_id: &str,
+        encoded: Vec<EncodedVariant>,
+    ) -> Result<Vec<ImageVariant>, ApplicationError> {
+        let mut variants: Vec<ImageVariant> = Vec::new();
+
+        for encoded in encoded {
+            let filename = variant_filename(image_id, encoded.width, encoded.format);
+            let file_size = encoded.bytes.len() as i32;
+            let uploaded = self.storage_repository
+                .upload_image(profile_id, &filename, encoded.bytes, encoded.format.mime_type())
+                .await
+                .map_app_err("Failed to upload image variant to storage");
+
+            match uploaded {
+                Ok(storage_url) => variants.push(ImageVariant {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    image_id: image_id.to_string(),
+                    filename,
+                    storage_url,
+                    width: encoded.width as i32,
+                    height: encoded.height as i32,
+                    mime_type: encoded.format.mime_type().to_string(),
+                    file_size,
+                    created_at: sqlx::types::chrono::Utc::now().to_rfc3339(),
+                }),
+                Err(e) => {
+                    for variant in &variants {
+                        let _ = self.storage_repository.delete_image(profile_id, &variant.filename).await;
+                    }
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(variants)
     }
 }
 
@@ -232,76 +519,158 @@ where
     type Output = ImageResult;
     type Error = ApplicationError;
 
-    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+    async fn execute(&self, mut input: Self::Input) -> Result<Self::Output, Self::Error> {
         input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
 
-        // Extract metadata from image bytes
-        let (width, height, mime_type) = match image::ImageReader::new(std::io::Cursor::new(&input.image_bytes))
-            .with_guessed_format()
-            .map_err(|e| ApplicationError::ValidationError { message: format!("Failed to read image: {}", e) })?
-            .decode() {
-                Ok(img) => {
-                    use image::GenericImageView;
-                    let (w, h) = img.dimensions();
-                    // Try to get mime type from format
-                    let format = image::guess_format(&input.image_bytes)
-                        .unwrap_or(image::ImageFormat::Jpeg);
-                    let mime = format.to_mime_type().to_string();
-                    (Some(w as i32), Some(h as i32), mime)
-                },
-                Err(_) => {
-                    // Fallback if decoding fails but we still want to try saving
-                    (None, None, input.mime_type.clone())
+        let bytes = std::mem::take(&mut input.image_bytes);
+        let limits = self.limits;
+        let upload = {
+            let _permit = self.permits
+                .acquire()
+                .await
+                .map_err(|e| ApplicationError::Internal { message: e.to_string() })?;
+            tokio::task::spawn_blocking(move || prepare_upload(bytes, &limits))
+                .await
+                .map_err(|e| ApplicationError::Internal { message: format!("Image upload task failed: {}", e) })?
+                .map_err(|message| ApplicationError::ValidationError { message })?
+        };
+        let width = Some(upload.image.width() as i32);
+        let height = Some(upload.image.height() as i32);
+        let mime_type = upload.format.to_mime_type().to_string();
+        let image_bytes = upload.bytes;
+        let decoded = upload.image;
+        let capture = upload.capture;
+
+        // Identical content already stored for this profile is reused rather than copied again
+        let content_hash = upload.content_hash;
+        if let Some(existing) = self.repository
+            .find_by_content_hash(&input.profile_id, &content_hash)
+            .await
+            .map_app_err("Failed to look up duplicate image")?
+        {
+            return match input.on_duplicate {
+                DuplicateImagePolicy::ReturnExisting => {
+                    load_image_result(&self.repository, &existing.id, &input.profile_id).await
                 }
+                DuplicateImagePolicy::Conflict => Err(ApplicationError::AlreadyExists {
+                    resource: "Image",
+                    existing_id: existing.id,
+                }),
             };
+        }
+
+        // Capture date and camera only survive for profiles that opted in
+        let capture = if capture == CaptureMetadata::default() {
+            capture
+        } else {
+            let keep = self.repository
+                .find_settings(&input.profile_id)
+                .await
+                .map_app_err("Failed to fetch image settings")?
+                .is_some_and(|settings| settings.keep_capture_metadata);
+            if keep { capture } else { CaptureMetadata::default() }
+        };
+        let captured_at = capture.captured_at.map(|d| d.format("%Y-%m-%dT%H:%M:%S").to_string());
+        let file_size = image_bytes.len() as i32;
 
         let id = uuid::Uuid::new_v4().to_string();
-        let extension = input.original_filename.split('.').last().unwrap_or("jpg");
-        let filename = format!("{}.{}", id, extension);
-        
+        let filename = format!("{}.{}", id, extension_for(upload.format));
+        let original_filename = sanitize_filename(&input.original_filename);
+
+        // Variants and placeholder are encoded before anything is stored, so a failure leaves nothing behind
+        let (encoded, placeholder) = {
+            let _permit = self.permits
+                .acquire()
+                .await
+                .map_err(|e| ApplicationError::Internal { message: e.to_string() })?;
+            let widths = self.variant_widths.clone();
+            let variant_mime = mime_type.clone();
+            tokio::task::spawn_blocking(move || encode_derivatives(&decoded, &widths, &variant_mime))
+                .await
+                .map_err(|e| ApplicationError::Internal { message: format!("Image variant task failed: {}", e) })?
+                .map_err(|message| ApplicationError::Internal { message })?
+        };
+
         // 1. Upload to storage
         let storage_url = self.storage_repository
-            .upload_image(&input.profile_id, &filename, input.image_bytes, &mime_type)
+            .upload_image(&input.profile_id, &filename, image_bytes, &mime_type)
             .await
             .map_app_err("Failed to upload image to storage")?;
 
+        // 2. Resized copies for responsive markup
+        let variants = match self.upload_variants(&input.profile_id, &id, encoded).await {
+            Ok(variants) => variants,
+            Err(e) => {
+                let _ = self.storage_repository.delete_image(&input.profile_id, &filename).await;
+                return Err(e);
+            }
+        };
+
+        // A missing placeholder only costs the frontend its blur-up, it never fails the upload
+        let (blurhash, dominant_color, lqip) = match placeholder {
+            Some(p) => (Some(p.blurhash), Some(p.dominant_color), Some(p.lqip)),
+            None => (None, None, None),
+        };
+
         let created_at = sqlx::types::chrono::Utc::now().to_rfc3339();
 
         let image = Image {
             id: id.clone(),
             profile_id: input.profile_id,
             filename: filename.clone(),
-            original_filename: input.original_filename.clone(),
+            original_filename: original_filename.clone(),
             storage_url: storage_url.clone(),
-            file_size: input.file_size,
+            file_size,
             width,
             height,
             mime_type: mime_type.clone(),
             alt_text: input.alt_text.clone(),
             caption: input.caption.clone(),
             created_at: created_at.clone(),
+            captured_at: captured_at.clone(),
+            camera_model: capture.camera_model.clone(),
+            content_hash: Some(content_hash),
+            blurhash: blurhash.clone(),
+            dominant_color: dominant_color.clone(),
+            lqip: lqip.clone(),
         };
 
-        // 2. Save metadata to database
+        // 3. Save metadata to database
         self.repository
             .create(image)
             .await
             .map_app_err("Failed to create image in database")?;
 
+        if !variants.is_empty() {
+            self.repository
+                .create_variants(variants.clone())
+                .await
+                .map_app_err("Failed to create image variants in database")?;
+        }
+        let (variants, srcset) = image_sources(&storage_url, width, &mime_type, variants);
+
         Ok(ImageResult {
             id: id.clone(),
             storage_url,
             filename,
-            original_filename: input.original_filename,
+            original_filename,
             width,
             height,
-            file_size: input.file_size,
+            file_size,
             mime_type,
             alt_text: input.alt_text,
             caption: input.caption,
             created_at,
+            captured_at,
+            camera_model: capture.camera_model,
+            blurhash,
+            dominant_color,
+            lqip,
             usage_count: Some(0),
-            performances: vec![],
+            owners: vec![],
+            tags: vec![],
+            variants,
+            srcset,
         })
     }
 }
@@ -426,30 +795,34 @@ where
     }
 }
 
-pub struct DeleteImageService<R, S>
+pub struct DeleteImageService<R, S, T>
 where
     R: ImageRepository,
     S: ImageStorageRepository,
+    T: ImageTransformCacheRepository,
 {
     repository: R,
     storage_repository: S,
+    transform_cache: T,
 }
 
-impl<R, S> DeleteImageService<R, S>
+impl<R, S, T> DeleteImageService<R, S, T>
 where
     R: ImageRepository,
     S: ImageStorageRepository,
+    T: ImageTransformCacheRepository,
 {
-    pub fn new(repository: R, storage_repository: S) -> Self {
-        Self { repository, storage_repository }
+    pub fn new(repository: R, storage_repository: S, transform_cache: T) -> Self {
+        Self { repository, storage_repository, transform_cache }
     }
 }
 
 #[async_trait]
-impl<R, S> UseCase for DeleteImageService<R, S>
+impl<R, S, T> UseCase for DeleteImageService<R, S, T>
 where
     R: ImageRepository + Send + Sync,
     S: ImageStorageRepository + Send + Sync,
+    T: ImageTransformCacheRepository + Send + Sync,
 {
     type Input = DeleteImageInput;
     type Output = MessageResult;
@@ -458,6 +831,8 @@ where
     async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
         input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
 
+        sync_owner_usage(&self.repository, &input.profile_id).await?;
+
         // 1. Check if image exists and get metadata for GCS deletion
         let (image, usage, _) = self.repository
             .find_by_id_and_profile_id(&input.id, &input.profile_id)
@@ -470,11 +845,13 @@ where
 
         if usage > 0 {
             return Err(ApplicationError::ValidationError { 
-                message: format!("Image is currently used in {} performances. Delete performances first.", usage) 
+                message: format!("Image is currently used {} times. Remove it from its owners first.", usage) 
             });
         }
 
-        // 2. Delete from storage
+        // 2. Delete from storage, variants and cached transforms first; variant rows go with the image
+        delete_variant_objects(&self.repository, &self.storage_repository, &input.profile_id, std::slice::from_ref(&image.id)).await;
+        delete_transform_objects(&self.transform_cache, &input.profile_id, std::slice::from_ref(&image.id)).await;
         self.storage_repository
             .delete_image(&input.profile_id, &image.filename)
             .await
@@ -493,30 +870,34 @@ where
     }
 }
 
-pub struct ForceDeleteImageService<R, S>
+pub struct ForceDeleteImageService<R, S, T>
 where
     R: ImageRepository,
     S: ImageStorageRepository,
+    T: ImageTransformCacheRepository,
 {
     repository: R,
     storage_repository: S,
+    transform_cache: T,
 }
 
-impl<R, S> ForceDeleteImageService<R, S>
+impl<R, S, T> ForceDeleteImageService<R, S, T>
 where
     R: ImageRepository,
     S: ImageStorageRepository,
+    T: ImageTransformCacheRepository,
 {
-    pub fn new(repository: R, storage_repository: S) -> Self {
-        Self { repository, storage_repository }
+    pub fn new(repository: R, storage_repository: S, transform_cache: T) -> Self {
+        Self { repository, storage_repository, transform_cache }
     }
 }
 
 #[async_trait]
-impl<R, S> UseCase for ForceDeleteImageService<R, S>
+impl<R, S, T> UseCase for ForceDeleteImageService<R, S, T>
 where
     R: ImageRepository + Send + Sync,
     S: ImageStorageRepository + Send + Sync,
+    T: ImageTransformCacheRepository + Send + Sync,
 {
     type Input = ForceDeleteImageInput;
     type Output = MessageResult;
@@ -535,7 +916,9 @@ where
                 identifier: input.id.clone(),
             })?;
 
-        // 2. Delete from storage
+        // 2. Delete from storage, variants and cached transforms first; variant rows go with the image
+        delete_variant_objects(&self.repository, &self.storage_repository, &input.profile_id, std::slice::from_ref(&image.id)).await;
+        delete_transform_objects(&self.transform_cache, &input.profile_id, std::slice::from_ref(&image.id)).await;
         self.storage_repository
             .delete_image(&input.profile_id, &image.filename)
             .await
@@ -582,6 +965,8 @@ where
     async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
         input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
 
+        sync_owner_usage(&self.repository, &input.profile_id).await?;
+
         let images = self.repository
             .find_unused_by_profile_id(&input.profile_id, input.days_old)
             .await
@@ -590,20 +975,44 @@ where
         let total_size_bytes = images.iter().map(|img| img.file_size as i64).sum();
         let count = images.len();
 
-        let unused_images = images.into_iter().map(|img| ImageResult {
-            id: img.id,
-            storage_url: img.storage_url,
-            filename: img.filename,
-            original_filename: img.original_filename,
-            width: img.width,
-            height: img.height,
-            file_size: img.file_size,
-            mime_type: img.mime_type,
-            alt_text: img.alt_text,
-            caption: img.caption,
-            created_at: img.created_at,
-            usage_count: Some(0),
-            performances: vec![],
+        let ids: Vec<String> = images.iter().map(|img| img.id.clone()).collect();
+        let mut variants = find_variants(&self.repository, &ids).await?;
+        let mut tags = self.repository
+            .find_tags_by_image_ids(&ids)
+            .await
+            .map_app_err("Failed to fetch image tags")?;
+
+        let unused_images = images.into_iter().map(|img| {
+            let (variants, srcset) = image_sources(
+                &img.storage_url,
+                img.width,
+                &img.mime_type,
+                variants.remove(&img.id).unwrap_or_default(),
+            );
+            let tags = tags.remove(&img.id).unwrap_or_default();
+            ImageResult {
+                id: img.id,
+                storage_url: img.storage_url,
+                filename: img.filename,
+                original_filename: img.original_filename,
+                width: img.width,
+                height: img.height,
+                file_size: img.file_size,
+                mime_type: img.mime_type,
+                alt_text: img.alt_text,
+                caption: img.caption,
+                created_at: img.created_at,
+                captured_at: img.captured_at,
+                camera_model: img.camera_model,
+                blurhash: img.blurhash,
+                dominant_color: img.dominant_color,
+                lqip: img.lqip,
+                usage_count: Some(0),
+                owners: vec![],
+                tags,
+                variants,
+                srcset,
+            }
         }).collect();
 
         Ok(UnusedImagesResult {
@@ -614,30 +1023,34 @@ where
     }
 }
 
-pub struct DeleteUnusedImagesService<R, S>
+pub struct DeleteUnusedImagesService<R, S, T>
 where
     R: ImageRepository,
     S: ImageStorageRepository,
+    T: ImageTransformCacheRepository,
 {
     repository: R,
     storage_repository: S,
+    transform_cache: T,
 }
 
-impl<R, S> DeleteUnusedImagesService<R, S>
+impl<R, S, T> DeleteUnusedImagesService<R, S, T>
 where
     R: ImageRepository,
     S: ImageStorageRepository,
+    T: ImageTransformCacheRepository,
 {
-    pub fn new(repository: R, storage_repository: S) -> Self {
-        Self { repository, storage_repository }
+    pub fn new(repository: R, storage_repository: S, transform_cache: T) -> Self {
+        Self { repository, storage_repository, transform_cache }
     }
 }
 
 #[async_trait]
-impl<R, S> UseCase for DeleteUnusedImagesService<R, S>
+impl<R, S, T> UseCase for DeleteUnusedImagesService<R, S, T>
 where
     R: ImageRepository + Send + Sync,
     S: ImageStorageRepository + Send + Sync,
+    T: ImageTransformCacheRepository + Send + Sync,
 {
     type Input = DeleteUnusedImagesInput;
     type Output = DeleteUnusedImagesResult;
@@ -646,22 +1059,27 @@ where
     async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
         input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
 
-        // 1. Get list of unused images first to delete from storage
+        // 1. An avatar or logo set since the last sync must not count as unused
+        sync_owner_usage(&self.repository, &input.profile_id).await?;
+
+        // 2. Get list of unused images first to delete from storage
         let unused_images = self.repository
             .find_unused_by_profile_id(&input.profile_id, input.days_old)
             .await
             .map_app_err("Failed to fetch unused images for deletion")?;
 
-        // 2. Delete from storage
+        // 3. Delete from storage
+        let ids: Vec<String> = unused_images.iter().map(|img| img.id.clone()).collect();
+        delete_variant_objects(&self.repository, &self.storage_repository, &input.profile_id, &ids).await;
+        delete_transform_objects(&self.transform_cache, &input.profile_id, &ids).await;
         for image in &unused_images {
-            let _ = self.storage_repository
-                .delete_image(&input.profile_id, &image.filename)
-                .await;
-            // We ignore storage errors during bulk delete to ensure we try to delete all
-            // or we could collect errors, but usually for "unused" cleanup, we want to proceed.
+            // The row goes regardless; a file left behind is picked up by the storage reconciliation
+            if let Err(e) = self.storage_repository.delete_image(&input.profile_id, &image.filename).await {
+                tracing::warn!("Failed to delete unused image {} from storage: {:?}", image.id, e);
+            }
         }
 
-        // 3. Delete from database
+        // 4. Delete from database
         let (deleted_count, freed_bytes) = self.repository
             .delete_unused_by_profile_id(&input.profile_id, input.days_old)
             .await
@@ -673,3 +1091,102 @@ where
         })
     }
 }
+
+pub struct GetImageSettingsService<R>
+where
+    R: ImageRepository,
+{
+    repository: R,
+}
+
+impl<R> GetImageSettingsService<R>
+where
+    R: ImageRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for GetImageSettingsService<R>
+where
+    R: ImageRepository + Send + Sync,
+{
+    type Input = GetImageSettingsInput;
+    type Output = ImageSettingsResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let settings = self.repository
+            .find_settings(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch image settings")?
+            .unwrap_or_else(|| ImageSettings::default_for(&input.profile_id));
+
+        Ok(ImageSettingsResult {
+            keep_capture_metadata: settings.keep_capture_metadata,
+            updated_at: settings.updated_at,
+        })
+    }
+}
+
+pub struct UpdateImageSettingsService<R>
+where
+    R: ImageRepository,
+{
+    repository: R,
+}
+
+impl<R> UpdateImageSettingsService<R>
+where
+    R: ImageRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for UpdateImageSettingsService<R>
+where
+    R: ImageRepository + Send + Sync,
+{
+    type Input = UpdateImageSettingsInput;
+    type Output = ImageSettingsResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        self.repository
+            .save_settings(ImageSettings {
+                profile_id: input.profile_id.clone(),
+                keep_capture_metadata: input.keep_capture_metadata,
+                updated_at: None,
+            })
+            .await
+            .map_app_err("Failed to save image settings")?;
+
+        // Opting out also forgets what earlier uploads kept
+        if !input.keep_capture_metadata {
+            self.repository
+                .clear_capture_metadata(&input.profile_id)
+                .await
+                .map_app_err("Failed to clear image capture metadata")?;
+        }
+
+        let settings = self.repository
+            .find_settings(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch image settings")?
+            .unwrap_or_else(|| ImageSettings::default_for(&input.profile_id));
+
+        Ok(ImageSettingsResult {
+            keep_capture_metadata: settings.keep_capture_metadata,
+            updated_at: settings.updated_at,
+        })
+    }
+}
@@ -0,0 +1,307 @@
+use async_trait::async_trait;
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::image::dto::input::{
+    ListImageTagsInput, CreateImageTagInput, UpdateImageTagInput, DeleteImageTagInput, SetImageTagsInput
+};
+use crate::domain::entities::profile::image::image_tag::ImageTag;
+use crate::interface_adapters::gateways::repositories::profile::image::image_repository::ImageRepository;
+use super::result::{ImageTagListResult, ImageTagResult, ImageTagsResult, MessageResult};
+
+fn now() -> String {
+    sqlx::types::chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+fn clean_color(color: Option<String>) -> Option<String> {
+    color.map(|c| c.trim().to_string()).filter(|c| !c.is_empty())
+}
+
+/// Maps tag names to image tags, creating the ones the profile doesn't have yet.
+/// Names match case-insensitively, so "travel" reuses an existing "Travel"; repeats are dropped.
+async fn resolve_image_tags<R>(repository: &R, profile_id: &str, names: &[String]) -> Result<Vec<ImageTag>, ApplicationError>
+where
+    R: ImageRepository,
+{
+    let mut tags: Vec<ImageTag> = Vec::with_capacity(names.len());
+    for name in names {
+        let name = name.trim();
+        if tags.iter().any(|t| t.name.eq_ignore_ascii_case(name)) {
+            continue;
+        }
+
+        let existing = repository
+            .find_tag_by_name(profile_id, name)
+            .await
+            .map_app_err("Failed to fetch image tag")?;
+        let tag = match existing {
+            Some(tag) => tag,
+            None => repository
+                .create_tag(ImageTag {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    profile_id: profile_id.to_string(),
+                    name: name.to_string(),
+                    color: None,
+                    created_at: now(),
+                })
+                .await
+                .map_app_err("Failed to create image tag")?,
+        };
+        tags.push(tag);
+    }
+    Ok(tags)
+}
+
+async fn ensure_name_free<R>(repository: &R, profile_id: &str, name: &str, except_id: Option<&str>) -> Result<(), ApplicationError>
+where
+    R: ImageRepository,
+{
+    let existing = repository
+        .find_tag_by_name(profile_id, name)
+        .await
+        .map_app_err("Failed to fetch image tag")?;
+
+    match existing {
+        Some(tag) if Some(tag.id.as_str()) != except_id => Err(ApplicationError::Conflict {
+            message: format!("Image tag '{}' already exists", tag.name),
+        }),
+        _ => Ok(()),
+    }
+}
+
+pub struct ListImageTagsService<R>
+where
+    R: ImageRepository,
+{
+    repository: R,
+}
+
+impl<R> ListImageTagsService<R>
+where
+    R: ImageRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for ListImageTagsService<R>
+where
+    R: ImageRepository + Send + Sync,
+{
+    type Input = ListImageTagsInput;
+    type Output = ImageTagListResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let tags = self.repository
+            .find_tags(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch image tags")?;
+
+        Ok(ImageTagListResult { tags })
+    }
+}
+
+pub struct CreateImageTagService<R>
+where
+    R: ImageRepository,
+{
+    repository: R,
+}
+
+impl<R> CreateImageTagService<R>
+where
+    R: ImageRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for CreateImageTagService<R>
+where
+    R: ImageRepository + Send + Sync,
+{
+    type Input = CreateImageTagInput;
+    type Output = ImageTagResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let name = input.name.trim().to_string();
+        ensure_name_free(&self.repository, &input.profile_id, &name, None).await?;
+
+        let tag = self.repository
+            .create_tag(ImageTag {
+                id: uuid::Uuid::new_v4().to_string(),
+                profile_id: input.profile_id,
+                name,
+                color: clean_color(input.color),
+                created_at: now(),
+            })
+            .await
+            .map_app_err("Failed to create image tag")?;
+
+        Ok(ImageTagResult { tag })
+    }
+}
+
+pub struct UpdateImageTagService<R>
+where
+    R: ImageRepository,
+{
+    repository: R,
+}
+
+impl<R> UpdateImageTagService<R>
+where
+    R: ImageRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for UpdateImageTagService<R>
+where
+    R: ImageRepository + Send + Sync,
+{
+    type Input = UpdateImageTagInput;
+    type Output = ImageTagResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let old_tag = self.repository
+            .find_tag_by_id(&input.id, &input.profile_id)
+            .await
+            .map_app_err("Failed to fetch image tag")?
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "Image tag",
+                identifier: input.id.clone(),
+            })?;
+
+        let name = input.name.trim().to_string();
+        ensure_name_free(&self.repository, &input.profile_id, &name, Some(&input.id)).await?;
+
+        let tag = self.repository
+            .update_tag(ImageTag {
+                name,
+                color: clean_color(input.color),
+                ..old_tag
+            })
+            .await
+            .map_app_err("Failed to update image tag")?;
+
+        Ok(ImageTagResult { tag })
+    }
+}
+
+pub struct DeleteImageTagService<R>
+where
+    R: ImageRepository,
+{
+    repository: R,
+}
+
+impl<R> DeleteImageTagService<R>
+where
+    R: ImageRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for DeleteImageTagService<R>
+where
+    R: ImageRepository + Send + Sync,
+{
+    type Input = DeleteImageTagInput;
+    type Output = MessageResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        self.repository
+            .find_tag_by_id(&input.id, &input.profile_id)
+            .await
+            .map_app_err("Failed to fetch image tag")?
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "Image tag",
+                identifier: input.id.clone(),
+            })?;
+
+        // The images stay, they just lose the tag
+        self.repository
+            .delete_tag(&input.id, &input.profile_id)
+            .await
+            .map_app_err("Failed to delete image tag")?;
+
+        Ok(MessageResult {
+            message: "Image tag deleted".to_string(),
+            id: Some(input.id),
+        })
+    }
+}
+
+pub struct SetImageTagsService<R>
+where
+    R: ImageRepository,
+{
+    repository: R,
+}
+
+impl<R> SetImageTagsService<R>
+where
+    R: ImageRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for SetImageTagsService<R>
+where
+    R: ImageRepository + Send + Sync,
+{
+    type Input = SetImageTagsInput;
+    type Output = ImageTagsResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        self.repository
+            .find_by_id_and_profile_id(&input.image_id, &input.profile_id)
+            .await
+            .map_app_err("Failed to fetch image")?
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "Image",
+                identifier: input.image_id.clone(),
+            })?;
+
+        let tags = resolve_image_tags(&self.repository, &input.profile_id, &input.tags).await?;
+
+        let tag_ids: Vec<String> = tags.iter().map(|t| t.id.clone()).collect();
+        self.repository
+            .set_image_tags(&input.image_id, &tag_ids)
+            .await
+            .map_app_err("Failed to update image tags")?;
+
+        Ok(ImageTagsResult {
+            image_id: input.image_id,
+            tags,
+        })
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::Semaphore;
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::image::dto::input::TransformImageInput;
+use crate::application::services::profile::image::result::TransformedImageResult;
+use crate::interface_adapters::gateways::repositories::profile::image::image_repository::ImageRepository;
+use crate::interface_adapters::gateways::repositories::profile::image::image_storage_repository::ImageStorageRepository;
+use crate::interface_adapters::gateways::repositories::profile::image::image_transform_cache_repository::ImageTransformCacheRepository;
+use crate::shared::utils::http_cache::is_not_modified;
+use crate::shared::utils::image_transform::transform_image;
+
+pub struct TransformImageService<R, S, T>
+where
+    R: ImageRepository,
+    S: ImageStorageRepository,
+    T: ImageTransformCacheRepository,
+{
+    repository: R,
+    storage_repository: S,
+    transform_cache: T,
+    /// Cache misses decode and resample on the blocking pool; this caps how many run at once
+    permits: Arc<Semaphore>,
+}
+
+impl<R, S, T> TransformImageService<R, S, T>
+where
+    R: ImageRepository,
+    S: ImageStorageRepository,
+    T: ImageTransformCacheRepository,
+{
+    pub fn new(repository: R, storage_repository: S, transform_cache: T) -> Self {
+        let workers = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(2);
+        Self {
+            repository,
+            storage_repository,
+            transform_cache,
+            permits: Arc::new(Semaphore::new(workers)),
+        }
+    }
+}
+
+#[async_trait]
+impl<R, S, T> UseCase for TransformImageService<R, S, T>
+where
+    R: ImageRepository + Send + Sync,
+    S: ImageStorageRepository + Send + Sync,
+    T: ImageTransformCacheRepository + Send + Sync,
+{
+    type Input = TransformImageInput;
+    type Output = TransformedImageResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+        let params = input.params().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let image = self.repository
+            .find_by_id(&input.image_id)
+            .await
+            .map_app_err("Failed to fetch image")?
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "Image",
+                identifier: input.image_id.clone(),
+            })?;
+
+        let key = params.cache_key(&image.mime_type);
+        let content_type = params.output_format(&image.mime_type).mime_type();
+        let etag = format!("\"{}-{}\"", image.id, key);
+
+        if is_not_modified(input.if_none_match.as_deref(), None, &etag, None) {
+            return Ok(TransformedImageResult { bytes: Vec::new(), content_type, etag, not_modified: true });
+        }
+
+        if let Some(bytes) = self.transform_cache
+            .find_transform(&image.profile_id, &image.id, &key)
+            .await
+            .map_app_err("Failed to fetch cached transform")?
+        {
+            return Ok(TransformedImageResult { bytes, content_type, etag, not_modified: false });
+        }
+
+        let _permit = self.permits
+            .acquire()
+            .await
+            .map_err(|e| ApplicationError::Internal { message: e.to_string() })?;
+
+        let original = self.storage_repository
+            .download_image(&image.profile_id, &image.filename)
+            .await
+            .map_app_err("Failed to download original image")?;
+
+        let mime_type = image.mime_type.clone();
+        let (bytes, _) = tokio::task::spawn_blocking(move || transform_image(&original, &mime_type, &params))
+            .await
+            .map_err(|e| ApplicationError::Internal { message: format!("Image transform task failed: {}", e) })?
+            .map_err(|message| ApplicationError::ValidationError { message })?;
+
+        // A failed cache write only costs the next request another transform
+        if let Err(e) = self.transform_cache
+            .save_transform(&image.profile_id, &image.id, &key, content_type, bytes.clone())
+            .await
+        {
+            tracing::warn!("Failed to cache transform {} of image {}: {:?}", key, image.id, e);
+        }
+
+        Ok(TransformedImageResult { bytes, content_type, etag, not_modified: false })
+    }
+}
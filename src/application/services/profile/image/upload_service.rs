@@ -0,0 +1,357 @@
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDateTime, Utc};
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::image::dto::input::{
+    CreateImageInput, CreateImageUploadInput, AppendImageUploadChunkInput, ImageUploadInput,
+    PurgeExpiredImageUploadsInput, DuplicateImagePolicy
+};
+use crate::application::services::profile::image::service::CreateImageService;
+use crate::domain::entities::profile::image::image_upload_session::ImageUploadSession;
+use crate::interface_adapters::gateways::repositories::profile::image::image_repository::ImageRepository;
+use crate::interface_adapters::gateways::repositories::profile::image::image_storage_repository::ImageStorageRepository;
+use crate::interface_adapters::gateways::repositories::profile::image::image_upload_session_repository::ImageUploadSessionRepository;
+use crate::shared::utils::image_upload::sanitize_filename;
+use super::result::{ImageResult, ImageUploadPurgeResult, ImageUploadSessionResult, MessageResult};
+
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+fn format_datetime(datetime: NaiveDateTime) -> String {
+    datetime.format(DATETIME_FORMAT).to_string()
+}
+
+/// A session that expired is treated as gone even before the cleanup job got to it.
+async fn find_live_session<U>(repository: &U, upload_id: &str, profile_id: &str) -> Result<ImageUploadSession, ApplicationError>
+where
+    U: ImageUploadSessionRepository,
+{
+    let session = repository
+        .find_by_id(upload_id, profile_id)
+        .await
+        .map_app_err("Failed to fetch upload session")?;
+
+    let now = Utc::now().naive_utc();
+    session
+        .filter(|s| NaiveDateTime::parse_from_str(&s.expires_at, DATETIME_FORMAT).is_ok_and(|expires_at| expires_at >= now))
+        .ok_or_else(|| ApplicationError::NotFound {
+            resource: "Upload session",
+            identifier: upload_id.to_string(),
+        })
+}
+
+pub struct CreateImageUploadService<U>
+where
+    U: ImageUploadSessionRepository,
+{
+    repository: U,
+    max_upload_bytes: usize,
+    ttl: Duration,
+}
+
+impl<U> CreateImageUploadService<U>
+where
+    U: ImageUploadSessionRepository,
+{
+    /// `ttl` is how long a session may sit idle before its chunks are dropped.
+    pub fn new(repository: U, max_upload_bytes: usize, ttl: Duration) -> Self {
+        Self { repository, max_upload_bytes, ttl }
+    }
+}
+
+#[async_trait]
+impl<U> UseCase for CreateImageUploadService<U>
+where
+    U: ImageUploadSessionRepository + Send + Sync,
+{
+    type Input = CreateImageUploadInput;
+    type Output = ImageUploadSessionResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        // Refused up front rather than after the client spent its data plan on it
+        if input.total_size as u64 > self.max_upload_bytes as u64 {
+            return Err(ApplicationError::ValidationError {
+                message: format!("Image cannot be larger than {} bytes", self.max_upload_bytes),
+            });
+        }
+
+        let now = Utc::now().naive_utc();
+        let session = self.repository
+            .create(ImageUploadSession {
+                id: uuid::Uuid::new_v4().to_string(),
+                profile_id: input.profile_id,
+                original_filename: sanitize_filename(&input.original_filename),
+                total_size: input.total_size,
+                received_bytes: 0,
+                alt_text: input.alt_text,
+                caption: input.caption,
+                on_duplicate: input.on_duplicate.as_str().to_string(),
+                created_at: format_datetime(now),
+                expires_at: format_datetime(now + self.ttl),
+            })
+            .await
+            .map_app_err("Failed to create upload session")?;
+
+        Ok(session.into())
+    }
+}
+
+pub struct AppendImageUploadChunkService<U>
+where
+    U: ImageUploadSessionRepository,
+{
+    repository: U,
+    ttl: Duration,
+}
+
+impl<U> AppendImageUploadChunkService<U>
+where
+    U: ImageUploadSessionRepository,
+{
+    pub fn new(repository: U, ttl: Duration) -> Self {
+        Self { repository, ttl }
+    }
+}
+
+#[async_trait]
+impl<U> UseCase for AppendImageUploadChunkService<U>
+where
+    U: ImageUploadSessionRepository + Send + Sync,
+{
+    type Input = AppendImageUploadChunkInput;
+    type Output = ImageUploadSessionResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let session = find_live_session(&self.repository, &input.upload_id, &input.profile_id).await?;
+
+        // A client that lost track after a dropped connection asks for the offset and resumes from there
+        if input.offset != session.received_bytes {
+            return Err(ApplicationError::Conflict {
+                message: format!("Upload is at offset {}, not {}", session.received_bytes, input.offset),
+            });
+        }
+        if input.offset + input.bytes.len() as i64 > session.total_size {
+            return Err(ApplicationError::ValidationError {
+                message: format!("Chunk runs past the announced size of {} bytes", session.total_size),
+            });
+        }
+
+        let expires_at = format_datetime(Utc::now().naive_utc() + self.ttl);
+        let appended = self.repository
+            .append_chunk(&input.upload_id, input.offset, &input.bytes, &expires_at)
+            .await
+            .map_app_err("Failed to store upload chunk")?;
+        if !appended {
+            return Err(ApplicationError::Conflict {
+                message: "Another chunk was received at this offset first, ask for the current offset".to_string(),
+            });
+        }
+
+        Ok(ImageUploadSession {
+            received_bytes: session.received_bytes + input.bytes.len() as i64,
+            expires_at,
+            ..session
+        }
+        .into())
+    }
+}
+
+pub struct GetImageUploadService<U>
+where
+    U: ImageUploadSessionRepository,
+{
+    repository: U,
+}
+
+impl<U> GetImageUploadService<U>
+where
+    U: ImageUploadSessionRepository,
+{
+    pub fn new(repository: U) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<U> UseCase for GetImageUploadService<U>
+where
+    U: ImageUploadSessionRepository + Send + Sync,
+{
+    type Input = ImageUploadInput;
+    type Output = ImageUploadSessionResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let session = find_live_session(&self.repository, &input.upload_id, &input.profile_id).await?;
+
+        Ok(session.into())
+    }
+}
+
+/// Turns a fully received upload into an image through the regular upload path.
+pub struct CompleteImageUploadService<U, R, S>
+where
+    U: ImageUploadSessionRepository,
+    R: ImageRepository,
+    S: ImageStorageRepository,
+{
+    repository: U,
+    image_service: CreateImageService<R, S>,
+}
+
+impl<U, R, S> CompleteImageUploadService<U, R, S>
+where
+    U: ImageUploadSessionRepository,
+    R: ImageRepository,
+    S: ImageStorageRepository,
+{
+    pub fn new(repository: U, image_service: CreateImageService<R, S>) -> Self {
+        Self { repository, image_service }
+    }
+}
+
+#[async_trait]
+impl<U, R, S> UseCase for CompleteImageUploadService<U, R, S>
+where
+    U: ImageUploadSessionRepository + Send + Sync,
+    R: ImageRepository + Send + Sync,
+    S: ImageStorageRepository + Send + Sync,
+{
+    type Input = ImageUploadInput;
+    type Output = ImageResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let session = find_live_session(&self.repository, &input.upload_id, &input.profile_id).await?;
+        if session.received_bytes < session.total_size {
+            return Err(ApplicationError::Conflict {
+                message: format!("Upload is incomplete, {} of {} bytes received", session.received_bytes, session.total_size),
+            });
+        }
+
+        let image_bytes = self.repository
+            .read_bytes(&session.id)
+            .await
+            .map_app_err("Failed to read upload chunks")?;
+        if image_bytes.len() as i64 != session.total_size {
+            return Err(ApplicationError::Internal {
+                message: format!("Upload {} has {} bytes stored, expected {}", session.id, image_bytes.len(), session.total_size),
+            });
+        }
+
+        let on_duplicate = DuplicateImagePolicy::parse(Some(&session.on_duplicate))
+            .map_err(|message| ApplicationError::Internal { message })?;
+
+        // On failure the session stays, so a retry doesn't need the bytes sent again
+        let image = self.image_service
+            .execute(CreateImageInput {
+                profile_id: session.profile_id.clone(),
+                original_filename: session.original_filename.clone(),
+                alt_text: session.alt_text.clone(),
+                caption: session.caption.clone(),
+                image_bytes,
+                on_duplicate,
+            })
+            .await?;
+
+        if let Err(e) = self.repository.delete(&session.id, &session.profile_id).await {
+            tracing::warn!("Failed to delete completed upload session {}: {:?}", session.id, e);
+        }
+
+        Ok(image)
+    }
+}
+
+pub struct CancelImageUploadService<U>
+where
+    U: ImageUploadSessionRepository,
+{
+    repository: U,
+}
+
+impl<U> CancelImageUploadService<U>
+where
+    U: ImageUploadSessionRepository,
+{
+    pub fn new(repository: U) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<U> UseCase for CancelImageUploadService<U>
+where
+    U: ImageUploadSessionRepository + Send + Sync,
+{
+    type Input = ImageUploadInput;
+    type Output = MessageResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        self.repository
+            .find_by_id(&input.upload_id, &input.profile_id)
+            .await
+            .map_app_err("Failed to fetch upload session")?
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "Upload session",
+                identifier: input.upload_id.clone(),
+            })?;
+
+        self.repository
+            .delete(&input.upload_id, &input.profile_id)
+            .await
+            .map_app_err("Failed to delete upload session")?;
+
+        Ok(MessageResult {
+            message: "Upload cancelled".to_string(),
+            id: Some(input.upload_id),
+        })
+    }
+}
+
+/// Drops the sessions left behind by clients that never came back, run periodically in the background.
+pub struct PurgeExpiredImageUploadsService<U>
+where
+    U: ImageUploadSessionRepository,
+{
+    repository: U,
+}
+
+impl<U> PurgeExpiredImageUploadsService<U>
+where
+    U: ImageUploadSessionRepository,
+{
+    pub fn new(repository: U) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<U> UseCase for PurgeExpiredImageUploadsService<U>
+where
+    U: ImageUploadSessionRepository + Send + Sync,
+{
+    type Input = PurgeExpiredImageUploadsInput;
+    type Output = ImageUploadPurgeResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, _input: Self::Input) -> Result<Self::Output, Self::Error> {
+        let deleted = self.repository
+            .delete_expired(&format_datetime(Utc::now().naive_utc()))
+            .await
+            .map_app_err("Failed to delete expired upload sessions")?;
+
+        Ok(ImageUploadPurgeResult { deleted })
+    }
+}
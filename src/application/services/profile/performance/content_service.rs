@@ -2,12 +2,16 @@ use async_trait::async_trait;
 use crate::application::errors::{ApplicationError, MapToApplicationError};
 use crate::application::use_cases::use_case::UseCase;
 use crate::application::use_cases::profile::performance::dto::input::{
-    UpdatePerformanceContentInput, GetPerformanceContentInput
+    UpdatePerformanceContentInput, GetPerformanceContentInput, RenderPerformanceContentInput
 };
+use crate::domain::entities::profile::visibility::visibility::VISIBILITY_PUBLIC;
 use crate::interface_adapters::gateways::repositories::profile::performance::performance_repository::PerformanceRepository;
 use crate::interface_adapters::gateways::repositories::profile::performance_content::performance_content_repository::PerformanceContentRepository;
-use super::result::{PerformanceContentResult, PerformanceContentUpdateResult};
-use crate::shared::utils::markdown::{parse_image_ids, strip_markdown};
+use crate::interface_adapters::gateways::repositories::profile::share_link::share_link_repository::ShareLinkRepository;
+use crate::application::services::profile::share_link::service::ShareLinkAccess;
+use super::render_cache::RenderedContentCache;
+use super::result::{PerformanceContentResult, PerformanceContentUpdateResult, PerformanceRenderedResult};
+use crate::shared::utils::content_analysis::{analyze_markdown, excerpt, PREVIEW_MAX_CHARS};
 
 pub struct GetPerformanceContentService<C>
 where
@@ -89,12 +93,8 @@ where
             .await
             .map_app_err("Failed to update performance content")?;
 
-        let stripped_content = strip_markdown(&input.content_markdown);
-        let content_preview = if stripped_content.len() > 500 {
-            Some(stripped_content[..500].to_string())
-        } else {
-            Some(stripped_content)
-        };
+        let analysis = analyze_markdown(&input.content_markdown);
+        let content_preview = Some(excerpt(&analysis.plain_text, PREVIEW_MAX_CHARS));
 
         // Update performance with new preview and content_url
         let mut updated_perf = old_perf.clone();
@@ -108,7 +108,7 @@ where
             .map_app_err("Failed to update performance metadata")?;
 
         // Image tracking
-        let image_ids = parse_image_ids(&input.content_markdown);
+        let image_ids = analysis.image_ids;
         self.repository
             .sync_image_usage(&input.performance_id, &image_ids)
             .await
@@ -121,3 +121,83 @@ where
         })
     }
 }
+
+pub struct RenderPerformanceContentService<R, C, S>
+where
+    R: PerformanceRepository,
+    C: PerformanceContentRepository,
+    S: ShareLinkRepository,
+{
+    repository: R,
+    content_repository: C,
+    share_access: ShareLinkAccess<S>,
+    cache: RenderedContentCache,
+}
+
+impl<R, C, S> RenderPerformanceContentService<R, C, S>
+where
+    R: PerformanceRepository,
+    C: PerformanceContentRepository,
+    S: ShareLinkRepository,
+{
+    pub fn new(repository: R, content_repository: C, share_access: ShareLinkAccess<S>, cache: RenderedContentCache) -> Self {
+        Self { repository, content_repository, share_access, cache }
+    }
+}
+
+#[async_trait]
+impl<R, C, S> UseCase for RenderPerformanceContentService<R, C, S>
+where
+    R: PerformanceRepository + Send + Sync,
+    C: PerformanceContentRepository + Send + Sync,
+    S: ShareLinkRepository + Send + Sync,
+{
+    type Input = RenderPerformanceContentInput;
+    type Output = PerformanceRenderedResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        // A valid share link for this very performance lifts the visibility check;
+        // a link to another performance is refused rather than counted as a visit
+        let shared = match &input.share_token {
+            Some(token) => {
+                let link = self.share_access.verify(token, input.share_password.as_deref()).await?;
+                if link.performance_id != input.performance_id {
+                    return Err(ApplicationError::Forbidden);
+                }
+                self.share_access.record_access(&link).await?;
+                true
+            }
+            None => false,
+        };
+
+        // Only public performances of this profile can be rendered; anything else is reported as missing
+        let perf = self.repository
+            .find_by_id(&input.performance_id)
+            .await
+            .map_app_err("Failed to fetch performance")?
+            .filter(|p| p.profile_id == input.profile_id && (shared || p.visibility_id == VISIBILITY_PUBLIC))
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "Performance",
+                identifier: input.performance_id.clone(),
+            })?;
+
+        let content = self.content_repository
+            .get_content(&perf.profile_id, &perf.id)
+            .await
+            .map_app_err("Failed to fetch performance content")?;
+
+        let (content_hash, rendered) = self.cache.get_or_render(&content);
+
+        Ok(PerformanceRenderedResult {
+            performance_id: perf.id,
+            content_hash,
+            html: rendered.html.clone(),
+            toc: rendered.toc.clone(),
+            word_count: rendered.word_count,
+            reading_time_minutes: rendered.reading_time_minutes,
+        })
+    }
+}
@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::performance::dto::input::GetPerformanceFeedInput;
+use crate::domain::entities::profile::visibility::visibility::VISIBILITY_PUBLIC;
+use crate::interface_adapters::gateways::repositories::profile::performance::performance_repository::PerformanceRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance_content::performance_content_repository::PerformanceContentRepository;
+use crate::interface_adapters::gateways::repositories::profile::profile_repository::ProfileRepository;
+use crate::shared::utils::feed::{render_feed, FeedItem, FeedMeta};
+use crate::shared::utils::http_cache::{etag_for, http_date, is_not_modified};
+use crate::shared::utils::public_url::PublicUrls;
+use crate::interface_adapters::gateways::repositories::profile::tag::tag_repository::TagRepository;
+use crate::application::services::profile::tag::service::attach_tags;
+use super::render_cache::RenderedContentCache;
+use super::result::PerformanceFeedResult;
+
+/// Only the newest performances are published; readers keep older entries themselves.
+const FEED_MAX_ITEMS: usize = 20;
+
+/// Performance dates are stored as `YYYY-MM-DD`; feeds need full timestamps, so midnight UTC is used.
+fn day_to_utc(date: &str) -> Option<DateTime<Utc>> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+}
+
+pub struct GetPerformanceFeedService<R, P, C, T>
+where
+    R: PerformanceRepository,
+    P: ProfileRepository,
+    C: PerformanceContentRepository,
+    T: TagRepository,
+{
+    repository: R,
+    profile_repository: P,
+    content_repository: C,
+    tag_repository: T,
+    cache: RenderedContentCache,
+    urls: PublicUrls,
+}
+
+impl<R, P, C, T> GetPerformanceFeedService<R, P, C, T>
+where
+    R: PerformanceRepository,
+    P: ProfileRepository,
+    C: PerformanceContentRepository,
+    T: TagRepository,
+{
+    pub fn new(
+        repository: R,
+        profile_repository: P,
+        content_repository: C,
+        tag_repository: T,
+        cache: RenderedContentCache,
+        urls: PublicUrls,
+    ) -> Self {
+        Self { repository, profile_repository, content_repository, tag_repository, cache, urls }
+    }
+}
+
+#[async_trait]
+impl<R, P, C, T> UseCase for GetPerformanceFeedService<R, P, C, T>
+where
+    R: PerformanceRepository + Send + Sync,
+    P: ProfileRepository + Send + Sync,
+    C: PerformanceContentRepository + Send + Sync,
+    T: TagRepository + Send + Sync,
+{
+    type Input = GetPerformanceFeedInput;
+    type Output = PerformanceFeedResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let profile = self.profile_repository
+            .find_by_id(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch profile")?
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "Profile",
+                identifier: input.profile_id.clone(),
+            })?;
+
+        let mut performances = self.repository
+            .find_by_profile_id(&input.profile_id, Some(VISIBILITY_PUBLIC))
+            .await
+            .map_app_err("Failed to fetch performances")?;
+        // The repository returns the profile's manual order; a feed wants the newest first
+        performances.sort_by(|a, b| b.created_at.cmp(&a.created_at).then_with(|| a.id.cmp(&b.id)));
+        performances.truncate(FEED_MAX_ITEMS);
+        attach_tags(&self.tag_repository, &mut performances).await?;
+
+        let mut items = Vec::with_capacity(FEED_MAX_ITEMS);
+        for perf in performances {
+            let content = self.content_repository
+                .get_content(&perf.profile_id, &perf.id)
+                .await
+                .map_app_err("Failed to fetch performance content")?;
+            let (_, rendered) = self.cache.get_or_render(&content);
+
+            let published = day_to_utc(&perf.created_at).unwrap_or_default();
+            let updated = perf.updated_at
+                .as_deref()
+                .and_then(day_to_utc)
+                .unwrap_or(published);
+
+            items.push(FeedItem {
+                id: format!("urn:uuid:{}", perf.id),
+                url: self.urls.performance_page(&perf.profile_id, &perf.slug()),
+                summary: perf.content_preview.clone().filter(|p| !p.is_empty()).or(perf.summary.clone()),
+                content_html: Some(rendered.html.clone()).filter(|html| !html.is_empty()),
+                tags: perf.tags.iter().map(|t| t.name.clone()).collect(),
+                title: perf.title,
+                published,
+                updated,
+            });
+        }
+
+        let last_modified = items.iter()
+            .map(|item| item.updated)
+            .max()
+            .or_else(|| profile.updated_at.as_deref().and_then(day_to_utc))
+            .or_else(|| day_to_utc(&profile.created_at));
+
+        let meta = FeedMeta {
+            id: self.urls.profile_page(&profile.id),
+            title: profile.display_name.clone(),
+            subtitle: profile.headline.clone(),
+            author: profile.display_name,
+            home_url: self.urls.profile_page(&profile.id),
+            self_url: self.urls.api(&format!("/profiles/{}/{}", profile.id, input.format.file_name())),
+            updated: last_modified.unwrap_or_default(),
+        };
+
+        let body = render_feed(input.format, &meta, &items);
+        let etag = etag_for(body.as_bytes());
+        let not_modified = is_not_modified(
+            input.if_none_match.as_deref(),
+            input.if_modified_since.as_deref(),
+            &etag,
+            last_modified.as_ref(),
+        );
+
+        Ok(PerformanceFeedResult {
+            content_type: input.format.content_type(),
+            body,
+            etag,
+            last_modified: last_modified.as_ref().map(http_date),
+            not_modified,
+        })
+    }
+}
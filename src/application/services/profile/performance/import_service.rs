@@ -0,0 +1,350 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::image::dto::input::{CreateImageInput, DuplicateImagePolicy};
+use crate::application::use_cases::profile::performance::dto::input::{ImportPerformancesInput, IMPORT_ARCHIVE_LIMITS};
+use crate::application::use_cases::profile::tag::dto::input::{validate_name, MAX_TAGS_PER_PERFORMANCE};
+use crate::application::services::profile::image::service::CreateImageService;
+use crate::application::services::profile::tag::service::resolve_tags;
+use crate::domain::entities::profile::performance::performance::Performance;
+use crate::domain::entities::profile::performance_category::performance_category::PerformanceCategory;
+use crate::domain::entities::profile::visibility::visibility::{Visibility, VISIBILITY_PRIVATE};
+use crate::interface_adapters::gateways::repositories::profile::image::image_repository::ImageRepository;
+use crate::interface_adapters::gateways::repositories::profile::image::image_storage_repository::ImageStorageRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance::performance_repository::PerformanceRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance_content::performance_content_repository::PerformanceContentRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance_category::performance_category_repository::PerformanceCategoryRepository;
+use crate::interface_adapters::gateways::repositories::profile::visibility::visibility_repository::VisibilityRepository;
+use crate::interface_adapters::gateways::repositories::profile::tag::tag_repository::TagRepository;
+use crate::shared::utils::content_analysis::{analyze_markdown, excerpt, PREVIEW_MAX_CHARS};
+use crate::shared::utils::front_matter::{normalize_date, split_front_matter, title_from_path};
+use crate::shared::utils::markdown_archive::{
+    image_links, read_archive, resolve_local_link, rewrite_image_links, ArchiveDocument, MarkdownArchive
+};
+use super::result::{ImportPerformancesResult, ImportedFileResult};
+
+/// Matches a front matter reference against ids first, then names (case-insensitive).
+fn find_by_id_or_name<'a, T>(items: &'a [T], value: &str, id: fn(&T) -> &str, name: fn(&T) -> &str) -> Option<&'a T> {
+    let value = value.trim();
+    items.iter().find(|item| id(item) == value)
+        .or_else(|| items.iter().find(|item| name(item).eq_ignore_ascii_case(value)))
+}
+
+/// Everything a single file needs besides the file itself.
+struct ImportContext<'a> {
+    profile_id: &'a str,
+    archive: &'a MarkdownArchive,
+    categories: &'a [PerformanceCategory],
+    visibilities: &'a [Visibility],
+    default_category_id: Option<&'a str>,
+    default_visibility_id: &'a str,
+}
+
+struct ImportedDocument {
+    performance_id: String,
+    title: String,
+    images_uploaded: usize,
+    missing_images: Vec<String>,
+    warnings: Vec<String>,
+}
+
+pub struct ImportPerformancesService<R, C, K, V, T, I, S>
+where
+    R: PerformanceRepository,
+    C: PerformanceContentRepository,
+    K: PerformanceCategoryRepository,
+    V: VisibilityRepository,
+    T: TagRepository,
+    I: ImageRepository,
+    S: ImageStorageRepository,
+{
+    repository: R,
+    content_repository: C,
+    category_repository: K,
+    visibility_repository: V,
+    tag_repository: T,
+    image_service: CreateImageService<I, S>,
+}
+
+impl<R, C, K, V, T, I, S> ImportPerformancesService<R, C, K, V, T, I, S>
+where
+    R: PerformanceRepository + Send + Sync,
+    C: PerformanceContentRepository + Send + Sync,
+    K: PerformanceCategoryRepository + Send + Sync,
+    V: VisibilityRepository + Send + Sync,
+    T: TagRepository + Send + Sync,
+    I: ImageRepository + Send + Sync,
+    S: ImageStorageRepository + Send + Sync,
+{
+    pub fn new(
+        repository: R,
+        content_repository: C,
+        category_repository: K,
+        visibility_repository: V,
+        tag_repository: T,
+        image_service: CreateImageService<I, S>,
+    ) -> Self {
+        Self { repository, content_repository, category_repository, visibility_repository, tag_repository, image_service }
+    }
+
+    /// Imports one markdown file. `uploaded` maps archive paths to storage URLs and is shared
+    /// across files so an image referenced from several posts is only uploaded once.
+    async fn import_document(
+        &self,
+        context: &ImportContext<'_>,
+        document: &ArchiveDocument,
+        uploaded: &mut HashMap<String, String>,
+    ) -> Result<ImportedDocument, ApplicationError> {
+        let invalid = |message: String| ApplicationError::ValidationError { message };
+
+        let (front_matter, body) = split_front_matter(&document.source).map_err(invalid)?;
+
+        let title = front_matter.title
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| title_from_path(&document.path));
+        if title.is_empty() {
+            return Err(invalid("title cannot be empty".to_string()));
+        }
+
+        let category_id = match front_matter.category.as_deref() {
+            Some(category) => find_by_id_or_name(context.categories, category, |c| &c.id, |c| &c.name)
+                .map(|c| c.id.clone())
+                .ok_or_else(|| invalid(format!("Unknown category: {}", category)))?,
+            None => context.default_category_id
+                .map(str::to_string)
+                .ok_or_else(|| invalid("No category: set `category` in the front matter or pass category_id".to_string()))?,
+        };
+        let visibility_id = match front_matter.visibility.as_deref() {
+            Some(visibility) => find_by_id_or_name(context.visibilities, visibility, |v| &v.id, |v| &v.name)
+                .map(|v| v.id.clone())
+                .ok_or_else(|| invalid(format!("Unknown visibility: {}", visibility)))?,
+            None => context.default_visibility_id.to_string(),
+        };
+
+        let start_date = front_matter.start_date.as_deref().map(normalize_date).transpose().map_err(invalid)?;
+        let end_date = front_matter.end_date.as_deref().map(normalize_date).transpose().map_err(invalid)?;
+
+        if front_matter.tags.len() > MAX_TAGS_PER_PERFORMANCE {
+            return Err(invalid(format!("a performance can have at most {} tags", MAX_TAGS_PER_PERFORMANCE)));
+        }
+        front_matter.tags.iter().try_for_each(|name| validate_name(name)).map_err(invalid)?;
+
+        // Upload local images and point the links at their new URLs
+        let mut replacements = HashMap::new();
+        let mut images_uploaded = 0;
+        let mut missing_images = Vec::new();
+        for link in image_links(body) {
+            let Some(path) = resolve_local_link(&document.path, &link) else {
+                continue;
+            };
+            if let Some(url) = uploaded.get(&path) {
+                replacements.insert(link, url.clone());
+                continue;
+            }
+            let Some(bytes) = context.archive.files.get(&path) else {
+                missing_images.push(link);
+                continue;
+            };
+
+            let image = self.image_service
+                .execute(CreateImageInput {
+                    profile_id: context.profile_id.to_string(),
+                    original_filename: path.rsplit('/').next().unwrap_or(&path).to_string(),
+                    alt_text: None,
+                    caption: None,
+                    image_bytes: bytes.clone(),
+                    on_duplicate: DuplicateImagePolicy::ReturnExisting,
+                })
+                .await
+                .map_err(|e| match e {
+                    ApplicationError::ValidationError { message } => invalid(format!("{}: {}", link, message)),
+                    e => e,
+                })?;
+            images_uploaded += 1;
+            uploaded.insert(path, image.storage_url.clone());
+            replacements.insert(link, image.storage_url);
+        }
+        let content = rewrite_image_links(body, &replacements);
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let sort_order = self.repository
+            .first_sort_order(context.profile_id)
+            .await
+            .map_app_err("Failed to fetch performance order")?;
+        let content_url = self.content_repository
+            .upload_content(context.profile_id, &id, &content)
+            .await
+            .map_app_err("Failed to upload performance content")?;
+
+        let analysis = analyze_markdown(&content);
+        let created = self.repository
+            .create(Performance {
+                id: id.clone(),
+                profile_id: context.profile_id.to_string(),
+                category_id,
+                visibility_id,
+                title: title.clone(),
+                summary: front_matter.summary,
+                content_url: Some(content_url),
+                content_type: "markdown".to_string(),
+                content_preview: Some(excerpt(&analysis.plain_text, PREVIEW_MAX_CHARS)),
+                start_date,
+                end_date,
+                location: front_matter.location,
+                close: false,
+                created_at: sqlx::types::chrono::Utc::now().format("%Y-%m-%d").to_string(),
+                updated_at: None,
+                sort_order,
+                pinned: front_matter.pinned,
+                tags: Vec::new(),
+            })
+            .await
+            .map_app_err("Failed to create performance");
+        if let Err(e) = created {
+            if let Err(cleanup) = self.content_repository.delete_content(context.profile_id, &id).await {
+                tracing::warn!("Failed to delete content of unimported performance {}: {:?}", id, cleanup);
+            }
+            return Err(e);
+        }
+
+        // The performance exists from here on, so later failures are warnings: reporting the
+        // file as failed would make a retry import it a second time
+        let mut warnings = Vec::new();
+        if let Err(e) = self.repository
+            .sync_image_usage(&id, &analysis.image_ids)
+            .await
+            .map_app_err("Failed to sync image usage")
+        {
+            warnings.push(client_message(&e));
+        }
+
+        if !front_matter.tags.is_empty() {
+            let tagged = match resolve_tags(&self.tag_repository, context.profile_id, &front_matter.tags).await {
+                Ok(tags) => {
+                    let tag_ids: Vec<String> = tags.into_iter().map(|t| t.id).collect();
+                    self.tag_repository
+                        .set_performance_tags(&id, &tag_ids)
+                        .await
+                        .map_app_err("Failed to update performance tags")
+                }
+                Err(e) => Err(e),
+            };
+            if let Err(e) = tagged {
+                warnings.push(format!("Tags not set: {}", client_message(&e)));
+            }
+        }
+
+        Ok(ImportedDocument { performance_id: id, title, images_uploaded, missing_images, warnings })
+    }
+}
+
+/// Message for the per-file report. Internal errors are already logged and their text may
+/// carry storage or database details, so the client only gets a generic line.
+fn client_message(error: &ApplicationError) -> String {
+    match error {
+        ApplicationError::Internal { .. } | ApplicationError::Unexpected { .. } => {
+            "Internal error, see server logs".to_string()
+        }
+        e => e.to_string(),
+    }
+}
+
+#[async_trait]
+impl<R, C, K, V, T, I, S> UseCase for ImportPerformancesService<R, C, K, V, T, I, S>
+where
+    R: PerformanceRepository + Send + Sync,
+    C: PerformanceContentRepository + Send + Sync,
+    K: PerformanceCategoryRepository + Send + Sync,
+    V: VisibilityRepository + Send + Sync,
+    T: TagRepository + Send + Sync,
+    I: ImageRepository + Send + Sync,
+    S: ImageStorageRepository + Send + Sync,
+{
+    type Input = ImportPerformancesInput;
+    type Output = ImportPerformancesResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let archive = read_archive(&input.archive, IMPORT_ARCHIVE_LIMITS)
+            .map_err(|e| ApplicationError::ValidationError { message: e })?;
+        if archive.documents.is_empty() {
+            return Err(ApplicationError::ValidationError {
+                message: "archive contains no markdown files".to_string(),
+            });
+        }
+
+        let categories = self.category_repository
+            .find_by_profile_id(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch performance categories")?;
+        let visibilities = self.visibility_repository
+            .find_all()
+            .await
+            .map_app_err("Failed to fetch visibilities")?;
+
+        // Bad defaults would fail every file, so reject them up front
+        if let Some(category_id) = input.category_id.as_deref() {
+            if !categories.iter().any(|c| c.id == category_id) {
+                return Err(ApplicationError::ValidationError {
+                    message: format!("Unknown category_id: {}", category_id),
+                });
+            }
+        }
+        let default_visibility_id = input.visibility_id.as_deref().unwrap_or(VISIBILITY_PRIVATE);
+        if !visibilities.iter().any(|v| v.id == default_visibility_id) {
+            return Err(ApplicationError::ValidationError {
+                message: format!("Unknown visibility_id: {}", default_visibility_id),
+            });
+        }
+
+        let context = ImportContext {
+            profile_id: &input.profile_id,
+            archive: &archive,
+            categories: &categories,
+            visibilities: &visibilities,
+            default_category_id: input.category_id.as_deref(),
+            default_visibility_id,
+        };
+
+        let mut uploaded = HashMap::new();
+        let mut files = Vec::with_capacity(archive.documents.len());
+        for document in &archive.documents {
+            let file = match self.import_document(&context, document, &mut uploaded).await {
+                Ok(imported) => ImportedFileResult {
+                    path: document.path.clone(),
+                    success: true,
+                    performance_id: Some(imported.performance_id),
+                    title: Some(imported.title),
+                    images_uploaded: imported.images_uploaded,
+                    missing_images: imported.missing_images,
+                    warnings: imported.warnings,
+                    error: None,
+                },
+                Err(e) => ImportedFileResult {
+                    path: document.path.clone(),
+                    success: false,
+                    performance_id: None,
+                    title: None,
+                    images_uploaded: 0,
+                    missing_images: Vec::new(),
+                    warnings: Vec::new(),
+                    error: Some(client_message(&e)),
+                },
+            };
+            files.push(file);
+        }
+
+        let imported = files.iter().filter(|f| f.success).count();
+        Ok(ImportPerformancesResult {
+            imported,
+            failed: files.len() - imported,
+            files,
+        })
+    }
+}
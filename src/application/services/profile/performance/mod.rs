@@ -1,3 +1,7 @@
 pub mod service;
 pub mod result;
 pub mod content_service;
+pub mod render_cache;
+pub mod public_service;
+pub mod feed_service;
+pub mod import_service;
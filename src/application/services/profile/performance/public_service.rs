@@ -0,0 +1,180 @@
+use async_trait::async_trait;
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::performance::dto::input::GetPublicPerformanceInput;
+use crate::domain::entities::profile::performance::performance::Performance;
+use crate::domain::entities::profile::visibility::visibility::VISIBILITY_PUBLIC;
+use crate::interface_adapters::gateways::repositories::profile::performance::performance_repository::PerformanceRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance_content::performance_content_repository::PerformanceContentRepository;
+use crate::interface_adapters::gateways::repositories::profile::tag::tag_repository::TagRepository;
+use crate::application::services::profile::tag::service::attach_tags;
+use crate::interface_adapters::gateways::repositories::profile::share_link::share_link_repository::ShareLinkRepository;
+use crate::application::services::profile::share_link::service::ShareLinkAccess;
+use crate::application::services::profile::image::service::{find_variants, image_sources};
+use crate::interface_adapters::gateways::repositories::profile::image::image_repository::ImageRepository;
+use super::render_cache::RenderedContentCache;
+use super::result::{PerformanceNeighbour, PublicImageResult, PublicPerformanceResult};
+
+pub struct GetPublicPerformanceService<R, C, T, S, I>
+where
+    R: PerformanceRepository,
+    C: PerformanceContentRepository,
+    T: TagRepository,
+    S: ShareLinkRepository,
+    I: ImageRepository,
+{
+    repository: R,
+    content_repository: C,
+    tag_repository: T,
+    share_access: ShareLinkAccess<S>,
+    image_repository: I,
+    cache: RenderedContentCache,
+}
+
+impl<R, C, T, S, I> GetPublicPerformanceService<R, C, T, S, I>
+where
+    R: PerformanceRepository,
+    C: PerformanceContentRepository,
+    T: TagRepository,
+    S: ShareLinkRepository,
+    I: ImageRepository,
+{
+    pub fn new(
+        repository: R,
+        content_repository: C,
+        tag_repository: T,
+        share_access: ShareLinkAccess<S>,
+        image_repository: I,
+        cache: RenderedContentCache,
+    ) -> Self {
+        Self { repository, content_repository, tag_repository, share_access, image_repository, cache }
+    }
+}
+
+fn neighbour(perf: &Performance) -> PerformanceNeighbour {
+    PerformanceNeighbour {
+        id: perf.id.clone(),
+        slug: perf.slug(),
+        title: perf.title.clone(),
+        start_date: perf.start_date.clone(),
+    }
+}
+
+#[async_trait]
+impl<R, C, T, S, I> UseCase for GetPublicPerformanceService<R, C, T, S, I>
+where
+    R: PerformanceRepository + Send + Sync,
+    C: PerformanceContentRepository + Send + Sync,
+    T: TagRepository + Send + Sync,
+    S: ShareLinkRepository + Send + Sync,
+    I: ImageRepository + Send + Sync,
+{
+    type Input = GetPublicPerformanceInput;
+    type Output = PublicPerformanceResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let not_found = || ApplicationError::NotFound {
+            resource: "Performance",
+            identifier: input.slug_or_id.clone(),
+        };
+
+        let (mut perf, previous, next) = match &input.share_token {
+            // A share link opens exactly one performance, whatever its visibility, and no neighbours
+            Some(token) => {
+                let link = self.share_access.verify(token, input.share_password.as_deref()).await?;
+                let perf = self.repository
+                    .find_by_id(&link.performance_id)
+                    .await
+                    .map_app_err("Failed to fetch performance")?
+                    .filter(|p| p.profile_id == input.profile_id)
+                    .filter(|p| p.id == input.slug_or_id || p.slug() == input.slug_or_id)
+                    .ok_or_else(not_found)?;
+                self.share_access.record_access(&link).await?;
+                (perf, None, None)
+            }
+            None => {
+                // Only public performances are loaded, so private ones are indistinguishable from missing ones
+                let mut performances = self.repository
+                    .find_by_profile_id(&input.profile_id, Some(VISIBILITY_PUBLIC))
+                    .await
+                    .map_app_err("Failed to fetch performances")?;
+                performances.sort_by(|a, b| a.chronological_key().cmp(&b.chronological_key()));
+
+                // An exact id wins; otherwise the chronologically first performance with a matching slug
+                let position = performances.iter()
+                    .position(|p| p.id == input.slug_or_id)
+                    .or_else(|| performances.iter().position(|p| p.slug() == input.slug_or_id))
+                    .ok_or_else(not_found)?;
+
+                let previous = position.checked_sub(1).map(|i| neighbour(&performances[i]));
+                let next = performances.get(position + 1).map(neighbour);
+                (performances.swap_remove(position), previous, next)
+            }
+        };
+        attach_tags(&self.tag_repository, std::slice::from_mut(&mut perf)).await?;
+
+        let content = self.content_repository
+            .get_content(&perf.profile_id, &perf.id)
+            .await
+            .map_app_err("Failed to fetch performance content")?;
+
+        let images = self.repository
+            .find_images_by_performance_id(&perf.id)
+            .await
+            .map_app_err("Failed to fetch performance images")?;
+        let ids: Vec<String> = images.iter().map(|img| img.id.clone()).collect();
+        let mut variants = find_variants(&self.image_repository, &ids).await?;
+        let images = images
+            .into_iter()
+            .map(|img| {
+                let (_, srcset) = image_sources(
+                    &img.storage_url,
+                    img.width,
+                    &img.mime_type,
+                    variants.remove(&img.id).unwrap_or_default(),
+                );
+                PublicImageResult {
+                    id: img.id,
+                    url: img.storage_url,
+                    width: img.width,
+                    height: img.height,
+                    mime_type: img.mime_type,
+                    alt_text: img.alt_text,
+                    caption: img.caption,
+                    captured_at: img.captured_at,
+                    camera_model: img.camera_model,
+                    blurhash: img.blurhash,
+                    dominant_color: img.dominant_color,
+                    lqip: img.lqip,
+                    srcset,
+                }
+            })
+            .collect();
+
+        let (content_hash, rendered) = self.cache.get_or_render(&content);
+        let format = input.format.unwrap_or_else(|| "html".to_string());
+        let (content_markdown, content_html) = if format == "markdown" {
+            (Some(content), None)
+        } else {
+            (None, Some(rendered.html.clone()))
+        };
+
+        Ok(PublicPerformanceResult {
+            slug: perf.slug(),
+            performance: perf,
+            format,
+            content_markdown,
+            content_html,
+            content_hash,
+            toc: rendered.toc.clone(),
+            word_count: rendered.word_count,
+            reading_time_minutes: rendered.reading_time_minutes,
+            images,
+            previous,
+            next,
+        })
+    }
+}
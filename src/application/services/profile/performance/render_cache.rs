@@ -0,0 +1,67 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use crate::shared::utils::hash::sha256_hex;
+use crate::shared::utils::markdown_renderer::{render_markdown, RenderedMarkdown};
+
+/// Number of rendered documents kept in memory before the oldest is evicted.
+pub const DEFAULT_RENDER_CACHE_CAPACITY: usize = 256;
+
+/// In-memory cache of rendered markdown keyed by the SHA-256 of the source,
+/// so unchanged content is never re-rendered regardless of which performance it belongs to.
+#[derive(Clone)]
+pub struct RenderedContentCache {
+    capacity: usize,
+    state: Arc<Mutex<CacheState>>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<String, Arc<RenderedMarkdown>>,
+    insertion_order: VecDeque<String>,
+}
+
+impl RenderedContentCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            state: Arc::new(Mutex::new(CacheState::default())),
+        }
+    }
+
+    /// Returns the content hash together with the (possibly cached) rendering.
+    pub fn get_or_render(&self, content: &str) -> (String, Arc<RenderedMarkdown>) {
+        let hash = sha256_hex(content.as_bytes());
+
+        if let Some(hit) = self.lock().entries.get(&hash) {
+            return (hash, hit.clone());
+        }
+
+        // Render outside the lock; a concurrent miss on the same hash just renders twice.
+        let rendered = Arc::new(render_markdown(content));
+
+        let mut state = self.lock();
+        if !state.entries.contains_key(&hash) {
+            while state.entries.len() >= self.capacity {
+                match state.insertion_order.pop_front() {
+                    Some(oldest) => { state.entries.remove(&oldest); }
+                    None => break,
+                }
+            }
+            state.entries.insert(hash.clone(), rendered.clone());
+            state.insertion_order.push_back(hash.clone());
+        }
+
+        (hash, rendered)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheState> {
+        // A panic while holding the lock cannot leave the map half-updated, so recover from poisoning.
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for RenderedContentCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_RENDER_CACHE_CAPACITY)
+    }
+}
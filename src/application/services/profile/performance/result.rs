@@ -87,12 +87,98 @@ impl IntoResponse for PerformanceContentUpdateResult {
     }
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct PerformanceRenderedResult {
+    pub performance_id: String,
+    pub content_hash: String,
+    pub html: String,
+    pub toc: Vec<TocEntry>,
+    pub word_count: usize,
+    pub reading_time_minutes: usize,
+}
+
+impl IntoResponse for PerformanceRenderedResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
 use crate::domain::entities::profile::performance::performance::Performance;
-use crate::application::services::profile::image::result::ImageResult;
+use crate::domain::entities::profile::performance_category::performance_category::PerformanceCategory;
+use crate::application::services::profile::image::result::{ImageResult, ImageSrcset};
+use crate::shared::utils::markdown_renderer::TocEntry;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedFileResult {
+    /// Path of the markdown file inside the archive
+    pub path: String,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performance_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub images_uploaded: usize,
+    /// Local image links with no matching file in the archive; left unchanged in the content
+    pub missing_images: Vec<String>,
+    /// Problems after the performance was created; it is imported but may lack tags or image usage
+    pub warnings: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportPerformancesResult {
+    pub imported: usize,
+    pub failed: usize,
+    pub files: Vec<ImportedFileResult>,
+}
+
+impl IntoResponse for ImportPerformancesResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PerformanceOrderResult {
+    pub profile_id: String,
+    /// Every performance of the profile in its new order (pinned ones still list first)
+    pub performance_ids: Vec<String>,
+}
+
+impl IntoResponse for PerformanceOrderResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
 
 #[derive(Debug, Clone, Serialize)]
 pub struct PerformanceListResult {
     pub performances: Vec<Performance>,
+    /// Only present when grouping was requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<PerformanceCategoryGroup>>,
+}
+
+/// Performances of one category, referenced by id into `PerformanceListResult::performances`.
+#[derive(Debug, Clone, Serialize)]
+pub struct PerformanceCategoryGroup {
+    pub category_id: String,
+    /// `None` when the performance points at a category that no longer exists
+    pub category: Option<PerformanceCategory>,
+    pub performance_ids: Vec<String>,
 }
 
 impl IntoResponse for PerformanceListResult {
@@ -120,3 +206,95 @@ impl IntoResponse for PerformanceImagesResult {
             .into_response()
     }
 }
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PublicImageResult {
+    pub id: String,
+    pub url: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub mime_type: String,
+    pub alt_text: Option<String>,
+    pub caption: Option<String>,
+    /// Shared only by profiles that keep capture metadata
+    pub captured_at: Option<String>,
+    pub camera_model: Option<String>,
+    /// Placeholders to show while the image loads
+    pub blurhash: Option<String>,
+    pub dominant_color: Option<String>,
+    pub lqip: Option<String>,
+    /// Responsive candidates per format, WebP first
+    pub srcset: Vec<ImageSrcset>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PerformanceNeighbour {
+    pub id: String,
+    pub slug: String,
+    pub title: String,
+    pub start_date: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PublicPerformanceResult {
+    pub performance: Performance,
+    pub slug: String,
+    /// "html" or "markdown", whichever of the two content fields is filled
+    pub format: String,
+    pub content_markdown: Option<String>,
+    pub content_html: Option<String>,
+    pub content_hash: String,
+    pub toc: Vec<TocEntry>,
+    pub word_count: usize,
+    pub reading_time_minutes: usize,
+    pub images: Vec<PublicImageResult>,
+    pub previous: Option<PerformanceNeighbour>,
+    pub next: Option<PerformanceNeighbour>,
+}
+
+impl IntoResponse for PublicPerformanceResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+/// A serialized feed document plus the validators used for conditional GET.
+/// When `not_modified` is set the body is omitted and a 304 is returned.
+#[derive(Debug, Clone)]
+pub struct PerformanceFeedResult {
+    pub content_type: &'static str,
+    pub body: String,
+    pub etag: String,
+    pub last_modified: Option<String>,
+    pub not_modified: bool,
+}
+
+impl IntoResponse for PerformanceFeedResult {
+    fn into_response(self) -> axum::response::Response {
+        use axum::http::{header, StatusCode};
+
+        let mut headers = vec![
+            (header::ETAG, self.etag),
+            (header::CACHE_CONTROL, "public, max-age=300".to_string()),
+        ];
+        if let Some(last_modified) = self.last_modified {
+            headers.push((header::LAST_MODIFIED, last_modified));
+        }
+
+        let mut response = if self.not_modified {
+            StatusCode::NOT_MODIFIED.into_response()
+        } else {
+            (StatusCode::OK, [(header::CONTENT_TYPE, self.content_type)], self.body).into_response()
+        };
+        for (name, value) in headers {
+            if let Ok(value) = value.parse() {
+                response.headers_mut().insert(name, value);
+            }
+        }
+        response
+    }
+}
@@ -1,39 +1,91 @@
+use std::collections::HashSet;
 use async_trait::async_trait;
 use crate::application::errors::{ApplicationError, MapToApplicationError};
 use crate::application::use_cases::use_case::UseCase;
 use crate::application::use_cases::profile::performance::dto::input::{
     CreatePerformanceInput, UpdatePerformanceInput, DeletePerformanceInput,
-    ListPerformancesInput, GetPerformanceImagesInput
+    DuplicatePerformanceInput, ReorderPerformancesInput, ListPerformancesInput, GetPerformanceImagesInput
 };
-use crate::application::services::profile::image::result::{ImageResult, PerformanceUsageInfo};
+use crate::application::services::profile::image::result::ImageResult;
+use crate::application::services::profile::image::service::{find_variants, image_sources, sync_owner_usage};
 use crate::interface_adapters::gateways::repositories::profile::image::image_repository::ImageRepository;
 use crate::interface_adapters::gateways::repositories::profile::performance::performance_repository::PerformanceRepository;
 use crate::interface_adapters::gateways::repositories::profile::performance_content::performance_content_repository::PerformanceContentRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance_category::performance_category_repository::PerformanceCategoryRepository;
+use crate::interface_adapters::gateways::repositories::profile::visibility::visibility_repository::VisibilityRepository;
+use crate::interface_adapters::gateways::repositories::profile::tag::tag_repository::TagRepository;
+use crate::application::services::profile::tag::service::attach_tags;
+use crate::domain::entities::profile::tag::tag::Tag;
+use crate::domain::entities::profile::visibility::visibility::VISIBILITY_PRIVATE;
+use crate::shared::utils::content_analysis::analyze_markdown;
 use super::result::{
     PerformanceResult, PerformanceUpdateResult, PerformanceDeleteResult,
-    PerformanceListResult, PerformanceImagesResult
+    PerformanceListResult, PerformanceImagesResult, PerformanceCategoryGroup, PerformanceOrderResult
 };
 
-pub struct ListPerformancesService<R>
+/// Rejects category / visibility ids that don't exist (categories must also belong to the profile).
+async fn ensure_known_references<K, V>(
+    category_repository: &K,
+    visibility_repository: &V,
+    profile_id: &str,
+    category_id: &str,
+    visibility_id: &str,
+) -> Result<(), ApplicationError>
+where
+    K: PerformanceCategoryRepository,
+    V: VisibilityRepository,
+{
+    let category = category_repository
+        .find_by_id(category_id, profile_id)
+        .await
+        .map_app_err("Failed to fetch performance category")?;
+    if category.is_none() {
+        return Err(ApplicationError::ValidationError {
+            message: format!("Unknown category_id: {}", category_id),
+        });
+    }
+
+    let visibility = visibility_repository
+        .find_by_id(visibility_id)
+        .await
+        .map_app_err("Failed to fetch visibility")?;
+    if visibility.is_none() {
+        return Err(ApplicationError::ValidationError {
+            message: format!("Unknown visibility_id: {}", visibility_id),
+        });
+    }
+
+    Ok(())
+}
+
+pub struct ListPerformancesService<R, K, T>
 where
     R: PerformanceRepository,
+    K: PerformanceCategoryRepository,
+    T: TagRepository,
 {
     repository: R,
+    category_repository: K,
+    tag_repository: T,
 }
 
-impl<R> ListPerformancesService<R>
+impl<R, K, T> ListPerformancesService<R, K, T>
 where
     R: PerformanceRepository,
+    K: PerformanceCategoryRepository,
+    T: TagRepository,
 {
-    pub fn new(repository: R) -> Self {
-        Self { repository }
+    pub fn new(repository: R, category_repository: K, tag_repository: T) -> Self {
+        Self { repository, category_repository, tag_repository }
     }
 }
 
 #[async_trait]
-impl<R> UseCase for ListPerformancesService<R>
+impl<R, K, T> UseCase for ListPerformancesService<R, K, T>
 where
     R: PerformanceRepository + Send + Sync,
+    K: PerformanceCategoryRepository + Send + Sync,
+    T: TagRepository + Send + Sync,
 {
     type Input = ListPerformancesInput;
     type Output = PerformanceListResult;
@@ -42,40 +94,85 @@ where
     async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
         input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
 
-        let performances = self.repository
+        let mut performances = self.repository
             .find_by_profile_id(&input.profile_id, input.visibility_id.as_deref())
             .await
             .map_app_err("Failed to fetch performances")?;
+        attach_tags(&self.tag_repository, &mut performances).await?;
+
+        if !input.tags.is_empty() {
+            let wanted: Vec<String> = input.tags.iter().map(|t| Tag::slug_for(t)).collect();
+            performances.retain(|p| wanted.iter().all(|slug| p.tags.iter().any(|t| &t.slug == slug)));
+        }
+
+        let groups = if input.group_by.as_deref() == Some("category") {
+            let categories = self.category_repository
+                .find_by_profile_id(&input.profile_id)
+                .await
+                .map_app_err("Failed to fetch performance categories")?;
+
+            // Categories in display order, empty ones skipped; unknown ids end up last with no category
+            let mut groups: Vec<PerformanceCategoryGroup> = categories
+                .into_iter()
+                .map(|category| PerformanceCategoryGroup {
+                    category_id: category.id.clone(),
+                    category: Some(category),
+                    performance_ids: vec![],
+                })
+                .collect();
+            for perf in &performances {
+                match groups.iter_mut().find(|g| g.category_id == perf.category_id) {
+                    Some(group) => group.performance_ids.push(perf.id.clone()),
+                    None => groups.push(PerformanceCategoryGroup {
+                        category_id: perf.category_id.clone(),
+                        category: None,
+                        performance_ids: vec![perf.id.clone()],
+                    }),
+                }
+            }
+            groups.retain(|g| !g.performance_ids.is_empty());
+            Some(groups)
+        } else {
+            None
+        };
 
-        Ok(PerformanceListResult { performances })
+        Ok(PerformanceListResult { performances, groups })
     }
 }
 use crate::domain::entities::profile::performance::performance::Performance;
 
-pub struct CreatePerformanceService<R, C>
+pub struct CreatePerformanceService<R, C, K, V>
 where
     R: PerformanceRepository,
     C: PerformanceContentRepository,
+    K: PerformanceCategoryRepository,
+    V: VisibilityRepository,
 {
     repository: R,
     content_repository: C,
+    category_repository: K,
+    visibility_repository: V,
 }
 
-impl<R, C> CreatePerformanceService<R, C>
+impl<R, C, K, V> CreatePerformanceService<R, C, K, V>
 where
     R: PerformanceRepository,
     C: PerformanceContentRepository,
+    K: PerformanceCategoryRepository,
+    V: VisibilityRepository,
 {
-    pub fn new(repository: R, content_repository: C) -> Self {
-        Self { repository, content_repository }
+    pub fn new(repository: R, content_repository: C, category_repository: K, visibility_repository: V) -> Self {
+        Self { repository, content_repository, category_repository, visibility_repository }
     }
 }
 
 #[async_trait]
-impl<R, C> UseCase for CreatePerformanceService<R, C>
+impl<R, C, K, V> UseCase for CreatePerformanceService<R, C, K, V>
 where
     R: PerformanceRepository + Send + Sync,
     C: PerformanceContentRepository + Send + Sync,
+    K: PerformanceCategoryRepository + Send + Sync,
+    V: VisibilityRepository + Send + Sync,
 {
     type Input = CreatePerformanceInput;
     type Output = PerformanceResult;
@@ -83,6 +180,13 @@ where
 
     async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
         input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+        ensure_known_references(
+            &self.category_repository,
+            &self.visibility_repository,
+            &input.profile_id,
+            &input.category_id,
+            &input.visibility_id,
+        ).await?;
 
         let id = uuid::Uuid::new_v4().to_string();
         let created_at = sqlx::types::chrono::Utc::now().format("%Y-%m-%d").to_string();
@@ -93,6 +197,12 @@ where
             .await
             .map_app_err("Failed to create initial performance content")?;
 
+        // Ahead of every existing one, so the new performance lists first
+        let sort_order = self.repository
+            .first_sort_order(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch performance order")?;
+
         let perf = Performance {
             id: id.clone(),
             profile_id: input.profile_id,
@@ -109,6 +219,9 @@ where
             close: false,
             created_at: created_at.clone(),
             updated_at: None,
+            sort_order,
+            pinned: input.pinned,
+            tags: Vec::new(),
         };
 
         let created_perf = self.repository
@@ -126,26 +239,34 @@ where
     }
 }
 
-pub struct UpdatePerformanceService<R>
+pub struct UpdatePerformanceService<R, K, V>
 where
     R: PerformanceRepository,
+    K: PerformanceCategoryRepository,
+    V: VisibilityRepository,
 {
     repository: R,
+    category_repository: K,
+    visibility_repository: V,
 }
 
-impl<R> UpdatePerformanceService<R>
+impl<R, K, V> UpdatePerformanceService<R, K, V>
 where
     R: PerformanceRepository,
+    K: PerformanceCategoryRepository,
+    V: VisibilityRepository,
 {
-    pub fn new(repository: R) -> Self {
-        Self { repository }
+    pub fn new(repository: R, category_repository: K, visibility_repository: V) -> Self {
+        Self { repository, category_repository, visibility_repository }
     }
 }
 
 #[async_trait]
-impl<R> UseCase for UpdatePerformanceService<R>
+impl<R, K, V> UseCase for UpdatePerformanceService<R, K, V>
 where
     R: PerformanceRepository + Send + Sync,
+    K: PerformanceCategoryRepository + Send + Sync,
+    V: VisibilityRepository + Send + Sync,
 {
     type Input = UpdatePerformanceInput;
     type Output = PerformanceUpdateResult;
@@ -153,6 +274,13 @@ where
 
     async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
         input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+        ensure_known_references(
+            &self.category_repository,
+            &self.visibility_repository,
+            &input.profile_id,
+            &input.category_id,
+            &input.visibility_id,
+        ).await?;
 
         let old_perf = self.repository
             .find_by_id(&input.id)
@@ -178,6 +306,9 @@ where
             close: input.close,
             created_at: old_perf.created_at,
             updated_at: Some(updated_at.clone()),
+            sort_order: old_perf.sort_order,
+            pinned: input.pinned.unwrap_or(old_perf.pinned),
+            tags: old_perf.tags,
         };
 
         self.repository
@@ -232,12 +363,7 @@ where
             .await
             .map_app_err("Failed to delete performance content from storage")?;
 
-        // Delete image usage associations first
-        self.repository
-            .delete_image_usage_by_performance_id(&input.id)
-            .await
-            .map_app_err("Failed to delete performance image usage")?;
-
+        // Image usage goes with the row
         self.repository
             .delete(&input.id)
             .await
@@ -250,6 +376,166 @@ where
     }
 }
 
+pub struct DuplicatePerformanceService<R, C, T>
+where
+    R: PerformanceRepository,
+    C: PerformanceContentRepository,
+    T: TagRepository,
+{
+    repository: R,
+    content_repository: C,
+    tag_repository: T,
+}
+
+impl<R, C, T> DuplicatePerformanceService<R, C, T>
+where
+    R: PerformanceRepository,
+    C: PerformanceContentRepository,
+    T: TagRepository,
+{
+    pub fn new(repository: R, content_repository: C, tag_repository: T) -> Self {
+        Self { repository, content_repository, tag_repository }
+    }
+}
+
+#[async_trait]
+impl<R, C, T> UseCase for DuplicatePerformanceService<R, C, T>
+where
+    R: PerformanceRepository + Send + Sync,
+    C: PerformanceContentRepository + Send + Sync,
+    T: TagRepository + Send + Sync,
+{
+    type Input = DuplicatePerformanceInput;
+    type Output = PerformanceResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let source = self.repository
+            .find_by_id(&input.id)
+            .await
+            .map_app_err("Failed to fetch existing performance")?
+            .filter(|p| p.profile_id == input.profile_id)
+            .ok_or_else(|| ApplicationError::NotFound { resource: "Performance", identifier: input.id.clone() })?;
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let created_at = sqlx::types::chrono::Utc::now().format("%Y-%m-%d").to_string();
+
+        let content = self.content_repository
+            .get_content(&input.profile_id, &source.id)
+            .await
+            .map_app_err("Failed to fetch performance content")?;
+        let content_url = self.content_repository
+            .upload_content(&input.profile_id, &id, &content)
+            .await
+            .map_app_err("Failed to copy performance content")?;
+
+        // A copy starts private, it is usually edited before anyone should see it
+        let created_perf = self.repository
+            .create(Performance {
+                id: id.clone(),
+                title: format!("{} (copy)", source.title),
+                visibility_id: VISIBILITY_PRIVATE.to_string(),
+                content_url: Some(content_url),
+                created_at,
+                updated_at: None,
+                pinned: false,
+                tags: Vec::new(),
+                ..source.clone()
+            })
+            .await
+            .map_app_err("Failed to create performance")?;
+
+        // The copy points at the same image files; only the usage rows are new
+        let image_ids = analyze_markdown(&content).image_ids;
+        self.repository
+            .sync_image_usage(&id, &image_ids)
+            .await
+            .map_app_err("Failed to sync image usage")?;
+
+        let mut sources = [source];
+        attach_tags(&self.tag_repository, &mut sources).await?;
+        let tag_ids: Vec<String> = sources[0].tags.iter().map(|t| t.id.clone()).collect();
+        if !tag_ids.is_empty() {
+            self.tag_repository
+                .set_performance_tags(&id, &tag_ids)
+                .await
+                .map_app_err("Failed to copy performance tags")?;
+        }
+
+        Ok(PerformanceResult {
+            id: created_perf.id,
+            title: created_perf.title,
+            content_url: created_perf.content_url,
+            images_tracked: image_ids.iter().collect::<HashSet<_>>().len(),
+            created_at: created_perf.created_at,
+        })
+    }
+}
+
+pub struct ReorderPerformancesService<R>
+where
+    R: PerformanceRepository,
+{
+    repository: R,
+}
+
+impl<R> ReorderPerformancesService<R>
+where
+    R: PerformanceRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for ReorderPerformancesService<R>
+where
+    R: PerformanceRepository + Send + Sync,
+{
+    type Input = ReorderPerformancesInput;
+    type Output = PerformanceOrderResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let current: Vec<String> = self.repository
+            .find_by_profile_id(&input.profile_id, None)
+            .await
+            .map_app_err("Failed to fetch performances")?
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+
+        if let Some(unknown) = input.performance_ids.iter().find(|id| !current.contains(id)) {
+            return Err(ApplicationError::ValidationError {
+                message: format!("Unknown performance id: {}", unknown),
+            });
+        }
+
+        // Every performance gets a position so the order stays total once new ones are added
+        let rest: Vec<String> = current
+            .into_iter()
+            .filter(|id| !input.performance_ids.contains(id))
+            .collect();
+        let mut performance_ids = input.performance_ids;
+        performance_ids.extend(rest);
+
+        self.repository
+            .reorder(&input.profile_id, &performance_ids)
+            .await
+            .map_app_err("Failed to reorder performances")?;
+
+        Ok(PerformanceOrderResult {
+            profile_id: input.profile_id,
+            performance_ids,
+        })
+    }
+}
+
 pub struct GetPerformanceImagesService<R, I>
 where
     R: PerformanceRepository,
@@ -290,17 +576,34 @@ where
                 identifier: input.performance_id.clone(),
             })?;
 
+        // Each image lists all of its owners, not only this performance
+        sync_owner_usage(&self.image_repository, &input.profile_id).await?;
+
         let images_data = self.repository
             .find_images_by_performance_id(&input.performance_id)
             .await
             .map_app_err("Failed to fetch performance images")?;
 
+        let ids: Vec<String> = images_data.iter().map(|img| img.id.clone()).collect();
+        let mut variants = find_variants(&self.image_repository, &ids).await?;
+        let mut tags = self.image_repository
+            .find_tags_by_image_ids(&ids)
+            .await
+            .map_app_err("Failed to fetch image tags")?;
+
         let mut images = Vec::new();
         for img in images_data {
             // Fetch usage and performances for each image to be consistent with ImageResult
             // However, maybe it's better to just get what we need. 
             // find_by_id_and_profile_id returns (Image, total_usage, Vec<ImageUsageInfo>)
-            if let Ok(Some((img, usage, perfs))) = self.image_repository.find_by_id_and_profile_id(&img.id, &input.profile_id).await {
+            if let Ok(Some((img, usage, owners))) = self.image_repository.find_by_id_and_profile_id(&img.id, &input.profile_id).await {
+                 let (variants, srcset) = image_sources(
+                     &img.storage_url,
+                     img.width,
+                     &img.mime_type,
+                     variants.remove(&img.id).unwrap_or_default(),
+                 );
+                 let tags = tags.remove(&img.id).unwrap_or_default();
                  images.push(ImageResult {
                     id: img.id,
                     storage_url: img.storage_url,
@@ -313,14 +616,16 @@ where
                     alt_text: img.alt_text,
                     caption: img.caption,
                     created_at: img.created_at,
+                    captured_at: img.captured_at,
+                    camera_model: img.camera_model,
+                    blurhash: img.blurhash,
+                    dominant_color: img.dominant_color,
+                    lqip: img.lqip,
                     usage_count: Some(usage),
-                    performances: perfs.into_iter().map(|p| PerformanceUsageInfo {
-                        performance_id: p.performance_id,
-                        title: p.title,
-                        usage_count: p.usage_count,
-                        first_used_at: p.first_used_at,
-                        last_used_at: p.last_used_at,
-                    }).collect(),
+                    owners: owners.into_iter().map(Into::into).collect(),
+                    tags,
+                    variants,
+                    srcset,
                 });
             }
         }
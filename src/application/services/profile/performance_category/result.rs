@@ -0,0 +1,50 @@
+use serde::Serialize;
+use axum::response::IntoResponse;
+use crate::domain::entities::profile::performance_category::performance_category::PerformanceCategory;
+use crate::interface_adapters::http::v1::presenters::common::api_response::ApiResponse;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PerformanceCategoryListResult {
+    pub categories: Vec<PerformanceCategory>,
+}
+
+impl IntoResponse for PerformanceCategoryListResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PerformanceCategoryResult {
+    pub category: PerformanceCategory,
+}
+
+impl IntoResponse for PerformanceCategoryResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PerformanceCategoryDeleteResult {
+    pub message: String,
+    pub deleted_id: String,
+}
+
+impl IntoResponse for PerformanceCategoryDeleteResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
@@ -0,0 +1,249 @@
+use async_trait::async_trait;
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::performance_category::dto::input::{
+    ListPerformanceCategoriesInput, CreatePerformanceCategoryInput,
+    UpdatePerformanceCategoryInput, DeletePerformanceCategoryInput
+};
+use crate::domain::entities::profile::performance_category::performance_category::PerformanceCategory;
+use crate::interface_adapters::gateways::repositories::profile::performance_category::performance_category_repository::PerformanceCategoryRepository;
+use super::result::{PerformanceCategoryListResult, PerformanceCategoryResult, PerformanceCategoryDeleteResult};
+
+fn now() -> String {
+    sqlx::types::chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+/// Category names are unique per profile, compared case-insensitively.
+fn ensure_unique_name(
+    existing: &[PerformanceCategory],
+    name: &str,
+    except_id: Option<&str>,
+) -> Result<(), ApplicationError> {
+    let taken = existing
+        .iter()
+        .any(|c| Some(c.id.as_str()) != except_id && c.name.trim().eq_ignore_ascii_case(name.trim()));
+
+    if taken {
+        return Err(ApplicationError::Conflict {
+            message: format!("A category named '{}' already exists", name.trim()),
+        });
+    }
+    Ok(())
+}
+
+pub struct ListPerformanceCategoriesService<R>
+where
+    R: PerformanceCategoryRepository,
+{
+    repository: R,
+}
+
+impl<R> ListPerformanceCategoriesService<R>
+where
+    R: PerformanceCategoryRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for ListPerformanceCategoriesService<R>
+where
+    R: PerformanceCategoryRepository + Send + Sync,
+{
+    type Input = ListPerformanceCategoriesInput;
+    type Output = PerformanceCategoryListResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let categories = self.repository
+            .find_by_profile_id(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch performance categories")?;
+
+        Ok(PerformanceCategoryListResult { categories })
+    }
+}
+
+pub struct CreatePerformanceCategoryService<R>
+where
+    R: PerformanceCategoryRepository,
+{
+    repository: R,
+}
+
+impl<R> CreatePerformanceCategoryService<R>
+where
+    R: PerformanceCategoryRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for CreatePerformanceCategoryService<R>
+where
+    R: PerformanceCategoryRepository + Send + Sync,
+{
+    type Input = CreatePerformanceCategoryInput;
+    type Output = PerformanceCategoryResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let existing = self.repository
+            .find_by_profile_id(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch performance categories")?;
+        ensure_unique_name(&existing, &input.name, None)?;
+
+        // New categories go to the end unless an order is given
+        let sort_order = input.sort_order.unwrap_or_else(|| {
+            existing.iter().map(|c| c.sort_order).max().map_or(0, |max| max + 1)
+        });
+
+        let category = PerformanceCategory {
+            id: uuid::Uuid::new_v4().to_string(),
+            profile_id: input.profile_id,
+            name: input.name.trim().to_string(),
+            icon: input.icon,
+            color: input.color,
+            sort_order,
+            created_at: now(),
+            updated_at: None,
+        };
+
+        let category = self.repository
+            .create(category)
+            .await
+            .map_app_err("Failed to create performance category")?;
+
+        Ok(PerformanceCategoryResult { category })
+    }
+}
+
+pub struct UpdatePerformanceCategoryService<R>
+where
+    R: PerformanceCategoryRepository,
+{
+    repository: R,
+}
+
+impl<R> UpdatePerformanceCategoryService<R>
+where
+    R: PerformanceCategoryRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for UpdatePerformanceCategoryService<R>
+where
+    R: PerformanceCategoryRepository + Send + Sync,
+{
+    type Input = UpdatePerformanceCategoryInput;
+    type Output = PerformanceCategoryResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let existing = self.repository
+            .find_by_profile_id(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch performance categories")?;
+
+        let old_category = existing
+            .iter()
+            .find(|c| c.id == input.id)
+            .cloned()
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "PerformanceCategory",
+                identifier: input.id.clone(),
+            })?;
+        ensure_unique_name(&existing, &input.name, Some(&input.id))?;
+
+        let category = PerformanceCategory {
+            name: input.name.trim().to_string(),
+            icon: input.icon,
+            color: input.color,
+            sort_order: input.sort_order,
+            updated_at: Some(now()),
+            ..old_category
+        };
+
+        let category = self.repository
+            .update(category)
+            .await
+            .map_app_err("Failed to update performance category")?;
+
+        Ok(PerformanceCategoryResult { category })
+    }
+}
+
+pub struct DeletePerformanceCategoryService<R>
+where
+    R: PerformanceCategoryRepository,
+{
+    repository: R,
+}
+
+impl<R> DeletePerformanceCategoryService<R>
+where
+    R: PerformanceCategoryRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for DeletePerformanceCategoryService<R>
+where
+    R: PerformanceCategoryRepository + Send + Sync,
+{
+    type Input = DeletePerformanceCategoryInput;
+    type Output = PerformanceCategoryDeleteResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        self.repository
+            .find_by_id(&input.id, &input.profile_id)
+            .await
+            .map_app_err("Failed to fetch performance category")?
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "PerformanceCategory",
+                identifier: input.id.clone(),
+            })?;
+
+        // Performances must be moved to another category first
+        let in_use = self.repository
+            .count_performances(&input.id)
+            .await
+            .map_app_err("Failed to count performances in category")?;
+        if in_use > 0 {
+            return Err(ApplicationError::Conflict {
+                message: format!("Category is still used by {} performance(s)", in_use),
+            });
+        }
+
+        self.repository
+            .delete(&input.id, &input.profile_id)
+            .await
+            .map_app_err("Failed to delete performance category")?;
+
+        Ok(PerformanceCategoryDeleteResult {
+            message: "Performance category deleted".to_string(),
+            deleted_id: input.id,
+        })
+    }
+}
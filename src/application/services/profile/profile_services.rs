@@ -1,29 +1,75 @@
+use std::sync::Arc;
+use chrono::Duration;
 use crate::application::services::profile::life_status::service::GetCurrentLifeStatusService;
 use crate::application::services::profile::service::GetProfileService;
+use crate::application::services::profile::resume_service::{GetProfileResumeService, GetProfileVCardService};
 use crate::application::services::profile::announce::service::GetAnnounceListService;
+use crate::application::services::profile::image::transform_service::TransformImageService;
+use crate::application::services::profile::image::dedupe_service::MergeDuplicateImagesService;
+use crate::application::services::profile::image::placeholder_service::BackfillImagePlaceholdersService;
+use crate::application::services::profile::image::upload_service::{
+    CreateImageUploadService, AppendImageUploadChunkService, GetImageUploadService, CompleteImageUploadService,
+    CancelImageUploadService, PurgeExpiredImageUploadsService
+};
+use crate::application::services::profile::image::tag_service::{
+    ListImageTagsService, CreateImageTagService, UpdateImageTagService, DeleteImageTagService, SetImageTagsService
+};
 use crate::application::services::profile::image::service::{
     GetImagesService, GetImageService, GetImageUsageService,
     CreateImageService, UpdateImageMetadataService, DeleteImageService, ForceDeleteImageService,
-    GetUnusedImagesService, DeleteUnusedImagesService, TrackImageUsageService, UntrackImageUsageService
+    GetUnusedImagesService, DeleteUnusedImagesService, TrackImageUsageService, UntrackImageUsageService,
+    GetImageSettingsService, UpdateImageSettingsService
 };
 use crate::application::services::profile::performance::service::{
     CreatePerformanceService, UpdatePerformanceService, DeletePerformanceService,
-    ListPerformancesService, GetPerformanceImagesService
+    DuplicatePerformanceService, ReorderPerformancesService, ListPerformancesService, GetPerformanceImagesService
 };
 use crate::application::services::profile::performance::content_service::{
-    GetPerformanceContentService, UpdatePerformanceContentService
+    GetPerformanceContentService, UpdatePerformanceContentService, RenderPerformanceContentService
+};
+use crate::application::services::profile::performance::public_service::GetPublicPerformanceService;
+use crate::application::services::profile::performance::feed_service::GetPerformanceFeedService;
+use crate::application::services::profile::performance::import_service::ImportPerformancesService;
+use crate::application::services::profile::performance::render_cache::RenderedContentCache;
+use crate::application::services::profile::share_link::service::{
+    ShareLinkAccess, CreateShareLinkService, ListShareLinksService, RevokeShareLinkService
+};
+use crate::application::services::profile::archive::service::{ExportProfileArchiveService, RestoreProfileArchiveService};
+use crate::application::services::profile::album::service::{
+    ListAlbumsService, GetAlbumService, CreateAlbumService, UpdateAlbumService, DeleteAlbumService,
+    SetAlbumImagesService, GetPublicAlbumsService, GetPublicAlbumService
+};
+use crate::application::services::profile::storage::service::{ReconcileStorageService, CollectUnusedImagesService};
+use crate::application::services::auth::password_service::PasswordService;
+use crate::application::services::profile::performance_category::service::{
+    ListPerformanceCategoriesService, CreatePerformanceCategoryService,
+    UpdatePerformanceCategoryService, DeletePerformanceCategoryService
 };
+use crate::application::services::profile::visibility::service::{ListVisibilitiesService, UpdateVisibilityService};
+use crate::application::services::profile::tag::service::{
+    ListTagsService, CreateTagService, UpdateTagService, DeleteTagService,
+    SetPerformanceTagsService, GetTagPerformancesService
+};
+use crate::config::config::Config;
 use crate::infrastructure::repositories::Repositories;
 use crate::infrastructure::repository_impl::profile::life_status::repository::LifeStatusRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::announce::repository::AnnounceRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::image::repository::ImageRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::image::storage_repository::GcsImageStorageRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::transform_cache_repository::GcsImageTransformCacheRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::upload_session_repository::ImageUploadSessionRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::performance::repository::PerformanceRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::performance_content::repository::GcsPerformanceContentRepositoryImpl;
-use crate::infrastructure::repository_impl::profile::repository::ProfileRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::performance_category::repository::PerformanceCategoryRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::visibility::repository::VisibilityRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::tag::repository::TagRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::share_link::repository::ShareLinkRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::archive::repository::ProfileArchiveRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::album::repository::AlbumRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::data::repository::ProfileDataRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::skill::repository::SkillRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::social::repository::SocialRepositoryImpl;
+use crate::shared::utils::public_url::PublicUrls;
 
 pub struct ProfileServices {
     pub profile_get_one: GetProfileService<
@@ -32,6 +78,15 @@ pub struct ProfileServices {
         SkillRepositoryImpl,
         SocialRepositoryImpl
     >,
+    pub profile_resume: GetProfileResumeService<
+        ProfileDataRepositoryImpl,
+        SkillRepositoryImpl,
+        SocialRepositoryImpl,
+        PerformanceRepositoryImpl,
+        PerformanceCategoryRepositoryImpl,
+        TagRepositoryImpl
+    >,
+    pub profile_vcard: GetProfileVCardService<ProfileDataRepositoryImpl, SocialRepositoryImpl>,
     pub life_status: GetCurrentLifeStatusService<LifeStatusRepositoryImpl>,
     pub announce: GetAnnounceListService<AnnounceRepositoryImpl>,
     pub image_get_all: GetImagesService<ImageRepositoryImpl>,
@@ -39,23 +94,82 @@ pub struct ProfileServices {
     pub image_get_usage: GetImageUsageService<ImageRepositoryImpl>,
     pub image_create: CreateImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>,
     pub image_update_metadata: UpdateImageMetadataService<ImageRepositoryImpl>,
-    pub image_delete: DeleteImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>,
-    pub image_force_delete: ForceDeleteImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>,
+    pub image_delete: DeleteImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>,
+    pub image_force_delete: ForceDeleteImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>,
     pub image_get_unused: GetUnusedImagesService<ImageRepositoryImpl>,
-    pub image_delete_unused: DeleteUnusedImagesService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>,
+    pub image_delete_unused: DeleteUnusedImagesService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>,
     pub image_track_usage: TrackImageUsageService<ImageRepositoryImpl>,
     pub image_untrack_usage: UntrackImageUsageService<ImageRepositoryImpl>,
-    pub performance_create: CreatePerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl>,
-    pub performance_update: UpdatePerformanceService<PerformanceRepositoryImpl>,
+    pub image_get_settings: GetImageSettingsService<ImageRepositoryImpl>,
+    pub image_update_settings: UpdateImageSettingsService<ImageRepositoryImpl>,
+    pub image_transform: TransformImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>,
+    pub image_merge_duplicates: MergeDuplicateImagesService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl, GcsPerformanceContentRepositoryImpl>,
+    pub image_list_tags: ListImageTagsService<ImageRepositoryImpl>,
+    pub image_create_tag: CreateImageTagService<ImageRepositoryImpl>,
+    pub image_update_tag: UpdateImageTagService<ImageRepositoryImpl>,
+    pub image_delete_tag: DeleteImageTagService<ImageRepositoryImpl>,
+    pub image_set_tags: SetImageTagsService<ImageRepositoryImpl>,
+    pub image_backfill_placeholders: BackfillImagePlaceholdersService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>,
+    pub image_upload_create: CreateImageUploadService<ImageUploadSessionRepositoryImpl>,
+    pub image_upload_append: AppendImageUploadChunkService<ImageUploadSessionRepositoryImpl>,
+    pub image_upload_get: GetImageUploadService<ImageUploadSessionRepositoryImpl>,
+    pub image_upload_complete: CompleteImageUploadService<ImageUploadSessionRepositoryImpl, ImageRepositoryImpl, GcsImageStorageRepositoryImpl>,
+    pub image_upload_cancel: CancelImageUploadService<ImageUploadSessionRepositoryImpl>,
+    pub image_upload_purge_expired: PurgeExpiredImageUploadsService<ImageUploadSessionRepositoryImpl>,
+    pub performance_create: CreatePerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, PerformanceCategoryRepositoryImpl, VisibilityRepositoryImpl>,
+    pub performance_update: UpdatePerformanceService<PerformanceRepositoryImpl, PerformanceCategoryRepositoryImpl, VisibilityRepositoryImpl>,
     pub performance_delete: DeletePerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl>,
+    pub performance_duplicate: DuplicatePerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, TagRepositoryImpl>,
     pub performance_get_content: GetPerformanceContentService<GcsPerformanceContentRepositoryImpl>,
     pub performance_update_content: UpdatePerformanceContentService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl>,
-    pub performance_get_all: ListPerformancesService<PerformanceRepositoryImpl>,
+    pub performance_render_content: RenderPerformanceContentService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, ShareLinkRepositoryImpl>,
+    pub performance_get_public: GetPublicPerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, TagRepositoryImpl, ShareLinkRepositoryImpl, ImageRepositoryImpl>,
+    pub performance_feed: GetPerformanceFeedService<PerformanceRepositoryImpl, ProfileDataRepositoryImpl, GcsPerformanceContentRepositoryImpl, TagRepositoryImpl>,
+    pub performance_import: ImportPerformancesService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, PerformanceCategoryRepositoryImpl, VisibilityRepositoryImpl, TagRepositoryImpl, ImageRepositoryImpl, GcsImageStorageRepositoryImpl>,
+    pub performance_reorder: ReorderPerformancesService<PerformanceRepositoryImpl>,
+    pub performance_get_all: ListPerformancesService<PerformanceRepositoryImpl, PerformanceCategoryRepositoryImpl, TagRepositoryImpl>,
     pub performance_get_images: GetPerformanceImagesService<PerformanceRepositoryImpl, ImageRepositoryImpl>,
+    pub performance_category_get_all: ListPerformanceCategoriesService<PerformanceCategoryRepositoryImpl>,
+    pub performance_category_create: CreatePerformanceCategoryService<PerformanceCategoryRepositoryImpl>,
+    pub performance_category_update: UpdatePerformanceCategoryService<PerformanceCategoryRepositoryImpl>,
+    pub performance_category_delete: DeletePerformanceCategoryService<PerformanceCategoryRepositoryImpl>,
+    pub visibility_get_all: ListVisibilitiesService<VisibilityRepositoryImpl>,
+    pub visibility_update: UpdateVisibilityService<VisibilityRepositoryImpl>,
+    pub tag_get_all: ListTagsService<TagRepositoryImpl>,
+    pub tag_create: CreateTagService<TagRepositoryImpl>,
+    pub tag_update: UpdateTagService<TagRepositoryImpl>,
+    pub tag_delete: DeleteTagService<TagRepositoryImpl>,
+    pub tag_set_performance_tags: SetPerformanceTagsService<PerformanceRepositoryImpl, TagRepositoryImpl>,
+    pub tag_get_performances: GetTagPerformancesService<PerformanceRepositoryImpl, TagRepositoryImpl>,
+    pub share_link_create: CreateShareLinkService<PerformanceRepositoryImpl, ShareLinkRepositoryImpl>,
+    pub share_link_get_all: ListShareLinksService<PerformanceRepositoryImpl, ShareLinkRepositoryImpl>,
+    pub share_link_revoke: RevokeShareLinkService<ShareLinkRepositoryImpl>,
+    pub archive_export: ExportProfileArchiveService<ProfileArchiveRepositoryImpl, GcsPerformanceContentRepositoryImpl, GcsImageStorageRepositoryImpl>,
+    pub archive_restore: RestoreProfileArchiveService<ProfileArchiveRepositoryImpl, GcsPerformanceContentRepositoryImpl, GcsImageStorageRepositoryImpl, ImageRepositoryImpl>,
+    pub album_get_all: ListAlbumsService<AlbumRepositoryImpl>,
+    pub album_get_one: GetAlbumService<AlbumRepositoryImpl, ImageRepositoryImpl>,
+    pub album_create: CreateAlbumService<AlbumRepositoryImpl, ImageRepositoryImpl>,
+    pub album_update: UpdateAlbumService<AlbumRepositoryImpl, ImageRepositoryImpl>,
+    pub album_delete: DeleteAlbumService<AlbumRepositoryImpl>,
+    pub album_set_images: SetAlbumImagesService<AlbumRepositoryImpl, ImageRepositoryImpl>,
+    pub album_get_public_all: GetPublicAlbumsService<AlbumRepositoryImpl>,
+    pub album_get_public: GetPublicAlbumService<AlbumRepositoryImpl, ImageRepositoryImpl>,
+    pub storage_reconcile: ReconcileStorageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl, PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl>,
+    pub storage_collect_unused_images: CollectUnusedImagesService<ProfileDataRepositoryImpl, ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>,
 }
 
 impl ProfileServices {
-    pub fn new(repos: &Repositories) -> Self {
+    pub fn new(repos: &Repositories, config: &Config) -> Self {
+        let render_cache = RenderedContentCache::default();
+        let public_urls = PublicUrls::new(&config.public_site_url, &config.public_api_url);
+        let password_service = Arc::new(PasswordService::new(
+            config.argon2_salt.clone(),
+            config.argon2_memory_cost,
+            config.argon2_iterations,
+            config.argon2_parallelism,
+        ));
+        let upload_session_ttl = Duration::seconds(config.image_upload_session_ttl_secs);
+
         Self {
             profile_get_one: GetProfileService::new(
                 repos.profile.profile_data.clone(),
@@ -63,26 +177,206 @@ impl ProfileServices {
                 repos.profile.skill.clone(),
                 repos.profile.social.clone(),
             ),
+            profile_resume: GetProfileResumeService::new(
+                repos.profile.profile_data.clone(),
+                repos.profile.skill.clone(),
+                repos.profile.social.clone(),
+                repos.profile.performance.clone(),
+                repos.profile.performance_category.clone(),
+                repos.profile.tag.clone(),
+                public_urls.clone(),
+            ),
+            profile_vcard: GetProfileVCardService::new(
+                repos.profile.profile_data.clone(),
+                repos.profile.social.clone(),
+                public_urls.clone(),
+            ),
             life_status: GetCurrentLifeStatusService::new(repos.profile.life_status.clone()),
             announce: GetAnnounceListService::new(repos.profile.announce.clone()),
             image_get_all: GetImagesService::new(repos.profile.image.clone()),
             image_get_one: GetImageService::new(repos.profile.image.clone()),
             image_get_usage: GetImageUsageService::new(repos.profile.image.clone()),
-            image_create: CreateImageService::new(repos.profile.image.clone(), repos.profile.image_storage.clone()),
+            image_create: CreateImageService::new(
+                repos.profile.image.clone(),
+                repos.profile.image_storage.clone(),
+                config.image_variant_widths.clone(),
+                config.image_upload_limits,
+            ),
             image_update_metadata: UpdateImageMetadataService::new(repos.profile.image.clone()),
-            image_delete: DeleteImageService::new(repos.profile.image.clone(), repos.profile.image_storage.clone()),
-            image_force_delete: ForceDeleteImageService::new(repos.profile.image.clone(), repos.profile.image_storage.clone()),
+            image_delete: DeleteImageService::new(
+                repos.profile.image.clone(),
+                repos.profile.image_storage.clone(),
+                repos.profile.image_transform_cache.clone(),
+            ),
+            image_force_delete: ForceDeleteImageService::new(
+                repos.profile.image.clone(),
+                repos.profile.image_storage.clone(),
+                repos.profile.image_transform_cache.clone(),
+            ),
             image_get_unused: GetUnusedImagesService::new(repos.profile.image.clone()),
-            image_delete_unused: DeleteUnusedImagesService::new(repos.profile.image.clone(), repos.profile.image_storage.clone()),
+            image_delete_unused: DeleteUnusedImagesService::new(
+                repos.profile.image.clone(),
+                repos.profile.image_storage.clone(),
+                repos.profile.image_transform_cache.clone(),
+            ),
             image_track_usage: TrackImageUsageService::new(repos.profile.image.clone()),
             image_untrack_usage: UntrackImageUsageService::new(repos.profile.image.clone()),
-            performance_create: CreatePerformanceService::new(repos.profile.performance.clone(), repos.profile.performance_content.clone()),
-            performance_update: UpdatePerformanceService::new(repos.profile.performance.clone()),
+            image_get_settings: GetImageSettingsService::new(repos.profile.image.clone()),
+            image_update_settings: UpdateImageSettingsService::new(repos.profile.image.clone()),
+            image_transform: TransformImageService::new(
+                repos.profile.image.clone(),
+                repos.profile.image_storage.clone(),
+                repos.profile.image_transform_cache.clone(),
+            ),
+            image_merge_duplicates: MergeDuplicateImagesService::new(
+                repos.profile.image.clone(),
+                repos.profile.image_storage.clone(),
+                repos.profile.image_transform_cache.clone(),
+                repos.profile.performance_content.clone(),
+            ),
+            image_list_tags: ListImageTagsService::new(repos.profile.image.clone()),
+            image_create_tag: CreateImageTagService::new(repos.profile.image.clone()),
+            image_update_tag: UpdateImageTagService::new(repos.profile.image.clone()),
+            image_delete_tag: DeleteImageTagService::new(repos.profile.image.clone()),
+            image_set_tags: SetImageTagsService::new(repos.profile.image.clone()),
+            image_backfill_placeholders: BackfillImagePlaceholdersService::new(
+                repos.profile.image.clone(),
+                repos.profile.image_storage.clone(),
+            ),
+            image_upload_create: CreateImageUploadService::new(
+                repos.profile.image_upload_session.clone(),
+                config.image_upload_limits.max_bytes,
+                upload_session_ttl,
+            ),
+            image_upload_append: AppendImageUploadChunkService::new(repos.profile.image_upload_session.clone(), upload_session_ttl),
+            image_upload_get: GetImageUploadService::new(repos.profile.image_upload_session.clone()),
+            image_upload_complete: CompleteImageUploadService::new(
+                repos.profile.image_upload_session.clone(),
+                CreateImageService::new(
+                    repos.profile.image.clone(),
+                    repos.profile.image_storage.clone(),
+                    config.image_variant_widths.clone(),
+                    config.image_upload_limits,
+                ),
+            ),
+            image_upload_cancel: CancelImageUploadService::new(repos.profile.image_upload_session.clone()),
+            image_upload_purge_expired: PurgeExpiredImageUploadsService::new(repos.profile.image_upload_session.clone()),
+            performance_create: CreatePerformanceService::new(
+                repos.profile.performance.clone(),
+                repos.profile.performance_content.clone(),
+                repos.profile.performance_category.clone(),
+                repos.profile.visibility.clone(),
+            ),
+            performance_update: UpdatePerformanceService::new(
+                repos.profile.performance.clone(),
+                repos.profile.performance_category.clone(),
+                repos.profile.visibility.clone(),
+            ),
             performance_delete: DeletePerformanceService::new(repos.profile.performance.clone(), repos.profile.performance_content.clone()),
+            performance_duplicate: DuplicatePerformanceService::new(
+                repos.profile.performance.clone(),
+                repos.profile.performance_content.clone(),
+                repos.profile.tag.clone(),
+            ),
             performance_get_content: GetPerformanceContentService::new((), repos.profile.performance_content.clone()),
             performance_update_content: UpdatePerformanceContentService::new(repos.profile.performance.clone(), repos.profile.performance_content.clone()),
-            performance_get_all: ListPerformancesService::new(repos.profile.performance.clone()),
+            performance_render_content: RenderPerformanceContentService::new(
+                repos.profile.performance.clone(),
+                repos.profile.performance_content.clone(),
+                ShareLinkAccess::new(repos.profile.share_link.clone(), password_service.clone()),
+                render_cache.clone(),
+            ),
+            performance_get_public: GetPublicPerformanceService::new(
+                repos.profile.performance.clone(),
+                repos.profile.performance_content.clone(),
+                repos.profile.tag.clone(),
+                ShareLinkAccess::new(repos.profile.share_link.clone(), password_service.clone()),
+                repos.profile.image.clone(),
+                render_cache.clone(),
+            ),
+            performance_feed: GetPerformanceFeedService::new(
+                repos.profile.performance.clone(),
+                repos.profile.profile_data.clone(),
+                repos.profile.performance_content.clone(),
+                repos.profile.tag.clone(),
+                render_cache.clone(),
+                public_urls.clone(),
+            ),
+            performance_import: ImportPerformancesService::new(
+                repos.profile.performance.clone(),
+                repos.profile.performance_content.clone(),
+                repos.profile.performance_category.clone(),
+                repos.profile.visibility.clone(),
+                repos.profile.tag.clone(),
+                CreateImageService::new(
+                    repos.profile.image.clone(),
+                    repos.profile.image_storage.clone(),
+                    config.image_variant_widths.clone(),
+                config.image_upload_limits,
+                ),
+            ),
+            performance_reorder: ReorderPerformancesService::new(repos.profile.performance.clone()),
+            performance_get_all: ListPerformancesService::new(
+                repos.profile.performance.clone(),
+                repos.profile.performance_category.clone(),
+                repos.profile.tag.clone(),
+            ),
             performance_get_images: GetPerformanceImagesService::new(repos.profile.performance.clone(), repos.profile.image.clone()),
+            performance_category_get_all: ListPerformanceCategoriesService::new(repos.profile.performance_category.clone()),
+            performance_category_create: CreatePerformanceCategoryService::new(repos.profile.performance_category.clone()),
+            performance_category_update: UpdatePerformanceCategoryService::new(repos.profile.performance_category.clone()),
+            performance_category_delete: DeletePerformanceCategoryService::new(repos.profile.performance_category.clone()),
+            visibility_get_all: ListVisibilitiesService::new(repos.profile.visibility.clone()),
+            visibility_update: UpdateVisibilityService::new(repos.profile.visibility.clone()),
+            tag_get_all: ListTagsService::new(repos.profile.tag.clone()),
+            tag_create: CreateTagService::new(repos.profile.tag.clone()),
+            tag_update: UpdateTagService::new(repos.profile.tag.clone()),
+            tag_delete: DeleteTagService::new(repos.profile.tag.clone()),
+            tag_set_performance_tags: SetPerformanceTagsService::new(repos.profile.performance.clone(), repos.profile.tag.clone()),
+            tag_get_performances: GetTagPerformancesService::new(repos.profile.performance.clone(), repos.profile.tag.clone()),
+            share_link_create: CreateShareLinkService::new(
+                repos.profile.performance.clone(),
+                repos.profile.share_link.clone(),
+                password_service.clone(),
+                public_urls.clone(),
+            ),
+            share_link_get_all: ListShareLinksService::new(repos.profile.performance.clone(), repos.profile.share_link.clone()),
+            share_link_revoke: RevokeShareLinkService::new(repos.profile.share_link.clone()),
+            archive_export: ExportProfileArchiveService::new(
+                repos.profile.archive.clone(),
+                repos.profile.performance_content.clone(),
+                repos.profile.image_storage.clone(),
+            ),
+            archive_restore: RestoreProfileArchiveService::new(
+                repos.profile.archive.clone(),
+                repos.profile.performance_content.clone(),
+                repos.profile.image_storage.clone(),
+                repos.profile.image.clone(),
+                config.image_upload_limits,
+            ),
+            album_get_all: ListAlbumsService::new(repos.profile.album.clone()),
+            album_get_one: GetAlbumService::new(repos.profile.album.clone(), repos.profile.image.clone()),
+            album_create: CreateAlbumService::new(repos.profile.album.clone(), repos.profile.image.clone()),
+            album_update: UpdateAlbumService::new(repos.profile.album.clone(), repos.profile.image.clone()),
+            album_delete: DeleteAlbumService::new(repos.profile.album.clone()),
+            album_set_images: SetAlbumImagesService::new(repos.profile.album.clone(), repos.profile.image.clone()),
+            album_get_public_all: GetPublicAlbumsService::new(repos.profile.album.clone()),
+            album_get_public: GetPublicAlbumService::new(repos.profile.album.clone(), repos.profile.image.clone()),
+            storage_reconcile: ReconcileStorageService::new(
+                repos.profile.image.clone(),
+                repos.profile.image_storage.clone(),
+                repos.profile.image_transform_cache.clone(),
+                repos.profile.performance.clone(),
+                repos.profile.performance_content.clone(),
+            ),
+            storage_collect_unused_images: CollectUnusedImagesService::new(
+                repos.profile.profile_data.clone(),
+                DeleteUnusedImagesService::new(
+                    repos.profile.image.clone(),
+                    repos.profile.image_storage.clone(),
+                    repos.profile.image_transform_cache.clone(),
+                ),
+            ),
         }
     }
 }
@@ -5,6 +5,7 @@ use crate::domain::entities::profile::profile::Profile;
 use crate::domain::entities::profile::announce::announce::Announce;
 use crate::domain::entities::profile::skill::skill::Skill;
 use crate::domain::entities::profile::social::social::Social;
+use crate::shared::utils::json_resume::Resume;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ProfileResult {
@@ -23,3 +24,46 @@ impl IntoResponse for ProfileResult {
             .into_response()
     }
 }
+
+/// A JSON Resume document, served as-is rather than wrapped in `ApiResponse`
+/// so resume tooling can consume it directly.
+#[derive(Debug, Clone)]
+pub struct ProfileResumeResult {
+    pub resume: Resume,
+}
+
+impl IntoResponse for ProfileResumeResult {
+    fn into_response(self) -> axum::response::Response {
+        use axum::http::{header, StatusCode};
+
+        (
+            StatusCode::OK,
+            [(header::CACHE_CONTROL, "public, max-age=300")],
+            axum::Json(self.resume),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProfileVCardResult {
+    pub filename: String,
+    pub body: String,
+}
+
+impl IntoResponse for ProfileVCardResult {
+    fn into_response(self) -> axum::response::Response {
+        use axum::http::{header, StatusCode};
+
+        (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/vcard; charset=utf-8".to_string()),
+                (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", self.filename)),
+                (header::CACHE_CONTROL, "public, max-age=300".to_string()),
+            ],
+            self.body,
+        )
+            .into_response()
+    }
+}
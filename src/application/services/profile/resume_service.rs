@@ -0,0 +1,267 @@
+use async_trait::async_trait;
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::profile::dto::input::{GetProfileResumeInput, GetProfileVCardInput};
+use crate::application::services::profile::tag::service::attach_tags;
+use crate::domain::entities::profile::performance_category::performance_category::PerformanceCategory;
+use crate::domain::entities::profile::profile::Profile;
+use crate::domain::entities::profile::visibility::visibility::VISIBILITY_PUBLIC;
+use crate::interface_adapters::gateways::repositories::profile::profile_repository::ProfileRepository;
+use crate::interface_adapters::gateways::repositories::profile::skill::skill_repository::SkillRepository;
+use crate::interface_adapters::gateways::repositories::profile::social::social_repository::SocialRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance::performance_repository::PerformanceRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance_category::performance_category_repository::PerformanceCategoryRepository;
+use crate::interface_adapters::gateways::repositories::profile::tag::tag_repository::TagRepository;
+use crate::shared::utils::json_resume::{
+    skill_level, social_username, Resume, ResumeBasics, ResumeMeta, ResumeProfile, ResumeProject, ResumeSkill, ResumeWork,
+    JSON_RESUME_SCHEMA, JSON_RESUME_VERSION
+};
+use crate::shared::utils::public_url::PublicUrls;
+use crate::shared::utils::vcard::{render_vcard, VCard};
+use super::result::{ProfileResumeResult, ProfileVCardResult};
+
+/// Category names treated as employment when the caller doesn't pick work categories.
+const WORK_CATEGORY_WORDS: [&str; 5] = ["work", "experience", "employment", "job", "career"];
+
+async fn find_profile<P>(repository: &P, profile_id: &str) -> Result<Profile, ApplicationError>
+where
+    P: ProfileRepository,
+{
+    repository
+        .find_by_id(profile_id)
+        .await
+        .map_app_err("Failed to fetch profile")?
+        .ok_or_else(|| ApplicationError::NotFound {
+            resource: "Profile",
+            identifier: profile_id.to_string(),
+        })
+}
+
+fn is_work_category(category: &PerformanceCategory, work_categories: &[String]) -> bool {
+    if work_categories.is_empty() {
+        let name = category.name.to_lowercase();
+        return WORK_CATEGORY_WORDS.iter().any(|word| name.contains(word));
+    }
+    work_categories
+        .iter()
+        .any(|c| c == &category.id || c.eq_ignore_ascii_case(&category.name))
+}
+
+pub struct GetProfileResumeService<P, SK, SO, R, K, T>
+where
+    P: ProfileRepository,
+    SK: SkillRepository,
+    SO: SocialRepository,
+    R: PerformanceRepository,
+    K: PerformanceCategoryRepository,
+    T: TagRepository,
+{
+    profile_repository: P,
+    skill_repository: SK,
+    social_repository: SO,
+    performance_repository: R,
+    category_repository: K,
+    tag_repository: T,
+    urls: PublicUrls,
+}
+
+impl<P, SK, SO, R, K, T> GetProfileResumeService<P, SK, SO, R, K, T>
+where
+    P: ProfileRepository,
+    SK: SkillRepository,
+    SO: SocialRepository,
+    R: PerformanceRepository,
+    K: PerformanceCategoryRepository,
+    T: TagRepository,
+{
+    pub fn new(
+        profile_repository: P,
+        skill_repository: SK,
+        social_repository: SO,
+        performance_repository: R,
+        category_repository: K,
+        tag_repository: T,
+        urls: PublicUrls,
+    ) -> Self {
+        Self {
+            profile_repository,
+            skill_repository,
+            social_repository,
+            performance_repository,
+            category_repository,
+            tag_repository,
+            urls,
+        }
+    }
+}
+
+#[async_trait]
+impl<P, SK, SO, R, K, T> UseCase for GetProfileResumeService<P, SK, SO, R, K, T>
+where
+    P: ProfileRepository + Send + Sync,
+    SK: SkillRepository + Send + Sync,
+    SO: SocialRepository + Send + Sync,
+    R: PerformanceRepository + Send + Sync,
+    K: PerformanceCategoryRepository + Send + Sync,
+    T: TagRepository + Send + Sync,
+{
+    type Input = GetProfileResumeInput;
+    type Output = ProfileResumeResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let profile = find_profile(&self.profile_repository, &input.profile_id).await?;
+
+        let skills = self.skill_repository
+            .find_by_profile_id(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch skills")?;
+
+        let socials = self.social_repository
+            .find_by_profile_id(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch socials")?;
+
+        let categories = self.category_repository
+            .find_by_profile_id(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch performance categories")?;
+
+        let mut performances = self.performance_repository
+            .find_by_profile_id(&input.profile_id, Some(VISIBILITY_PUBLIC))
+            .await
+            .map_app_err("Failed to fetch performances")?;
+        attach_tags(&self.tag_repository, &mut performances).await?;
+
+        let mut work = Vec::new();
+        let mut projects = Vec::new();
+        for perf in performances {
+            let Some(category) = categories.iter().find(|c| c.id == perf.category_id) else {
+                continue;
+            };
+            let url = self.urls.performance_page(&perf.profile_id, &perf.slug());
+            let summary = perf.summary.clone()
+                .filter(|s| !s.trim().is_empty())
+                .or_else(|| perf.content_preview.clone().filter(|p| !p.trim().is_empty()));
+
+            if is_work_category(category, &input.work_categories) {
+                work.push(ResumeWork {
+                    name: perf.title,
+                    location: perf.location,
+                    url,
+                    start_date: perf.start_date,
+                    end_date: perf.end_date,
+                    summary,
+                    highlights: Vec::new(),
+                });
+            } else {
+                projects.push(ResumeProject {
+                    name: perf.title,
+                    description: summary,
+                    url,
+                    start_date: perf.start_date,
+                    end_date: perf.end_date,
+                    keywords: perf.tags.into_iter().map(|t| t.name).collect(),
+                    kind: category.name.clone(),
+                });
+            }
+        }
+
+        let resume = Resume {
+            schema: JSON_RESUME_SCHEMA,
+            basics: ResumeBasics {
+                url: self.urls.profile_page(&profile.id),
+                name: profile.display_name,
+                label: profile.headline,
+                image: profile.avatar_url,
+                email: profile.contact_email.filter(|e| !e.trim().is_empty()),
+                summary: profile.bio,
+                profiles: socials
+                    .into_iter()
+                    .map(|s| ResumeProfile {
+                        username: social_username(&s.link),
+                        network: s.name,
+                        url: s.link,
+                    })
+                    .collect(),
+            },
+            work,
+            projects,
+            skills: skills
+                .into_iter()
+                .map(|s| ResumeSkill {
+                    name: s.name,
+                    level: Some(skill_level(s.scale_value)),
+                    keywords: vec![s.skill_type],
+                })
+                .collect(),
+            meta: ResumeMeta {
+                canonical: self.urls.api(&format!("/profiles/{}/resume.json", profile.id)),
+                version: JSON_RESUME_VERSION,
+                last_modified: profile.updated_at.or(Some(profile.created_at)),
+            },
+        };
+
+        Ok(ProfileResumeResult { resume })
+    }
+}
+
+pub struct GetProfileVCardService<P, SO>
+where
+    P: ProfileRepository,
+    SO: SocialRepository,
+{
+    profile_repository: P,
+    social_repository: SO,
+    urls: PublicUrls,
+}
+
+impl<P, SO> GetProfileVCardService<P, SO>
+where
+    P: ProfileRepository,
+    SO: SocialRepository,
+{
+    pub fn new(profile_repository: P, social_repository: SO, urls: PublicUrls) -> Self {
+        Self { profile_repository, social_repository, urls }
+    }
+}
+
+#[async_trait]
+impl<P, SO> UseCase for GetProfileVCardService<P, SO>
+where
+    P: ProfileRepository + Send + Sync,
+    SO: SocialRepository + Send + Sync,
+{
+    type Input = GetProfileVCardInput;
+    type Output = ProfileVCardResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let profile = find_profile(&self.profile_repository, &input.profile_id).await?;
+
+        let socials = self.social_repository
+            .find_by_profile_id(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch socials")?;
+
+        let card = VCard {
+            url: Some(self.urls.profile_page(&profile.id)),
+            title: profile.headline,
+            email: profile.contact_email,
+            photo_url: profile.avatar_url,
+            note: profile.bio,
+            social_links: socials.into_iter().map(|s| (s.name, s.link)).collect(),
+            revision: profile.updated_at.or(Some(profile.created_at)),
+            full_name: profile.display_name,
+        };
+
+        Ok(ProfileVCardResult {
+            filename: format!("{}.vcf", profile.id),
+            body: render_vcard(&card),
+        })
+    }
+}
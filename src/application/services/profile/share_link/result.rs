@@ -0,0 +1,70 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use axum::response::IntoResponse;
+use crate::domain::entities::profile::share_link::share_link::ShareLink;
+use crate::interface_adapters::http::v1::presenters::common::api_response::ApiResponse;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ShareLinkResult {
+    #[serde(flatten)]
+    pub link: ShareLink,
+    pub has_password: bool,
+    /// Neither expired nor revoked
+    pub active: bool,
+}
+
+impl ShareLinkResult {
+    pub fn from_link(link: ShareLink, now: DateTime<Utc>) -> Self {
+        Self {
+            has_password: link.has_password(),
+            active: link.is_active(now),
+            link,
+        }
+    }
+}
+
+impl IntoResponse for ShareLinkResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+/// Returned once, on creation: the token cannot be recovered afterwards.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatedShareLinkResult {
+    #[serde(flatten)]
+    pub link: ShareLinkResult,
+    pub token: String,
+    /// Website URL carrying the token
+    pub url: String,
+}
+
+impl IntoResponse for CreatedShareLinkResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ShareLinkListResult {
+    pub performance_id: String,
+    pub links: Vec<ShareLinkResult>,
+}
+
+impl IntoResponse for ShareLinkListResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
@@ -0,0 +1,259 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::services::auth::password_service::PasswordService;
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::share_link::dto::input::{
+    CreateShareLinkInput, ListShareLinksInput, RevokeShareLinkInput, DEFAULT_EXPIRES_IN_HOURS
+};
+use crate::domain::entities::profile::performance::performance::Performance;
+use crate::domain::entities::profile::share_link::share_link::ShareLink;
+use crate::interface_adapters::gateways::repositories::profile::performance::performance_repository::PerformanceRepository;
+use crate::interface_adapters::gateways::repositories::profile::share_link::share_link_repository::ShareLinkRepository;
+use crate::shared::utils::hash::sha256_hex;
+use crate::shared::utils::public_url::PublicUrls;
+use super::result::{ShareLinkResult, ShareLinkListResult, CreatedShareLinkResult};
+
+/// Resolves share tokens presented on public endpoints.
+pub struct ShareLinkAccess<S>
+where
+    S: ShareLinkRepository,
+{
+    repository: S,
+    password_service: Arc<PasswordService>,
+}
+
+impl<S> ShareLinkAccess<S>
+where
+    S: ShareLinkRepository,
+{
+    pub fn new(repository: S, password_service: Arc<PasswordService>) -> Self {
+        Self { repository, password_service }
+    }
+
+    /// Returns the link behind `token` without counting a visit; call `record_access` once the caller
+    /// has checked the link is for the performance asked for.
+    /// Unknown, expired and revoked tokens are `Forbidden`; a missing or wrong password is `Unauthorized`.
+    pub async fn verify(&self, token: &str, password: Option<&str>) -> Result<ShareLink, ApplicationError> {
+        let now = Utc::now();
+        let link = self.repository
+            .find_by_token_hash(&sha256_hex(token.trim().as_bytes()))
+            .await
+            .map_app_err("Failed to fetch share link")?
+            .filter(|link| link.is_active(now))
+            .ok_or(ApplicationError::Forbidden)?;
+
+        if let Some(password_hash) = &link.password_hash {
+            let password = password.ok_or(ApplicationError::Unauthorized)?;
+            if !self.password_service.verify_password(password, password_hash)? {
+                return Err(ApplicationError::Unauthorized);
+            }
+        }
+
+        Ok(link)
+    }
+
+    /// Counts a visit through `link`.
+    pub async fn record_access(&self, link: &ShareLink) -> Result<(), ApplicationError> {
+        self.repository
+            .record_access(&link.id, Utc::now())
+            .await
+            .map_app_err("Failed to record share link access")
+    }
+}
+
+/// Loads a performance and makes sure it belongs to the profile.
+async fn find_owned_performance<R>(repository: &R, profile_id: &str, performance_id: &str) -> Result<Performance, ApplicationError>
+where
+    R: PerformanceRepository,
+{
+    repository
+        .find_by_id(performance_id)
+        .await
+        .map_app_err("Failed to fetch performance")?
+        .filter(|p| p.profile_id == profile_id)
+        .ok_or_else(|| ApplicationError::NotFound {
+            resource: "Performance",
+            identifier: performance_id.to_string(),
+        })
+}
+
+pub struct CreateShareLinkService<R, S>
+where
+    R: PerformanceRepository,
+    S: ShareLinkRepository,
+{
+    performance_repository: R,
+    repository: S,
+    password_service: Arc<PasswordService>,
+    urls: PublicUrls,
+}
+
+impl<R, S> CreateShareLinkService<R, S>
+where
+    R: PerformanceRepository,
+    S: ShareLinkRepository,
+{
+    pub fn new(performance_repository: R, repository: S, password_service: Arc<PasswordService>, urls: PublicUrls) -> Self {
+        Self { performance_repository, repository, password_service, urls }
+    }
+}
+
+#[async_trait]
+impl<R, S> UseCase for CreateShareLinkService<R, S>
+where
+    R: PerformanceRepository + Send + Sync,
+    S: ShareLinkRepository + Send + Sync,
+{
+    type Input = CreateShareLinkInput;
+    type Output = CreatedShareLinkResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let perf = find_owned_performance(&self.performance_repository, &input.profile_id, &input.performance_id).await?;
+
+        // 256 random bits; only the hash is stored
+        let token = format!("{}{}", uuid::Uuid::new_v4().simple(), uuid::Uuid::new_v4().simple());
+        let password_hash = input.password
+            .as_deref()
+            .map(|password| self.password_service.hash_password(password))
+            .transpose()?;
+
+        let now = Utc::now();
+        let link = ShareLink {
+            id: uuid::Uuid::new_v4().to_string(),
+            profile_id: input.profile_id,
+            performance_id: input.performance_id,
+            token_hash: sha256_hex(token.as_bytes()),
+            password_hash,
+            label: input.label.map(|l| l.trim().to_string()).filter(|l| !l.is_empty()),
+            expires_at: now + Duration::hours(input.expires_in_hours.unwrap_or(DEFAULT_EXPIRES_IN_HOURS)),
+            access_count: 0,
+            last_accessed_at: None,
+            revoked_at: None,
+            created_at: now,
+        };
+
+        let link = self.repository
+            .create(link)
+            .await
+            .map_app_err("Failed to create share link")?;
+
+        let url = format!("{}?share={}", self.urls.performance_page(&perf.profile_id, &perf.slug()), token);
+
+        Ok(CreatedShareLinkResult {
+            link: ShareLinkResult::from_link(link, now),
+            token,
+            url,
+        })
+    }
+}
+
+pub struct ListShareLinksService<R, S>
+where
+    R: PerformanceRepository,
+    S: ShareLinkRepository,
+{
+    performance_repository: R,
+    repository: S,
+}
+
+impl<R, S> ListShareLinksService<R, S>
+where
+    R: PerformanceRepository,
+    S: ShareLinkRepository,
+{
+    pub fn new(performance_repository: R, repository: S) -> Self {
+        Self { performance_repository, repository }
+    }
+}
+
+#[async_trait]
+impl<R, S> UseCase for ListShareLinksService<R, S>
+where
+    R: PerformanceRepository + Send + Sync,
+    S: ShareLinkRepository + Send + Sync,
+{
+    type Input = ListShareLinksInput;
+    type Output = ShareLinkListResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        find_owned_performance(&self.performance_repository, &input.profile_id, &input.performance_id).await?;
+
+        let now = Utc::now();
+        let links = self.repository
+            .find_by_performance_id(&input.performance_id)
+            .await
+            .map_app_err("Failed to fetch share links")?
+            .into_iter()
+            .map(|link| ShareLinkResult::from_link(link, now))
+            .collect();
+
+        Ok(ShareLinkListResult {
+            performance_id: input.performance_id,
+            links,
+        })
+    }
+}
+
+pub struct RevokeShareLinkService<S>
+where
+    S: ShareLinkRepository,
+{
+    repository: S,
+}
+
+impl<S> RevokeShareLinkService<S>
+where
+    S: ShareLinkRepository,
+{
+    pub fn new(repository: S) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<S> UseCase for RevokeShareLinkService<S>
+where
+    S: ShareLinkRepository + Send + Sync,
+{
+    type Input = RevokeShareLinkInput;
+    type Output = ShareLinkResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let not_found = || ApplicationError::NotFound {
+            resource: "Share link",
+            identifier: input.id.clone(),
+        };
+
+        self.repository
+            .find_by_id(&input.id, &input.profile_id)
+            .await
+            .map_app_err("Failed to fetch share link")?
+            .filter(|link| link.performance_id == input.performance_id)
+            .ok_or_else(not_found)?;
+
+        let now = Utc::now();
+        self.repository
+            .revoke(&input.id, &input.profile_id, now)
+            .await
+            .map_app_err("Failed to revoke share link")?;
+
+        let link = self.repository
+            .find_by_id(&input.id, &input.profile_id)
+            .await
+            .map_app_err("Failed to fetch share link")?
+            .ok_or_else(not_found)?;
+
+        Ok(ShareLinkResult::from_link(link, now))
+    }
+}
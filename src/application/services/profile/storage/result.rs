@@ -0,0 +1,53 @@
+use serde::Serialize;
+use axum::response::IntoResponse;
+use crate::interface_adapters::http::v1::presenters::common::api_response::ApiResponse;
+
+/// A stored file no database row points at.
+#[derive(Debug, Clone, Serialize)]
+pub struct OrphanFile {
+    pub name: String,
+    pub size: i64,
+}
+
+/// An image row whose original file is gone from storage.
+#[derive(Debug, Clone, Serialize)]
+pub struct MissingImageFile {
+    pub image_id: String,
+    pub filename: String,
+    /// Used by a performance or an album, so it is kept for the owner to replace
+    pub in_use: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StorageReconciliationResult {
+    pub dry_run: bool,
+    /// Files under `performance_image/` without an image or variant row
+    pub orphan_image_files: Vec<OrphanFile>,
+    /// Image rows without their original file
+    pub missing_image_files: Vec<MissingImageFile>,
+    /// Performance ids with a stored `content.md` but no performance row
+    pub orphan_contents: Vec<OrphanFile>,
+    /// Performances without a stored `content.md`, reported only
+    pub missing_contents: Vec<String>,
+    pub files_deleted: i32,
+    pub image_rows_deleted: i32,
+    /// Files and rows that could not be deleted; they show up again on the next run
+    pub failed: Vec<String>,
+}
+
+impl IntoResponse for StorageReconciliationResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnusedImagesCollectionResult {
+    pub profiles: i32,
+    pub deleted_count: i64,
+    pub freed_bytes: i64,
+}
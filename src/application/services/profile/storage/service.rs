@@ -0,0 +1,263 @@
+use std::collections::HashSet;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::image::dto::input::DeleteUnusedImagesInput;
+use crate::application::use_cases::profile::storage::dto::input::{CollectUnusedImagesInput, ReconcileStorageInput};
+use crate::application::services::profile::image::service::{
+    delete_transform_objects, delete_variant_objects, sync_owner_usage, DeleteUnusedImagesService
+};
+use crate::interface_adapters::gateways::common::stored_object::StoredObject;
+use crate::interface_adapters::gateways::repositories::profile::image::image_repository::ImageRepository;
+use crate::interface_adapters::gateways::repositories::profile::image::image_storage_repository::ImageStorageRepository;
+use crate::interface_adapters::gateways::repositories::profile::image::image_transform_cache_repository::ImageTransformCacheRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance::performance_repository::PerformanceRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance_content::performance_content_repository::PerformanceContentRepository;
+use crate::interface_adapters::gateways::repositories::profile::profile_repository::ProfileRepository;
+use super::result::{MissingImageFile, OrphanFile, StorageReconciliationResult, UnusedImagesCollectionResult};
+
+/// Uploads store the file before the row, so a fresh file without a row may just be mid-upload.
+const ORPHAN_MIN_AGE_MINUTES: i64 = 60;
+
+fn is_settled(object: &StoredObject, cutoff: DateTime<Utc>) -> bool {
+    object.created_at.is_none_or(|created_at| created_at < cutoff)
+}
+
+/// Compares what storage holds for a profile with the `image` and `performance` tables and
+/// reports the drift on both sides. Outside a dry run, files without a row are deleted, and so
+/// are unused image rows whose original is gone. Used images and performances missing their
+/// content are only reported, removing them would break published pages.
+pub struct ReconcileStorageService<R, S, T, P, C>
+where
+    R: ImageRepository,
+    S: ImageStorageRepository,
+    T: ImageTransformCacheRepository,
+    P: PerformanceRepository,
+    C: PerformanceContentRepository,
+{
+    image_repository: R,
+    storage_repository: S,
+    transform_cache: T,
+    performance_repository: P,
+    content_repository: C,
+}
+
+impl<R, S, T, P, C> ReconcileStorageService<R, S, T, P, C>
+where
+    R: ImageRepository,
+    S: ImageStorageRepository,
+    T: ImageTransformCacheRepository,
+    P: PerformanceRepository,
+    C: PerformanceContentRepository,
+{
+    pub fn new(image_repository: R, storage_repository: S, transform_cache: T, performance_repository: P, content_repository: C) -> Self {
+        Self { image_repository, storage_repository, transform_cache, performance_repository, content_repository }
+    }
+}
+
+#[async_trait]
+impl<R, S, T, P, C> UseCase for ReconcileStorageService<R, S, T, P, C>
+where
+    R: ImageRepository + Send + Sync,
+    S: ImageStorageRepository + Send + Sync,
+    T: ImageTransformCacheRepository + Send + Sync,
+    P: PerformanceRepository + Send + Sync,
+    C: PerformanceContentRepository + Send + Sync,
+{
+    type Input = ReconcileStorageInput;
+    type Output = StorageReconciliationResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+        let profile_id = input.profile_id.as_str();
+        let cutoff = Utc::now() - Duration::minutes(ORPHAN_MIN_AGE_MINUTES);
+
+        // 1. Image files against image and variant rows
+        let images = self.image_repository
+            .find_by_profile_id(profile_id)
+            .await
+            .map_app_err("Failed to fetch images")?;
+        let image_ids: Vec<String> = images.iter().map(|image| image.id.clone()).collect();
+        let variants = self.image_repository
+            .find_variants_by_image_ids(&image_ids)
+            .await
+            .map_app_err("Failed to fetch image variants")?;
+        let stored_images = self.storage_repository
+            .list_images(profile_id)
+            .await
+            .map_app_err("Failed to list stored images")?;
+
+        let known_files: HashSet<&str> = images.iter().map(|image| image.filename.as_str())
+            .chain(variants.iter().map(|variant| variant.filename.as_str()))
+            .collect();
+        let stored_files: HashSet<&str> = stored_images.iter().map(|object| object.name.as_str()).collect();
+
+        let orphan_image_files: Vec<OrphanFile> = stored_images
+            .iter()
+            .filter(|object| !known_files.contains(object.name.as_str()) && is_settled(object, cutoff))
+            .map(|object| OrphanFile { name: object.name.clone(), size: object.size })
+            .collect();
+
+        sync_owner_usage(&self.image_repository, profile_id).await?;
+        let unused_ids: HashSet<String> = self.image_repository
+            .find_unused_by_profile_id(profile_id, 0)
+            .await
+            .map_app_err("Failed to fetch unused images")?
+            .into_iter()
+            .map(|image| image.id)
+            .collect();
+        let missing_image_files: Vec<MissingImageFile> = images
+            .iter()
+            .filter(|image| !stored_files.contains(image.filename.as_str()))
+            .map(|image| MissingImageFile {
+                image_id: image.id.clone(),
+                filename: image.filename.clone(),
+                in_use: !unused_ids.contains(&image.id),
+            })
+            .collect();
+
+        // 2. Content files against performance rows
+        let performances = self.performance_repository
+            .find_by_profile_id(profile_id, None)
+            .await
+            .map_app_err("Failed to fetch performances")?;
+        let stored_contents = self.content_repository
+            .list_contents(profile_id)
+            .await
+            .map_app_err("Failed to list stored performance contents")?;
+
+        let performance_ids: HashSet<&str> = performances.iter().map(|p| p.id.as_str()).collect();
+        let content_ids: HashSet<&str> = stored_contents.iter().map(|object| object.name.as_str()).collect();
+
+        let orphan_contents: Vec<OrphanFile> = stored_contents
+            .iter()
+            .filter(|object| !performance_ids.contains(object.name.as_str()) && is_settled(object, cutoff))
+            .map(|object| OrphanFile { name: object.name.clone(), size: object.size })
+            .collect();
+        let missing_contents: Vec<String> = performances
+            .iter()
+            .filter(|p| !content_ids.contains(p.id.as_str()))
+            .map(|p| p.id.clone())
+            .collect();
+
+        // 3. Apply
+        let mut files_deleted = 0;
+        let mut image_rows_deleted = 0;
+        let mut failed = Vec::new();
+        if !input.dry_run {
+            for file in &orphan_image_files {
+                match self.storage_repository.delete_image(profile_id, &file.name).await {
+                    Ok(()) => files_deleted += 1,
+                    Err(e) => {
+                        tracing::warn!("Failed to delete orphan image file {}: {:?}", file.name, e);
+                        failed.push(format!("performance_image/{}/{}", profile_id, file.name));
+                    }
+                }
+            }
+
+            for content in &orphan_contents {
+                match self.content_repository.delete_content(profile_id, &content.name).await {
+                    Ok(()) => files_deleted += 1,
+                    Err(e) => {
+                        tracing::warn!("Failed to delete orphan content of performance {}: {:?}", content.name, e);
+                        failed.push(format!("performance_content/{}/{}/content.md", profile_id, content.name));
+                    }
+                }
+            }
+
+            for missing in missing_image_files.iter().filter(|missing| !missing.in_use) {
+                let ids = std::slice::from_ref(&missing.image_id);
+                delete_variant_objects(&self.image_repository, &self.storage_repository, profile_id, ids).await;
+                delete_transform_objects(&self.transform_cache, profile_id, ids).await;
+                match self.image_repository.delete(&missing.image_id, profile_id).await {
+                    Ok(()) => image_rows_deleted += 1,
+                    Err(e) => {
+                        tracing::warn!("Failed to delete image {} without a file: {:?}", missing.image_id, e);
+                        failed.push(format!("image {}", missing.image_id));
+                    }
+                }
+            }
+        }
+
+        Ok(StorageReconciliationResult {
+            dry_run: input.dry_run,
+            orphan_image_files,
+            missing_image_files,
+            orphan_contents,
+            missing_contents,
+            files_deleted,
+            image_rows_deleted,
+            failed,
+        })
+    }
+}
+
+/// Runs the unused image cleanup for every profile, for the scheduled garbage collection.
+/// A profile that fails is logged and skipped so the others still get cleaned.
+pub struct CollectUnusedImagesService<P, R, S, T>
+where
+    P: ProfileRepository,
+    R: ImageRepository,
+    S: ImageStorageRepository,
+    T: ImageTransformCacheRepository,
+{
+    profile_repository: P,
+    delete_unused: DeleteUnusedImagesService<R, S, T>,
+}
+
+impl<P, R, S, T> CollectUnusedImagesService<P, R, S, T>
+where
+    P: ProfileRepository,
+    R: ImageRepository,
+    S: ImageStorageRepository,
+    T: ImageTransformCacheRepository,
+{
+    pub fn new(profile_repository: P, delete_unused: DeleteUnusedImagesService<R, S, T>) -> Self {
+        Self { profile_repository, delete_unused }
+    }
+}
+
+#[async_trait]
+impl<P, R, S, T> UseCase for CollectUnusedImagesService<P, R, S, T>
+where
+    P: ProfileRepository + Send + Sync,
+    R: ImageRepository + Send + Sync,
+    S: ImageStorageRepository + Send + Sync,
+    T: ImageTransformCacheRepository + Send + Sync,
+{
+    type Input = CollectUnusedImagesInput;
+    type Output = UnusedImagesCollectionResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let profiles = self.profile_repository
+            .find_all()
+            .await
+            .map_app_err("Failed to fetch profiles")?;
+
+        let mut result = UnusedImagesCollectionResult { profiles: 0, deleted_count: 0, freed_bytes: 0 };
+        for profile in profiles {
+            let deleted = self.delete_unused
+                .execute(DeleteUnusedImagesInput {
+                    profile_id: profile.id.clone(),
+                    days_old: input.days_old,
+                    confirm: true,
+                })
+                .await;
+            match deleted {
+                Ok(deleted) => {
+                    result.profiles += 1;
+                    result.deleted_count += deleted.deleted_count;
+                    result.freed_bytes += deleted.freed_bytes;
+                }
+                Err(e) => tracing::warn!("Failed to delete unused images of profile {}: {}", profile.id, e),
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,83 @@
+use serde::Serialize;
+use axum::response::IntoResponse;
+use crate::domain::entities::profile::performance::performance::Performance;
+use crate::domain::entities::profile::tag::tag::{Tag, TagCount};
+use crate::interface_adapters::http::v1::presenters::common::api_response::ApiResponse;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TagListResult {
+    pub tags: Vec<TagCount>,
+}
+
+impl IntoResponse for TagListResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TagResult {
+    pub tag: Tag,
+}
+
+impl IntoResponse for TagResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TagDeleteResult {
+    pub message: String,
+    pub deleted_id: String,
+}
+
+impl IntoResponse for TagDeleteResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PerformanceTagsResult {
+    pub performance_id: String,
+    pub tags: Vec<Tag>,
+}
+
+impl IntoResponse for PerformanceTagsResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TagPerformancesResult {
+    pub tag: Tag,
+    pub performances: Vec<Performance>,
+}
+
+impl IntoResponse for TagPerformancesResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
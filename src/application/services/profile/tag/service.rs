@@ -0,0 +1,388 @@
+use async_trait::async_trait;
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::tag::dto::input::{
+    ListTagsInput, CreateTagInput, UpdateTagInput, DeleteTagInput,
+    SetPerformanceTagsInput, GetTagPerformancesInput
+};
+use crate::domain::entities::profile::performance::performance::Performance;
+use crate::domain::entities::profile::tag::tag::Tag;
+use crate::domain::entities::profile::visibility::visibility::VISIBILITY_PUBLIC;
+use crate::interface_adapters::gateways::repositories::profile::performance::performance_repository::PerformanceRepository;
+use crate::interface_adapters::gateways::repositories::profile::tag::tag_repository::TagRepository;
+use super::result::{TagListResult, TagResult, TagDeleteResult, PerformanceTagsResult, TagPerformancesResult};
+
+fn now() -> String {
+    sqlx::types::chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+/// Fills `Performance::tags` for every performance in one query.
+pub(crate) async fn attach_tags<T>(repository: &T, performances: &mut [Performance]) -> Result<(), ApplicationError>
+where
+    T: TagRepository,
+{
+    let ids: Vec<String> = performances.iter().map(|p| p.id.clone()).collect();
+    let mut tags = repository
+        .find_by_performance_ids(&ids)
+        .await
+        .map_app_err("Failed to fetch performance tags")?;
+
+    for perf in performances.iter_mut() {
+        perf.tags = tags.remove(&perf.id).unwrap_or_default();
+    }
+    Ok(())
+}
+
+/// Maps tag names to tags, creating the ones the profile doesn't have yet.
+/// Names are matched by slug, so "rust" reuses an existing "Rust"; repeats are dropped.
+pub(crate) async fn resolve_tags<T>(repository: &T, profile_id: &str, names: &[String]) -> Result<Vec<Tag>, ApplicationError>
+where
+    T: TagRepository,
+{
+    let mut tags: Vec<Tag> = Vec::with_capacity(names.len());
+    for name in names {
+        let slug = Tag::slug_for(name);
+        if tags.iter().any(|t| t.slug == slug) {
+            continue;
+        }
+
+        let existing = repository
+            .find_by_slug(profile_id, &slug)
+            .await
+            .map_app_err("Failed to fetch tag")?;
+        let tag = match existing {
+            Some(tag) => tag,
+            None => repository
+                .create(Tag {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    profile_id: profile_id.to_string(),
+                    name: name.trim().to_string(),
+                    slug,
+                    created_at: now(),
+                })
+                .await
+                .map_app_err("Failed to create tag")?,
+        };
+        tags.push(tag);
+    }
+    Ok(tags)
+}
+
+async fn ensure_slug_free<T>(repository: &T, profile_id: &str, slug: &str, except_id: Option<&str>) -> Result<(), ApplicationError>
+where
+    T: TagRepository,
+{
+    let existing = repository
+        .find_by_slug(profile_id, slug)
+        .await
+        .map_app_err("Failed to fetch tag")?;
+
+    match existing {
+        Some(tag) if Some(tag.id.as_str()) != except_id => Err(ApplicationError::Conflict {
+            message: format!("Tag '{}' already exists", tag.name),
+        }),
+        _ => Ok(()),
+    }
+}
+
+pub struct ListTagsService<T>
+where
+    T: TagRepository,
+{
+    repository: T,
+}
+
+impl<T> ListTagsService<T>
+where
+    T: TagRepository,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> UseCase for ListTagsService<T>
+where
+    T: TagRepository + Send + Sync,
+{
+    type Input = ListTagsInput;
+    type Output = TagListResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let prefix = input.prefix
+            .as_deref()
+            .map(str::trim)
+            .filter(|p| !p.is_empty());
+
+        let tags = self.repository
+            .find_by_profile_id(&input.profile_id, prefix, input.limit)
+            .await
+            .map_app_err("Failed to fetch tags")?;
+
+        Ok(TagListResult { tags })
+    }
+}
+
+pub struct CreateTagService<T>
+where
+    T: TagRepository,
+{
+    repository: T,
+}
+
+impl<T> CreateTagService<T>
+where
+    T: TagRepository,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> UseCase for CreateTagService<T>
+where
+    T: TagRepository + Send + Sync,
+{
+    type Input = CreateTagInput;
+    type Output = TagResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let slug = Tag::slug_for(&input.name);
+        ensure_slug_free(&self.repository, &input.profile_id, &slug, None).await?;
+
+        let tag = self.repository
+            .create(Tag {
+                id: uuid::Uuid::new_v4().to_string(),
+                profile_id: input.profile_id,
+                name: input.name.trim().to_string(),
+                slug,
+                created_at: now(),
+            })
+            .await
+            .map_app_err("Failed to create tag")?;
+
+        Ok(TagResult { tag })
+    }
+}
+
+pub struct UpdateTagService<T>
+where
+    T: TagRepository,
+{
+    repository: T,
+}
+
+impl<T> UpdateTagService<T>
+where
+    T: TagRepository,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> UseCase for UpdateTagService<T>
+where
+    T: TagRepository + Send + Sync,
+{
+    type Input = UpdateTagInput;
+    type Output = TagResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let old_tag = self.repository
+            .find_by_id(&input.id, &input.profile_id)
+            .await
+            .map_app_err("Failed to fetch tag")?
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "Tag",
+                identifier: input.id.clone(),
+            })?;
+
+        // Renaming changes the slug, so tag page URLs follow the new name
+        let slug = Tag::slug_for(&input.name);
+        ensure_slug_free(&self.repository, &input.profile_id, &slug, Some(&input.id)).await?;
+
+        let tag = self.repository
+            .update(Tag {
+                name: input.name.trim().to_string(),
+                slug,
+                ..old_tag
+            })
+            .await
+            .map_app_err("Failed to update tag")?;
+
+        Ok(TagResult { tag })
+    }
+}
+
+pub struct DeleteTagService<T>
+where
+    T: TagRepository,
+{
+    repository: T,
+}
+
+impl<T> DeleteTagService<T>
+where
+    T: TagRepository,
+{
+    pub fn new(repository: T) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<T> UseCase for DeleteTagService<T>
+where
+    T: TagRepository + Send + Sync,
+{
+    type Input = DeleteTagInput;
+    type Output = TagDeleteResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        self.repository
+            .find_by_id(&input.id, &input.profile_id)
+            .await
+            .map_app_err("Failed to fetch tag")?
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "Tag",
+                identifier: input.id.clone(),
+            })?;
+
+        // Unlike categories, tags are optional metadata: deleting one just detaches it
+        self.repository
+            .delete(&input.id, &input.profile_id)
+            .await
+            .map_app_err("Failed to delete tag")?;
+
+        Ok(TagDeleteResult {
+            message: "Tag deleted".to_string(),
+            deleted_id: input.id,
+        })
+    }
+}
+
+pub struct SetPerformanceTagsService<R, T>
+where
+    R: PerformanceRepository,
+    T: TagRepository,
+{
+    performance_repository: R,
+    repository: T,
+}
+
+impl<R, T> SetPerformanceTagsService<R, T>
+where
+    R: PerformanceRepository,
+    T: TagRepository,
+{
+    pub fn new(performance_repository: R, repository: T) -> Self {
+        Self { performance_repository, repository }
+    }
+}
+
+#[async_trait]
+impl<R, T> UseCase for SetPerformanceTagsService<R, T>
+where
+    R: PerformanceRepository + Send + Sync,
+    T: TagRepository + Send + Sync,
+{
+    type Input = SetPerformanceTagsInput;
+    type Output = PerformanceTagsResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        self.performance_repository
+            .find_by_id(&input.performance_id)
+            .await
+            .map_app_err("Failed to fetch performance")?
+            .filter(|p| p.profile_id == input.profile_id)
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "Performance",
+                identifier: input.performance_id.clone(),
+            })?;
+
+        let tags = resolve_tags(&self.repository, &input.profile_id, &input.tags).await?;
+
+        let tag_ids: Vec<String> = tags.iter().map(|t| t.id.clone()).collect();
+        self.repository
+            .set_performance_tags(&input.performance_id, &tag_ids)
+            .await
+            .map_app_err("Failed to update performance tags")?;
+
+        Ok(PerformanceTagsResult {
+            performance_id: input.performance_id,
+            tags,
+        })
+    }
+}
+
+pub struct GetTagPerformancesService<R, T>
+where
+    R: PerformanceRepository,
+    T: TagRepository,
+{
+    performance_repository: R,
+    repository: T,
+}
+
+impl<R, T> GetTagPerformancesService<R, T>
+where
+    R: PerformanceRepository,
+    T: TagRepository,
+{
+    pub fn new(performance_repository: R, repository: T) -> Self {
+        Self { performance_repository, repository }
+    }
+}
+
+#[async_trait]
+impl<R, T> UseCase for GetTagPerformancesService<R, T>
+where
+    R: PerformanceRepository + Send + Sync,
+    T: TagRepository + Send + Sync,
+{
+    type Input = GetTagPerformancesInput;
+    type Output = TagPerformancesResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        // Accept the display name as well ("C#" finds "c-sharp")
+        let tag = self.repository
+            .find_by_slug(&input.profile_id, &Tag::slug_for(&input.tag))
+            .await
+            .map_app_err("Failed to fetch tag")?
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "Tag",
+                identifier: input.tag.clone(),
+            })?;
+
+        let mut performances = self.performance_repository
+            .find_by_profile_id(&input.profile_id, Some(VISIBILITY_PUBLIC))
+            .await
+            .map_app_err("Failed to fetch performances")?;
+        attach_tags(&self.repository, &mut performances).await?;
+        performances.retain(|p| p.tags.iter().any(|t| t.id == tag.id));
+
+        Ok(TagPerformancesResult { tag, performances })
+    }
+}
@@ -0,0 +1,34 @@
+use serde::Serialize;
+use axum::response::IntoResponse;
+use crate::domain::entities::profile::visibility::visibility::Visibility;
+use crate::interface_adapters::http::v1::presenters::common::api_response::ApiResponse;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VisibilityListResult {
+    pub visibilities: Vec<Visibility>,
+}
+
+impl IntoResponse for VisibilityListResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VisibilityResult {
+    pub visibility: Visibility,
+}
+
+impl IntoResponse for VisibilityResult {
+    fn into_response(self) -> axum::response::Response {
+        (
+            axum::http::StatusCode::OK,
+            axum::Json(ApiResponse::success(self)),
+        )
+            .into_response()
+    }
+}
@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::profile::visibility::dto::input::{ListVisibilitiesInput, UpdateVisibilityInput};
+use crate::domain::entities::profile::visibility::visibility::Visibility;
+use crate::interface_adapters::gateways::repositories::profile::visibility::visibility_repository::VisibilityRepository;
+use super::result::{VisibilityListResult, VisibilityResult};
+
+pub struct ListVisibilitiesService<R>
+where
+    R: VisibilityRepository,
+{
+    repository: R,
+}
+
+impl<R> ListVisibilitiesService<R>
+where
+    R: VisibilityRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for ListVisibilitiesService<R>
+where
+    R: VisibilityRepository + Send + Sync,
+{
+    type Input = ListVisibilitiesInput;
+    type Output = VisibilityListResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, _input: Self::Input) -> Result<Self::Output, Self::Error> {
+        let visibilities = self.repository
+            .find_all()
+            .await
+            .map_app_err("Failed to fetch visibilities")?;
+
+        Ok(VisibilityListResult { visibilities })
+    }
+}
+
+pub struct UpdateVisibilityService<R>
+where
+    R: VisibilityRepository,
+{
+    repository: R,
+}
+
+impl<R> UpdateVisibilityService<R>
+where
+    R: VisibilityRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for UpdateVisibilityService<R>
+where
+    R: VisibilityRepository + Send + Sync,
+{
+    type Input = UpdateVisibilityInput;
+    type Output = VisibilityResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        self.repository
+            .find_by_id(&input.id)
+            .await
+            .map_app_err("Failed to fetch visibility")?
+            .ok_or_else(|| ApplicationError::NotFound {
+                resource: "Visibility",
+                identifier: input.id.clone(),
+            })?;
+
+        let visibility = self.repository
+            .update(Visibility {
+                id: input.id,
+                name: input.name.trim().to_string(),
+                description: input.description,
+                sort_order: input.sort_order,
+            })
+            .await
+            .map_app_err("Failed to update visibility")?;
+
+        Ok(VisibilityResult { visibility })
+    }
+}
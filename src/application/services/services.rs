@@ -12,8 +12,8 @@ pub struct Services {
 impl Services {
     pub fn new(infra: Infrastructure) -> Self {
         Self {
-            profile: ProfileServices::new(&infra.repositories),
-            website: WebsiteServices::new(&infra.repositories),
+            profile: ProfileServices::new(&infra.repositories, &infra.config),
+            website: WebsiteServices::new(&infra.repositories, &infra.config),
             auth: AuthServices::new(&infra.repositories, &infra.config),
         }
     }
@@ -1,3 +1,4 @@
 pub mod website_services;
 pub mod feature_status;
 pub mod config;
+pub mod seo;
@@ -0,0 +1,292 @@
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::application::seo::dto::input::{
+    GetPerformanceMetadataInput, GetProfileMetadataInput, PageMetadataFormat
+};
+use crate::application::services::profile::tag::service::attach_tags;
+use crate::application::services::website::seo::result::PageMetadataResult;
+use crate::domain::entities::profile::performance::performance::Performance;
+use crate::domain::entities::profile::profile::Profile;
+use crate::domain::entities::profile::visibility::visibility::VISIBILITY_PUBLIC;
+use crate::interface_adapters::gateways::repositories::profile::performance::performance_repository::PerformanceRepository;
+use crate::interface_adapters::gateways::repositories::profile::profile_repository::ProfileRepository;
+use crate::interface_adapters::gateways::repositories::profile::social::social_repository::SocialRepository;
+use crate::interface_adapters::gateways::repositories::profile::tag::tag_repository::TagRepository;
+use crate::shared::utils::content_analysis::excerpt;
+use crate::shared::utils::og_card::{OG_CARD_HEIGHT, OG_CARD_WIDTH};
+use crate::shared::utils::public_url::PublicUrls;
+use crate::shared::utils::seo_meta::{render_head, render_html_shell, MetaTag, PageMeta, META_DESCRIPTION_MAX_CHARS};
+
+pub(super) async fn find_profile<P>(repository: &P, profile_id: &str) -> Result<Profile, ApplicationError>
+where
+    P: ProfileRepository,
+{
+    repository
+        .find_by_id(profile_id)
+        .await
+        .map_app_err("Failed to fetch profile")?
+        .ok_or_else(|| ApplicationError::NotFound {
+            resource: "Profile",
+            identifier: profile_id.to_string(),
+        })
+}
+
+/// Same resolution as the public performance page: exact id first, then the chronologically first slug match.
+pub(super) async fn find_public_performance<R>(
+    repository: &R,
+    profile_id: &str,
+    slug_or_id: &str,
+) -> Result<Performance, ApplicationError>
+where
+    R: PerformanceRepository,
+{
+    let mut performances = repository
+        .find_by_profile_id(profile_id, Some(VISIBILITY_PUBLIC))
+        .await
+        .map_app_err("Failed to fetch performances")?;
+    performances.sort_by(|a, b| a.chronological_key().cmp(&b.chronological_key()));
+    let position = performances.iter()
+        .position(|p| p.id == slug_or_id)
+        .or_else(|| performances.iter().position(|p| p.slug() == slug_or_id))
+        .ok_or_else(|| ApplicationError::NotFound {
+            resource: "Performance",
+            identifier: slug_or_id.to_string(),
+        })?;
+    Ok(performances.swap_remove(position))
+}
+
+/// Stable API path of a performance's OpenGraph card; the id keeps it valid across title changes.
+fn og_card_path(profile_id: &str, performance_id: &str) -> String {
+    format!("/seo/profiles/{}/performances/{}/og.png", profile_id, performance_id)
+}
+
+fn description(text: Option<&str>) -> Option<String> {
+    text.map(|t| excerpt(t, META_DESCRIPTION_MAX_CHARS)).filter(|t| !t.is_empty())
+}
+
+/// OpenGraph tags followed by their Twitter card counterparts.
+fn social_tags(og_type: &str, title: &str, description: Option<&str>, url: &str, image: Option<&str>) -> Vec<MetaTag> {
+    let mut tags = vec![
+        MetaTag::property("og:type", og_type),
+        MetaTag::property("og:title", title),
+        MetaTag::property("og:url", url),
+    ];
+    if let Some(description) = description {
+        tags.push(MetaTag::property("og:description", description));
+    }
+    if let Some(image) = image {
+        tags.push(MetaTag::property("og:image", image));
+    }
+
+    let card = if og_type == "article" && image.is_some() { "summary_large_image" } else { "summary" };
+    tags.push(MetaTag::name("twitter:card", card));
+    tags.push(MetaTag::name("twitter:title", title));
+    if let Some(description) = description {
+        tags.push(MetaTag::name("twitter:description", description));
+    }
+    if let Some(image) = image {
+        tags.push(MetaTag::name("twitter:image", image));
+    }
+    tags
+}
+
+fn page_result(
+    format: PageMetadataFormat,
+    page: PageMeta,
+    description: Option<String>,
+    image_url: Option<String>,
+) -> PageMetadataResult {
+    PageMetadataResult {
+        format,
+        head_html: render_head(&page),
+        html_shell: render_html_shell(&page),
+        title: page.title,
+        description,
+        canonical_url: page.canonical_url,
+        image_url,
+        meta: page.tags,
+        json_ld: page.json_ld,
+    }
+}
+
+/// Drops `null` members so optional fields don't show up in the JSON-LD.
+fn without_nulls(mut value: Value) -> Value {
+    if let Value::Object(map) = &mut value {
+        map.retain(|_, v| !v.is_null());
+        for v in map.values_mut() {
+            *v = without_nulls(v.take());
+        }
+    }
+    value
+}
+
+pub struct GetProfileMetadataService<P, SO>
+where
+    P: ProfileRepository,
+    SO: SocialRepository,
+{
+    profile_repository: P,
+    social_repository: SO,
+    urls: PublicUrls,
+}
+
+impl<P, SO> GetProfileMetadataService<P, SO>
+where
+    P: ProfileRepository,
+    SO: SocialRepository,
+{
+    pub fn new(profile_repository: P, social_repository: SO, urls: PublicUrls) -> Self {
+        Self { profile_repository, social_repository, urls }
+    }
+}
+
+#[async_trait]
+impl<P, SO> UseCase for GetProfileMetadataService<P, SO>
+where
+    P: ProfileRepository + Send + Sync,
+    SO: SocialRepository + Send + Sync,
+{
+    type Input = GetProfileMetadataInput;
+    type Output = PageMetadataResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let profile = find_profile(&self.profile_repository, &input.profile_id).await?;
+        let socials = self.social_repository
+            .find_by_profile_id(&input.profile_id)
+            .await
+            .map_app_err("Failed to fetch socials")?;
+
+        let url = self.urls.profile_page(&profile.id);
+        let title = match profile.headline.as_deref().filter(|h| !h.trim().is_empty()) {
+            Some(headline) => format!("{} – {}", profile.display_name, headline),
+            None => profile.display_name.clone(),
+        };
+        let description = description(profile.bio.as_deref().or(profile.headline.as_deref()));
+
+        let tags = social_tags("profile", &title, description.as_deref(), &url, profile.avatar_url.as_deref());
+
+        let json_ld = without_nulls(json!({
+            "@context": "https://schema.org",
+            "@type": "ProfilePage",
+            "url": url,
+            "dateCreated": profile.created_at,
+            "dateModified": profile.updated_at,
+            "mainEntity": {
+                "@type": "Person",
+                "name": profile.display_name,
+                "jobTitle": profile.headline,
+                "description": description,
+                "image": profile.avatar_url,
+                "url": url,
+                "sameAs": socials.iter().map(|s| s.link.as_str()).collect::<Vec<_>>(),
+            },
+        }));
+
+        let page = PageMeta { title, canonical_url: url, tags, json_ld };
+        Ok(page_result(input.format, page, description, profile.avatar_url))
+    }
+}
+
+pub struct GetPerformanceMetadataService<P, R, T>
+where
+    P: ProfileRepository,
+    R: PerformanceRepository,
+    T: TagRepository,
+{
+    profile_repository: P,
+    performance_repository: R,
+    tag_repository: T,
+    urls: PublicUrls,
+}
+
+impl<P, R, T> GetPerformanceMetadataService<P, R, T>
+where
+    P: ProfileRepository,
+    R: PerformanceRepository,
+    T: TagRepository,
+{
+    pub fn new(profile_repository: P, performance_repository: R, tag_repository: T, urls: PublicUrls) -> Self {
+        Self { profile_repository, performance_repository, tag_repository, urls }
+    }
+}
+
+#[async_trait]
+impl<P, R, T> UseCase for GetPerformanceMetadataService<P, R, T>
+where
+    P: ProfileRepository + Send + Sync,
+    R: PerformanceRepository + Send + Sync,
+    T: TagRepository + Send + Sync,
+{
+    type Input = GetPerformanceMetadataInput;
+    type Output = PageMetadataResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let profile = find_profile(&self.profile_repository, &input.profile_id).await?;
+
+        let mut perf = find_public_performance(&self.performance_repository, &input.profile_id, &input.slug_or_id).await?;
+        attach_tags(&self.tag_repository, std::slice::from_mut(&mut perf)).await?;
+
+        let image = self.performance_repository
+            .find_images_by_performance_id(&perf.id)
+            .await
+            .map_app_err("Failed to fetch performance images")?
+            .into_iter()
+            .next();
+
+        let url = self.urls.performance_page(&perf.profile_id, &perf.slug());
+        let author_url = self.urls.profile_page(&profile.id);
+        let description = description(
+            perf.content_preview.as_deref().filter(|p| !p.trim().is_empty()).or(perf.summary.as_deref()),
+        );
+        // The generated card is always there, so previews get a large image even without uploads
+        let card_url = self.urls.api(&og_card_path(&perf.profile_id, &perf.id));
+        let image_url = image.as_ref().map(|img| img.storage_url.clone());
+        let modified = perf.updated_at.clone().unwrap_or_else(|| perf.created_at.clone());
+
+        let mut tags = social_tags("article", &perf.title, description.as_deref(), &url, Some(&card_url));
+        let mut article_tags = vec![
+            MetaTag::property("og:image:type", "image/png"),
+            MetaTag::property("og:image:width", OG_CARD_WIDTH.to_string()),
+            MetaTag::property("og:image:height", OG_CARD_HEIGHT.to_string()),
+            MetaTag::property("og:image:alt", perf.title.clone()),
+            MetaTag::property("article:published_time", perf.created_at.clone()),
+            MetaTag::property("article:modified_time", modified.clone()),
+            MetaTag::property("article:author", author_url.clone()),
+        ];
+        article_tags.extend(perf.tags.iter().map(|t| MetaTag::property("article:tag", t.name.clone())));
+        // Keep the article properties next to the other OpenGraph tags, before the Twitter ones
+        let twitter_start = tags.iter().position(|t| t.attribute == "name").unwrap_or(tags.len());
+        tags.splice(twitter_start..twitter_start, article_tags);
+
+        let json_ld = without_nulls(json!({
+            "@context": "https://schema.org",
+            "@type": "Article",
+            "headline": perf.title,
+            "description": description,
+            "url": url,
+            "mainEntityOfPage": url,
+            "image": image_url.as_deref().unwrap_or(&card_url),
+            "datePublished": perf.created_at,
+            "dateModified": modified,
+            "keywords": perf.tags.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(),
+            "locationCreated": perf.location.as_ref().map(|l| json!({ "@type": "Place", "name": l })),
+            "author": {
+                "@type": "Person",
+                "name": profile.display_name,
+                "url": author_url,
+            },
+        }));
+
+        let page = PageMeta { title: perf.title.clone(), canonical_url: url, tags, json_ld };
+        Ok(page_result(input.format, page, description, Some(card_url)))
+    }
+}
@@ -0,0 +1,71 @@
+use service::{GetRobotsTxtService, GetSitemapService};
+use metadata_service::{GetPerformanceMetadataService, GetProfileMetadataService};
+use og_card_service::GetPerformanceOgCardService;
+use crate::config::config::Config;
+use crate::infrastructure::repositories::Repositories;
+use crate::infrastructure::repository_impl::application::feature_status::repository::AppRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::data::repository::ProfileDataRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::storage_repository::GcsImageStorageRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::og_card::repository::GcsOgCardRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::performance::repository::PerformanceRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::performance_category::repository::PerformanceCategoryRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::social::repository::SocialRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::tag::repository::TagRepositoryImpl;
+use crate::shared::utils::public_url::PublicUrls;
+
+pub mod result;
+pub mod service;
+pub mod metadata_service;
+pub mod og_card_service;
+
+pub struct SeoServices {
+    pub get_sitemap: GetSitemapService<ProfileDataRepositoryImpl, PerformanceRepositoryImpl>,
+    pub get_robots_txt: GetRobotsTxtService<AppRepositoryImpl>,
+    pub get_profile_metadata: GetProfileMetadataService<ProfileDataRepositoryImpl, SocialRepositoryImpl>,
+    pub get_performance_metadata: GetPerformanceMetadataService<ProfileDataRepositoryImpl, PerformanceRepositoryImpl, TagRepositoryImpl>,
+    pub get_performance_og_card: GetPerformanceOgCardService<
+        ProfileDataRepositoryImpl,
+        PerformanceRepositoryImpl,
+        PerformanceCategoryRepositoryImpl,
+        GcsImageStorageRepositoryImpl,
+        GcsOgCardRepositoryImpl,
+    >,
+}
+
+impl SeoServices {
+    pub fn new(repos: &Repositories, config: &Config) -> Self {
+        let public_urls = PublicUrls::new(&config.public_site_url, &config.public_api_url);
+
+        Self {
+            get_sitemap: GetSitemapService::new(
+                repos.profile.profile_data.clone(),
+                repos.profile.performance.clone(),
+                public_urls.clone(),
+                config.sitemap_max_urls,
+            ),
+            get_robots_txt: GetRobotsTxtService::new(
+                repos.website.repository.clone(),
+                public_urls.clone(),
+                config.site_app_id.clone(),
+            ),
+            get_profile_metadata: GetProfileMetadataService::new(
+                repos.profile.profile_data.clone(),
+                repos.profile.social.clone(),
+                public_urls.clone(),
+            ),
+            get_performance_metadata: GetPerformanceMetadataService::new(
+                repos.profile.profile_data.clone(),
+                repos.profile.performance.clone(),
+                repos.profile.tag.clone(),
+                public_urls,
+            ),
+            get_performance_og_card: GetPerformanceOgCardService::new(
+                repos.profile.profile_data.clone(),
+                repos.profile.performance.clone(),
+                repos.profile.performance_category.clone(),
+                repos.profile.image_storage.clone(),
+                repos.profile.og_card.clone(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::Semaphore;
+
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::application::seo::dto::input::GetPerformanceOgCardInput;
+use crate::application::services::website::seo::metadata_service::{find_profile, find_public_performance};
+use crate::application::services::website::seo::result::PerformanceOgCardResult;
+use crate::interface_adapters::gateways::repositories::profile::image::image_storage_repository::ImageStorageRepository;
+use crate::interface_adapters::gateways::repositories::profile::og_card::og_card_repository::OgCardRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance::performance_repository::PerformanceRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance_category::performance_category_repository::PerformanceCategoryRepository;
+use crate::interface_adapters::gateways::repositories::profile::profile_repository::ProfileRepository;
+use crate::shared::utils::http_cache::is_not_modified;
+use crate::shared::utils::image_transform::decode_bounded;
+use crate::shared::utils::og_card::{og_card_key, render_og_card, OgCardSpec};
+
+pub struct GetPerformanceOgCardService<P, R, K, S, C>
+where
+    P: ProfileRepository,
+    R: PerformanceRepository,
+    K: PerformanceCategoryRepository,
+    S: ImageStorageRepository,
+    C: OgCardRepository,
+{
+    profile_repository: P,
+    performance_repository: R,
+    category_repository: K,
+    image_storage: S,
+    og_card_repository: C,
+    /// Cache misses decode the cover and render on the blocking pool; this caps how many run at once
+    permits: Arc<Semaphore>,
+}
+
+impl<P, R, K, S, C> GetPerformanceOgCardService<P, R, K, S, C>
+where
+    P: ProfileRepository,
+    R: PerformanceRepository,
+    K: PerformanceCategoryRepository,
+    S: ImageStorageRepository,
+    C: OgCardRepository,
+{
+    pub fn new(
+        profile_repository: P,
+        performance_repository: R,
+        category_repository: K,
+        image_storage: S,
+        og_card_repository: C,
+    ) -> Self {
+        let workers = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(2);
+        Self {
+            profile_repository,
+            performance_repository,
+            category_repository,
+            image_storage,
+            og_card_repository,
+            permits: Arc::new(Semaphore::new(workers)),
+        }
+    }
+}
+
+#[async_trait]
+impl<P, R, K, S, C> UseCase for GetPerformanceOgCardService<P, R, K, S, C>
+where
+    P: ProfileRepository + Send + Sync,
+    R: PerformanceRepository + Send + Sync,
+    K: PerformanceCategoryRepository + Send + Sync,
+    S: ImageStorageRepository + Send + Sync,
+    C: OgCardRepository + Send + Sync,
+{
+    type Input = GetPerformanceOgCardInput;
+    type Output = PerformanceOgCardResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let profile = find_profile(&self.profile_repository, &input.profile_id).await?;
+        let perf = find_public_performance(&self.performance_repository, &input.profile_id, &input.slug_or_id).await?;
+
+        let category = self.category_repository
+            .find_by_id(&perf.category_id, &perf.profile_id)
+            .await
+            .map_app_err("Failed to fetch performance category")?;
+
+        let cover = self.performance_repository
+            .find_images_by_performance_id(&perf.id)
+            .await
+            .map_app_err("Failed to fetch performance images")?
+            .into_iter()
+            .next();
+
+        let spec = OgCardSpec {
+            title: perf.title.clone(),
+            profile_name: profile.display_name,
+            category_name: category.as_ref().map(|c| c.name.clone()),
+            category_color: category.and_then(|c| c.color),
+            cover_id: cover.as_ref().map(|img| img.id.clone()),
+        };
+        let mut key = og_card_key(&spec);
+        let mut etag = format!("\"{}\"", key);
+
+        if is_not_modified(input.if_none_match.as_deref(), None, &etag, None) {
+            return Ok(PerformanceOgCardResult { png: Vec::new(), etag, not_modified: true });
+        }
+
+        if let Some(png) = self.og_card_repository
+            .find_card(&perf.profile_id, &perf.id, &key)
+            .await
+            .map_app_err("Failed to fetch OpenGraph card")?
+        {
+            return Ok(PerformanceOgCardResult { png, etag, not_modified: false });
+        }
+
+        let _permit = self.permits
+            .acquire()
+            .await
+            .map_err(|e| ApplicationError::Internal { message: e.to_string() })?;
+
+        let cover_data = match &cover {
+            Some(img) => match self.image_storage.download_image(&img.profile_id, &img.filename).await {
+                Ok(data) => Some(data),
+                Err(e) => {
+                    tracing::warn!("Failed to download cover {} of performance {}: {:?}", img.id, perf.id, e);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let render_spec = spec.clone();
+        let (png, has_cover) = tokio::task::spawn_blocking(move || {
+            let cover_image = cover_data.and_then(|data| decode_bounded(&data).ok());
+            let spec = match cover_image {
+                Some(_) => render_spec,
+                None => OgCardSpec { cover_id: None, ..render_spec },
+            };
+            render_og_card(&spec, cover_image.as_ref()).map(|png| (png, cover_image.is_some()))
+        })
+        .await
+        .map_err(|e| ApplicationError::Internal { message: format!("OpenGraph card task failed: {}", e) })?
+        .map_err(|message| ApplicationError::Internal { message })?;
+
+        // A cover that can't be fetched or decoded still yields a card, cached under the
+        // cover-less key so the cover key keeps promising the cover
+        if cover.is_some() && !has_cover {
+            key = og_card_key(&OgCardSpec { cover_id: None, ..spec });
+            etag = format!("\"{}\"", key);
+        }
+
+        self.og_card_repository
+            .save_card(&perf.profile_id, &perf.id, &key, png.clone())
+            .await
+            .map_app_err("Failed to store OpenGraph card")?;
+
+        Ok(PerformanceOgCardResult { png, etag, not_modified: false })
+    }
+}
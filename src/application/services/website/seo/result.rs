@@ -0,0 +1,111 @@
+use axum::http::header;
+use axum::response::IntoResponse;
+use serde::Serialize;
+use crate::application::use_cases::application::seo::dto::input::PageMetadataFormat;
+use crate::interface_adapters::http::v1::presenters::common::api_response::ApiResponse;
+use crate::interface_adapters::http::v1::presenters::common::presenter_output::PresenterOutput;
+use crate::shared::utils::seo_meta::MetaTag;
+
+#[derive(Debug, Clone)]
+pub struct SitemapResult {
+    pub xml: String,
+}
+
+impl PresenterOutput for SitemapResult {
+    fn into_response(self) -> impl IntoResponse {
+        (
+            axum::http::StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "application/xml; charset=utf-8"),
+                (header::CACHE_CONTROL, "public, max-age=3600"),
+            ],
+            self.xml,
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RobotsTxtResult {
+    pub body: String,
+}
+
+impl PresenterOutput for RobotsTxtResult {
+    fn into_response(self) -> impl IntoResponse {
+        (
+            axum::http::StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/plain; charset=utf-8"),
+                (header::CACHE_CONTROL, "public, max-age=3600"),
+            ],
+            self.body,
+        )
+    }
+}
+
+/// Crawler-facing metadata of a public page. Served as JSON, or as an HTML shell when asked for.
+#[derive(Debug, Clone, Serialize)]
+pub struct PageMetadataResult {
+    #[serde(skip)]
+    pub format: PageMetadataFormat,
+    pub title: String,
+    pub description: Option<String>,
+    pub canonical_url: String,
+    pub image_url: Option<String>,
+    /// OpenGraph and Twitter card tags
+    pub meta: Vec<MetaTag>,
+    pub json_ld: serde_json::Value,
+    /// `<title>`, canonical link, meta tags and JSON-LD script, ready to paste into `<head>`
+    pub head_html: String,
+    #[serde(skip)]
+    pub html_shell: String,
+}
+
+impl PresenterOutput for PageMetadataResult {
+    fn into_response(self) -> impl IntoResponse {
+        match self.format {
+            PageMetadataFormat::Html => (
+                axum::http::StatusCode::OK,
+                [
+                    (header::CONTENT_TYPE, "text/html; charset=utf-8"),
+                    (header::CACHE_CONTROL, "public, max-age=300"),
+                ],
+                self.html_shell,
+            )
+                .into_response(),
+            PageMetadataFormat::Json => (
+                axum::http::StatusCode::OK,
+                [(header::CACHE_CONTROL, "public, max-age=300")],
+                axum::Json(ApiResponse::success(self)),
+            )
+                .into_response(),
+        }
+    }
+}
+
+/// PNG preview card of a performance. The ETag is the card key, so a 304 needs no storage access.
+#[derive(Debug, Clone)]
+pub struct PerformanceOgCardResult {
+    pub png: Vec<u8>,
+    pub etag: String,
+    pub not_modified: bool,
+}
+
+impl PresenterOutput for PerformanceOgCardResult {
+    fn into_response(self) -> impl IntoResponse {
+        let headers = [
+            (header::ETAG, self.etag),
+            (header::CACHE_CONTROL, "public, max-age=3600".to_string()),
+        ];
+        if self.not_modified {
+            (axum::http::StatusCode::NOT_MODIFIED, headers).into_response()
+        } else {
+            (
+                axum::http::StatusCode::OK,
+                headers,
+                [(header::CONTENT_TYPE, "image/png")],
+                self.png,
+            )
+                .into_response()
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use async_trait::async_trait;
+
+use crate::application::errors::{ApplicationError, MapToApplicationError};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::use_cases::application::seo::dto::input::{GetRobotsTxtInput, GetSitemapInput};
+use crate::domain::entities::profile::visibility::visibility::VISIBILITY_PUBLIC;
+use crate::interface_adapters::gateways::repositories::application::feature_status::app_repository::AppRepository;
+use crate::interface_adapters::gateways::repositories::profile::performance::performance_repository::PerformanceRepository;
+use crate::interface_adapters::gateways::repositories::profile::profile_repository::ProfileRepository;
+use crate::application::services::website::seo::result::{RobotsTxtResult, SitemapResult};
+use crate::shared::utils::public_url::PublicUrls;
+use crate::shared::utils::sitemap::{sitemap_index, urlset, SitemapUrl, SITEMAP_URL_LIMIT};
+
+pub struct GetSitemapService<P, R>
+where
+    P: ProfileRepository,
+    R: PerformanceRepository,
+{
+    profile_repository: P,
+    performance_repository: R,
+    urls: PublicUrls,
+    max_urls: usize,
+}
+
+impl<P, R> GetSitemapService<P, R>
+where
+    P: ProfileRepository,
+    R: PerformanceRepository,
+{
+    pub fn new(profile_repository: P, performance_repository: R, urls: PublicUrls, max_urls: usize) -> Self {
+        Self {
+            profile_repository,
+            performance_repository,
+            urls,
+            max_urls: max_urls.clamp(1, SITEMAP_URL_LIMIT),
+        }
+    }
+}
+
+#[async_trait]
+impl<P, R> UseCase for GetSitemapService<P, R>
+where
+    P: ProfileRepository + Send + Sync,
+    R: PerformanceRepository + Send + Sync,
+{
+    type Input = GetSitemapInput;
+    type Output = SitemapResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let profiles = self.profile_repository
+            .find_all()
+            .await
+            .map_app_err("Failed to fetch profiles")?;
+
+        let performances = self.performance_repository
+            .find_by_visibility(VISIBILITY_PUBLIC)
+            .await
+            .map_app_err("Failed to fetch performances")?;
+
+        let mut entries: Vec<SitemapUrl> = profiles
+            .iter()
+            .map(|profile| SitemapUrl {
+                loc: self.urls.profile_page(&profile.id),
+                lastmod: Some(profile.updated_at.clone().unwrap_or_else(|| profile.created_at.clone())),
+            })
+            .collect();
+        entries.extend(performances.iter().map(|perf| SitemapUrl {
+            loc: self.urls.performance_page(&perf.profile_id, &perf.slug()),
+            lastmod: Some(perf.updated_at.clone().unwrap_or_else(|| perf.created_at.clone())),
+        }));
+
+        let pages: Vec<&[SitemapUrl]> = entries.chunks(self.max_urls).collect();
+
+        let xml = match input.page {
+            // Small enough for a single file
+            None if pages.len() <= 1 => urlset(&entries),
+            None => {
+                let index: Vec<SitemapUrl> = pages
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, page)| SitemapUrl {
+                        loc: self.urls.api(&format!("/sitemaps/{}", idx + 1)),
+                        lastmod: page.iter().filter_map(|e| e.lastmod.clone()).max(),
+                    })
+                    .collect();
+                sitemap_index(&index)
+            }
+            Some(page) => {
+                let urls = match pages.get(page - 1) {
+                    Some(urls) => urls,
+                    // An empty site still has a (empty) first page
+                    None if page == 1 => &[][..],
+                    None => {
+                        return Err(ApplicationError::NotFound {
+                            resource: "Sitemap page",
+                            identifier: page.to_string(),
+                        });
+                    }
+                };
+                urlset(urls)
+            }
+        };
+
+        Ok(SitemapResult { xml })
+    }
+}
+
+pub struct GetRobotsTxtService<R>
+where
+    R: AppRepository,
+{
+    repository: R,
+    urls: PublicUrls,
+    default_app_id: String,
+}
+
+impl<R> GetRobotsTxtService<R>
+where
+    R: AppRepository,
+{
+    pub fn new(repository: R, urls: PublicUrls, default_app_id: String) -> Self {
+        Self { repository, urls, default_app_id }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase for GetRobotsTxtService<R>
+where
+    R: AppRepository + Send + Sync,
+{
+    type Input = GetRobotsTxtInput;
+    type Output = RobotsTxtResult;
+    type Error = ApplicationError;
+
+    async fn execute(&self, input: Self::Input) -> Result<Self::Output, Self::Error> {
+        input.validate().map_err(|e| ApplicationError::ValidationError { message: e })?;
+
+        let app_id = input.app_id.unwrap_or_else(|| self.default_app_id.clone());
+        let configured = self.repository
+            .find_robots_txt(&app_id)
+            .await
+            .map_app_err("Failed to fetch robots.txt configuration")?
+            .filter(|text| !text.trim().is_empty());
+
+        let sitemap_line = format!("Sitemap: {}", self.urls.api("/sitemap.xml"));
+
+        // Configured rules are served as written; the sitemap location is added unless they declare one
+        let body = match configured {
+            Some(text) if text.to_ascii_lowercase().contains("sitemap:") => text,
+            Some(text) => format!("{}\n\n{}\n", text.trim_end(), sitemap_line),
+            None => format!("User-agent: *\nAllow: /\n\n{}\n", sitemap_line),
+        };
+
+        Ok(RobotsTxtResult { body })
+    }
+}
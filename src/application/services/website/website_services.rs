@@ -1,17 +1,21 @@
 use crate::infrastructure::repositories::Repositories;
 use crate::application::services::website::feature_status::FeatureStatusServices;
 use crate::application::services::website::config::WebsiteConfigServices;
+use crate::application::services::website::seo::SeoServices;
+use crate::config::config::Config;
 
 pub struct WebsiteServices {
     pub feature_status: FeatureStatusServices,
     pub config: WebsiteConfigServices,
+    pub seo: SeoServices,
 }
 
 impl WebsiteServices {
-    pub fn new(repos: &Repositories) -> Self {
+    pub fn new(repos: &Repositories, config: &Config) -> Self {
         Self {
             feature_status: FeatureStatusServices::new(repos),
             config: WebsiteConfigServices::new(repos),
+            seo: SeoServices::new(repos, config),
         }
     }
 }
@@ -1,14 +1,17 @@
 pub mod feature_status;
 pub mod config;
+pub mod seo;
 
 use crate::application::services::website::website_services::WebsiteServices;
 use crate::application::use_cases::application::feature_status::FeatureStatusUseCases;
 use crate::application::use_cases::application::config::AppConfigUseCases;
+use crate::application::use_cases::application::seo::SeoUseCases;
 
 #[derive(Clone)]
 pub struct WebsiteUseCases {
     pub feature_status: FeatureStatusUseCases,
     pub config: AppConfigUseCases,
+    pub seo: SeoUseCases,
 }
 
 impl WebsiteUseCases {
@@ -16,6 +19,7 @@ impl WebsiteUseCases {
         Self {
             feature_status: FeatureStatusUseCases::new(services.feature_status),
             config: AppConfigUseCases::new(services.config),
+            seo: SeoUseCases::new(services.seo),
         }
     }
 }
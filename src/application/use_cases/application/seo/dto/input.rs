@@ -0,0 +1,105 @@
+pub struct GetSitemapInput {
+    /// `None` for `/sitemap.xml`, otherwise the 1-based page of `/sitemaps/{page}`
+    pub page: Option<usize>,
+}
+
+impl GetSitemapInput {
+    pub fn new(page: Option<usize>) -> Self {
+        Self { page }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.page == Some(0) {
+            return Err("page must start at 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct GetRobotsTxtInput {
+    /// `None` serves the robots.txt of the site this API belongs to
+    pub app_id: Option<String>,
+}
+
+impl GetRobotsTxtInput {
+    pub fn new(app_id: Option<String>) -> Self {
+        Self { app_id }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(app_id) = &self.app_id {
+            if app_id.trim().is_empty() {
+                return Err("app_id cannot be empty".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageMetadataFormat {
+    /// Structured tags plus a ready-to-embed `head_html`
+    Json,
+    /// A complete HTML document for crawlers that don't run JavaScript
+    Html,
+}
+
+impl PageMetadataFormat {
+    pub fn parse(value: Option<&str>) -> Result<Self, String> {
+        match value.map(str::trim) {
+            None | Some("") | Some("json") => Ok(PageMetadataFormat::Json),
+            Some("html") => Ok(PageMetadataFormat::Html),
+            Some(other) => Err(format!("Unknown format '{}', expected json or html", other)),
+        }
+    }
+}
+
+pub struct GetProfileMetadataInput {
+    pub profile_id: String,
+    pub format: PageMetadataFormat,
+}
+
+impl GetProfileMetadataInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct GetPerformanceMetadataInput {
+    pub profile_id: String,
+    pub slug_or_id: String,
+    pub format: PageMetadataFormat,
+}
+
+impl GetPerformanceMetadataInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.slug_or_id.trim().is_empty() {
+            return Err("slug_or_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct GetPerformanceOgCardInput {
+    pub profile_id: String,
+    pub slug_or_id: String,
+    pub if_none_match: Option<String>,
+}
+
+impl GetPerformanceOgCardInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.slug_or_id.trim().is_empty() {
+            return Err("slug_or_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
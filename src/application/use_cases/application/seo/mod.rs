@@ -0,0 +1,42 @@
+pub mod dto;
+
+use std::sync::Arc;
+use crate::application::services::website::seo::SeoServices;
+use crate::application::services::website::seo::service::{GetRobotsTxtService, GetSitemapService};
+use crate::application::services::website::seo::metadata_service::{GetPerformanceMetadataService, GetProfileMetadataService};
+use crate::application::services::website::seo::og_card_service::GetPerformanceOgCardService;
+use crate::infrastructure::repository_impl::application::feature_status::repository::AppRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::data::repository::ProfileDataRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::storage_repository::GcsImageStorageRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::og_card::repository::GcsOgCardRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::performance::repository::PerformanceRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::performance_category::repository::PerformanceCategoryRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::social::repository::SocialRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::tag::repository::TagRepositoryImpl;
+
+#[derive(Clone)]
+pub struct SeoUseCases {
+    pub get_sitemap: Arc<GetSitemapService<ProfileDataRepositoryImpl, PerformanceRepositoryImpl>>,
+    pub get_robots_txt: Arc<GetRobotsTxtService<AppRepositoryImpl>>,
+    pub get_profile_metadata: Arc<GetProfileMetadataService<ProfileDataRepositoryImpl, SocialRepositoryImpl>>,
+    pub get_performance_metadata: Arc<GetPerformanceMetadataService<ProfileDataRepositoryImpl, PerformanceRepositoryImpl, TagRepositoryImpl>>,
+    pub get_performance_og_card: Arc<GetPerformanceOgCardService<
+        ProfileDataRepositoryImpl,
+        PerformanceRepositoryImpl,
+        PerformanceCategoryRepositoryImpl,
+        GcsImageStorageRepositoryImpl,
+        GcsOgCardRepositoryImpl,
+    >>,
+}
+
+impl SeoUseCases {
+    pub fn new(services: SeoServices) -> Self {
+        Self {
+            get_sitemap: Arc::new(services.get_sitemap),
+            get_robots_txt: Arc::new(services.get_robots_txt),
+            get_profile_metadata: Arc::new(services.get_profile_metadata),
+            get_performance_metadata: Arc::new(services.get_performance_metadata),
+            get_performance_og_card: Arc::new(services.get_performance_og_card),
+        }
+    }
+}
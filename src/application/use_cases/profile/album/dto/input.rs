@@ -0,0 +1,167 @@
+use crate::domain::entities::profile::visibility::visibility::{VISIBILITY_PRIVATE, VISIBILITY_PUBLIC, VISIBILITY_UNLISTED};
+
+const TITLE_MAX_LEN: usize = 200;
+const DESCRIPTION_MAX_LEN: usize = 5000;
+pub const MAX_IMAGES_PER_ALBUM: usize = 500;
+
+/// Albums have no password of their own, so password visibility is not offered.
+const ALBUM_VISIBILITIES: [&str; 3] = [VISIBILITY_PUBLIC, VISIBILITY_UNLISTED, VISIBILITY_PRIVATE];
+
+fn validate_album_fields(title: &str, description: Option<&str>, visibility_id: &str) -> Result<(), String> {
+    if title.trim().is_empty() {
+        return Err("title cannot be empty".to_string());
+    }
+    if title.trim().chars().count() > TITLE_MAX_LEN {
+        return Err(format!("title cannot be longer than {} characters", TITLE_MAX_LEN));
+    }
+    if description.is_some_and(|d| d.chars().count() > DESCRIPTION_MAX_LEN) {
+        return Err(format!("description cannot be longer than {} characters", DESCRIPTION_MAX_LEN));
+    }
+    if !ALBUM_VISIBILITIES.contains(&visibility_id) {
+        return Err(format!("visibility_id must be one of {}", ALBUM_VISIBILITIES.join(", ")));
+    }
+    Ok(())
+}
+
+pub struct ListAlbumsInput {
+    pub profile_id: String,
+}
+
+impl ListAlbumsInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct GetAlbumInput {
+    pub id: String,
+    pub profile_id: String,
+}
+
+impl GetAlbumInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("id cannot be empty".to_string());
+        }
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct CreateAlbumInput {
+    pub profile_id: String,
+    pub title: String,
+    pub description: Option<String>,
+    /// Defaults to private
+    pub visibility_id: Option<String>,
+    pub cover_image_id: Option<String>,
+}
+
+impl CreateAlbumInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        validate_album_fields(
+            &self.title,
+            self.description.as_deref(),
+            self.visibility_id.as_deref().unwrap_or(VISIBILITY_PRIVATE),
+        )
+    }
+}
+
+pub struct UpdateAlbumInput {
+    pub id: String,
+    pub profile_id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub visibility_id: String,
+    pub cover_image_id: Option<String>,
+}
+
+impl UpdateAlbumInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("id cannot be empty".to_string());
+        }
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        validate_album_fields(&self.title, self.description.as_deref(), &self.visibility_id)
+    }
+}
+
+pub struct DeleteAlbumInput {
+    pub id: String,
+    pub profile_id: String,
+}
+
+impl DeleteAlbumInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("id cannot be empty".to_string());
+        }
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct SetAlbumImagesInput {
+    pub id: String,
+    pub profile_id: String,
+    /// Album order; repeats are dropped
+    pub image_ids: Vec<String>,
+}
+
+impl SetAlbumImagesInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("id cannot be empty".to_string());
+        }
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.image_ids.len() > MAX_IMAGES_PER_ALBUM {
+            return Err(format!("an album can have at most {} images", MAX_IMAGES_PER_ALBUM));
+        }
+        Ok(())
+    }
+}
+
+pub struct GetPublicAlbumsInput {
+    pub profile_id: String,
+}
+
+impl GetPublicAlbumsInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct GetPublicAlbumInput {
+    pub profile_id: String,
+    /// The album slug, or its id
+    pub slug_or_id: String,
+}
+
+impl GetPublicAlbumInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.slug_or_id.trim().is_empty() {
+            return Err("album cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+pub mod dto;
+
+use std::sync::Arc;
+use crate::application::services::profile::album::service::{
+    ListAlbumsService, GetAlbumService, CreateAlbumService, UpdateAlbumService, DeleteAlbumService,
+    SetAlbumImagesService, GetPublicAlbumsService, GetPublicAlbumService
+};
+use crate::infrastructure::repository_impl::profile::album::repository::AlbumRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::repository::ImageRepositoryImpl;
+
+#[derive(Clone)]
+pub struct AlbumUseCases {
+    pub get_all: Arc<ListAlbumsService<AlbumRepositoryImpl>>,
+    pub get_one: Arc<GetAlbumService<AlbumRepositoryImpl, ImageRepositoryImpl>>,
+    pub create: Arc<CreateAlbumService<AlbumRepositoryImpl, ImageRepositoryImpl>>,
+    pub update: Arc<UpdateAlbumService<AlbumRepositoryImpl, ImageRepositoryImpl>>,
+    pub delete: Arc<DeleteAlbumService<AlbumRepositoryImpl>>,
+    pub set_images: Arc<SetAlbumImagesService<AlbumRepositoryImpl, ImageRepositoryImpl>>,
+    pub get_public_all: Arc<GetPublicAlbumsService<AlbumRepositoryImpl>>,
+    pub get_public: Arc<GetPublicAlbumService<AlbumRepositoryImpl, ImageRepositoryImpl>>,
+}
+
+impl AlbumUseCases {
+    pub fn new(
+        get_all: ListAlbumsService<AlbumRepositoryImpl>,
+        get_one: GetAlbumService<AlbumRepositoryImpl, ImageRepositoryImpl>,
+        create: CreateAlbumService<AlbumRepositoryImpl, ImageRepositoryImpl>,
+        update: UpdateAlbumService<AlbumRepositoryImpl, ImageRepositoryImpl>,
+        delete: DeleteAlbumService<AlbumRepositoryImpl>,
+        set_images: SetAlbumImagesService<AlbumRepositoryImpl, ImageRepositoryImpl>,
+        get_public_all: GetPublicAlbumsService<AlbumRepositoryImpl>,
+        get_public: GetPublicAlbumService<AlbumRepositoryImpl, ImageRepositoryImpl>,
+    ) -> Self {
+        Self {
+            get_all: Arc::new(get_all),
+            get_one: Arc::new(get_one),
+            create: Arc::new(create),
+            update: Arc::new(update),
+            delete: Arc::new(delete),
+            set_images: Arc::new(set_images),
+            get_public_all: Arc::new(get_public_all),
+            get_public: Arc::new(get_public),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::shared::utils::zip_archive::ArchiveLimits;
+
+/// Largest archive accepted by the restore endpoint
+pub const MAX_RESTORE_ARCHIVE_BYTES: usize = 200 * 1024 * 1024;
+pub const RESTORE_ARCHIVE_LIMITS: ArchiveLimits = ArchiveLimits {
+    max_entries: 20_000,
+    max_total_bytes: 1024 * 1024 * 1024,
+};
+
+pub struct ExportProfileArchiveInput {
+    pub profile_id: String,
+}
+
+impl ExportProfileArchiveInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct RestoreProfileArchiveInput {
+    /// Profile to restore into; created for `user_id` when it does not exist yet
+    pub profile_id: String,
+    pub user_id: String,
+    /// Zip produced by the export endpoint
+    pub archive: Vec<u8>,
+}
+
+impl RestoreProfileArchiveInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.user_id.trim().is_empty() {
+            return Err("user_id cannot be empty".to_string());
+        }
+        if self.archive.is_empty() {
+            return Err("archive cannot be empty".to_string());
+        }
+        if self.archive.len() > MAX_RESTORE_ARCHIVE_BYTES {
+            return Err(format!("archive cannot be larger than {} bytes", MAX_RESTORE_ARCHIVE_BYTES));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+pub mod dto;
+
+use std::sync::Arc;
+use crate::application::services::profile::archive::service::{
+    ExportProfileArchiveService, RestoreProfileArchiveService
+};
+use crate::infrastructure::repository_impl::profile::archive::repository::ProfileArchiveRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::repository::ImageRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::storage_repository::GcsImageStorageRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::performance_content::repository::GcsPerformanceContentRepositoryImpl;
+
+#[derive(Clone)]
+pub struct ProfileArchiveUseCases {
+    pub export: Arc<ExportProfileArchiveService<ProfileArchiveRepositoryImpl, GcsPerformanceContentRepositoryImpl, GcsImageStorageRepositoryImpl>>,
+    pub restore: Arc<RestoreProfileArchiveService<ProfileArchiveRepositoryImpl, GcsPerformanceContentRepositoryImpl, GcsImageStorageRepositoryImpl, ImageRepositoryImpl>>,
+}
+
+impl ProfileArchiveUseCases {
+    pub fn new(
+        export: ExportProfileArchiveService<ProfileArchiveRepositoryImpl, GcsPerformanceContentRepositoryImpl, GcsImageStorageRepositoryImpl>,
+        restore: RestoreProfileArchiveService<ProfileArchiveRepositoryImpl, GcsPerformanceContentRepositoryImpl, GcsImageStorageRepositoryImpl, ImageRepositoryImpl>,
+    ) -> Self {
+        Self {
+            export: Arc::new(export),
+            restore: Arc::new(restore),
+        }
+    }
+}
@@ -1,19 +1,47 @@
+use crate::shared::utils::image_transform::TransformParams;
+
+const TAG_NAME_MAX_LEN: usize = 50;
+const TAG_COLOR_MAX_LEN: usize = 20;
+pub const MAX_TAGS_PER_IMAGE: usize = 20;
+
+fn validate_tag_name(name: &str) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("tag name cannot be empty".to_string());
+    }
+    if name.trim().chars().count() > TAG_NAME_MAX_LEN {
+        return Err(format!("tag name cannot be longer than {} characters", TAG_NAME_MAX_LEN));
+    }
+    Ok(())
+}
+
+fn validate_tag_color(color: Option<&str>) -> Result<(), String> {
+    if color.is_some_and(|c| c.chars().count() > TAG_COLOR_MAX_LEN) {
+        return Err(format!("color cannot be longer than {} characters", TAG_COLOR_MAX_LEN));
+    }
+    Ok(())
+}
+
 pub struct GetImagesInput {
     pub profile_id: String,
     pub search: Option<String>,
+    /// Only images carrying all of these tag names
+    pub tags: Vec<String>,
     pub limit: Option<i32>,
     pub offset: Option<i32>,
 }
 
 impl GetImagesInput {
-    pub fn new(profile_id: String, search: Option<String>, limit: Option<i32>, offset: Option<i32>) -> Self {
-        Self { profile_id, search, limit, offset }
+    pub fn new(profile_id: String, search: Option<String>, tags: Vec<String>, limit: Option<i32>, offset: Option<i32>) -> Self {
+        Self { profile_id, search, tags, limit, offset }
     }
 
     pub fn validate(&self) -> Result<(), String> {
         if self.profile_id.trim().is_empty() {
             return Err("profile_id cannot be empty".to_string());
         }
+        if self.tags.len() > MAX_TAGS_PER_IMAGE {
+            return Err(format!("cannot filter by more than {} tags", MAX_TAGS_PER_IMAGE));
+        }
         Ok(())
     }
 }
@@ -39,16 +67,41 @@ impl GetImageInput {
     }
 }
 
+/// What an upload does when the profile already has an image with identical content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateImagePolicy {
+    /// Answer with the existing image instead of storing a second copy
+    #[default]
+    ReturnExisting,
+    /// Fail with a conflict carrying the existing image id
+    Conflict,
+}
+
+impl DuplicateImagePolicy {
+    pub fn parse(value: Option<&str>) -> Result<Self, String> {
+        match value.map(str::trim) {
+            None | Some("") | Some("existing") => Ok(Self::ReturnExisting),
+            Some("conflict") => Ok(Self::Conflict),
+            Some(other) => Err(format!("Unsupported on_duplicate '{}', expected existing or conflict", other)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ReturnExisting => "existing",
+            Self::Conflict => "conflict",
+        }
+    }
+}
+
 pub struct CreateImageInput {
     pub profile_id: String,
     pub original_filename: String,
-    pub mime_type: String,
-    pub file_size: i32,
-    pub width: Option<i32>,
-    pub height: Option<i32>,
     pub alt_text: Option<String>,
     pub caption: Option<String>,
+    /// Format, size and dimensions are all taken from these, never from the client
     pub image_bytes: Vec<u8>,
+    pub on_duplicate: DuplicateImagePolicy,
 }
 
 impl CreateImageInput {
@@ -171,3 +224,257 @@ impl TrackImageUsageInput {
         Ok(())
     }
 }
+
+/// Raw `?w=&h=&fit=&format=&q=` of a transform request; bounds are checked by [`TransformParams`].
+pub struct TransformImageInput {
+    pub image_id: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub fit: Option<String>,
+    pub format: Option<String>,
+    pub quality: Option<u32>,
+    pub if_none_match: Option<String>,
+}
+
+impl TransformImageInput {
+    pub fn params(&self) -> Result<TransformParams, String> {
+        TransformParams::parse(self.width, self.height, self.fit.as_deref(), self.format.as_deref(), self.quality)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.image_id.trim().is_empty() {
+            return Err("image id cannot be empty".to_string());
+        }
+        self.params().map(|_| ())
+    }
+}
+
+pub struct GetImageSettingsInput {
+    pub profile_id: String,
+}
+
+impl GetImageSettingsInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct UpdateImageSettingsInput {
+    pub profile_id: String,
+    pub keep_capture_metadata: bool,
+}
+
+impl UpdateImageSettingsInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct MergeDuplicateImagesInput {
+    pub profile_id: String,
+    /// Report the duplicate groups without merging them
+    pub dry_run: bool,
+}
+
+impl MergeDuplicateImagesInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+const PLACEHOLDER_BACKFILL_DEFAULT_LIMIT: i32 = 50;
+const PLACEHOLDER_BACKFILL_MAX_LIMIT: i32 = 500;
+
+pub struct BackfillImagePlaceholdersInput {
+    pub profile_id: String,
+    /// Images to try in this run, oldest first; failures count too
+    pub limit: Option<i32>,
+}
+
+impl BackfillImagePlaceholdersInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if let Some(limit) = self.limit {
+            if !(1..=PLACEHOLDER_BACKFILL_MAX_LIMIT).contains(&limit) {
+                return Err(format!("limit must be between 1 and {}", PLACEHOLDER_BACKFILL_MAX_LIMIT));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_limit(&self) -> usize {
+        self.limit.unwrap_or(PLACEHOLDER_BACKFILL_DEFAULT_LIMIT) as usize
+    }
+}
+
+pub struct ListImageTagsInput {
+    pub profile_id: String,
+}
+
+impl ListImageTagsInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct CreateImageTagInput {
+    pub profile_id: String,
+    pub name: String,
+    pub color: Option<String>,
+}
+
+impl CreateImageTagInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        validate_tag_name(&self.name)?;
+        validate_tag_color(self.color.as_deref())
+    }
+}
+
+pub struct UpdateImageTagInput {
+    pub id: String,
+    pub profile_id: String,
+    pub name: String,
+    pub color: Option<String>,
+}
+
+impl UpdateImageTagInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("id cannot be empty".to_string());
+        }
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        validate_tag_name(&self.name)?;
+        validate_tag_color(self.color.as_deref())
+    }
+}
+
+pub struct DeleteImageTagInput {
+    pub id: String,
+    pub profile_id: String,
+}
+
+impl DeleteImageTagInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("id cannot be empty".to_string());
+        }
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct SetImageTagsInput {
+    pub profile_id: String,
+    pub image_id: String,
+    /// Tag names; unknown ones are created
+    pub tags: Vec<String>,
+}
+
+impl SetImageTagsInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.image_id.trim().is_empty() {
+            return Err("image_id cannot be empty".to_string());
+        }
+        if self.tags.len() > MAX_TAGS_PER_IMAGE {
+            return Err(format!("an image can have at most {} tags", MAX_TAGS_PER_IMAGE));
+        }
+        self.tags.iter().try_for_each(|name| validate_tag_name(name))
+    }
+}
+
+pub struct CreateImageUploadInput {
+    pub profile_id: String,
+    pub original_filename: String,
+    /// Size of the whole file in bytes, announced up front
+    pub total_size: i64,
+    pub alt_text: Option<String>,
+    pub caption: Option<String>,
+    pub on_duplicate: DuplicateImagePolicy,
+}
+
+impl CreateImageUploadInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.total_size <= 0 {
+            return Err("total_size must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct AppendImageUploadChunkInput {
+    pub profile_id: String,
+    pub upload_id: String,
+    /// Where the chunk starts in the whole file, has to match the bytes received so far
+    pub offset: i64,
+    pub bytes: Vec<u8>,
+}
+
+impl AppendImageUploadChunkInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.upload_id.trim().is_empty() {
+            return Err("upload_id cannot be empty".to_string());
+        }
+        if self.offset < 0 {
+            return Err("offset cannot be negative".to_string());
+        }
+        if self.bytes.is_empty() {
+            return Err("chunk cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Addresses one upload session, for reading, completing or cancelling it.
+pub struct ImageUploadInput {
+    pub profile_id: String,
+    pub upload_id: String,
+}
+
+impl ImageUploadInput {
+    pub fn new(profile_id: String, upload_id: String) -> Self {
+        Self { profile_id, upload_id }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.upload_id.trim().is_empty() {
+            return Err("upload_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Removes the upload sessions of every profile that expired unfinished.
+pub struct PurgeExpiredImageUploadsInput;
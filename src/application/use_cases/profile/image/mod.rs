@@ -4,10 +4,24 @@ use std::sync::Arc;
 use crate::application::services::profile::image::service::{
     GetImagesService, GetImageService, GetImageUsageService,
     CreateImageService, UpdateImageMetadataService, DeleteImageService, ForceDeleteImageService,
-    GetUnusedImagesService, DeleteUnusedImagesService, TrackImageUsageService, UntrackImageUsageService
+    GetUnusedImagesService, DeleteUnusedImagesService, TrackImageUsageService, UntrackImageUsageService,
+    GetImageSettingsService, UpdateImageSettingsService
+};
+use crate::application::services::profile::image::transform_service::TransformImageService;
+use crate::application::services::profile::image::dedupe_service::MergeDuplicateImagesService;
+use crate::application::services::profile::image::placeholder_service::BackfillImagePlaceholdersService;
+use crate::application::services::profile::image::upload_service::{
+    CreateImageUploadService, AppendImageUploadChunkService, GetImageUploadService, CompleteImageUploadService,
+    CancelImageUploadService, PurgeExpiredImageUploadsService
+};
+use crate::application::services::profile::image::tag_service::{
+    ListImageTagsService, CreateImageTagService, UpdateImageTagService, DeleteImageTagService, SetImageTagsService
 };
 use crate::infrastructure::repository_impl::profile::image::repository::ImageRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::image::storage_repository::GcsImageStorageRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::transform_cache_repository::GcsImageTransformCacheRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::upload_session_repository::ImageUploadSessionRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::performance_content::repository::GcsPerformanceContentRepositoryImpl;
 
 #[derive(Clone)]
 pub struct ImageUseCases {
@@ -16,12 +30,28 @@ pub struct ImageUseCases {
     pub get_usage: Arc<GetImageUsageService<ImageRepositoryImpl>>,
     pub create: Arc<CreateImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>>,
     pub update_metadata: Arc<UpdateImageMetadataService<ImageRepositoryImpl>>,
-    pub delete: Arc<DeleteImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>>,
-    pub force_delete: Arc<ForceDeleteImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>>,
+    pub delete: Arc<DeleteImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>>,
+    pub force_delete: Arc<ForceDeleteImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>>,
     pub get_unused: Arc<GetUnusedImagesService<ImageRepositoryImpl>>,
-    pub delete_unused: Arc<DeleteUnusedImagesService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>>,
+    pub delete_unused: Arc<DeleteUnusedImagesService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>>,
     pub track_usage: Arc<TrackImageUsageService<ImageRepositoryImpl>>,
     pub untrack_usage: Arc<UntrackImageUsageService<ImageRepositoryImpl>>,
+    pub get_settings: Arc<GetImageSettingsService<ImageRepositoryImpl>>,
+    pub update_settings: Arc<UpdateImageSettingsService<ImageRepositoryImpl>>,
+    pub transform: Arc<TransformImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>>,
+    pub merge_duplicates: Arc<MergeDuplicateImagesService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl, GcsPerformanceContentRepositoryImpl>>,
+    pub list_tags: Arc<ListImageTagsService<ImageRepositoryImpl>>,
+    pub create_tag: Arc<CreateImageTagService<ImageRepositoryImpl>>,
+    pub update_tag: Arc<UpdateImageTagService<ImageRepositoryImpl>>,
+    pub delete_tag: Arc<DeleteImageTagService<ImageRepositoryImpl>>,
+    pub set_tags: Arc<SetImageTagsService<ImageRepositoryImpl>>,
+    pub backfill_placeholders: Arc<BackfillImagePlaceholdersService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>>,
+    pub upload_create: Arc<CreateImageUploadService<ImageUploadSessionRepositoryImpl>>,
+    pub upload_append: Arc<AppendImageUploadChunkService<ImageUploadSessionRepositoryImpl>>,
+    pub upload_get: Arc<GetImageUploadService<ImageUploadSessionRepositoryImpl>>,
+    pub upload_complete: Arc<CompleteImageUploadService<ImageUploadSessionRepositoryImpl, ImageRepositoryImpl, GcsImageStorageRepositoryImpl>>,
+    pub upload_cancel: Arc<CancelImageUploadService<ImageUploadSessionRepositoryImpl>>,
+    pub upload_purge_expired: Arc<PurgeExpiredImageUploadsService<ImageUploadSessionRepositoryImpl>>,
 }
 
 impl ImageUseCases {
@@ -31,12 +61,28 @@ impl ImageUseCases {
         get_usage: GetImageUsageService<ImageRepositoryImpl>,
         create: CreateImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>,
         update_metadata: UpdateImageMetadataService<ImageRepositoryImpl>,
-        delete: DeleteImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>,
-        force_delete: ForceDeleteImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>,
+        delete: DeleteImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>,
+        force_delete: ForceDeleteImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>,
         get_unused: GetUnusedImagesService<ImageRepositoryImpl>,
-        delete_unused: DeleteUnusedImagesService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>,
+        delete_unused: DeleteUnusedImagesService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>,
         track_usage: TrackImageUsageService<ImageRepositoryImpl>,
         untrack_usage: UntrackImageUsageService<ImageRepositoryImpl>,
+        get_settings: GetImageSettingsService<ImageRepositoryImpl>,
+        update_settings: UpdateImageSettingsService<ImageRepositoryImpl>,
+        transform: TransformImageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>,
+        merge_duplicates: MergeDuplicateImagesService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl, GcsPerformanceContentRepositoryImpl>,
+        list_tags: ListImageTagsService<ImageRepositoryImpl>,
+        create_tag: CreateImageTagService<ImageRepositoryImpl>,
+        update_tag: UpdateImageTagService<ImageRepositoryImpl>,
+        delete_tag: DeleteImageTagService<ImageRepositoryImpl>,
+        set_tags: SetImageTagsService<ImageRepositoryImpl>,
+        backfill_placeholders: BackfillImagePlaceholdersService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl>,
+        upload_create: CreateImageUploadService<ImageUploadSessionRepositoryImpl>,
+        upload_append: AppendImageUploadChunkService<ImageUploadSessionRepositoryImpl>,
+        upload_get: GetImageUploadService<ImageUploadSessionRepositoryImpl>,
+        upload_complete: CompleteImageUploadService<ImageUploadSessionRepositoryImpl, ImageRepositoryImpl, GcsImageStorageRepositoryImpl>,
+        upload_cancel: CancelImageUploadService<ImageUploadSessionRepositoryImpl>,
+        upload_purge_expired: PurgeExpiredImageUploadsService<ImageUploadSessionRepositoryImpl>,
     ) -> Self {
         Self {
             get_all: Arc::new(get_all),
@@ -50,6 +96,22 @@ impl ImageUseCases {
             delete_unused: Arc::new(delete_unused),
             track_usage: Arc::new(track_usage),
             untrack_usage: Arc::new(untrack_usage),
+            get_settings: Arc::new(get_settings),
+            update_settings: Arc::new(update_settings),
+            transform: Arc::new(transform),
+            merge_duplicates: Arc::new(merge_duplicates),
+            list_tags: Arc::new(list_tags),
+            create_tag: Arc::new(create_tag),
+            update_tag: Arc::new(update_tag),
+            delete_tag: Arc::new(delete_tag),
+            set_tags: Arc::new(set_tags),
+            backfill_placeholders: Arc::new(backfill_placeholders),
+            upload_create: Arc::new(upload_create),
+            upload_append: Arc::new(upload_append),
+            upload_get: Arc::new(upload_get),
+            upload_complete: Arc::new(upload_complete),
+            upload_cancel: Arc::new(upload_cancel),
+            upload_purge_expired: Arc::new(upload_purge_expired),
         }
     }
 }
@@ -2,5 +2,12 @@ pub mod life_status;
 pub mod announce;
 pub mod image;
 pub mod performance;
+pub mod performance_category;
+pub mod visibility;
+pub mod tag;
+pub mod share_link;
+pub mod archive;
+pub mod album;
+pub mod storage;
 pub mod profile_use_cases;
 pub mod profile;
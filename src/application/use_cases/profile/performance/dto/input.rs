@@ -1,3 +1,13 @@
+use crate::shared::utils::feed::FeedFormat;
+use crate::shared::utils::zip_archive::ArchiveLimits;
+
+/// Largest zip accepted by the import endpoint
+pub const MAX_IMPORT_ARCHIVE_BYTES: usize = 50 * 1024 * 1024;
+pub const IMPORT_ARCHIVE_LIMITS: ArchiveLimits = ArchiveLimits {
+    max_entries: 2_000,
+    max_total_bytes: 200 * 1024 * 1024,
+};
+
 pub struct CreatePerformanceInput {
     pub profile_id: String,
     pub category_id: String,
@@ -7,6 +17,7 @@ pub struct CreatePerformanceInput {
     pub start_date: Option<String>,
     pub end_date: Option<String>,
     pub location: Option<String>,
+    pub pinned: bool,
 }
 
 impl CreatePerformanceInput {
@@ -32,6 +43,8 @@ pub struct UpdatePerformanceInput {
     pub end_date: Option<String>,
     pub location: Option<String>,
     pub close: bool,
+    /// `None` keeps the current value
+    pub pinned: Option<bool>,
 }
 
 impl UpdatePerformanceInput {
@@ -66,6 +79,74 @@ impl DeletePerformanceInput {
     }
 }
 
+pub struct DuplicatePerformanceInput {
+    pub id: String,
+    pub profile_id: String,
+}
+
+impl DuplicatePerformanceInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("id cannot be empty".to_string());
+        }
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct ReorderPerformancesInput {
+    pub profile_id: String,
+    /// Performances listed here come first, in this order; the rest keep their relative order after them
+    pub performance_ids: Vec<String>,
+}
+
+impl ReorderPerformancesInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.performance_ids.is_empty() {
+            return Err("performance_ids cannot be empty".to_string());
+        }
+        for (i, id) in self.performance_ids.iter().enumerate() {
+            if id.trim().is_empty() {
+                return Err("performance_ids cannot contain empty ids".to_string());
+            }
+            if self.performance_ids[..i].contains(id) {
+                return Err(format!("Duplicate performance id: {}", id));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct ImportPerformancesInput {
+    pub profile_id: String,
+    /// Zip of markdown files with YAML front matter, plus the images they reference
+    pub archive: Vec<u8>,
+    /// Used when a file's front matter has no `category`
+    pub category_id: Option<String>,
+    /// Used when a file's front matter has no `visibility`; defaults to private
+    pub visibility_id: Option<String>,
+}
+
+impl ImportPerformancesInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.archive.is_empty() {
+            return Err("archive cannot be empty".to_string());
+        }
+        if self.archive.len() > MAX_IMPORT_ARCHIVE_BYTES {
+            return Err(format!("archive cannot be larger than {} bytes", MAX_IMPORT_ARCHIVE_BYTES));
+        }
+        Ok(())
+    }
+}
+
 pub struct UpdatePerformanceContentInput {
     pub performance_id: String,
     pub profile_id: String,
@@ -89,6 +170,26 @@ pub struct GetPerformanceContentInput {
     pub profile_id: String,
 }
 
+pub struct RenderPerformanceContentInput {
+    pub performance_id: String,
+    pub profile_id: String,
+    /// Grants access to a non-public performance
+    pub share_token: Option<String>,
+    pub share_password: Option<String>,
+}
+
+impl RenderPerformanceContentInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.performance_id.trim().is_empty() {
+            return Err("performance_id cannot be empty".to_string());
+        }
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
 pub struct GetPerformanceImagesInput {
     pub performance_id: String,
     pub profile_id: String,
@@ -97,9 +198,62 @@ pub struct GetPerformanceImagesInput {
 pub struct ListPerformancesInput {
     pub profile_id: String,
     pub visibility_id: Option<String>,
+    /// Only "category" is supported
+    pub group_by: Option<String>,
+    /// Tag slugs; a performance must carry all of them
+    pub tags: Vec<String>,
 }
 
 impl ListPerformancesInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if let Some(group_by) = &self.group_by {
+            if group_by != "category" {
+                return Err("group_by must be 'category'".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct GetPublicPerformanceInput {
+    pub profile_id: String,
+    /// Either the performance id or the slug of its title
+    pub slug_or_id: String,
+    /// "html" (default) or "markdown"
+    pub format: Option<String>,
+    /// Grants access to a non-public performance
+    pub share_token: Option<String>,
+    pub share_password: Option<String>,
+}
+
+impl GetPublicPerformanceInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.slug_or_id.trim().is_empty() {
+            return Err("slug_or_id cannot be empty".to_string());
+        }
+        if let Some(format) = &self.format {
+            if format != "html" && format != "markdown" {
+                return Err("format must be either 'html' or 'markdown'".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct GetPerformanceFeedInput {
+    pub profile_id: String,
+    pub format: FeedFormat,
+    pub if_none_match: Option<String>,
+    pub if_modified_since: Option<String>,
+}
+
+impl GetPerformanceFeedInput {
     pub fn validate(&self) -> Result<(), String> {
         if self.profile_id.trim().is_empty() {
             return Err("profile_id cannot be empty".to_string());
@@ -3,42 +3,69 @@ pub mod dto;
 use std::sync::Arc;
 use crate::application::services::profile::performance::service::{
     CreatePerformanceService, UpdatePerformanceService, DeletePerformanceService,
-    ListPerformancesService, GetPerformanceImagesService
+    DuplicatePerformanceService, ReorderPerformancesService, ListPerformancesService, GetPerformanceImagesService
 };
+use crate::application::services::profile::performance::public_service::GetPublicPerformanceService;
+use crate::application::services::profile::performance::feed_service::GetPerformanceFeedService;
+use crate::application::services::profile::performance::import_service::ImportPerformancesService;
 use crate::application::services::profile::performance::content_service::{
-    GetPerformanceContentService, UpdatePerformanceContentService
+    GetPerformanceContentService, UpdatePerformanceContentService, RenderPerformanceContentService
 };
 use crate::infrastructure::repository_impl::profile::performance::repository::PerformanceRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::image::repository::ImageRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::storage_repository::GcsImageStorageRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::data::repository::ProfileDataRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::performance_content::repository::GcsPerformanceContentRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::performance_category::repository::PerformanceCategoryRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::visibility::repository::VisibilityRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::tag::repository::TagRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::share_link::repository::ShareLinkRepositoryImpl;
 
 #[derive(Clone)]
 pub struct PerformanceUseCases {
-    pub create: Arc<CreatePerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl>>,
-    pub update: Arc<UpdatePerformanceService<PerformanceRepositoryImpl>>,
+    pub create: Arc<CreatePerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, PerformanceCategoryRepositoryImpl, VisibilityRepositoryImpl>>,
+    pub update: Arc<UpdatePerformanceService<PerformanceRepositoryImpl, PerformanceCategoryRepositoryImpl, VisibilityRepositoryImpl>>,
     pub delete: Arc<DeletePerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl>>,
+    pub duplicate: Arc<DuplicatePerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, TagRepositoryImpl>>,
     pub get_content: Arc<GetPerformanceContentService<GcsPerformanceContentRepositoryImpl>>,
     pub update_content: Arc<UpdatePerformanceContentService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl>>,
-    pub list: Arc<ListPerformancesService<PerformanceRepositoryImpl>>,
+    pub render_content: Arc<RenderPerformanceContentService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, ShareLinkRepositoryImpl>>,
+    pub get_public: Arc<GetPublicPerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, TagRepositoryImpl, ShareLinkRepositoryImpl, ImageRepositoryImpl>>,
+    pub feed: Arc<GetPerformanceFeedService<PerformanceRepositoryImpl, ProfileDataRepositoryImpl, GcsPerformanceContentRepositoryImpl, TagRepositoryImpl>>,
+    pub import: Arc<ImportPerformancesService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, PerformanceCategoryRepositoryImpl, VisibilityRepositoryImpl, TagRepositoryImpl, ImageRepositoryImpl, GcsImageStorageRepositoryImpl>>,
+    pub reorder: Arc<ReorderPerformancesService<PerformanceRepositoryImpl>>,
+    pub list: Arc<ListPerformancesService<PerformanceRepositoryImpl, PerformanceCategoryRepositoryImpl, TagRepositoryImpl>>,
     pub get_images: Arc<GetPerformanceImagesService<PerformanceRepositoryImpl, ImageRepositoryImpl>>,
 }
 
 impl PerformanceUseCases {
     pub fn new(
-        create: CreatePerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl>,
-        update: UpdatePerformanceService<PerformanceRepositoryImpl>,
+        create: CreatePerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, PerformanceCategoryRepositoryImpl, VisibilityRepositoryImpl>,
+        update: UpdatePerformanceService<PerformanceRepositoryImpl, PerformanceCategoryRepositoryImpl, VisibilityRepositoryImpl>,
         delete: DeletePerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl>,
+        duplicate: DuplicatePerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, TagRepositoryImpl>,
         get_content: GetPerformanceContentService<GcsPerformanceContentRepositoryImpl>,
         update_content: UpdatePerformanceContentService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl>,
-        list: ListPerformancesService<PerformanceRepositoryImpl>,
+        render_content: RenderPerformanceContentService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, ShareLinkRepositoryImpl>,
+        get_public: GetPublicPerformanceService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, TagRepositoryImpl, ShareLinkRepositoryImpl, ImageRepositoryImpl>,
+        feed: GetPerformanceFeedService<PerformanceRepositoryImpl, ProfileDataRepositoryImpl, GcsPerformanceContentRepositoryImpl, TagRepositoryImpl>,
+        import: ImportPerformancesService<PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl, PerformanceCategoryRepositoryImpl, VisibilityRepositoryImpl, TagRepositoryImpl, ImageRepositoryImpl, GcsImageStorageRepositoryImpl>,
+        reorder: ReorderPerformancesService<PerformanceRepositoryImpl>,
+        list: ListPerformancesService<PerformanceRepositoryImpl, PerformanceCategoryRepositoryImpl, TagRepositoryImpl>,
         get_images: GetPerformanceImagesService<PerformanceRepositoryImpl, ImageRepositoryImpl>,
     ) -> Self {
         Self {
             create: Arc::new(create),
             update: Arc::new(update),
             delete: Arc::new(delete),
+            duplicate: Arc::new(duplicate),
             get_content: Arc::new(get_content),
             update_content: Arc::new(update_content),
+            render_content: Arc::new(render_content),
+            get_public: Arc::new(get_public),
+            feed: Arc::new(feed),
+            import: Arc::new(import),
+            reorder: Arc::new(reorder),
             list: Arc::new(list),
             get_images: Arc::new(get_images),
         }
@@ -0,0 +1,87 @@
+const NAME_MAX_LEN: usize = 100;
+const ICON_MAX_LEN: usize = 100;
+const COLOR_MAX_LEN: usize = 32;
+
+fn validate_fields(name: &str, icon: Option<&str>, color: Option<&str>) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("name cannot be empty".to_string());
+    }
+    if name.chars().count() > NAME_MAX_LEN {
+        return Err(format!("name cannot be longer than {} characters", NAME_MAX_LEN));
+    }
+    if icon.is_some_and(|icon| icon.chars().count() > ICON_MAX_LEN) {
+        return Err(format!("icon cannot be longer than {} characters", ICON_MAX_LEN));
+    }
+    if color.is_some_and(|color| color.chars().count() > COLOR_MAX_LEN) {
+        return Err(format!("color cannot be longer than {} characters", COLOR_MAX_LEN));
+    }
+    Ok(())
+}
+
+pub struct ListPerformanceCategoriesInput {
+    pub profile_id: String,
+}
+
+impl ListPerformanceCategoriesInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct CreatePerformanceCategoryInput {
+    pub profile_id: String,
+    pub name: String,
+    pub icon: Option<String>,
+    pub color: Option<String>,
+    pub sort_order: Option<i32>,
+}
+
+impl CreatePerformanceCategoryInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        validate_fields(&self.name, self.icon.as_deref(), self.color.as_deref())
+    }
+}
+
+pub struct UpdatePerformanceCategoryInput {
+    pub id: String,
+    pub profile_id: String,
+    pub name: String,
+    pub icon: Option<String>,
+    pub color: Option<String>,
+    pub sort_order: i32,
+}
+
+impl UpdatePerformanceCategoryInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("id cannot be empty".to_string());
+        }
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        validate_fields(&self.name, self.icon.as_deref(), self.color.as_deref())
+    }
+}
+
+pub struct DeletePerformanceCategoryInput {
+    pub id: String,
+    pub profile_id: String,
+}
+
+impl DeletePerformanceCategoryInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("id cannot be empty".to_string());
+        }
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+pub mod dto;
+
+use std::sync::Arc;
+use crate::application::services::profile::performance_category::service::{
+    ListPerformanceCategoriesService, CreatePerformanceCategoryService,
+    UpdatePerformanceCategoryService, DeletePerformanceCategoryService
+};
+use crate::infrastructure::repository_impl::profile::performance_category::repository::PerformanceCategoryRepositoryImpl;
+
+#[derive(Clone)]
+pub struct PerformanceCategoryUseCases {
+    pub list: Arc<ListPerformanceCategoriesService<PerformanceCategoryRepositoryImpl>>,
+    pub create: Arc<CreatePerformanceCategoryService<PerformanceCategoryRepositoryImpl>>,
+    pub update: Arc<UpdatePerformanceCategoryService<PerformanceCategoryRepositoryImpl>>,
+    pub delete: Arc<DeletePerformanceCategoryService<PerformanceCategoryRepositoryImpl>>,
+}
+
+impl PerformanceCategoryUseCases {
+    pub fn new(
+        list: ListPerformanceCategoriesService<PerformanceCategoryRepositoryImpl>,
+        create: CreatePerformanceCategoryService<PerformanceCategoryRepositoryImpl>,
+        update: UpdatePerformanceCategoryService<PerformanceCategoryRepositoryImpl>,
+        delete: DeletePerformanceCategoryService<PerformanceCategoryRepositoryImpl>,
+    ) -> Self {
+        Self {
+            list: Arc::new(list),
+            create: Arc::new(create),
+            update: Arc::new(update),
+            delete: Arc::new(delete),
+        }
+    }
+}
@@ -7,3 +7,32 @@ impl GetProfileInput {
         Self { profile_id }
     }
 }
+
+pub struct GetProfileResumeInput {
+    pub profile_id: String,
+    /// Category ids or names listed under `work`; the rest become `projects`.
+    /// When empty, categories named like "Work" or "Experience" are used.
+    pub work_categories: Vec<String>,
+}
+
+impl GetProfileResumeInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct GetProfileVCardInput {
+    pub profile_id: String,
+}
+
+impl GetProfileVCardInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
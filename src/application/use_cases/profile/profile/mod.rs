@@ -2,12 +2,16 @@ pub mod dto;
 
 use std::sync::Arc;
 use crate::application::services::profile::service::GetProfileService;
+use crate::application::services::profile::resume_service::{GetProfileResumeService, GetProfileVCardService};
 use crate::infrastructure::repository_impl::profile::repository::ProfileRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::announce::repository::AnnounceRepositoryImpl;
 
 use crate::infrastructure::repository_impl::profile::data::repository::ProfileDataRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::skill::repository::SkillRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::social::repository::SocialRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::performance::repository::PerformanceRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::performance_category::repository::PerformanceCategoryRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::tag::repository::TagRepositoryImpl;
 
 #[derive(Clone)]
 pub struct ProfileBaseUseCases {
@@ -17,17 +21,39 @@ pub struct ProfileBaseUseCases {
         SkillRepositoryImpl,
         SocialRepositoryImpl
     >>,
+    pub resume: Arc<GetProfileResumeService<
+        ProfileDataRepositoryImpl,
+        SkillRepositoryImpl,
+        SocialRepositoryImpl,
+        PerformanceRepositoryImpl,
+        PerformanceCategoryRepositoryImpl,
+        TagRepositoryImpl
+    >>,
+    pub vcard: Arc<GetProfileVCardService<ProfileDataRepositoryImpl, SocialRepositoryImpl>>,
 }
 
 impl ProfileBaseUseCases {
-    pub fn new(get_one: GetProfileService<
-        ProfileDataRepositoryImpl, 
-        AnnounceRepositoryImpl,
-        SkillRepositoryImpl,
-        SocialRepositoryImpl
-    >) -> Self {
+    pub fn new(
+        get_one: GetProfileService<
+            ProfileDataRepositoryImpl,
+            AnnounceRepositoryImpl,
+            SkillRepositoryImpl,
+            SocialRepositoryImpl
+        >,
+        resume: GetProfileResumeService<
+            ProfileDataRepositoryImpl,
+            SkillRepositoryImpl,
+            SocialRepositoryImpl,
+            PerformanceRepositoryImpl,
+            PerformanceCategoryRepositoryImpl,
+            TagRepositoryImpl
+        >,
+        vcard: GetProfileVCardService<ProfileDataRepositoryImpl, SocialRepositoryImpl>,
+    ) -> Self {
         Self {
             get_one: Arc::new(get_one),
+            resume: Arc::new(resume),
+            vcard: Arc::new(vcard),
         }
     }
 }
@@ -4,6 +4,13 @@ use crate::application::use_cases::profile::life_status::LifeStatusUseCases;
 use crate::application::use_cases::profile::announce::AnnounceUseCases;
 use crate::application::use_cases::profile::image::ImageUseCases;
 use crate::application::use_cases::profile::performance::PerformanceUseCases;
+use crate::application::use_cases::profile::performance_category::PerformanceCategoryUseCases;
+use crate::application::use_cases::profile::visibility::VisibilityUseCases;
+use crate::application::use_cases::profile::tag::TagUseCases;
+use crate::application::use_cases::profile::share_link::ShareLinkUseCases;
+use crate::application::use_cases::profile::archive::ProfileArchiveUseCases;
+use crate::application::use_cases::profile::album::AlbumUseCases;
+use crate::application::use_cases::profile::storage::StorageUseCases;
 
 #[derive(Clone)]
 pub struct ProfileUseCases {
@@ -12,11 +19,22 @@ pub struct ProfileUseCases {
     pub announce: AnnounceUseCases,
     pub image: ImageUseCases,
     pub performance: PerformanceUseCases,
+    pub performance_category: PerformanceCategoryUseCases,
+    pub visibility: VisibilityUseCases,
+    pub tag: TagUseCases,
+    pub share_link: ShareLinkUseCases,
+    pub archive: ProfileArchiveUseCases,
+    pub album: AlbumUseCases,
+    pub storage: StorageUseCases,
 }
 
 impl ProfileUseCases {
     pub fn new(services: ProfileServices) -> Self {
-        let profile = ProfileBaseUseCases::new(services.profile_get_one);
+        let profile = ProfileBaseUseCases::new(
+            services.profile_get_one,
+            services.profile_resume,
+            services.profile_vcard,
+        );
         let life_status = LifeStatusUseCases::new(services.life_status);
         let announce = AnnounceUseCases::new(services.announce);
         let image = ImageUseCases::new(
@@ -31,22 +49,92 @@ impl ProfileUseCases {
             services.image_delete_unused,
             services.image_track_usage,
             services.image_untrack_usage,
+            services.image_get_settings,
+            services.image_update_settings,
+            services.image_transform,
+            services.image_merge_duplicates,
+            services.image_list_tags,
+            services.image_create_tag,
+            services.image_update_tag,
+            services.image_delete_tag,
+            services.image_set_tags,
+            services.image_backfill_placeholders,
+            services.image_upload_create,
+            services.image_upload_append,
+            services.image_upload_get,
+            services.image_upload_complete,
+            services.image_upload_cancel,
+            services.image_upload_purge_expired,
         );
         let performance = PerformanceUseCases::new(
             services.performance_create,
             services.performance_update,
             services.performance_delete,
+            services.performance_duplicate,
             services.performance_get_content,
             services.performance_update_content,
+            services.performance_render_content,
+            services.performance_get_public,
+            services.performance_feed,
+            services.performance_import,
+            services.performance_reorder,
             services.performance_get_all,
             services.performance_get_images,
         );
+        let performance_category = PerformanceCategoryUseCases::new(
+            services.performance_category_get_all,
+            services.performance_category_create,
+            services.performance_category_update,
+            services.performance_category_delete,
+        );
+        let visibility = VisibilityUseCases::new(
+            services.visibility_get_all,
+            services.visibility_update,
+        );
+        let tag = TagUseCases::new(
+            services.tag_get_all,
+            services.tag_create,
+            services.tag_update,
+            services.tag_delete,
+            services.tag_set_performance_tags,
+            services.tag_get_performances,
+        );
+        let share_link = ShareLinkUseCases::new(
+            services.share_link_create,
+            services.share_link_get_all,
+            services.share_link_revoke,
+        );
+        let archive = ProfileArchiveUseCases::new(
+            services.archive_export,
+            services.archive_restore,
+        );
+        let album = AlbumUseCases::new(
+            services.album_get_all,
+            services.album_get_one,
+            services.album_create,
+            services.album_update,
+            services.album_delete,
+            services.album_set_images,
+            services.album_get_public_all,
+            services.album_get_public,
+        );
+        let storage = StorageUseCases::new(
+            services.storage_reconcile,
+            services.storage_collect_unused_images,
+        );
         Self {
             profile,
             life_status,
             announce,
             image,
             performance,
+            performance_category,
+            visibility,
+            tag,
+            share_link,
+            archive,
+            album,
+            storage,
         }
     }
 }
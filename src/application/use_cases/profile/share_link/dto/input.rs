@@ -0,0 +1,75 @@
+const LABEL_MAX_LEN: usize = 100;
+const PASSWORD_MIN_LEN: usize = 6;
+/// Two weeks unless the owner says otherwise
+pub const DEFAULT_EXPIRES_IN_HOURS: i64 = 24 * 14;
+const MAX_EXPIRES_IN_HOURS: i64 = 24 * 365;
+
+pub struct CreateShareLinkInput {
+    pub profile_id: String,
+    pub performance_id: String,
+    pub label: Option<String>,
+    /// Plain password the recipient has to provide; hashed before storage
+    pub password: Option<String>,
+    pub expires_in_hours: Option<i64>,
+}
+
+impl CreateShareLinkInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.performance_id.trim().is_empty() {
+            return Err("performance_id cannot be empty".to_string());
+        }
+        if self.label.as_ref().is_some_and(|l| l.chars().count() > LABEL_MAX_LEN) {
+            return Err(format!("label cannot be longer than {} characters", LABEL_MAX_LEN));
+        }
+        if self.password.as_ref().is_some_and(|p| p.chars().count() < PASSWORD_MIN_LEN) {
+            return Err(format!("password must be at least {} characters", PASSWORD_MIN_LEN));
+        }
+        if let Some(hours) = self.expires_in_hours {
+            if !(1..=MAX_EXPIRES_IN_HOURS).contains(&hours) {
+                return Err(format!("expires_in_hours must be between 1 and {}", MAX_EXPIRES_IN_HOURS));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct ListShareLinksInput {
+    pub profile_id: String,
+    pub performance_id: String,
+}
+
+impl ListShareLinksInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.performance_id.trim().is_empty() {
+            return Err("performance_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct RevokeShareLinkInput {
+    pub id: String,
+    pub profile_id: String,
+    pub performance_id: String,
+}
+
+impl RevokeShareLinkInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("id cannot be empty".to_string());
+        }
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.performance_id.trim().is_empty() {
+            return Err("performance_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
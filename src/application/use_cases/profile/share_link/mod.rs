@@ -0,0 +1,29 @@
+pub mod dto;
+
+use std::sync::Arc;
+use crate::application::services::profile::share_link::service::{
+    CreateShareLinkService, ListShareLinksService, RevokeShareLinkService
+};
+use crate::infrastructure::repository_impl::profile::performance::repository::PerformanceRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::share_link::repository::ShareLinkRepositoryImpl;
+
+#[derive(Clone)]
+pub struct ShareLinkUseCases {
+    pub create: Arc<CreateShareLinkService<PerformanceRepositoryImpl, ShareLinkRepositoryImpl>>,
+    pub list: Arc<ListShareLinksService<PerformanceRepositoryImpl, ShareLinkRepositoryImpl>>,
+    pub revoke: Arc<RevokeShareLinkService<ShareLinkRepositoryImpl>>,
+}
+
+impl ShareLinkUseCases {
+    pub fn new(
+        create: CreateShareLinkService<PerformanceRepositoryImpl, ShareLinkRepositoryImpl>,
+        list: ListShareLinksService<PerformanceRepositoryImpl, ShareLinkRepositoryImpl>,
+        revoke: RevokeShareLinkService<ShareLinkRepositoryImpl>,
+    ) -> Self {
+        Self {
+            create: Arc::new(create),
+            list: Arc::new(list),
+            revoke: Arc::new(revoke),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+const UNUSED_DAYS_MAX: i32 = 3650;
+
+pub struct ReconcileStorageInput {
+    pub profile_id: String,
+    /// Report only; nothing is deleted
+    pub dry_run: bool,
+}
+
+impl ReconcileStorageInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Deletes the unused images of every profile, for the scheduled cleanup.
+pub struct CollectUnusedImagesInput {
+    /// Only images uploaded at least this many days ago
+    pub days_old: i32,
+}
+
+impl CollectUnusedImagesInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0..=UNUSED_DAYS_MAX).contains(&self.days_old) {
+            return Err(format!("days_old must be between 0 and {}", UNUSED_DAYS_MAX));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+pub mod dto;
+
+use std::sync::Arc;
+use crate::application::services::profile::storage::service::{ReconcileStorageService, CollectUnusedImagesService};
+use crate::infrastructure::repository_impl::profile::data::repository::ProfileDataRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::repository::ImageRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::storage_repository::GcsImageStorageRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::transform_cache_repository::GcsImageTransformCacheRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::performance::repository::PerformanceRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::performance_content::repository::GcsPerformanceContentRepositoryImpl;
+
+#[derive(Clone)]
+pub struct StorageUseCases {
+    pub reconcile: Arc<ReconcileStorageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl, PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl>>,
+    pub collect_unused_images: Arc<CollectUnusedImagesService<ProfileDataRepositoryImpl, ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>>,
+}
+
+impl StorageUseCases {
+    pub fn new(
+        reconcile: ReconcileStorageService<ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl, PerformanceRepositoryImpl, GcsPerformanceContentRepositoryImpl>,
+        collect_unused_images: CollectUnusedImagesService<ProfileDataRepositoryImpl, ImageRepositoryImpl, GcsImageStorageRepositoryImpl, GcsImageTransformCacheRepositoryImpl>,
+    ) -> Self {
+        Self {
+            reconcile: Arc::new(reconcile),
+            collect_unused_images: Arc::new(collect_unused_images),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::domain::entities::profile::tag::tag::Tag;
+
+const NAME_MAX_LEN: usize = 50;
+/// `tag.slug` column size; "#" and "+" spell out longer than they are typed
+const SLUG_MAX_LEN: usize = 80;
+const LIMIT_MAX: i64 = 100;
+pub const MAX_TAGS_PER_PERFORMANCE: usize = 20;
+
+pub(crate) fn validate_name(name: &str) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("tag name cannot be empty".to_string());
+    }
+    if name.chars().count() > NAME_MAX_LEN {
+        return Err(format!("tag name cannot be longer than {} characters", NAME_MAX_LEN));
+    }
+    let slug = Tag::slug_for(name);
+    if slug.is_empty() {
+        return Err(format!("tag name '{}' must contain at least one letter or digit", name.trim()));
+    }
+    if slug.chars().count() > SLUG_MAX_LEN {
+        return Err(format!("tag name '{}' is too long once '#' and '+' are spelled out", name.trim()));
+    }
+    Ok(())
+}
+
+pub struct ListTagsInput {
+    pub profile_id: String,
+    /// Autocomplete: only tags whose name or slug starts with this
+    pub prefix: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl ListTagsInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if let Some(limit) = self.limit {
+            if !(1..=LIMIT_MAX).contains(&limit) {
+                return Err(format!("limit must be between 1 and {}", LIMIT_MAX));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct CreateTagInput {
+    pub profile_id: String,
+    pub name: String,
+}
+
+impl CreateTagInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        validate_name(&self.name)
+    }
+}
+
+pub struct UpdateTagInput {
+    pub id: String,
+    pub profile_id: String,
+    pub name: String,
+}
+
+impl UpdateTagInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("id cannot be empty".to_string());
+        }
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        validate_name(&self.name)
+    }
+}
+
+pub struct DeleteTagInput {
+    pub id: String,
+    pub profile_id: String,
+}
+
+impl DeleteTagInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("id cannot be empty".to_string());
+        }
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub struct SetPerformanceTagsInput {
+    pub profile_id: String,
+    pub performance_id: String,
+    /// Tag names; unknown ones are created
+    pub tags: Vec<String>,
+}
+
+impl SetPerformanceTagsInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.performance_id.trim().is_empty() {
+            return Err("performance_id cannot be empty".to_string());
+        }
+        if self.tags.len() > MAX_TAGS_PER_PERFORMANCE {
+            return Err(format!("a performance can have at most {} tags", MAX_TAGS_PER_PERFORMANCE));
+        }
+        self.tags.iter().try_for_each(|name| validate_name(name))
+    }
+}
+
+pub struct GetTagPerformancesInput {
+    pub profile_id: String,
+    /// Tag slug
+    pub tag: String,
+}
+
+impl GetTagPerformancesInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.profile_id.trim().is_empty() {
+            return Err("profile_id cannot be empty".to_string());
+        }
+        if self.tag.trim().is_empty() {
+            return Err("tag cannot be empty".to_string());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+pub mod dto;
+
+use std::sync::Arc;
+use crate::application::services::profile::tag::service::{
+    ListTagsService, CreateTagService, UpdateTagService, DeleteTagService,
+    SetPerformanceTagsService, GetTagPerformancesService
+};
+use crate::infrastructure::repository_impl::profile::performance::repository::PerformanceRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::tag::repository::TagRepositoryImpl;
+
+#[derive(Clone)]
+pub struct TagUseCases {
+    pub list: Arc<ListTagsService<TagRepositoryImpl>>,
+    pub create: Arc<CreateTagService<TagRepositoryImpl>>,
+    pub update: Arc<UpdateTagService<TagRepositoryImpl>>,
+    pub delete: Arc<DeleteTagService<TagRepositoryImpl>>,
+    pub set_performance_tags: Arc<SetPerformanceTagsService<PerformanceRepositoryImpl, TagRepositoryImpl>>,
+    pub get_performances: Arc<GetTagPerformancesService<PerformanceRepositoryImpl, TagRepositoryImpl>>,
+}
+
+impl TagUseCases {
+    pub fn new(
+        list: ListTagsService<TagRepositoryImpl>,
+        create: CreateTagService<TagRepositoryImpl>,
+        update: UpdateTagService<TagRepositoryImpl>,
+        delete: DeleteTagService<TagRepositoryImpl>,
+        set_performance_tags: SetPerformanceTagsService<PerformanceRepositoryImpl, TagRepositoryImpl>,
+        get_performances: GetTagPerformancesService<PerformanceRepositoryImpl, TagRepositoryImpl>,
+    ) -> Self {
+        Self {
+            list: Arc::new(list),
+            create: Arc::new(create),
+            update: Arc::new(update),
+            delete: Arc::new(delete),
+            set_performance_tags: Arc::new(set_performance_tags),
+            get_performances: Arc::new(get_performances),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+pub struct ListVisibilitiesInput;
+
+pub struct UpdateVisibilityInput {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub sort_order: i32,
+}
+
+impl UpdateVisibilityInput {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("id cannot be empty".to_string());
+        }
+        if self.name.trim().is_empty() {
+            return Err("name cannot be empty".to_string());
+        }
+        if self.name.chars().count() > 50 {
+            return Err("name cannot be longer than 50 characters".to_string());
+        }
+        Ok(())
+    }
+}
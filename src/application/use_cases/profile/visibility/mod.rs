@@ -0,0 +1,23 @@
+pub mod dto;
+
+use std::sync::Arc;
+use crate::application::services::profile::visibility::service::{ListVisibilitiesService, UpdateVisibilityService};
+use crate::infrastructure::repository_impl::profile::visibility::repository::VisibilityRepositoryImpl;
+
+#[derive(Clone)]
+pub struct VisibilityUseCases {
+    pub list: Arc<ListVisibilitiesService<VisibilityRepositoryImpl>>,
+    pub update: Arc<UpdateVisibilityService<VisibilityRepositoryImpl>>,
+}
+
+impl VisibilityUseCases {
+    pub fn new(
+        list: ListVisibilitiesService<VisibilityRepositoryImpl>,
+        update: UpdateVisibilityService<VisibilityRepositoryImpl>,
+    ) -> Self {
+        Self {
+            list: Arc::new(list),
+            update: Arc::new(update),
+        }
+    }
+}
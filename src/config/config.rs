@@ -1,5 +1,9 @@
 use std::env;
 use anyhow::{Context, Result};
+use crate::shared::utils::image_upload::{
+    UploadLimits, DEFAULT_MAX_UPLOAD_BYTES, DEFAULT_MAX_UPLOAD_DIMENSION, DEFAULT_MAX_UPLOAD_PIXELS
+};
+use crate::shared::utils::image_variants::{parse_variant_widths, DEFAULT_VARIANT_WIDTHS};
 
 #[derive(Clone)]
 pub struct Config {
@@ -13,6 +17,20 @@ pub struct Config {
     pub argon2_parallelism: u32,
     pub gcs_bucket_name: String,
     pub google_application_credentials: Option<String>,
+    pub public_site_url: String,
+    pub public_api_url: String,
+    pub site_app_id: String,
+    pub sitemap_max_urls: usize,
+    /// Widths of the resized copies generated for every upload
+    pub image_variant_widths: Vec<u32>,
+    /// Size and pixel limits every image upload is checked against
+    pub image_upload_limits: UploadLimits,
+    /// How long a resumable upload may sit idle before it is dropped
+    pub image_upload_session_ttl_secs: i64,
+    /// Hours between scheduled deletions of unused images; `None` turns them off
+    pub image_gc_interval_hours: Option<u64>,
+    /// Age in days an unused image must reach before the scheduled deletion takes it
+    pub image_gc_unused_days: i32,
 }
 
 impl Config {
@@ -47,6 +65,49 @@ impl Config {
             gcs_bucket_name: env::var("GCS_BUCKET_NAME")
                 .unwrap_or_else(|_| "my-bucket".to_string()),
             google_application_credentials: env::var("GOOGLE_APPLICATION_CREDENTIALS").ok(),
+            public_site_url: env::var("PUBLIC_SITE_URL")
+                .unwrap_or_else(|_| "http://localhost:3000".to_string()),
+            public_api_url: env::var("PUBLIC_API_URL")
+                .unwrap_or_else(|_| "http://localhost:3000".to_string()),
+            site_app_id: env::var("SITE_APP_ID")
+                .unwrap_or_else(|_| "personal_website".to_string()),
+            sitemap_max_urls: env::var("SITEMAP_MAX_URLS")
+                .unwrap_or_else(|_| "50000".to_string())
+                .parse()
+                .context("SITEMAP_MAX_URLS must be a number")?,
+            image_variant_widths: match env::var("IMAGE_VARIANT_WIDTHS") {
+                Ok(value) => parse_variant_widths(&value)
+                    .map_err(anyhow::Error::msg)
+                    .context("IMAGE_VARIANT_WIDTHS must be a comma separated list of widths")?,
+                Err(_) => DEFAULT_VARIANT_WIDTHS.to_vec(),
+            },
+            image_upload_limits: UploadLimits {
+                max_bytes: env::var("IMAGE_MAX_UPLOAD_BYTES")
+                    .unwrap_or_else(|_| DEFAULT_MAX_UPLOAD_BYTES.to_string())
+                    .parse()
+                    .context("IMAGE_MAX_UPLOAD_BYTES must be a number")?,
+                max_dimension: env::var("IMAGE_MAX_DIMENSION")
+                    .unwrap_or_else(|_| DEFAULT_MAX_UPLOAD_DIMENSION.to_string())
+                    .parse()
+                    .context("IMAGE_MAX_DIMENSION must be a number")?,
+                max_pixels: env::var("IMAGE_MAX_PIXELS")
+                    .unwrap_or_else(|_| DEFAULT_MAX_UPLOAD_PIXELS.to_string())
+                    .parse()
+                    .context("IMAGE_MAX_PIXELS must be a number")?,
+            },
+            image_upload_session_ttl_secs: env::var("IMAGE_UPLOAD_SESSION_TTL_SECS")
+                .unwrap_or_else(|_| "86400".to_string())
+                .parse()
+                .context("IMAGE_UPLOAD_SESSION_TTL_SECS must be a number")?,
+            image_gc_interval_hours: match env::var("IMAGE_GC_INTERVAL_HOURS") {
+                Ok(value) => Some(value.parse::<u64>().context("IMAGE_GC_INTERVAL_HOURS must be a number")?)
+                    .filter(|hours| *hours > 0),
+                Err(_) => None,
+            },
+            image_gc_unused_days: env::var("IMAGE_GC_UNUSED_DAYS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .context("IMAGE_GC_UNUSED_DAYS must be a number")?,
         })
     }
 }
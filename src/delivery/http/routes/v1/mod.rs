@@ -1,2 +1,15 @@
 pub mod private;
 pub mod public;
+
+#[cfg(test)]
+mod tests {
+    use super::private::private_route_table;
+    use super::public::public_v1_routes;
+
+    /// `create_router` merges both trees; a path registered twice, or one segment with two
+    /// parameter names, panics there at startup.
+    #[test]
+    fn test_public_and_private_routes_merge() {
+        let _ = public_v1_routes().merge(private_route_table());
+    }
+}
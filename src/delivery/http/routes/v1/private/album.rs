@@ -0,0 +1,26 @@
+use axum::Router;
+use axum::routing::{get, put};
+use crate::delivery::http::server::state::AppState;
+use crate::interface_adapters::http::v1::controllers::profile::album::controller::{
+    get_albums_ctrl, create_album_ctrl, get_album_ctrl, update_album_ctrl, delete_album_ctrl,
+    set_album_images_ctrl
+};
+
+pub fn album_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/",
+            get(get_albums_ctrl)
+                .post(create_album_ctrl),
+        )
+        .route(
+            "/{album_id}",
+            get(get_album_ctrl)
+                .patch(update_album_ctrl)
+                .delete(delete_album_ctrl),
+        )
+        .route(
+            "/{album_id}/images",
+            put(set_album_images_ctrl),
+        )
+}
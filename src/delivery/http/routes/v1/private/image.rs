@@ -1,25 +1,73 @@
 use axum::Router;
-use axum::routing::{get, post, patch, delete};
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{get, post, put, patch, delete};
 use crate::delivery::http::server::state::AppState;
 use crate::interface_adapters::http::v1::controllers::profile::image::controller::{
     upload_image_ctrl, update_image_metadata_ctrl, delete_image_ctrl,
     force_delete_image_ctrl, delete_unused_images_ctrl, track_image_usage_ctrl,
     untrack_image_usage_ctrl, get_images_ctrl, get_unused_images_ctrl,
-    get_image_usage_ctrl, get_image_ctrl
+    get_image_usage_ctrl, get_image_ctrl, get_image_settings_ctrl, update_image_settings_ctrl,
+    get_duplicate_images_ctrl, merge_duplicate_images_ctrl, get_image_tags_ctrl, create_image_tag_ctrl,
+    update_image_tag_ctrl, delete_image_tag_ctrl, set_image_tags_ctrl, backfill_image_placeholders_ctrl,
+    create_image_upload_ctrl, get_image_upload_ctrl, append_image_upload_chunk_ctrl, complete_image_upload_ctrl,
+    cancel_image_upload_ctrl
 };
 
 pub fn image_routes() -> Router<AppState> {
     Router::new()
         .route(
             "/",
-            post(upload_image_ctrl)
+            // The upload handler streams the file against the configured IMAGE_MAX_UPLOAD_BYTES instead
+            post(upload_image_ctrl).layer(DefaultBodyLimit::disable())
                 .get(get_images_ctrl)
         )
+        .route(
+            "/uploads",
+            post(create_image_upload_ctrl),
+        )
+        .route(
+            "/uploads/{upload_id}",
+            // Chunks are checked against IMAGE_MAX_UPLOAD_BYTES by the handler
+            patch(append_image_upload_chunk_ctrl).layer(DefaultBodyLimit::disable())
+                .get(get_image_upload_ctrl)
+                .delete(cancel_image_upload_ctrl),
+        )
+        .route(
+            "/uploads/{upload_id}/complete",
+            post(complete_image_upload_ctrl),
+        )
         .route(
             "/unused",
             delete(delete_unused_images_ctrl)
                 .get(get_unused_images_ctrl),
         )
+        .route(
+            "/settings",
+            get(get_image_settings_ctrl)
+                .put(update_image_settings_ctrl),
+        )
+        .route(
+            "/duplicates",
+            get(get_duplicate_images_ctrl),
+        )
+        .route(
+            "/duplicates/merge",
+            post(merge_duplicate_images_ctrl),
+        )
+        .route(
+            "/placeholders/backfill",
+            post(backfill_image_placeholders_ctrl),
+        )
+        .route(
+            "/tags",
+            get(get_image_tags_ctrl)
+                .post(create_image_tag_ctrl),
+        )
+        .route(
+            "/tags/{tag_id}",
+            patch(update_image_tag_ctrl)
+                .delete(delete_image_tag_ctrl),
+        )
         .route(
             "/usage",
             post(track_image_usage_ctrl)
@@ -31,10 +79,19 @@ pub fn image_routes() -> Router<AppState> {
                 .delete(delete_image_ctrl)
                 .get(get_image_ctrl),
         )
+        .route(
+            "/{image_id}/usage",
+            get(get_image_usage_ctrl),
+        )
+        // Older name of `/usage`, from when only performances used images
         .route(
             "/{image_id}/performances",
             get(get_image_usage_ctrl),
         )
+        .route(
+            "/{image_id}/tags",
+            put(set_image_tags_ctrl),
+        )
         .route(
             "/{image_id}/force",
             delete(force_delete_image_ctrl),
@@ -3,16 +3,33 @@ use crate::delivery::http::server::state::AppState;
 use crate::delivery::http::routes::v1::private::profile::profile_routes;
 use crate::delivery::http::routes::v1::private::image::image_routes;
 use crate::delivery::http::routes::v1::private::performance::performance_routes;
+use crate::delivery::http::routes::v1::private::performance_category::performance_category_routes;
+use crate::delivery::http::routes::v1::private::visibility::visibility_routes;
+use crate::delivery::http::routes::v1::private::tag::tag_routes;
+use crate::delivery::http::routes::v1::private::album::album_routes;
 use crate::delivery::http::middleware::auth_middleware::auth_middleware;
 
 pub mod profile;
 pub mod image;
 pub mod performance;
+pub mod performance_category;
+pub mod visibility;
+pub mod tag;
+pub mod album;
 
 pub fn private_v1_routes(state: AppState) -> Router<AppState> {
+    private_route_table()
+        .layer(middleware::from_fn_with_state(state, auth_middleware))
+}
+
+/// Every private route, before authentication is layered on.
+pub(crate) fn private_route_table() -> Router<AppState> {
     Router::new()
         .nest("/profiles", profile_routes())
         .nest("/profiles/{profile_id}/images", image_routes())
         .nest("/profiles/{profile_id}/performances", performance_routes())
-        .layer(middleware::from_fn_with_state(state, auth_middleware))
+        .nest("/profiles/{profile_id}/performance-categories", performance_category_routes())
+        .nest("/profiles/{profile_id}/tags", tag_routes())
+        .nest("/profiles/{profile_id}/albums", album_routes())
+        .nest("/visibilities", visibility_routes())
 }
@@ -1,11 +1,18 @@
 use axum::Router;
-use axum::routing::{get, post, patch};
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{get, post, patch, put, delete};
 use crate::delivery::http::server::state::AppState;
+use crate::application::use_cases::profile::performance::dto::input::MAX_IMPORT_ARCHIVE_BYTES;
 use crate::interface_adapters::http::v1::controllers::profile::performance::controller::{
-    create_performance_ctrl, update_performance_ctrl, delete_performance_ctrl,
+    create_performance_ctrl, update_performance_ctrl, delete_performance_ctrl, duplicate_performance_ctrl,
+    reorder_performances_ctrl, import_performances_ctrl,
     get_performance_content_ctrl, update_performance_content_ctrl, get_performances_ctrl,
     get_performance_images_ctrl
 };
+use crate::interface_adapters::http::v1::controllers::profile::tag::controller::set_performance_tags_ctrl;
+use crate::interface_adapters::http::v1::controllers::profile::share_link::controller::{
+    create_share_link_ctrl, get_share_links_ctrl, revoke_share_link_ctrl
+};
 
 pub fn performance_routes() -> Router<AppState> {
     Router::new()
@@ -14,10 +21,23 @@ pub fn performance_routes() -> Router<AppState> {
             post(create_performance_ctrl)
                 .get(get_performances_ctrl),
         )
+        .route(
+            "/import",
+            // Room for the multipart framing and the form fields around the archive
+            post(import_performances_ctrl).layer(DefaultBodyLimit::max(MAX_IMPORT_ARCHIVE_BYTES + 64 * 1024)),
+        )
+        .route(
+            "/order",
+            put(reorder_performances_ctrl),
+        )
         .route(
             "/{performance_id}",
             patch(update_performance_ctrl).delete(delete_performance_ctrl),
         )
+        .route(
+            "/{performance_id}/duplicate",
+            post(duplicate_performance_ctrl),
+        )
         .route(
             "/{performance_id}/content",
             get(get_performance_content_ctrl).patch(update_performance_content_ctrl),
@@ -26,4 +46,17 @@ pub fn performance_routes() -> Router<AppState> {
             "/{performance_id}/images",
             get(get_performance_images_ctrl),
         )
+        .route(
+            "/{performance_id}/tags",
+            put(set_performance_tags_ctrl),
+        )
+        .route(
+            "/{performance_id}/share-links",
+            post(create_share_link_ctrl)
+                .get(get_share_links_ctrl),
+        )
+        .route(
+            "/{performance_id}/share-links/{share_link_id}",
+            delete(revoke_share_link_ctrl),
+        )
 }
@@ -0,0 +1,21 @@
+use axum::Router;
+use axum::routing::{patch, post};
+use crate::delivery::http::server::state::AppState;
+use crate::interface_adapters::http::v1::controllers::profile::performance_category::controller::{
+    create_performance_category_ctrl,
+    update_performance_category_ctrl, delete_performance_category_ctrl
+};
+
+pub fn performance_category_routes() -> Router<AppState> {
+    Router::new()
+        // Listing is public, see the public taxonomy routes
+        .route(
+            "/",
+            post(create_performance_category_ctrl),
+        )
+        .route(
+            "/{category_id}",
+            patch(update_performance_category_ctrl)
+                .delete(delete_performance_category_ctrl),
+        )
+}
@@ -1,7 +1,15 @@
 use axum::Router;
-use axum::routing::get;
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{get, post};
 use crate::delivery::http::server::state::AppState;
+use crate::application::use_cases::profile::archive::dto::input::MAX_RESTORE_ARCHIVE_BYTES;
 use crate::interface_adapters::http::v1::controllers::profile::controller::get_profile_ctrl;
+use crate::interface_adapters::http::v1::controllers::profile::archive::controller::{
+    export_profile_archive_ctrl, restore_profile_archive_ctrl
+};
+use crate::interface_adapters::http::v1::controllers::profile::storage::controller::{
+    get_storage_reconciliation_ctrl, apply_storage_reconciliation_ctrl
+};
 
 pub fn profile_routes() -> Router<AppState> {
     Router::new()
@@ -9,4 +17,17 @@ pub fn profile_routes() -> Router<AppState> {
             "/{profile_id}",
             get(get_profile_ctrl),
         )
+        .route(
+            "/{profile_id}/export",
+            get(export_profile_archive_ctrl),
+        )
+        .route(
+            "/{profile_id}/restore",
+            post(restore_profile_archive_ctrl).layer(DefaultBodyLimit::max(MAX_RESTORE_ARCHIVE_BYTES + 64 * 1024)),
+        )
+        .route(
+            "/{profile_id}/storage/reconcile",
+            get(get_storage_reconciliation_ctrl)
+                .post(apply_storage_reconciliation_ctrl),
+        )
 }
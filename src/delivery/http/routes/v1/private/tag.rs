@@ -0,0 +1,20 @@
+use axum::Router;
+use axum::routing::{get, patch};
+use crate::delivery::http::server::state::AppState;
+use crate::interface_adapters::http::v1::controllers::profile::tag::controller::{
+    get_tags_ctrl, create_tag_ctrl, update_tag_ctrl, delete_tag_ctrl
+};
+
+pub fn tag_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/",
+            get(get_tags_ctrl)
+                .post(create_tag_ctrl),
+        )
+        .route(
+            "/{tag_id}",
+            patch(update_tag_ctrl)
+                .delete(delete_tag_ctrl),
+        )
+}
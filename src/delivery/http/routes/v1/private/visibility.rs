@@ -0,0 +1,13 @@
+use axum::Router;
+use axum::routing::patch;
+use crate::delivery::http::server::state::AppState;
+use crate::interface_adapters::http::v1::controllers::profile::visibility::controller::update_visibility_ctrl;
+
+pub fn visibility_routes() -> Router<AppState> {
+    // Listing is public, see the public taxonomy routes
+    Router::new()
+        .route(
+            "/{visibility_id}",
+            patch(update_visibility_ctrl),
+        )
+}
@@ -0,0 +1,19 @@
+use axum::Router;
+use axum::routing::get;
+use crate::delivery::http::server::state::AppState;
+use crate::interface_adapters::http::v1::controllers::profile::album::controller::{
+    get_public_albums_ctrl, get_public_album_ctrl
+};
+
+/// Under `publicAlbums` like `publicPerformances`: `albums` is the owner's private listing.
+pub fn album_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{profile_id}/publicAlbums",
+            get(get_public_albums_ctrl),
+        )
+        .route(
+            "/{profile_id}/publicAlbums/{slug_or_id}",
+            get(get_public_album_ctrl),
+        )
+}
@@ -0,0 +1,12 @@
+use axum::Router;
+use axum::routing::get;
+use crate::delivery::http::server::state::AppState;
+use crate::interface_adapters::http::v1::controllers::profile::image::controller::transform_image_ctrl;
+
+pub fn image_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/images/{image_id}/transform",
+            get(transform_image_ctrl),
+        )
+}
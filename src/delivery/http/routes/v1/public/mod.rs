@@ -3,13 +3,21 @@ use crate::delivery::http::server::state::AppState;
 use crate::delivery::http::routes::v1::public::profile::profile_routes;
 use crate::delivery::http::routes::v1::public::website::website_routes;
 use crate::delivery::http::routes::v1::public::performance::performance_routes;
+use crate::delivery::http::routes::v1::public::taxonomy::taxonomy_routes;
+use crate::delivery::http::routes::v1::public::image::image_routes;
+use crate::delivery::http::routes::v1::public::album::album_routes;
 
 pub mod profile;
 pub mod website;
 pub mod performance;
+pub mod taxonomy;
+pub mod image;
+pub mod album;
 
 pub fn public_v1_routes() -> Router<AppState> {
     Router::new()
-        .nest("/profiles", profile_routes().merge(performance_routes()))
+        .nest("/profiles", profile_routes().merge(performance_routes()).merge(album_routes()))
         .merge(website_routes())
+        .merge(taxonomy_routes())
+        .merge(image_routes())
 }
@@ -1,7 +1,10 @@
 use axum::Router;
 use axum::routing::get;
 use crate::delivery::http::server::state::AppState;
-use crate::interface_adapters::http::v1::controllers::profile::performance::controller::get_public_performances_ctrl;
+use crate::interface_adapters::http::v1::controllers::profile::performance::controller::{
+    get_public_performances_ctrl, get_public_performance_rendered_ctrl, get_public_performance_ctrl,
+    get_performance_atom_feed_ctrl, get_performance_rss_feed_ctrl, get_performance_json_feed_ctrl
+};
 
 pub fn performance_routes() -> Router<AppState> {
     Router::new()
@@ -9,4 +12,26 @@ pub fn performance_routes() -> Router<AppState> {
             "/{profile_id}/publicPerformances",
             get(get_public_performances_ctrl),
         )
+        .route(
+            "/{profile_id}/publicPerformances/{performance_id}/rendered",
+            get(get_public_performance_rendered_ctrl),
+        )
+        // Takes a slug or an id; named like the private `/performances/{performance_id}` routes,
+        // matchit rejects two names for one segment
+        .route(
+            "/{profile_id}/performances/{performance_id}",
+            get(get_public_performance_ctrl),
+        )
+        .route(
+            "/{profile_id}/feed.xml",
+            get(get_performance_atom_feed_ctrl),
+        )
+        .route(
+            "/{profile_id}/rss.xml",
+            get(get_performance_rss_feed_ctrl),
+        )
+        .route(
+            "/{profile_id}/feed.json",
+            get(get_performance_json_feed_ctrl),
+        )
 }
@@ -0,0 +1,23 @@
+use axum::Router;
+use axum::routing::get;
+use crate::delivery::http::server::state::AppState;
+use crate::interface_adapters::http::v1::controllers::profile::performance_category::controller::get_performance_categories_ctrl;
+use crate::interface_adapters::http::v1::controllers::profile::visibility::controller::get_visibilities_ctrl;
+use crate::interface_adapters::http::v1::controllers::profile::tag::controller::get_tag_performances_ctrl;
+
+pub fn taxonomy_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/profiles/{profile_id}/performance-categories",
+            get(get_performance_categories_ctrl),
+        )
+        // Same parameter name as the private tag routes, matchit rejects two names for one segment
+        .route(
+            "/profiles/{profile_id}/tags/{tag_id}",
+            get(get_tag_performances_ctrl),
+        )
+        .route(
+            "/visibilities",
+            get(get_visibilities_ctrl),
+        )
+}
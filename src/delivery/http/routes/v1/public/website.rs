@@ -3,6 +3,10 @@ use axum::routing::get;
 use crate::delivery::http::server::state::AppState;
 use crate::interface_adapters::http::v1::controllers::website::feature_status::controller::get_website_feature_status_ctrl;
 use crate::interface_adapters::http::v1::controllers::website::config::controller::get_app_config_ctrl;
+use crate::interface_adapters::http::v1::controllers::website::seo::controller::{
+    get_sitemap_ctrl, get_sitemap_page_ctrl, get_robots_txt_ctrl, get_app_robots_txt_ctrl,
+    get_profile_metadata_ctrl, get_performance_metadata_ctrl, get_performance_og_card_ctrl
+};
 
 pub fn website_routes() -> Router<AppState> {
     Router::new()
@@ -14,4 +18,32 @@ pub fn website_routes() -> Router<AppState> {
             "/app/{appID}/config",
             get(get_app_config_ctrl),
         )
+        .route(
+            "/app/{appID}/robots.txt",
+            get(get_app_robots_txt_ctrl),
+        )
+        .route(
+            "/robots.txt",
+            get(get_robots_txt_ctrl),
+        )
+        .route(
+            "/sitemap.xml",
+            get(get_sitemap_ctrl),
+        )
+        .route(
+            "/sitemaps/{page}",
+            get(get_sitemap_page_ctrl),
+        )
+        .route(
+            "/seo/profiles/{profile_id}",
+            get(get_profile_metadata_ctrl),
+        )
+        .route(
+            "/seo/profiles/{profile_id}/performances/{slug_or_id}",
+            get(get_performance_metadata_ctrl),
+        )
+        .route(
+            "/seo/profiles/{profile_id}/performances/{slug_or_id}/og.png",
+            get(get_performance_og_card_ctrl),
+        )
 }
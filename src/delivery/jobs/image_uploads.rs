@@ -0,0 +1,22 @@
+use std::time::Duration;
+use tracing::{info, warn};
+use crate::application::use_cases::profile::image::dto::input::PurgeExpiredImageUploadsInput;
+use crate::application::use_cases::use_case::UseCase;
+use crate::delivery::http::server::state::AppState;
+
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Drops resumable uploads whose session TTL ran out, along with their chunks.
+pub fn spawn_expired_upload_cleanup(state: AppState) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(CLEANUP_INTERVAL);
+        loop {
+            interval.tick().await;
+            match state.profile.image.upload_purge_expired.execute(PurgeExpiredImageUploadsInput).await {
+                Ok(result) if result.deleted > 0 => info!("INFO: Removed {} expired upload sessions", result.deleted),
+                Ok(_) => {}
+                Err(e) => warn!("Failed to remove expired upload sessions: {}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,13 @@
+pub mod image_uploads;
+pub mod unused_images;
+
+use crate::config::config::Config;
+use crate::delivery::http::server::state::AppState;
+
+/// Starts the periodic maintenance tasks. They run for the lifetime of the process.
+pub fn spawn_background_jobs(state: &AppState, config: &Config) {
+    image_uploads::spawn_expired_upload_cleanup(state.clone());
+    if let Some(hours) = config.image_gc_interval_hours {
+        unused_images::spawn_unused_image_collection(state.clone(), hours, config.image_gc_unused_days);
+    }
+}
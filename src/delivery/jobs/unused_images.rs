@@ -0,0 +1,25 @@
+use std::time::Duration;
+use tracing::{info, warn};
+use crate::application::use_cases::profile::storage::dto::input::CollectUnusedImagesInput;
+use crate::application::use_cases::use_case::UseCase;
+use crate::delivery::http::server::state::AppState;
+
+/// Deletes the unused images of every profile once they are `days_old`, every `interval_hours`.
+/// The first run waits a full interval, so a restart loop never turns into a deletion loop.
+pub fn spawn_unused_image_collection(state: AppState, interval_hours: u64, days_old: i32) {
+    info!("INFO: Unused images older than {} days are deleted every {} hours", days_old, interval_hours);
+    tokio::spawn(async move {
+        let period = Duration::from_secs(interval_hours * 60 * 60);
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        loop {
+            interval.tick().await;
+            match state.profile.storage.collect_unused_images.execute(CollectUnusedImagesInput { days_old }).await {
+                Ok(result) => info!(
+                    "INFO: Deleted {} unused images ({} bytes) across {} profiles",
+                    result.deleted_count, result.freed_bytes, result.profiles
+                ),
+                Err(e) => warn!("Failed to delete unused images: {}", e),
+            }
+        }
+    });
+}
@@ -1 +1,2 @@
-pub(crate) mod http;
\ No newline at end of file
+pub(crate) mod http;
+pub(crate) mod jobs;
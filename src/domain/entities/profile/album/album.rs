@@ -0,0 +1,40 @@
+use serde::Serialize;
+use crate::shared::utils::markdown_renderer::slugify;
+
+/// A named, ordered collection of images of one profile.
+#[derive(Debug, Clone, Serialize)]
+pub struct Album {
+    pub id: String,
+    pub profile_id: String,
+    pub visibility_id: String,
+    pub title: String,
+
+    /// URL form of the title, unique per profile
+    pub slug: String,
+
+    pub description: Option<String>,
+
+    /// `None` uses the first image as cover
+    pub cover_image_id: Option<String>,
+
+    pub created_at: String,
+    pub updated_at: Option<String>,
+}
+
+impl Album {
+    /// Slug for a title; falls back to `id` when the title has no sluggable characters.
+    pub fn slug_for(title: &str, id: &str) -> String {
+        let slug = slugify(title);
+        if slug.is_empty() { id.to_string() } else { slug }
+    }
+}
+
+/// An album as listed, with its size and the URL of its cover.
+#[derive(Debug, Clone, Serialize)]
+pub struct AlbumSummary {
+    #[serde(flatten)]
+    pub album: Album,
+    pub image_count: i64,
+    /// Chosen cover, otherwise the first image; `None` for an empty album
+    pub cover_url: Option<String>,
+}
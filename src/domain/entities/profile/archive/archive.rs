@@ -0,0 +1,229 @@
+use serde::{Deserialize, Serialize};
+
+/// `format` field of every manifest, so unrelated zips are rejected early.
+pub const ARCHIVE_FORMAT: &str = "personal-website-profile";
+/// Bumped whenever the snapshot layout changes. 2 added albums, image tags and capture metadata.
+pub const ARCHIVE_VERSION: u32 = 2;
+/// Oldest version still restored; parts it lacks are restored empty.
+pub const MIN_ARCHIVE_VERSION: u32 = 1;
+
+pub const MANIFEST_PATH: &str = "manifest.json";
+pub const SNAPSHOT_PATH: &str = "profile.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveFile {
+    pub path: String,
+    pub size: u64,
+    /// Lowercase hex SHA-256 of the file contents
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveManifest {
+    pub format: String,
+    pub version: u32,
+    pub exported_at: String,
+    pub profile_id: String,
+    /// Every file of the archive except the manifest itself
+    pub files: Vec<ArchiveFile>,
+    /// Storage objects that could not be read at export time
+    #[serde(default)]
+    pub missing: Vec<String>,
+}
+
+/// Whether a profile can receive a restore.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileArchiveTarget {
+    Missing,
+    Empty,
+    HasData,
+}
+
+/// Shared lookup rows (life statuses, skills, socials, announce types) are referenced by id and
+/// carried along so a fresh instance can recreate the ones it lacks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifeStatusEntry {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub color_token: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileEntry {
+    pub id: String,
+    pub display_name: String,
+    pub headline: Option<String>,
+    pub bio: Option<String>,
+    pub avatar_url: Option<String>,
+    pub contact_email: Option<String>,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+    pub current_status: Option<LifeStatusEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillEntry {
+    pub skill_id: String,
+    pub name: String,
+    pub skill_type_id: String,
+    pub skill_type: String,
+    pub scale_id: String,
+    pub logo_url: Option<String>,
+    pub scale_value: f64,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SocialEntry {
+    pub social_id: String,
+    pub name: String,
+    pub logo_url: Option<String>,
+    pub link: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnounceEntry {
+    pub id: String,
+    pub announce_type_id: String,
+    pub announce_type: String,
+    pub color_token: Option<String>,
+    pub title: Option<String>,
+    pub message: Option<String>,
+    pub link_url: Option<String>,
+    pub link_text: Option<String>,
+    pub starts_at: Option<String>,
+    pub ends_at: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryEntry {
+    pub id: String,
+    pub name: String,
+    pub icon: Option<String>,
+    pub color: Option<String>,
+    pub sort_order: i32,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagEntry {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerformanceEntry {
+    pub id: String,
+    pub category_id: String,
+    pub visibility_id: String,
+    pub title: String,
+    pub summary: Option<String>,
+    /// Where the content lived at export time; replaced on restore
+    pub content_url: Option<String>,
+    pub content_type: String,
+    pub content_preview: Option<String>,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub location: Option<String>,
+    pub close: bool,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+    pub sort_order: i32,
+    pub pinned: bool,
+    pub tag_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageEntry {
+    pub id: String,
+    pub filename: String,
+    pub original_filename: String,
+    pub storage_url: String,
+    pub file_size: i32,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub mime_type: String,
+    pub alt_text: Option<String>,
+    pub caption: Option<String>,
+    pub created_at: String,
+    #[serde(default)]
+    pub captured_at: Option<String>,
+    #[serde(default)]
+    pub camera_model: Option<String>,
+    #[serde(default)]
+    pub tag_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageTagEntry {
+    pub id: String,
+    pub name: String,
+    pub color: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlbumEntry {
+    pub id: String,
+    pub visibility_id: String,
+    pub title: String,
+    pub slug: String,
+    pub description: Option<String>,
+    pub cover_image_id: Option<String>,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+    /// Images in album order
+    pub image_ids: Vec<String>,
+}
+
+/// Archives written before usage had other owners only carry `performance_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageUsageEntry {
+    pub image_id: String,
+    /// One of the `ImageUsageOwner` names
+    #[serde(default = "performance_owner_type")]
+    pub owner_type: String,
+    #[serde(alias = "performance_id")]
+    pub owner_id: String,
+    pub usage_count: i32,
+    pub first_used_at: String,
+    pub last_used_at: String,
+}
+
+fn performance_owner_type() -> String {
+    "performance".to_string()
+}
+
+/// Database side of a profile, as stored in `profile.json`.
+/// Markdown content and image binaries travel as separate archive files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileSnapshot {
+    pub profile: ProfileEntry,
+    pub skills: Vec<SkillEntry>,
+    pub socials: Vec<SocialEntry>,
+    pub announces: Vec<AnnounceEntry>,
+    pub categories: Vec<CategoryEntry>,
+    pub tags: Vec<TagEntry>,
+    pub performances: Vec<PerformanceEntry>,
+    pub images: Vec<ImageEntry>,
+    pub image_usage: Vec<ImageUsageEntry>,
+    #[serde(default)]
+    pub image_tags: Vec<ImageTagEntry>,
+    #[serde(default)]
+    pub albums: Vec<AlbumEntry>,
+}
+
+impl ProfileSnapshot {
+    pub fn content_path(performance_id: &str) -> String {
+        format!("content/{}.md", performance_id)
+    }
+
+    pub fn image_path(image: &ImageEntry) -> String {
+        format!("images/{}", image.filename)
+    }
+}
@@ -11,4 +11,15 @@ pub struct Image {
     pub alt_text: Option<String>,
     pub caption: Option<String>,
     pub created_at: String,
+    /// EXIF capture date, only kept when the profile opted in
+    pub captured_at: Option<String>,
+    pub camera_model: Option<String>,
+    /// SHA-256 of the stored file; `None` for uploads that predate hashing
+    pub content_hash: Option<String>,
+    /// Loading placeholders; `None` until computed for images that predate them
+    pub blurhash: Option<String>,
+    /// "#rrggbb"
+    pub dominant_color: Option<String>,
+    /// Tiny preview as a `data:` URI
+    pub lqip: Option<String>,
 }
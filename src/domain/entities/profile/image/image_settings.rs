@@ -0,0 +1,18 @@
+/// How uploads of a profile are handled. Profiles without a stored row use `ImageSettings::default_for`.
+#[derive(Clone)]
+pub struct ImageSettings {
+    pub profile_id: String,
+    /// Keep EXIF capture date and camera model when stripping metadata from uploads
+    pub keep_capture_metadata: bool,
+    pub updated_at: Option<String>,
+}
+
+impl ImageSettings {
+    pub fn default_for(profile_id: &str) -> Self {
+        Self {
+            profile_id: profile_id.to_string(),
+            keep_capture_metadata: false,
+            updated_at: None,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+/// A label for organizing uploads ("screenshot", "diagram"). Names are unique per profile, ignoring case.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageTag {
+    pub id: String,
+    pub profile_id: String,
+    pub name: String,
+
+    /// Free-form color for the UI ("#1e88e5" or a design token)
+    pub color: Option<String>,
+
+    pub created_at: String,
+}
+
+/// An image tag with the number of images it is attached to.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageTagCount {
+    #[serde(flatten)]
+    pub tag: ImageTag,
+    pub image_count: i64,
+}
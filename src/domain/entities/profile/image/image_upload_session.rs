@@ -0,0 +1,20 @@
+use serde::Serialize;
+
+/// A resumable upload in progress. Chunks arrive in order, `received_bytes` is where the next one starts.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageUploadSession {
+    pub id: String,
+    pub profile_id: String,
+    pub original_filename: String,
+    pub total_size: i64,
+    pub received_bytes: i64,
+
+    // Applied to the image once the upload completes
+    pub alt_text: Option<String>,
+    pub caption: Option<String>,
+    /// "existing" or "conflict", see `DuplicateImagePolicy`
+    pub on_duplicate: String,
+
+    pub created_at: String,
+    pub expires_at: String,
+}
@@ -1,16 +1,74 @@
+/// What holds a reference to an image. Performances are tracked when their content is saved,
+/// every other owner is synced from its current row, see `sync_owner_usage`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageUsageOwner {
+    /// `owner_id` is the performance id
+    Performance,
+    /// `owner_id` is the profile id
+    Avatar,
+    Social,
+    Skill,
+    Announce,
+}
+
+impl ImageUsageOwner {
+    /// Owners whose usage is derived from the profile data rather than tracked on save.
+    pub const SYNCED: [ImageUsageOwner; 4] = [Self::Avatar, Self::Social, Self::Skill, Self::Announce];
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "performance" => Some(Self::Performance),
+            "avatar" => Some(Self::Avatar),
+            "social" => Some(Self::Social),
+            "skill" => Some(Self::Skill),
+            "announce" => Some(Self::Announce),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Performance => "performance",
+            Self::Avatar => "avatar",
+            Self::Social => "social",
+            Self::Skill => "skill",
+            Self::Announce => "announce",
+        }
+    }
+}
+
 pub struct ImageUsage {
     pub id: String,
     pub image_id: String,
-    pub performance_id: String,
+    pub owner_type: ImageUsageOwner,
+    pub owner_id: String,
     pub usage_count: i32,
     pub first_used_at: String,
     pub last_used_at: String,
 }
 
 pub struct ImageUsageInfo {
-    pub performance_id: String,
+    pub owner_type: ImageUsageOwner,
+    pub owner_id: String,
+    /// Performance title, profile display name, social or skill name, announce title
     pub title: String,
     pub usage_count: i32,
     pub first_used_at: String,
     pub last_used_at: String,
 }
+
+/// A profile row that may point at images: one URL (avatar, logo, announce link) and/or markdown (announce message).
+pub struct ImageOwnerSource {
+    pub owner_type: ImageUsageOwner,
+    pub owner_id: String,
+    pub url: Option<String>,
+    pub markdown: Option<String>,
+}
+
+/// How often one owner references one image, as written by `sync_owner_usage`.
+pub struct ImageOwnerReference {
+    pub image_id: String,
+    pub owner_type: ImageUsageOwner,
+    pub owner_id: String,
+    pub usage_count: i32,
+}
@@ -0,0 +1,13 @@
+/// Resized copy of an image, stored beside the original.
+#[derive(Clone)]
+pub struct ImageVariant {
+    pub id: String,
+    pub image_id: String,
+    pub filename: String,
+    pub storage_url: String,
+    pub width: i32,
+    pub height: i32,
+    pub mime_type: String,
+    pub file_size: i32,
+    pub created_at: String,
+}
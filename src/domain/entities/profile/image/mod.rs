@@ -1,2 +1,6 @@
 pub mod image;
+pub mod image_settings;
+pub mod image_tag;
 pub mod image_usage;
+pub mod image_variant;
+pub mod image_upload_session;
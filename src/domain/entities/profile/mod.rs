@@ -1,7 +1,13 @@
 pub mod life_status;
 pub mod announce;
 pub mod performance;
+pub mod performance_category;
+pub mod visibility;
+pub mod tag;
+pub mod share_link;
+pub mod archive;
 pub mod image;
+pub mod album;
 pub mod skill;
 pub mod social;
 pub mod profile;
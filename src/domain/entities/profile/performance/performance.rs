@@ -1,4 +1,6 @@
 use serde::Serialize;
+use crate::domain::entities::profile::tag::tag::Tag;
+use crate::shared::utils::markdown_renderer::slugify;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct Performance {
@@ -25,6 +27,14 @@ pub struct Performance {
     pub close: bool,
     pub created_at: String,
     pub updated_at: Option<String>,
+
+    /// Manual position within the profile; lower comes first
+    pub sort_order: i32,
+    /// Pinned performances are listed before all others
+    pub pinned: bool,
+
+    /// Stored separately from the performance row; empty unless the service loaded them
+    pub tags: Vec<Tag>,
 }
 
 impl Performance {
@@ -61,6 +71,24 @@ impl Performance {
             close,
             created_at,
             updated_at,
+            sort_order: 0,
+            pinned: false,
+            tags: Vec::new(),
         }
     }
-}
\ No newline at end of file
+
+    /// URL slug derived from the title; falls back to the id when the title has no sluggable characters.
+    pub fn slug(&self) -> String {
+        let slug = slugify(&self.title);
+        if slug.is_empty() { self.id.clone() } else { slug }
+    }
+
+    /// Key used to order performances chronologically: start date when known, then creation date.
+    pub fn chronological_key(&self) -> (&str, &str, &str) {
+        (
+            self.start_date.as_deref().unwrap_or(&self.created_at),
+            &self.created_at,
+            &self.id,
+        )
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PerformanceCategory {
+    pub id: String,
+    pub profile_id: String,
+    pub name: String,
+
+    /// Icon identifier understood by the frontend (e.g. "mdi:code-tags")
+    pub icon: Option<String>,
+
+    /// Hex color ("#1e88e5") or design-token name
+    pub color: Option<String>,
+
+    /// Ascending display order
+    pub sort_order: i32,
+
+    pub created_at: String,
+    pub updated_at: Option<String>,
+}
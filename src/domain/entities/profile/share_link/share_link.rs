@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// A revocable, expiring link that exposes one performance regardless of its visibility.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShareLink {
+    pub id: String,
+    pub profile_id: String,
+    pub performance_id: String,
+
+    /// SHA-256 of the token; the token itself is only shown once, at creation
+    #[serde(skip_serializing)]
+    pub token_hash: String,
+
+    /// Argon2 hash when the link is password protected
+    #[serde(skip_serializing)]
+    pub password_hash: Option<String>,
+
+    /// Free text for the owner ("Recruiter at ACME")
+    pub label: Option<String>,
+
+    pub expires_at: DateTime<Utc>,
+    pub access_count: i64,
+    pub last_accessed_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ShareLink {
+    pub fn has_password(&self) -> bool {
+        self.password_hash.is_some()
+    }
+
+    /// Not revoked and not expired
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+}
@@ -0,0 +1,60 @@
+use serde::Serialize;
+use crate::shared::utils::markdown_renderer::slugify;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Tag {
+    pub id: String,
+    pub profile_id: String,
+
+    /// Display name as first entered ("Rust", "C#")
+    pub name: String,
+
+    /// URL form of the name, unique per profile ("rust", "c-sharp")
+    pub slug: String,
+
+    pub created_at: String,
+}
+
+impl Tag {
+    /// Slug for a tag name. `#` and `+` are spelled out so "C#", "C++" and "C" stay distinct.
+    pub fn slug_for(name: &str) -> String {
+        slugify(&name.replace('#', " sharp ").replace('+', " plus "))
+    }
+}
+
+/// A tag with the number of performances it is attached to.
+#[derive(Debug, Clone, Serialize)]
+pub struct TagCount {
+    #[serde(flatten)]
+    pub tag: Tag,
+    pub performance_count: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slug_for_spells_out_symbols() {
+        assert_eq!(Tag::slug_for("Rust"), "rust");
+        assert_eq!(Tag::slug_for("C#"), "c-sharp");
+        assert_eq!(Tag::slug_for("C++"), "c-plus-plus");
+        assert_eq!(Tag::slug_for("  Machine   Learning "), "machine-learning");
+    }
+
+    #[test]
+    fn test_slug_for_keeps_similar_names_distinct() {
+        let slugs = [Tag::slug_for("C"), Tag::slug_for("C#"), Tag::slug_for("C++")];
+        assert_ne!(slugs[0], slugs[1]);
+        assert_ne!(slugs[1], slugs[2]);
+        assert_ne!(slugs[0], slugs[2]);
+    }
+
+    #[test]
+    fn test_slug_for_can_outgrow_the_name() {
+        // 50 characters, the longest name allowed, still make a 249 character slug
+        let name = "+".repeat(50);
+        assert_eq!(Tag::slug_for(&name).len(), 50 * "plus-".len() - 1);
+        assert_eq!(Tag::slug_for("!!!"), "");
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Serialize;
+
+/// Listed everywhere: public lists, feeds and sitemaps.
+pub const VISIBILITY_PUBLIC: &str = "visibility_public";
+/// Not listed anywhere, but not secret either.
+pub const VISIBILITY_UNLISTED: &str = "visibility_unlisted";
+/// Only visible to the owner.
+pub const VISIBILITY_PRIVATE: &str = "visibility_private";
+/// Requires a password to view.
+pub const VISIBILITY_PASSWORD: &str = "visibility_password";
+
+/// The visibility levels are fixed because the API's behaviour depends on them;
+/// only their labels and display order can be edited.
+#[derive(Debug, Clone, Serialize)]
+pub struct Visibility {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub sort_order: i32,
+}
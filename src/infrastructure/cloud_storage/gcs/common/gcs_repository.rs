@@ -1,4 +1,8 @@
+use chrono::DateTime;
 use google_cloud_storage::client::Client;
+use google_cloud_storage::http::objects::list::ListObjectsRequest;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+use crate::interface_adapters::gateways::common::stored_object::StoredObject;
 
 #[derive(Clone)]
 pub struct GcsRepository {
@@ -18,4 +22,31 @@ impl GcsRepository {
     pub fn client(&self) -> &Client {
         &self.client
     }
+
+    /// Every object under `prefix`, following all result pages. Names come back without the prefix.
+    pub async fn list_objects(&self, prefix: &str) -> Result<Vec<StoredObject>, RepositoryError> {
+        let mut objects = Vec::new();
+        let mut page_token = None;
+        loop {
+            let page = self.client.list_objects(&ListObjectsRequest {
+                bucket: self.bucket_name.clone(),
+                prefix: Some(prefix.to_string()),
+                page_token,
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| RepositoryError::InternalError(format!("GCS List Error: {}", e)))?;
+
+            objects.extend(page.items.unwrap_or_default().into_iter().map(|object| StoredObject {
+                name: object.name.strip_prefix(prefix).unwrap_or(&object.name).to_string(),
+                size: object.size,
+                created_at: object.time_created.and_then(|t| DateTime::from_timestamp(t.unix_timestamp(), 0)),
+            }));
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(objects),
+            }
+        }
+    }
 }
@@ -5,7 +5,16 @@ use crate::infrastructure::repository_impl::profile::announce::repository::Annou
 use crate::infrastructure::repository_impl::profile::image::repository::ImageRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::performance::repository::PerformanceRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::performance_content::repository::GcsPerformanceContentRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::og_card::repository::GcsOgCardRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::performance_category::repository::PerformanceCategoryRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::visibility::repository::VisibilityRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::tag::repository::TagRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::share_link::repository::ShareLinkRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::archive::repository::ProfileArchiveRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::album::repository::AlbumRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::image::storage_repository::GcsImageStorageRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::transform_cache_repository::GcsImageTransformCacheRepositoryImpl;
+use crate::infrastructure::repository_impl::profile::image::upload_session_repository::ImageUploadSessionRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::repository::ProfileRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::data::repository::ProfileDataRepositoryImpl;
 use crate::infrastructure::repository_impl::profile::skill::repository::SkillRepositoryImpl;
@@ -20,8 +29,17 @@ pub struct ProfileRepositories {
     pub announce: AnnounceRepositoryImpl,
     pub image: ImageRepositoryImpl,
     pub image_storage: GcsImageStorageRepositoryImpl,
+    pub image_transform_cache: GcsImageTransformCacheRepositoryImpl,
+    pub image_upload_session: ImageUploadSessionRepositoryImpl,
     pub performance: PerformanceRepositoryImpl,
     pub performance_content: GcsPerformanceContentRepositoryImpl,
+    pub og_card: GcsOgCardRepositoryImpl,
+    pub performance_category: PerformanceCategoryRepositoryImpl,
+    pub visibility: VisibilityRepositoryImpl,
+    pub tag: TagRepositoryImpl,
+    pub share_link: ShareLinkRepositoryImpl,
+    pub archive: ProfileArchiveRepositoryImpl,
+    pub album: AlbumRepositoryImpl,
 }
 
 impl ProfileRepositories {
@@ -35,8 +53,17 @@ impl ProfileRepositories {
             announce: AnnounceRepositoryImpl::new(dbs.mysql.clone()),
             image: ImageRepositoryImpl::new(dbs.mysql.clone()),
             image_storage: GcsImageStorageRepositoryImpl::new(cloud_storage.gcs.clone()),
+            image_transform_cache: GcsImageTransformCacheRepositoryImpl::new(cloud_storage.gcs.clone()),
+            image_upload_session: ImageUploadSessionRepositoryImpl::new(dbs.mysql.clone()),
             performance: PerformanceRepositoryImpl::new(dbs.mysql.clone()),
             performance_content: GcsPerformanceContentRepositoryImpl::new(cloud_storage.gcs.clone()),
+            og_card: GcsOgCardRepositoryImpl::new(cloud_storage.gcs.clone()),
+            performance_category: PerformanceCategoryRepositoryImpl::new(dbs.mysql.clone()),
+            visibility: VisibilityRepositoryImpl::new(dbs.mysql.clone()),
+            tag: TagRepositoryImpl::new(dbs.mysql.clone()),
+            share_link: ShareLinkRepositoryImpl::new(dbs.mysql.clone()),
+            archive: ProfileArchiveRepositoryImpl::new(dbs.mysql.clone()),
+            album: AlbumRepositoryImpl::new(dbs.mysql.clone()),
         }
     }
 }
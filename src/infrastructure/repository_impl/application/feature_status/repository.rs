@@ -53,4 +53,20 @@ impl AppRepository for AppRepositoryImpl {
             note: r.note,
         }).collect())
     }
+
+    async fn find_robots_txt(&self, website_id: &str) -> Result<Option<String>, RepositoryError> {
+        let robots_txt = sqlx::query_scalar::<_, Option<String>>(
+            r#"
+            SELECT robots_txt
+            FROM website_config
+            WHERE website_id = ?
+            "#
+        )
+        .bind(website_id)
+        .fetch_optional(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(robots_txt.flatten())
+    }
 }
@@ -0,0 +1,251 @@
+use async_trait::async_trait;
+use crate::domain::entities::profile::album::album::{Album, AlbumSummary};
+use crate::domain::entities::profile::image::image::Image;
+use crate::infrastructure::db::mysql::common::mysql_repository::MySqlRepository;
+use crate::infrastructure::repository_impl::profile::image::repository::{image_from_row, IMAGE_COLUMNS};
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+use crate::interface_adapters::gateways::repositories::profile::album::album_repository::AlbumRepository;
+
+#[derive(sqlx::FromRow)]
+struct AlbumRecord {
+    id: String,
+    profile_id: String,
+    visibility_id: String,
+    title: String,
+    slug: String,
+    description: Option<String>,
+    cover_image_id: Option<String>,
+    created_at: sqlx::types::chrono::NaiveDateTime,
+    updated_at: Option<sqlx::types::chrono::NaiveDateTime>,
+}
+
+#[derive(sqlx::FromRow)]
+struct AlbumSummaryRecord {
+    id: String,
+    profile_id: String,
+    visibility_id: String,
+    title: String,
+    slug: String,
+    description: Option<String>,
+    cover_image_id: Option<String>,
+    created_at: sqlx::types::chrono::NaiveDateTime,
+    updated_at: Option<sqlx::types::chrono::NaiveDateTime>,
+    image_count: i64,
+    cover_url: Option<String>,
+}
+
+fn record_to_album(r: AlbumRecord) -> Album {
+    Album {
+        id: r.id,
+        profile_id: r.profile_id,
+        visibility_id: r.visibility_id,
+        title: r.title,
+        slug: r.slug,
+        description: r.description,
+        cover_image_id: r.cover_image_id,
+        created_at: r.created_at.to_string(),
+        updated_at: r.updated_at.map(|d| d.to_string()),
+    }
+}
+
+const ALBUM_COLUMNS: &str = "id, profile_id, visibility_id, title, slug, description, cover_image_id, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct AlbumRepositoryImpl {
+    mysql: MySqlRepository,
+}
+
+impl AlbumRepositoryImpl {
+    pub fn new(mysql: MySqlRepository) -> Self {
+        Self { mysql }
+    }
+}
+
+#[async_trait]
+impl AlbumRepository for AlbumRepositoryImpl {
+    async fn find_by_profile_id(
+        &self,
+        profile_id: &str,
+        visibility_id: Option<&str>,
+    ) -> Result<Vec<AlbumSummary>, RepositoryError> {
+        let mut query_builder = sqlx::QueryBuilder::new(
+            r#"
+            SELECT a.id, a.profile_id, a.visibility_id, a.title, a.slug, a.description,
+                   a.cover_image_id, a.created_at, a.updated_at,
+                   (SELECT COUNT(*) FROM album_image ai WHERE ai.album_id = a.id) AS image_count,
+                   COALESCE(
+                       (SELECT c.storage_url FROM image c WHERE c.id = a.cover_image_id),
+                       (SELECT f.storage_url
+                        FROM album_image ai
+                        INNER JOIN image f ON f.id = ai.image_id
+                        WHERE ai.album_id = a.id
+                        ORDER BY ai.position
+                        LIMIT 1)
+                   ) AS cover_url
+            FROM album a
+            WHERE a.profile_id = "#
+        );
+        query_builder.push_bind(profile_id);
+        if let Some(vid) = visibility_id {
+            query_builder.push(" AND a.visibility_id = ");
+            query_builder.push_bind(vid);
+        }
+        query_builder.push(" ORDER BY a.created_at DESC");
+
+        let rows = query_builder
+            .build_query_as::<AlbumSummaryRecord>()
+            .fetch_all(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| AlbumSummary {
+                album: Album {
+                    id: r.id,
+                    profile_id: r.profile_id,
+                    visibility_id: r.visibility_id,
+                    title: r.title,
+                    slug: r.slug,
+                    description: r.description,
+                    cover_image_id: r.cover_image_id,
+                    created_at: r.created_at.to_string(),
+                    updated_at: r.updated_at.map(|d| d.to_string()),
+                },
+                image_count: r.image_count,
+                cover_url: r.cover_url,
+            })
+            .collect())
+    }
+
+    async fn find_by_id(&self, id: &str, profile_id: &str) -> Result<Option<Album>, RepositoryError> {
+        let row = sqlx::query_as::<_, AlbumRecord>(&format!(
+            "SELECT {} FROM album WHERE id = ? AND profile_id = ?",
+            ALBUM_COLUMNS
+        ))
+        .bind(id)
+        .bind(profile_id)
+        .fetch_optional(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(record_to_album))
+    }
+
+    async fn find_by_slug(&self, profile_id: &str, slug: &str) -> Result<Option<Album>, RepositoryError> {
+        let row = sqlx::query_as::<_, AlbumRecord>(&format!(
+            "SELECT {} FROM album WHERE profile_id = ? AND slug = ?",
+            ALBUM_COLUMNS
+        ))
+        .bind(profile_id)
+        .bind(slug)
+        .fetch_optional(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(record_to_album))
+    }
+
+    async fn create(&self, album: Album) -> Result<Album, RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO album (id, profile_id, visibility_id, title, slug, description, cover_image_id, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#
+        )
+        .bind(&album.id)
+        .bind(&album.profile_id)
+        .bind(&album.visibility_id)
+        .bind(&album.title)
+        .bind(&album.slug)
+        .bind(&album.description)
+        .bind(&album.cover_image_id)
+        .bind(&album.created_at)
+        .execute(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(album)
+    }
+
+    async fn update(&self, album: Album) -> Result<Album, RepositoryError> {
+        sqlx::query(
+            r#"
+            UPDATE album
+            SET visibility_id = ?, title = ?, slug = ?, description = ?, cover_image_id = ?, updated_at = ?
+            WHERE id = ? AND profile_id = ?
+            "#
+        )
+        .bind(&album.visibility_id)
+        .bind(&album.title)
+        .bind(&album.slug)
+        .bind(&album.description)
+        .bind(&album.cover_image_id)
+        .bind(&album.updated_at)
+        .bind(&album.id)
+        .bind(&album.profile_id)
+        .execute(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(album)
+    }
+
+    async fn delete(&self, id: &str, profile_id: &str) -> Result<(), RepositoryError> {
+        // album_image rows go with it (ON DELETE CASCADE), the images themselves stay
+        sqlx::query("DELETE FROM album WHERE id = ? AND profile_id = ?")
+            .bind(id)
+            .bind(profile_id)
+            .execute(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_images(&self, album_id: &str) -> Result<Vec<Image>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM image
+            INNER JOIN album_image ai ON ai.image_id = image.id
+            WHERE ai.album_id = ?
+            ORDER BY ai.position
+            "#,
+            IMAGE_COLUMNS
+        ))
+        .bind(album_id)
+        .fetch_all(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.iter().map(image_from_row).collect())
+    }
+
+    async fn set_images(&self, album_id: &str, image_ids: &[String]) -> Result<(), RepositoryError> {
+        let mut tx = self.mysql.pool().begin().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        sqlx::query("DELETE FROM album_image WHERE album_id = ?")
+            .bind(album_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if !image_ids.is_empty() {
+            let mut query_builder = sqlx::QueryBuilder::new("INSERT INTO album_image (album_id, image_id, position) ");
+            query_builder.push_values(image_ids.iter().enumerate(), |mut row, (position, image_id)| {
+                row.push_bind(album_id).push_bind(image_id).push_bind(position as i32);
+            });
+            query_builder.build()
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        tx.commit().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,865 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use crate::domain::entities::profile::archive::archive::{
+    AlbumEntry, AnnounceEntry, CategoryEntry, ImageEntry, ImageTagEntry, ImageUsageEntry, LifeStatusEntry,
+    PerformanceEntry, ProfileArchiveTarget, ProfileEntry, ProfileSnapshot, SkillEntry, SocialEntry, TagEntry
+};
+use crate::infrastructure::db::mysql::common::mysql_repository::MySqlRepository;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+use crate::interface_adapters::gateways::repositories::profile::archive::archive_repository::ProfileArchiveRepository;
+
+// Dates are read with CAST(... AS CHAR) so the archive keeps MySQL's own text form,
+// which MySQL parses back unchanged on restore.
+
+#[derive(sqlx::FromRow)]
+struct ProfileRecord {
+    id: String,
+    display_name: String,
+    headline: Option<String>,
+    bio: Option<String>,
+    avatar_url: Option<String>,
+    contact_email: Option<String>,
+    created_at: String,
+    updated_at: Option<String>,
+    status_id: Option<String>,
+    status_name: Option<String>,
+    status_description: Option<String>,
+    status_color_token: Option<String>,
+}
+
+#[derive(sqlx::FromRow)]
+struct SkillRecord {
+    skill_id: String,
+    name: String,
+    skill_type_id: String,
+    skill_type: String,
+    scale_id: String,
+    logo_url: Option<String>,
+    scale_value: f64,
+    description: Option<String>,
+}
+
+#[derive(sqlx::FromRow)]
+struct SocialRecord {
+    social_id: String,
+    name: String,
+    logo_url: Option<String>,
+    link: String,
+}
+
+#[derive(sqlx::FromRow)]
+struct AnnounceRecord {
+    id: String,
+    announce_type_id: String,
+    announce_type: String,
+    color_token: Option<String>,
+    title: Option<String>,
+    message: Option<String>,
+    link_url: Option<String>,
+    link_text: Option<String>,
+    starts_at: Option<String>,
+    ends_at: Option<String>,
+    created_at: String,
+}
+
+#[derive(sqlx::FromRow)]
+struct CategoryRecord {
+    id: String,
+    name: String,
+    icon: Option<String>,
+    color: Option<String>,
+    sort_order: i32,
+    created_at: String,
+    updated_at: Option<String>,
+}
+
+#[derive(sqlx::FromRow)]
+struct TagRecord {
+    id: String,
+    name: String,
+    slug: String,
+    created_at: String,
+}
+
+#[derive(sqlx::FromRow)]
+struct PerformanceRecord {
+    id: String,
+    category_id: String,
+    visibility_id: String,
+    title: String,
+    summary: Option<String>,
+    content_url: Option<String>,
+    content_type: Option<String>,
+    content_preview: Option<String>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    location: Option<String>,
+    close: i8,
+    created_at: String,
+    updated_at: Option<String>,
+    sort_order: i32,
+    pinned: i8,
+}
+
+#[derive(sqlx::FromRow)]
+struct PerformanceTagRecord {
+    performance_id: String,
+    tag_id: String,
+}
+
+#[derive(sqlx::FromRow)]
+struct ImageRecord {
+    id: String,
+    filename: String,
+    original_filename: String,
+    storage_url: String,
+    file_size: i32,
+    width: Option<i32>,
+    height: Option<i32>,
+    mime_type: String,
+    alt_text: Option<String>,
+    caption: Option<String>,
+    created_at: String,
+    captured_at: Option<String>,
+    camera_model: Option<String>,
+}
+
+#[derive(sqlx::FromRow)]
+struct ImageTagRecord {
+    id: String,
+    name: String,
+    color: Option<String>,
+    created_at: String,
+}
+
+#[derive(sqlx::FromRow)]
+struct ImageTagListRecord {
+    image_id: String,
+    tag_id: String,
+}
+
+#[derive(sqlx::FromRow)]
+struct AlbumRecord {
+    id: String,
+    visibility_id: String,
+    title: String,
+    slug: String,
+    description: Option<String>,
+    cover_image_id: Option<String>,
+    created_at: String,
+    updated_at: Option<String>,
+}
+
+#[derive(sqlx::FromRow)]
+struct AlbumImageRecord {
+    album_id: String,
+    image_id: String,
+}
+
+#[derive(sqlx::FromRow)]
+struct ImageUsageRecord {
+    image_id: String,
+    owner_type: String,
+    owner_id: String,
+    usage_count: Option<i32>,
+    first_used_at: String,
+    last_used_at: String,
+}
+
+#[derive(sqlx::FromRow)]
+struct TargetRecord {
+    profile_exists: i64,
+    has_data: i64,
+}
+
+fn db_err(e: sqlx::Error) -> RepositoryError {
+    RepositoryError::DatabaseError(e.to_string())
+}
+
+#[derive(Clone)]
+pub struct ProfileArchiveRepositoryImpl {
+    mysql: MySqlRepository,
+}
+
+impl ProfileArchiveRepositoryImpl {
+    pub fn new(mysql: MySqlRepository) -> Self {
+        Self { mysql }
+    }
+}
+
+#[async_trait]
+impl ProfileArchiveRepository for ProfileArchiveRepositoryImpl {
+    async fn load_snapshot(&self, profile_id: &str) -> Result<Option<ProfileSnapshot>, RepositoryError> {
+        let pool = self.mysql.pool();
+
+        let Some(profile) = sqlx::query_as::<_, ProfileRecord>(
+            r#"
+            SELECT
+                p.id, p.display_name, p.headline, p.bio, p.avatar_url, p.contact_email,
+                CAST(p.created_at AS CHAR) AS created_at, CAST(p.updated_at AS CHAR) AS updated_at,
+                ls.id AS status_id, ls.name AS status_name, ls.description AS status_description,
+                ls.color_token AS status_color_token
+            FROM profile p
+            LEFT JOIN life_status ls ON p.current_status_id = ls.id
+            WHERE p.id = ?
+            "#
+        )
+        .bind(profile_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(db_err)?
+        else {
+            return Ok(None);
+        };
+
+        let skills = sqlx::query_as::<_, SkillRecord>(
+            r#"
+            SELECT
+                s.id AS skill_id, s.name, s.skill_type_id, st.name AS skill_type, s.scale_id, s.logo_url,
+                CAST(sl.scale_value AS DOUBLE) AS scale_value, sl.description
+            FROM skill_list sl
+            JOIN skill s ON sl.skill_id = s.id
+            JOIN skill_type st ON s.skill_type_id = st.id
+            WHERE sl.profile_id = ?
+            "#
+        )
+        .bind(profile_id)
+        .fetch_all(pool)
+        .await
+        .map_err(db_err)?;
+
+        let socials = sqlx::query_as::<_, SocialRecord>(
+            r#"
+            SELECT s.id AS social_id, s.name, s.logo_url, sl.link
+            FROM social_list sl
+            JOIN social s ON sl.social_id = s.id
+            WHERE sl.profile_id = ?
+            "#
+        )
+        .bind(profile_id)
+        .fetch_all(pool)
+        .await
+        .map_err(db_err)?;
+
+        let announces = sqlx::query_as::<_, AnnounceRecord>(
+            r#"
+            SELECT
+                l.id, l.announce_type_id, t.name AS announce_type, t.color_token,
+                l.title, l.message, l.link_url, l.link_text,
+                CAST(l.starts_at AS CHAR) AS starts_at, CAST(l.ends_at AS CHAR) AS ends_at,
+                CAST(l.created_at AS CHAR) AS created_at
+            FROM announce_list l
+            JOIN announce_type t ON l.announce_type_id = t.id
+            WHERE l.profile_id = ?
+            "#
+        )
+        .bind(profile_id)
+        .fetch_all(pool)
+        .await
+        .map_err(db_err)?;
+
+        let categories = sqlx::query_as::<_, CategoryRecord>(
+            r#"
+            SELECT id, name, icon, color, sort_order,
+                   CAST(created_at AS CHAR) AS created_at, CAST(updated_at AS CHAR) AS updated_at
+            FROM performance_category
+            WHERE profile_id = ?
+            ORDER BY sort_order, name
+            "#
+        )
+        .bind(profile_id)
+        .fetch_all(pool)
+        .await
+        .map_err(db_err)?;
+
+        let tags = sqlx::query_as::<_, TagRecord>(
+            "SELECT id, name, slug, CAST(created_at AS CHAR) AS created_at FROM tag WHERE profile_id = ? ORDER BY name"
+        )
+        .bind(profile_id)
+        .fetch_all(pool)
+        .await
+        .map_err(db_err)?;
+
+        let performances = sqlx::query_as::<_, PerformanceRecord>(
+            r#"
+            SELECT
+                id, category_id, visibility_id, title, summary, content_url, content_type, content_preview,
+                CAST(start_date AS CHAR) AS start_date, CAST(end_date AS CHAR) AS end_date, location, close,
+                CAST(created_at AS CHAR) AS created_at, CAST(updated_at AS CHAR) AS updated_at,
+                sort_order, pinned
+            FROM performance
+            WHERE profile_id = ?
+            ORDER BY created_at, id
+            "#
+        )
+        .bind(profile_id)
+        .fetch_all(pool)
+        .await
+        .map_err(db_err)?;
+
+        let mut performance_tags: HashMap<String, Vec<String>> = HashMap::new();
+        let rows = sqlx::query_as::<_, PerformanceTagRecord>(
+            r#"
+            SELECT pt.performance_id, pt.tag_id
+            FROM performance_tag pt
+            JOIN performance p ON p.id = pt.performance_id
+            WHERE p.profile_id = ?
+            "#
+        )
+        .bind(profile_id)
+        .fetch_all(pool)
+        .await
+        .map_err(db_err)?;
+        for r in rows {
+            performance_tags.entry(r.performance_id).or_default().push(r.tag_id);
+        }
+
+        let images = sqlx::query_as::<_, ImageRecord>(
+            r#"
+            SELECT id, filename, original_filename, storage_url, file_size, width, height, mime_type,
+                   alt_text, caption, CAST(created_at AS CHAR) AS created_at,
+                   CAST(captured_at AS CHAR) AS captured_at, camera_model
+            FROM image
+            WHERE profile_id = ?
+            ORDER BY created_at, id
+            "#
+        )
+        .bind(profile_id)
+        .fetch_all(pool)
+        .await
+        .map_err(db_err)?;
+
+        let image_usage = sqlx::query_as::<_, ImageUsageRecord>(
+            r#"
+            SELECT iu.image_id, iu.owner_type, iu.owner_id, iu.usage_count,
+                   CAST(iu.first_used_at AS CHAR) AS first_used_at, CAST(iu.last_used_at AS CHAR) AS last_used_at
+            FROM image_usage iu
+            JOIN image i ON i.id = iu.image_id
+            WHERE i.profile_id = ?
+            "#
+        )
+        .bind(profile_id)
+        .fetch_all(pool)
+        .await
+        .map_err(db_err)?;
+
+        let image_tags = sqlx::query_as::<_, ImageTagRecord>(
+            "SELECT id, name, color, CAST(created_at AS CHAR) AS created_at FROM image_tag WHERE profile_id = ? ORDER BY name"
+        )
+        .bind(profile_id)
+        .fetch_all(pool)
+        .await
+        .map_err(db_err)?;
+
+        let mut image_tag_ids: HashMap<String, Vec<String>> = HashMap::new();
+        let rows = sqlx::query_as::<_, ImageTagListRecord>(
+            r#"
+            SELECT itl.image_id, itl.tag_id
+            FROM image_tag_list itl
+            JOIN image i ON i.id = itl.image_id
+            WHERE i.profile_id = ?
+            "#
+        )
+        .bind(profile_id)
+        .fetch_all(pool)
+        .await
+        .map_err(db_err)?;
+        for r in rows {
+            image_tag_ids.entry(r.image_id).or_default().push(r.tag_id);
+        }
+
+        let albums = sqlx::query_as::<_, AlbumRecord>(
+            r#"
+            SELECT id, visibility_id, title, slug, description, cover_image_id,
+                   CAST(created_at AS CHAR) AS created_at, CAST(updated_at AS CHAR) AS updated_at
+            FROM album
+            WHERE profile_id = ?
+            ORDER BY created_at, id
+            "#
+        )
+        .bind(profile_id)
+        .fetch_all(pool)
+        .await
+        .map_err(db_err)?;
+
+        let mut album_image_ids: HashMap<String, Vec<String>> = HashMap::new();
+        let rows = sqlx::query_as::<_, AlbumImageRecord>(
+            r#"
+            SELECT ai.album_id, ai.image_id
+            FROM album_image ai
+            JOIN album a ON a.id = ai.album_id
+            WHERE a.profile_id = ?
+            ORDER BY ai.album_id, ai.position
+            "#
+        )
+        .bind(profile_id)
+        .fetch_all(pool)
+        .await
+        .map_err(db_err)?;
+        for r in rows {
+            album_image_ids.entry(r.album_id).or_default().push(r.image_id);
+        }
+
+        let current_status = match (profile.status_id, profile.status_name, profile.status_color_token) {
+            (Some(id), Some(name), Some(color_token)) => Some(LifeStatusEntry {
+                id,
+                name,
+                description: profile.status_description,
+                color_token,
+            }),
+            _ => None,
+        };
+
+        Ok(Some(ProfileSnapshot {
+            profile: ProfileEntry {
+                id: profile.id,
+                display_name: profile.display_name,
+                headline: profile.headline,
+                bio: profile.bio,
+                avatar_url: profile.avatar_url,
+                contact_email: profile.contact_email,
+                created_at: profile.created_at,
+                updated_at: profile.updated_at,
+                current_status,
+            },
+            skills: skills.into_iter().map(|r| SkillEntry {
+                skill_id: r.skill_id,
+                name: r.name,
+                skill_type_id: r.skill_type_id,
+                skill_type: r.skill_type,
+                scale_id: r.scale_id,
+                logo_url: r.logo_url,
+                scale_value: r.scale_value,
+                description: r.description,
+            }).collect(),
+            socials: socials.into_iter().map(|r| SocialEntry {
+                social_id: r.social_id,
+                name: r.name,
+                logo_url: r.logo_url,
+                link: r.link,
+            }).collect(),
+            announces: announces.into_iter().map(|r| AnnounceEntry {
+                id: r.id,
+                announce_type_id: r.announce_type_id,
+                announce_type: r.announce_type,
+                color_token: r.color_token,
+                title: r.title,
+                message: r.message,
+                link_url: r.link_url,
+                link_text: r.link_text,
+                starts_at: r.starts_at,
+                ends_at: r.ends_at,
+                created_at: r.created_at,
+            }).collect(),
+            categories: categories.into_iter().map(|r| CategoryEntry {
+                id: r.id,
+                name: r.name,
+                icon: r.icon,
+                color: r.color,
+                sort_order: r.sort_order,
+                created_at: r.created_at,
+                updated_at: r.updated_at,
+            }).collect(),
+            tags: tags.into_iter().map(|r| TagEntry {
+                id: r.id,
+                name: r.name,
+                slug: r.slug,
+                created_at: r.created_at,
+            }).collect(),
+            performances: performances.into_iter().map(|r| PerformanceEntry {
+                tag_ids: performance_tags.remove(&r.id).unwrap_or_default(),
+                id: r.id,
+                category_id: r.category_id,
+                visibility_id: r.visibility_id,
+                title: r.title,
+                summary: r.summary,
+                content_url: r.content_url,
+                content_type: r.content_type.unwrap_or_else(|| "markdown".to_string()),
+                content_preview: r.content_preview,
+                start_date: r.start_date,
+                end_date: r.end_date,
+                location: r.location,
+                close: r.close != 0,
+                created_at: r.created_at,
+                updated_at: r.updated_at,
+                sort_order: r.sort_order,
+                pinned: r.pinned != 0,
+            }).collect(),
+            images: images.into_iter().map(|r| ImageEntry {
+                tag_ids: image_tag_ids.remove(&r.id).unwrap_or_default(),
+                id: r.id,
+                filename: r.filename,
+                original_filename: r.original_filename,
+                storage_url: r.storage_url,
+                file_size: r.file_size,
+                width: r.width,
+                height: r.height,
+                mime_type: r.mime_type,
+                alt_text: r.alt_text,
+                caption: r.caption,
+                created_at: r.created_at,
+                captured_at: r.captured_at,
+                camera_model: r.camera_model,
+            }).collect(),
+            image_usage: image_usage.into_iter().map(|r| ImageUsageEntry {
+                image_id: r.image_id,
+                owner_type: r.owner_type,
+                owner_id: r.owner_id,
+                usage_count: r.usage_count.unwrap_or(1),
+                first_used_at: r.first_used_at,
+                last_used_at: r.last_used_at,
+            }).collect(),
+            image_tags: image_tags.into_iter().map(|r| ImageTagEntry {
+                id: r.id,
+                name: r.name,
+                color: r.color,
+                created_at: r.created_at,
+            }).collect(),
+            albums: albums.into_iter().map(|r| AlbumEntry {
+                image_ids: album_image_ids.remove(&r.id).unwrap_or_default(),
+                id: r.id,
+                visibility_id: r.visibility_id,
+                title: r.title,
+                slug: r.slug,
+                description: r.description,
+                cover_image_id: r.cover_image_id,
+                created_at: r.created_at,
+                updated_at: r.updated_at,
+            }).collect(),
+        }))
+    }
+
+    async fn find_target(&self, profile_id: &str) -> Result<ProfileArchiveTarget, RepositoryError> {
+        let row = sqlx::query_as::<_, TargetRecord>(
+            r#"
+            SELECT
+                CAST(EXISTS(SELECT 1 FROM profile WHERE id = ?) AS SIGNED) AS profile_exists,
+                CAST(
+                    EXISTS(SELECT 1 FROM performance WHERE profile_id = ?)
+                    OR EXISTS(SELECT 1 FROM image WHERE profile_id = ?)
+                    OR EXISTS(SELECT 1 FROM performance_category WHERE profile_id = ?)
+                    OR EXISTS(SELECT 1 FROM tag WHERE profile_id = ?)
+                    OR EXISTS(SELECT 1 FROM skill_list WHERE profile_id = ?)
+                    OR EXISTS(SELECT 1 FROM social_list WHERE profile_id = ?)
+                    OR EXISTS(SELECT 1 FROM announce_list WHERE profile_id = ?)
+                    OR EXISTS(SELECT 1 FROM image_tag WHERE profile_id = ?)
+                    OR EXISTS(SELECT 1 FROM album WHERE profile_id = ?)
+                AS SIGNED) AS has_data
+            "#
+        )
+        .bind(profile_id)
+        .bind(profile_id)
+        .bind(profile_id)
+        .bind(profile_id)
+        .bind(profile_id)
+        .bind(profile_id)
+        .bind(profile_id)
+        .bind(profile_id)
+        .bind(profile_id)
+        .bind(profile_id)
+        .fetch_one(self.mysql.pool())
+        .await
+        .map_err(db_err)?;
+
+        Ok(match (row.profile_exists != 0, row.has_data != 0) {
+            (false, _) => ProfileArchiveTarget::Missing,
+            (true, false) => ProfileArchiveTarget::Empty,
+            (true, true) => ProfileArchiveTarget::HasData,
+        })
+    }
+
+    async fn restore_snapshot(&self, user_id: &str, snapshot: &ProfileSnapshot) -> Result<(), RepositoryError> {
+        let profile_id = snapshot.profile.id.as_str();
+        let mut tx = self.mysql.pool().begin().await.map_err(db_err)?;
+
+        // Shared lookup rows first; existing ones win
+        if let Some(status) = &snapshot.profile.current_status {
+            sqlx::query("INSERT IGNORE INTO life_status (id, name, description, color_token) VALUES (?, ?, ?, ?)")
+                .bind(&status.id)
+                .bind(&status.name)
+                .bind(&status.description)
+                .bind(&status.color_token)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_err)?;
+        }
+        for skill in &snapshot.skills {
+            sqlx::query("INSERT IGNORE INTO skill_type (id, name) VALUES (?, ?)")
+                .bind(&skill.skill_type_id)
+                .bind(&skill.skill_type)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_err)?;
+            sqlx::query("INSERT IGNORE INTO skill (id, name, skill_type_id, scale_id, logo_url) VALUES (?, ?, ?, ?, ?)")
+                .bind(&skill.skill_id)
+                .bind(&skill.name)
+                .bind(&skill.skill_type_id)
+                .bind(&skill.scale_id)
+                .bind(&skill.logo_url)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_err)?;
+        }
+        for social in &snapshot.socials {
+            sqlx::query("INSERT IGNORE INTO social (id, name, logo_url) VALUES (?, ?, ?)")
+                .bind(&social.social_id)
+                .bind(&social.name)
+                .bind(&social.logo_url)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_err)?;
+        }
+        for announce in &snapshot.announces {
+            sqlx::query("INSERT IGNORE INTO announce_type (id, name, color_token) VALUES (?, ?, ?)")
+                .bind(&announce.announce_type_id)
+                .bind(&announce.announce_type)
+                .bind(&announce.color_token)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_err)?;
+        }
+
+        let profile = &snapshot.profile;
+        sqlx::query(
+            r#"
+            INSERT INTO profile
+                (id, user_id, display_name, headline, bio, avatar_url, contact_email, created_at, updated_at, current_status_id)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                display_name = VALUES(display_name),
+                headline = VALUES(headline),
+                bio = VALUES(bio),
+                avatar_url = VALUES(avatar_url),
+                contact_email = VALUES(contact_email),
+                updated_at = VALUES(updated_at),
+                current_status_id = VALUES(current_status_id)
+            "#
+        )
+        .bind(profile_id)
+        .bind(user_id)
+        .bind(&profile.display_name)
+        .bind(&profile.headline)
+        .bind(&profile.bio)
+        .bind(&profile.avatar_url)
+        .bind(profile.contact_email.as_deref().unwrap_or_default())
+        .bind(&profile.created_at)
+        .bind(&profile.updated_at)
+        .bind(profile.current_status.as_ref().map(|s| &s.id))
+        .execute(&mut *tx)
+        .await
+        .map_err(db_err)?;
+
+        for skill in &snapshot.skills {
+            sqlx::query("INSERT INTO skill_list (profile_id, skill_id, scale_value, description) VALUES (?, ?, ?, ?)")
+                .bind(profile_id)
+                .bind(&skill.skill_id)
+                .bind(skill.scale_value)
+                .bind(&skill.description)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_err)?;
+        }
+        for social in &snapshot.socials {
+            sqlx::query("INSERT INTO social_list (profile_id, social_id, link) VALUES (?, ?, ?)")
+                .bind(profile_id)
+                .bind(&social.social_id)
+                .bind(&social.link)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_err)?;
+        }
+        for announce in &snapshot.announces {
+            sqlx::query(
+                r#"
+                INSERT INTO announce_list
+                    (id, profile_id, announce_type_id, title, message, link_url, link_text, starts_at, ends_at, created_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#
+            )
+            .bind(&announce.id)
+            .bind(profile_id)
+            .bind(&announce.announce_type_id)
+            .bind(&announce.title)
+            .bind(&announce.message)
+            .bind(&announce.link_url)
+            .bind(&announce.link_text)
+            .bind(&announce.starts_at)
+            .bind(&announce.ends_at)
+            .bind(&announce.created_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_err)?;
+        }
+
+        for category in &snapshot.categories {
+            sqlx::query(
+                r#"
+                INSERT INTO performance_category (id, profile_id, name, icon, color, sort_order, created_at, updated_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                "#
+            )
+            .bind(&category.id)
+            .bind(profile_id)
+            .bind(&category.name)
+            .bind(&category.icon)
+            .bind(&category.color)
+            .bind(category.sort_order)
+            .bind(&category.created_at)
+            .bind(&category.updated_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_err)?;
+        }
+        for tag in &snapshot.tags {
+            sqlx::query("INSERT INTO tag (id, profile_id, name, slug, created_at) VALUES (?, ?, ?, ?, ?)")
+                .bind(&tag.id)
+                .bind(profile_id)
+                .bind(&tag.name)
+                .bind(&tag.slug)
+                .bind(&tag.created_at)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_err)?;
+        }
+
+        for perf in &snapshot.performances {
+            sqlx::query(
+                r#"
+                INSERT INTO performance
+                    (id, profile_id, category_id, visibility_id, title, summary, content_url, content_type, content_preview,
+                     start_date, end_date, location, close, created_at, updated_at, sort_order, pinned)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#
+            )
+            .bind(&perf.id)
+            .bind(profile_id)
+            .bind(&perf.category_id)
+            .bind(&perf.visibility_id)
+            .bind(&perf.title)
+            .bind(&perf.summary)
+            .bind(&perf.content_url)
+            .bind(&perf.content_type)
+            .bind(&perf.content_preview)
+            .bind(&perf.start_date)
+            .bind(&perf.end_date)
+            .bind(&perf.location)
+            .bind(perf.close as i8)
+            .bind(&perf.created_at)
+            .bind(&perf.updated_at)
+            .bind(perf.sort_order)
+            .bind(perf.pinned as i8)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_err)?;
+
+            for tag_id in &perf.tag_ids {
+                sqlx::query("INSERT INTO performance_tag (performance_id, tag_id) VALUES (?, ?)")
+                    .bind(&perf.id)
+                    .bind(tag_id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(db_err)?;
+            }
+        }
+
+        for image in &snapshot.images {
+            sqlx::query(
+                r#"
+                INSERT INTO image
+                    (id, profile_id, filename, original_filename, storage_url, file_size, width, height,
+                     mime_type, alt_text, caption, created_at, captured_at, camera_model)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#
+            )
+            .bind(&image.id)
+            .bind(profile_id)
+            .bind(&image.filename)
+            .bind(&image.original_filename)
+            .bind(&image.storage_url)
+            .bind(image.file_size)
+            .bind(image.width)
+            .bind(image.height)
+            .bind(&image.mime_type)
+            .bind(&image.alt_text)
+            .bind(&image.caption)
+            .bind(&image.created_at)
+            .bind(&image.captured_at)
+            .bind(&image.camera_model)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_err)?;
+        }
+        for tag in &snapshot.image_tags {
+            sqlx::query("INSERT INTO image_tag (id, profile_id, name, color, created_at) VALUES (?, ?, ?, ?, ?)")
+                .bind(&tag.id)
+                .bind(profile_id)
+                .bind(&tag.name)
+                .bind(&tag.color)
+                .bind(&tag.created_at)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_err)?;
+        }
+        for image in &snapshot.images {
+            for tag_id in &image.tag_ids {
+                sqlx::query("INSERT INTO image_tag_list (image_id, tag_id) VALUES (?, ?)")
+                    .bind(&image.id)
+                    .bind(tag_id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(db_err)?;
+            }
+        }
+        for album in &snapshot.albums {
+            sqlx::query(
+                r#"
+                INSERT INTO album
+                    (id, profile_id, visibility_id, title, slug, description, cover_image_id, created_at, updated_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#
+            )
+            .bind(&album.id)
+            .bind(profile_id)
+            .bind(&album.visibility_id)
+            .bind(&album.title)
+            .bind(&album.slug)
+            .bind(&album.description)
+            .bind(&album.cover_image_id)
+            .bind(&album.created_at)
+            .bind(&album.updated_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_err)?;
+
+            for (position, image_id) in album.image_ids.iter().enumerate() {
+                sqlx::query("INSERT INTO album_image (album_id, image_id, position) VALUES (?, ?, ?)")
+                    .bind(&album.id)
+                    .bind(image_id)
+                    .bind(position as i32)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(db_err)?;
+            }
+        }
+        for usage in &snapshot.image_usage {
+            sqlx::query(
+                r#"
+                INSERT INTO image_usage (id, image_id, owner_type, owner_id, usage_count, first_used_at, last_used_at)
+                VALUES (UUID(), ?, ?, ?, ?, ?, ?)
+                "#
+            )
+            .bind(&usage.image_id)
+            .bind(&usage.owner_type)
+            .bind(&usage.owner_id)
+            .bind(usage.usage_count)
+            .bind(&usage.first_used_at)
+            .bind(&usage.last_used_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_err)?;
+        }
+
+        tx.commit().await.map_err(db_err)?;
+        Ok(())
+    }
+}
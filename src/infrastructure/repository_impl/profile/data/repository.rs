@@ -22,6 +22,31 @@ struct ProfileRecord {
     status_color_token: Option<String>,
 }
 
+fn record_to_profile(r: ProfileRecord) -> Profile {
+    let current_status = match (r.status_name, r.status_color_token) {
+        (Some(name), Some(color_token)) => Some(LifeStatus {
+            name,
+            description: r.status_description,
+            color_token,
+        }),
+        _ => None,
+    };
+
+    Profile {
+        id: r.id,
+        user_id: r.user_id,
+        display_name: r.display_name,
+        headline: r.headline,
+        bio: r.bio,
+        avatar_url: r.avatar_url,
+        contact_email: Some(r.contact_email),
+        created_at: r.created_at.to_string(),
+        updated_at: r.updated_at.map(|d| d.to_string()),
+        current_status_id: r.current_status_id,
+        current_status,
+    }
+}
+
 #[derive(Clone)]
 pub struct ProfileDataRepositoryImpl {
     mysql: MySqlRepository,
@@ -51,29 +76,24 @@ impl ProfileRepository for ProfileDataRepositoryImpl {
         .await
         .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(row.map(|r| {
-            let current_status = match (r.status_name, r.status_color_token) {
-                (Some(name), Some(color_token)) => Some(LifeStatus {
-                    name,
-                    description: r.status_description,
-                    color_token,
-                }),
-                _ => None,
-            };
+        Ok(row.map(record_to_profile))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Profile>, RepositoryError> {
+        let rows = sqlx::query_as::<_, ProfileRecord>(
+            r#"
+            SELECT 
+                p.id, p.user_id, p.display_name, p.headline, p.bio, p.avatar_url, p.contact_email, p.created_at, p.updated_at, p.current_status_id,
+                ls.name as status_name, ls.description as status_description, ls.color_token as status_color_token
+            FROM profile p
+            LEFT JOIN life_status ls ON p.current_status_id = ls.id
+            ORDER BY p.created_at
+            "#
+        )
+        .fetch_all(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-            Profile {
-                id: r.id,
-                user_id: r.user_id,
-                display_name: r.display_name,
-                headline: r.headline,
-                bio: r.bio,
-                avatar_url: r.avatar_url,
-                contact_email: Some(r.contact_email),
-                created_at: r.created_at.to_string(),
-                updated_at: r.updated_at.map(|d| d.to_string()),
-                current_status_id: r.current_status_id,
-                current_status,
-            }
-        }))
+        Ok(rows.into_iter().map(record_to_profile).collect())
     }
 }
@@ -1,2 +1,4 @@
 pub mod repository;
 pub mod storage_repository;
+pub mod transform_cache_repository;
+pub mod upload_session_repository;
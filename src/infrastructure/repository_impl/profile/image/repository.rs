@@ -1,7 +1,14 @@
+use std::collections::{HashMap, HashSet};
 use async_trait::async_trait;
-use sqlx::{Row};
+use sqlx::Row;
+use sqlx::mysql::MySqlRow;
 use crate::domain::entities::profile::image::image::Image;
-use crate::domain::entities::profile::image::image_usage::ImageUsageInfo;
+use crate::domain::entities::profile::image::image_settings::ImageSettings;
+use crate::domain::entities::profile::image::image_tag::{ImageTag, ImageTagCount};
+use crate::domain::entities::profile::image::image_usage::{
+    ImageOwnerReference, ImageOwnerSource, ImageUsageInfo, ImageUsageOwner
+};
+use crate::domain::entities::profile::image::image_variant::ImageVariant;
 use crate::infrastructure::db::mysql::common::mysql_repository::MySqlRepository;
 use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
 use crate::interface_adapters::gateways::repositories::profile::image::image_repository::ImageRepository;
@@ -17,12 +24,79 @@ impl ImageRepositoryImpl {
     }
 }
 
+pub(crate) const IMAGE_COLUMNS: &str = "id, profile_id, filename, original_filename, storage_url, \
+    file_size, width, height, mime_type, alt_text, caption, created_at, \
+    captured_at, camera_model, content_hash, blurhash, dominant_color, lqip";
+
+pub(crate) fn image_from_row(r: &MySqlRow) -> Image {
+    Image {
+        id: r.get("id"),
+        profile_id: r.get("profile_id"),
+        filename: r.get("filename"),
+        original_filename: r.get("original_filename"),
+        storage_url: r.get("storage_url"),
+        file_size: r.get("file_size"),
+        width: r.get("width"),
+        height: r.get("height"),
+        mime_type: r.get("mime_type"),
+        alt_text: r.get("alt_text"),
+        caption: r.get("caption"),
+        created_at: format!("{:?}", r.get_unchecked::<sqlx::types::chrono::NaiveDateTime, _>("created_at")),
+        captured_at: r.get::<Option<sqlx::types::chrono::NaiveDateTime>, _>("captured_at").map(|d| format!("{:?}", d)),
+        camera_model: r.get("camera_model"),
+        content_hash: r.get("content_hash"),
+        blurhash: r.get("blurhash"),
+        dominant_color: r.get("dominant_color"),
+        lqip: r.get("lqip"),
+    }
+}
+
+/// `AND` clause keeping images whose `id_column` carries all of `count` tag names.
+/// Binds: profile id, the names, `count`.
+fn tag_filter(id_column: &str, count: usize) -> String {
+    if count == 0 {
+        return String::new();
+    }
+    format!(
+        r#" AND {} IN (
+            SELECT itl.image_id
+            FROM image_tag_list itl
+            INNER JOIN image_tag it ON it.id = itl.tag_id
+            WHERE it.profile_id = ? AND it.name IN ({})
+            GROUP BY itl.image_id
+            HAVING COUNT(DISTINCT it.id) = ?
+        )"#,
+        id_column,
+        vec!["?"; count].join(",")
+    )
+}
+
+#[derive(sqlx::FromRow)]
+struct ImageTagRecord {
+    id: String,
+    profile_id: String,
+    name: String,
+    color: Option<String>,
+    created_at: sqlx::types::chrono::NaiveDateTime,
+}
+
+fn record_to_image_tag(r: ImageTagRecord) -> ImageTag {
+    ImageTag {
+        id: r.id,
+        profile_id: r.profile_id,
+        name: r.name,
+        color: r.color,
+        created_at: r.created_at.to_string(),
+    }
+}
+
 #[async_trait]
 impl ImageRepository for ImageRepositoryImpl {
     async fn find_all_by_profile_id(
         &self,
         profile_id: &str,
         search: Option<String>,
+        tags: &[String],
         limit: i32,
         offset: i32,
     ) -> Result<(Vec<(Image, i32, Vec<ImageUsageInfo>)>, usize), RepositoryError> {
@@ -31,6 +105,7 @@ impl ImageRepository for ImageRepositoryImpl {
             SELECT 
                 i.id, i.profile_id, i.filename, i.original_filename, i.storage_url,
                 i.file_size, i.width, i.height, i.mime_type, i.alt_text, i.caption, i.created_at,
+                i.captured_at, i.camera_model, i.content_hash, i.blurhash, i.dominant_color, i.lqip,
                 CAST(COALESCE(SUM(iu.usage_count), 0) AS SIGNED) as total_usage
             FROM image i
             LEFT JOIN image_usage iu ON i.id = iu.image_id
@@ -44,6 +119,8 @@ impl ImageRepository for ImageRepositoryImpl {
             }
         }
 
+        query_str.push_str(&tag_filter("i.id", tags.len()));
+
         query_str.push_str(" GROUP BY i.id ORDER BY i.created_at DESC LIMIT ? OFFSET ?");
 
         let mut query = sqlx::query(&query_str).bind(profile_id);
@@ -54,6 +131,13 @@ impl ImageRepository for ImageRepositoryImpl {
                 query = query.bind(s_bind.clone()).bind(s_bind.clone()).bind(s_bind);
             }
         }
+        if !tags.is_empty() {
+            query = query.bind(profile_id);
+            for tag in tags {
+                query = query.bind(tag);
+            }
+            query = query.bind(tags.len() as i64);
+        }
 
         let rows = query.bind(limit).bind(offset)
             .fetch_all(self.mysql.pool())
@@ -76,13 +160,19 @@ impl ImageRepository for ImageRepositoryImpl {
                 alt_text: r.get("alt_text"),
                 caption: r.get("caption"),
                 created_at: format!("{:?}", r.get_unchecked::<sqlx::types::chrono::NaiveDateTime, _>("created_at")),
+                captured_at: r.get::<Option<sqlx::types::chrono::NaiveDateTime>, _>("captured_at").map(|d| format!("{:?}", d)),
+                camera_model: r.get("camera_model"),
+                content_hash: r.get("content_hash"),
+                blurhash: r.get("blurhash"),
+                dominant_color: r.get("dominant_color"),
+                lqip: r.get("lqip"),
             };
             let usage: i64 = r.get("total_usage");
             
-            // Fetch performance usage for each image
-            let performances = self.find_usage_by_image_id(&image_id, profile_id).await?;
+            // Fetch the owners using each image
+            let owners = self.find_usage_by_image_id(&image_id, profile_id).await?;
             
-            images_with_usage.push((img, usage as i32, performances));
+            images_with_usage.push((img, usage as i32, owners));
         }
 
         // Count total
@@ -92,6 +182,7 @@ impl ImageRepository for ImageRepositoryImpl {
                 count_query_str.push_str(" AND (filename LIKE ? OR alt_text LIKE ? OR caption LIKE ?)");
             }
         }
+        count_query_str.push_str(&tag_filter("id", tags.len()));
         
         let mut count_query = sqlx::query_scalar::<_, i64>(&count_query_str).bind(profile_id);
         if let Some(ref s) = search {
@@ -100,6 +191,13 @@ impl ImageRepository for ImageRepositoryImpl {
                 count_query = count_query.bind(s_bind.clone()).bind(s_bind.clone()).bind(s_bind);
             }
         }
+        if !tags.is_empty() {
+            count_query = count_query.bind(profile_id);
+            for tag in tags {
+                count_query = count_query.bind(tag);
+            }
+            count_query = count_query.bind(tags.len() as i64);
+        }
 
         let total = count_query.fetch_one(self.mysql.pool())
             .await
@@ -118,6 +216,7 @@ impl ImageRepository for ImageRepositoryImpl {
             SELECT 
                 i.id, i.profile_id, i.filename, i.original_filename, i.storage_url,
                 i.file_size, i.width, i.height, i.mime_type, i.alt_text, i.caption, i.created_at,
+                i.captured_at, i.camera_model, i.content_hash, i.blurhash, i.dominant_color, i.lqip,
                 CAST(COALESCE(SUM(iu.usage_count), 0) AS SIGNED) as total_usage
             FROM image i
             LEFT JOIN image_usage iu ON i.id = iu.image_id
@@ -145,15 +244,31 @@ impl ImageRepository for ImageRepositoryImpl {
                 alt_text: r.get("alt_text"),
                 caption: r.get("caption"),
                 created_at: format!("{:?}", r.get_unchecked::<sqlx::types::chrono::NaiveDateTime, _>("created_at")),
+                captured_at: r.get::<Option<sqlx::types::chrono::NaiveDateTime>, _>("captured_at").map(|d| format!("{:?}", d)),
+                camera_model: r.get("camera_model"),
+                content_hash: r.get("content_hash"),
+                blurhash: r.get("blurhash"),
+                dominant_color: r.get("dominant_color"),
+                lqip: r.get("lqip"),
             };
             let usage: i64 = r.get("total_usage");
-            let performances = self.find_usage_by_image_id(id, profile_id).await?;
-            Ok(Some((img, usage as i32, performances)))
+            let owners = self.find_usage_by_image_id(id, profile_id).await?;
+            Ok(Some((img, usage as i32, owners)))
         } else {
             Ok(None)
         }
     }
 
+    async fn find_by_id(&self, id: &str) -> Result<Option<Image>, RepositoryError> {
+        let row = sqlx::query(&format!("SELECT {} FROM image WHERE id = ?", IMAGE_COLUMNS))
+        .bind(id)
+        .fetch_optional(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.as_ref().map(image_from_row))
+    }
+
     async fn find_usage_by_image_id(
         &self,
         image_id: &str,
@@ -162,15 +277,21 @@ impl ImageRepository for ImageRepositoryImpl {
         let rows = sqlx::query(
             r#"
             SELECT 
-                iu.performance_id,
-                p.title,
+                iu.owner_type,
+                iu.owner_id,
+                COALESCE(p.title, pr.display_name, so.name, sk.name, a.title, '') AS title,
                 iu.usage_count,
                 iu.first_used_at,
                 iu.last_used_at
             FROM image_usage iu
-            JOIN performance p ON iu.performance_id = p.id
             JOIN image i ON iu.image_id = i.id
+            LEFT JOIN performance p ON iu.owner_type = 'performance' AND p.id = iu.owner_id
+            LEFT JOIN profile pr ON iu.owner_type = 'avatar' AND pr.id = iu.owner_id
+            LEFT JOIN social so ON iu.owner_type = 'social' AND so.id = iu.owner_id
+            LEFT JOIN skill sk ON iu.owner_type = 'skill' AND sk.id = iu.owner_id
+            LEFT JOIN announce_list a ON iu.owner_type = 'announce' AND a.id = iu.owner_id
             WHERE iu.image_id = ? AND i.profile_id = ?
+            ORDER BY iu.owner_type, iu.first_used_at
             "#
         )
         .bind(image_id)
@@ -179,14 +300,17 @@ impl ImageRepository for ImageRepositoryImpl {
         .await
         .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(rows.into_iter().map(|r| {
-            ImageUsageInfo {
-                performance_id: r.get("performance_id"),
+        Ok(rows.into_iter().filter_map(|r| {
+            // Unknown types come from a newer release sharing the database, leave them out
+            let owner_type = ImageUsageOwner::parse(r.get("owner_type"))?;
+            Some(ImageUsageInfo {
+                owner_type,
+                owner_id: r.get("owner_id"),
                 title: r.get("title"),
                 usage_count: r.get("usage_count"),
                 first_used_at: format!("{:?}", r.get_unchecked::<sqlx::types::chrono::NaiveDateTime, _>("first_used_at")),
                 last_used_at: format!("{:?}", r.get_unchecked::<sqlx::types::chrono::NaiveDateTime, _>("last_used_at")),
-            }
+            })
         }).collect())
     }
 
@@ -194,13 +318,16 @@ impl ImageRepository for ImageRepositoryImpl {
         // Save to MySQL
         let created_at = sqlx::types::chrono::NaiveDateTime::parse_from_str(&image.created_at, "%Y-%m-%dT%H:%M:%SZ")
             .unwrap_or_else(|_| sqlx::types::chrono::Utc::now().naive_utc());
+        let captured_at = image.captured_at.as_deref()
+            .and_then(|d| sqlx::types::chrono::NaiveDateTime::parse_from_str(d, "%Y-%m-%dT%H:%M:%S").ok());
 
         sqlx::query(
             r#"
             INSERT INTO image (
                 id, profile_id, filename, original_filename, storage_url,
-                file_size, width, height, mime_type, alt_text, caption, created_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                file_size, width, height, mime_type, alt_text, caption, created_at,
+                captured_at, camera_model, content_hash, blurhash, dominant_color, lqip
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#
         )
         .bind(image.id)
@@ -215,6 +342,12 @@ impl ImageRepository for ImageRepositoryImpl {
         .bind(image.alt_text)
         .bind(image.caption)
         .bind(created_at)
+        .bind(captured_at)
+        .bind(image.camera_model)
+        .bind(image.content_hash)
+        .bind(image.blurhash)
+        .bind(image.dominant_color)
+        .bind(image.lqip)
         .execute(self.mysql.pool())
         .await
         .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
@@ -229,8 +362,8 @@ impl ImageRepository for ImageRepositoryImpl {
     ) -> Result<(), RepositoryError> {
         sqlx::query(
             r#"
-            INSERT INTO image_usage (id, image_id, performance_id, usage_count, first_used_at, last_used_at)
-            VALUES (UUID(), ?, ?, 1, NOW(), NOW())
+            INSERT INTO image_usage (id, image_id, owner_type, owner_id, usage_count, first_used_at, last_used_at)
+            VALUES (UUID(), ?, 'performance', ?, 1, NOW(), NOW())
             ON DUPLICATE KEY UPDATE
                 usage_count = usage_count + 1,
                 last_used_at = NOW()
@@ -250,43 +383,155 @@ impl ImageRepository for ImageRepositoryImpl {
         image_id: &str,
         performance_id: &str,
     ) -> Result<(), RepositoryError> {
-        let row = sqlx::query!(
+        let row: Option<(String, Option<i32>)> = sqlx::query_as(
             r#"
             SELECT id, usage_count FROM image_usage
-            WHERE image_id = ? AND performance_id = ?
-            "#,
-            image_id,
-            performance_id
+            WHERE image_id = ? AND owner_type = 'performance' AND owner_id = ?
+            "#
         )
+        .bind(image_id)
+        .bind(performance_id)
         .fetch_optional(self.mysql.pool())
         .await
         .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        if let Some(r) = row {
-            let current_count = r.usage_count.unwrap_or(0);
+        if let Some((id, usage_count)) = row {
+            let current_count = usage_count.unwrap_or(0);
             if current_count <= 1 {
-                sqlx::query!(
-                    r#"
-                    DELETE FROM image_usage WHERE id = ?
-                    "#,
-                    r.id
-                )
-                .execute(self.mysql.pool())
-                .await
-                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+                sqlx::query("DELETE FROM image_usage WHERE id = ?")
+                    .bind(&id)
+                    .execute(self.mysql.pool())
+                    .await
+                    .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
             } else {
-                sqlx::query!(
-                    r#"
-                    UPDATE image_usage SET usage_count = usage_count - 1 WHERE id = ?
-                    "#,
-                    r.id
-                )
-                .execute(self.mysql.pool())
-                .await
-                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+                sqlx::query("UPDATE image_usage SET usage_count = usage_count - 1 WHERE id = ?")
+                    .bind(&id)
+                    .execute(self.mysql.pool())
+                    .await
+                    .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn find_owner_sources(&self, profile_id: &str) -> Result<Vec<ImageOwnerSource>, RepositoryError> {
+        let rows: Vec<(String, String, Option<String>, Option<String>)> = sqlx::query_as(
+            r#"
+            SELECT 'avatar', id, avatar_url, NULL FROM profile
+            WHERE id = ? AND avatar_url IS NOT NULL
+            UNION ALL
+            SELECT 'social', s.id, s.logo_url, NULL
+            FROM social_list sl
+            JOIN social s ON sl.social_id = s.id
+            WHERE sl.profile_id = ? AND s.logo_url IS NOT NULL
+            UNION ALL
+            SELECT 'skill', s.id, s.logo_url, NULL
+            FROM skill_list sl
+            JOIN skill s ON sl.skill_id = s.id
+            WHERE sl.profile_id = ? AND s.logo_url IS NOT NULL
+            UNION ALL
+            SELECT 'announce', id, link_url, message FROM announce_list
+            WHERE profile_id = ?
+            "#
+        )
+        .bind(profile_id)
+        .bind(profile_id)
+        .bind(profile_id)
+        .bind(profile_id)
+        .fetch_all(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().filter_map(|(owner_type, owner_id, url, markdown)| {
+            Some(ImageOwnerSource {
+                owner_type: ImageUsageOwner::parse(&owner_type)?,
+                owner_id,
+                url,
+                markdown,
+            })
+        }).collect())
+    }
+
+    async fn sync_owner_usage(
+        &self,
+        profile_id: &str,
+        references: &[ImageOwnerReference],
+    ) -> Result<(), RepositoryError> {
+        let mut tx = self.mysql.pool().begin().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let mut existing_query = sqlx::QueryBuilder::new(
+            "SELECT iu.id, iu.image_id, iu.owner_type, iu.owner_id FROM image_usage iu \
+             JOIN image i ON iu.image_id = i.id WHERE i.profile_id = "
+        );
+        existing_query.push_bind(profile_id);
+        existing_query.push(" AND iu.owner_type IN (");
+        let mut separated = existing_query.separated(", ");
+        for owner_type in ImageUsageOwner::SYNCED {
+            separated.push_bind(owner_type.as_str());
+        }
+        separated.push_unseparated(")");
+        let existing: Vec<(String, String, String, String)> = existing_query.build_query_as()
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        let current: HashSet<(&str, &str, &str)> = references
+            .iter()
+            .map(|r| (r.image_id.as_str(), r.owner_type.as_str(), r.owner_id.as_str()))
+            .collect();
+        for (id, image_id, owner_type, owner_id) in &existing {
+            if !current.contains(&(image_id.as_str(), owner_type.as_str(), owner_id.as_str())) {
+                sqlx::query("DELETE FROM image_usage WHERE id = ?")
+                    .bind(id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
             }
         }
 
+        // last_used_at only moves when the count does, it is assigned before usage_count changes
+        for reference in references {
+            sqlx::query(
+                r#"
+                INSERT INTO image_usage (id, image_id, owner_type, owner_id, usage_count, first_used_at, last_used_at)
+                SELECT UUID(), i.id, ?, ?, ?, NOW(), NOW()
+                FROM image i
+                WHERE i.id = ? AND i.profile_id = ?
+                ON DUPLICATE KEY UPDATE
+                    last_used_at = IF(image_usage.usage_count = VALUES(usage_count), image_usage.last_used_at, NOW()),
+                    usage_count = VALUES(usage_count)
+                "#
+            )
+            .bind(reference.owner_type.as_str())
+            .bind(&reference.owner_id)
+            .bind(reference.usage_count)
+            .bind(&reference.image_id)
+            .bind(profile_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        // Performances removed by a foreign key cascade (category, profile) never pass through
+        // PerformanceRepository::delete, and image_usage has no foreign key to performance
+        sqlx::query(
+            r#"
+            DELETE iu FROM image_usage iu
+            JOIN image i ON iu.image_id = i.id
+            LEFT JOIN performance p ON p.id = iu.owner_id
+            WHERE i.profile_id = ? AND iu.owner_type = 'performance' AND p.id IS NULL
+            "#
+        )
+        .bind(profile_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        tx.commit().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
         Ok(())
     }
 
@@ -360,10 +605,12 @@ impl ImageRepository for ImageRepositoryImpl {
             r#"
             SELECT
                 i.id, i.profile_id, i.filename, i.original_filename, i.storage_url,
-                i.file_size, i.width, i.height, i.mime_type, i.alt_text, i.caption, i.created_at
+                i.file_size, i.width, i.height, i.mime_type, i.alt_text, i.caption, i.created_at,
+                i.captured_at, i.camera_model, i.content_hash, i.blurhash, i.dominant_color, i.lqip
             FROM image i
             LEFT JOIN image_usage iu ON i.id = iu.image_id
             WHERE i.profile_id = ?
+              AND NOT EXISTS (SELECT 1 FROM album_image ai WHERE ai.image_id = i.id)
             GROUP BY i.id
             HAVING COALESCE(SUM(iu.usage_count), 0) = 0
                AND DATEDIFF(NOW(), i.created_at) >= ?
@@ -388,6 +635,12 @@ impl ImageRepository for ImageRepositoryImpl {
                 alt_text: r.get("alt_text"),
                 caption: r.get("caption"),
                 created_at: format!("{:?}", r.get_unchecked::<sqlx::types::chrono::NaiveDateTime, _>("created_at")),
+                captured_at: r.get::<Option<sqlx::types::chrono::NaiveDateTime>, _>("captured_at").map(|d| format!("{:?}", d)),
+                camera_model: r.get("camera_model"),
+                content_hash: r.get("content_hash"),
+                blurhash: r.get("blurhash"),
+                dominant_color: r.get("dominant_color"),
+                lqip: r.get("lqip"),
             }
         }).collect())
     }
@@ -421,4 +674,443 @@ impl ImageRepository for ImageRepositoryImpl {
 
         Ok((count, total_size as i64))
     }
+
+    async fn create_variants(&self, variants: Vec<ImageVariant>) -> Result<(), RepositoryError> {
+        let mut tx = self.mysql.pool().begin().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        for variant in variants {
+            let created_at = sqlx::types::chrono::DateTime::parse_from_rfc3339(&variant.created_at)
+                .map(|d| d.naive_utc())
+                .unwrap_or_else(|_| sqlx::types::chrono::Utc::now().naive_utc());
+
+            sqlx::query(
+                r#"
+                INSERT INTO image_variant (
+                    id, image_id, filename, storage_url, width, height, mime_type, file_size, created_at
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#
+            )
+            .bind(variant.id)
+            .bind(variant.image_id)
+            .bind(variant.filename)
+            .bind(variant.storage_url)
+            .bind(variant.width)
+            .bind(variant.height)
+            .bind(variant.mime_type)
+            .bind(variant.file_size)
+            .bind(created_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        tx.commit().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_variants_by_image_ids(
+        &self,
+        image_ids: &[String],
+    ) -> Result<Vec<ImageVariant>, RepositoryError> {
+        if image_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let query_str = format!(
+            r#"
+            SELECT id, image_id, filename, storage_url, width, height, mime_type, file_size, created_at
+            FROM image_variant
+            WHERE image_id IN ({})
+            "#,
+            image_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",")
+        );
+        let mut query = sqlx::query(&query_str);
+        for id in image_ids {
+            query = query.bind(id);
+        }
+
+        let rows = query
+            .fetch_all(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| {
+            ImageVariant {
+                id: r.get("id"),
+                image_id: r.get("image_id"),
+                filename: r.get("filename"),
+                storage_url: r.get("storage_url"),
+                width: r.get("width"),
+                height: r.get("height"),
+                mime_type: r.get("mime_type"),
+                file_size: r.get("file_size"),
+                created_at: format!("{:?}", r.get_unchecked::<sqlx::types::chrono::NaiveDateTime, _>("created_at")),
+            }
+        }).collect())
+    }
+
+    async fn find_settings(&self, profile_id: &str) -> Result<Option<ImageSettings>, RepositoryError> {
+        let row = sqlx::query(
+            "SELECT profile_id, keep_capture_metadata, updated_at FROM image_settings WHERE profile_id = ?"
+        )
+        .bind(profile_id)
+        .fetch_optional(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(|r| ImageSettings {
+            profile_id: r.get("profile_id"),
+            keep_capture_metadata: r.get::<i8, _>("keep_capture_metadata") != 0,
+            updated_at: Some(format!("{:?}", r.get_unchecked::<sqlx::types::chrono::NaiveDateTime, _>("updated_at"))),
+        }))
+    }
+
+    async fn save_settings(&self, settings: ImageSettings) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO image_settings (profile_id, keep_capture_metadata, updated_at)
+            VALUES (?, ?, NOW())
+            ON DUPLICATE KEY UPDATE
+                keep_capture_metadata = VALUES(keep_capture_metadata),
+                updated_at = NOW()
+            "#
+        )
+        .bind(settings.profile_id)
+        .bind(settings.keep_capture_metadata as i8)
+        .execute(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn clear_capture_metadata(&self, profile_id: &str) -> Result<(), RepositoryError> {
+        sqlx::query("UPDATE image SET captured_at = NULL, camera_model = NULL WHERE profile_id = ?")
+            .bind(profile_id)
+            .execute(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_content_hash(
+        &self,
+        profile_id: &str,
+        content_hash: &str,
+    ) -> Result<Option<Image>, RepositoryError> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM image WHERE profile_id = ? AND content_hash = ? ORDER BY created_at, id LIMIT 1",
+            IMAGE_COLUMNS
+        ))
+        .bind(profile_id)
+        .bind(content_hash)
+        .fetch_optional(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.as_ref().map(image_from_row))
+    }
+
+    async fn find_by_profile_id(&self, profile_id: &str) -> Result<Vec<Image>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM image WHERE profile_id = ? ORDER BY created_at, id",
+            IMAGE_COLUMNS
+        ))
+        .bind(profile_id)
+        .fetch_all(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.iter().map(image_from_row).collect())
+    }
+
+    async fn set_content_hash(&self, id: &str, content_hash: &str) -> Result<(), RepositoryError> {
+        sqlx::query("UPDATE image SET content_hash = ? WHERE id = ?")
+            .bind(content_hash)
+            .bind(id)
+            .execute(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_missing_placeholders(&self, profile_id: &str) -> Result<Vec<Image>, RepositoryError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM image WHERE profile_id = ? AND blurhash IS NULL AND placeholder_failed_at IS NULL ORDER BY created_at, id",
+            IMAGE_COLUMNS
+        ))
+        .bind(profile_id)
+        .fetch_all(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.iter().map(image_from_row).collect())
+    }
+
+    async fn set_placeholder(
+        &self,
+        id: &str,
+        blurhash: &str,
+        dominant_color: &str,
+        lqip: &str,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query("UPDATE image SET blurhash = ?, dominant_color = ?, lqip = ? WHERE id = ?")
+            .bind(blurhash)
+            .bind(dominant_color)
+            .bind(lqip)
+            .bind(id)
+            .execute(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn mark_placeholder_failed(&self, id: &str) -> Result<(), RepositoryError> {
+        sqlx::query("UPDATE image SET placeholder_failed_at = NOW() WHERE id = ?")
+            .bind(id)
+            .execute(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn merge_duplicate(
+        &self,
+        profile_id: &str,
+        keep_id: &str,
+        duplicate_id: &str,
+    ) -> Result<(), RepositoryError> {
+        let mut tx = self.mysql.pool().begin().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        // Owners using both images keep one usage row with the counts added up
+        sqlx::query(
+            r#"
+            INSERT INTO image_usage (id, image_id, owner_type, owner_id, usage_count, first_used_at, last_used_at)
+            SELECT UUID(), ?, owner_type, owner_id, usage_count, first_used_at, last_used_at
+            FROM image_usage
+            WHERE image_id = ?
+            ON DUPLICATE KEY UPDATE
+                usage_count = image_usage.usage_count + VALUES(usage_count),
+                first_used_at = LEAST(image_usage.first_used_at, VALUES(first_used_at)),
+                last_used_at = GREATEST(image_usage.last_used_at, VALUES(last_used_at))
+            "#
+        )
+        .bind(keep_id)
+        .bind(duplicate_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        // Tags and album places carry over; albums holding both keep the earlier place
+        sqlx::query("INSERT IGNORE INTO image_tag_list (image_id, tag_id) SELECT ?, tag_id FROM image_tag_list WHERE image_id = ?")
+            .bind(keep_id)
+            .bind(duplicate_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO album_image (album_id, image_id, position)
+            SELECT album_id, ?, position FROM album_image WHERE image_id = ?
+            ON DUPLICATE KEY UPDATE position = LEAST(album_image.position, VALUES(position))
+            "#
+        )
+        .bind(keep_id)
+        .bind(duplicate_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        sqlx::query("UPDATE album SET cover_image_id = ? WHERE cover_image_id = ?")
+            .bind(keep_id)
+            .bind(duplicate_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        // Usage, tag, album and variant rows of the duplicate go with it
+        sqlx::query("DELETE FROM image WHERE id = ? AND profile_id = ?")
+            .bind(duplicate_id)
+            .bind(profile_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        tx.commit().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_tags(&self, profile_id: &str) -> Result<Vec<ImageTagCount>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT it.id, it.profile_id, it.name, it.color, it.created_at, COUNT(itl.image_id) AS image_count
+            FROM image_tag it
+            LEFT JOIN image_tag_list itl ON itl.tag_id = it.id
+            WHERE it.profile_id = ?
+            GROUP BY it.id, it.profile_id, it.name, it.color, it.created_at
+            ORDER BY it.name
+            "#
+        )
+        .bind(profile_id)
+        .fetch_all(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| ImageTagCount {
+            tag: ImageTag {
+                id: r.get("id"),
+                profile_id: r.get("profile_id"),
+                name: r.get("name"),
+                color: r.get("color"),
+                created_at: r.get::<sqlx::types::chrono::NaiveDateTime, _>("created_at").to_string(),
+            },
+            image_count: r.get("image_count"),
+        }).collect())
+    }
+
+    async fn find_tag_by_id(&self, id: &str, profile_id: &str) -> Result<Option<ImageTag>, RepositoryError> {
+        let row = sqlx::query_as::<_, ImageTagRecord>(
+            "SELECT id, profile_id, name, color, created_at FROM image_tag WHERE id = ? AND profile_id = ?"
+        )
+        .bind(id)
+        .bind(profile_id)
+        .fetch_optional(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(record_to_image_tag))
+    }
+
+    async fn find_tag_by_name(&self, profile_id: &str, name: &str) -> Result<Option<ImageTag>, RepositoryError> {
+        // The column collation is case-insensitive, like the unique key
+        let row = sqlx::query_as::<_, ImageTagRecord>(
+            "SELECT id, profile_id, name, color, created_at FROM image_tag WHERE profile_id = ? AND name = ?"
+        )
+        .bind(profile_id)
+        .bind(name)
+        .fetch_optional(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(record_to_image_tag))
+    }
+
+    async fn create_tag(&self, tag: ImageTag) -> Result<ImageTag, RepositoryError> {
+        sqlx::query("INSERT INTO image_tag (id, profile_id, name, color, created_at) VALUES (?, ?, ?, ?, ?)")
+            .bind(&tag.id)
+            .bind(&tag.profile_id)
+            .bind(&tag.name)
+            .bind(&tag.color)
+            .bind(&tag.created_at)
+            .execute(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(tag)
+    }
+
+    async fn update_tag(&self, tag: ImageTag) -> Result<ImageTag, RepositoryError> {
+        sqlx::query("UPDATE image_tag SET name = ?, color = ? WHERE id = ? AND profile_id = ?")
+            .bind(&tag.name)
+            .bind(&tag.color)
+            .bind(&tag.id)
+            .bind(&tag.profile_id)
+            .execute(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(tag)
+    }
+
+    async fn delete_tag(&self, id: &str, profile_id: &str) -> Result<(), RepositoryError> {
+        // image_tag_list rows go with it (ON DELETE CASCADE)
+        sqlx::query("DELETE FROM image_tag WHERE id = ? AND profile_id = ?")
+            .bind(id)
+            .bind(profile_id)
+            .execute(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_tags_by_image_ids(
+        &self,
+        image_ids: &[String],
+    ) -> Result<HashMap<String, Vec<ImageTag>>, RepositoryError> {
+        let mut tags: HashMap<String, Vec<ImageTag>> = HashMap::new();
+        if image_ids.is_empty() {
+            return Ok(tags);
+        }
+
+        let mut query_builder = sqlx::QueryBuilder::new(
+            r#"
+            SELECT itl.image_id, it.id, it.profile_id, it.name, it.color, it.created_at
+            FROM image_tag_list itl
+            INNER JOIN image_tag it ON it.id = itl.tag_id
+            WHERE itl.image_id IN (
+            "#
+        );
+        let mut separated = query_builder.separated(", ");
+        for id in image_ids {
+            separated.push_bind(id);
+        }
+        separated.push_unseparated(") ORDER BY it.name");
+
+        let rows = query_builder
+            .build()
+            .fetch_all(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        for r in rows {
+            tags.entry(r.get("image_id")).or_default().push(ImageTag {
+                id: r.get("id"),
+                profile_id: r.get("profile_id"),
+                name: r.get("name"),
+                color: r.get("color"),
+                created_at: r.get::<sqlx::types::chrono::NaiveDateTime, _>("created_at").to_string(),
+            });
+        }
+
+        Ok(tags)
+    }
+
+    async fn set_image_tags(&self, image_id: &str, tag_ids: &[String]) -> Result<(), RepositoryError> {
+        let mut tx = self.mysql.pool().begin().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        sqlx::query("DELETE FROM image_tag_list WHERE image_id = ?")
+            .bind(image_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if !tag_ids.is_empty() {
+            let mut query_builder = sqlx::QueryBuilder::new("INSERT INTO image_tag_list (image_id, tag_id) ");
+            query_builder.push_values(tag_ids, |mut row, tag_id| {
+                row.push_bind(image_id).push_bind(tag_id);
+            });
+            query_builder.build()
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        tx.commit().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
 }
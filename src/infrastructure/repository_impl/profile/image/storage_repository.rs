@@ -1,8 +1,11 @@
 use async_trait::async_trait;
 use google_cloud_storage::http::objects::delete::DeleteObjectRequest;
+use google_cloud_storage::http::objects::download::Range;
+use google_cloud_storage::http::objects::get::GetObjectRequest;
 use google_cloud_storage::http::objects::upload::{Media, UploadObjectRequest, UploadType};
 use crate::infrastructure::cloud_storage::gcs::common::gcs_repository::GcsRepository;
 use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+use crate::interface_adapters::gateways::common::stored_object::StoredObject;
 use crate::interface_adapters::gateways::repositories::profile::image::image_storage_repository::ImageStorageRepository;
 
 #[derive(Clone)]
@@ -58,6 +61,22 @@ impl ImageStorageRepository for GcsImageStorageRepositoryImpl {
         Ok(())
     }
 
+    async fn download_image(
+        &self,
+        profile_id: &str,
+        filename: &str,
+    ) -> Result<Vec<u8>, RepositoryError> {
+        let path = format!("performance_image/{}/{}", profile_id, filename);
+
+        self.gcs.client().download_object(&GetObjectRequest {
+            bucket: self.gcs.bucket_name().to_string(),
+            object: path,
+            ..Default::default()
+        }, &Range::default())
+        .await
+        .map_err(|e| RepositoryError::InternalError(format!("GCS Download Error: {}", e)))
+    }
+
     async fn get_image_url(
         &self,
         profile_id: &str,
@@ -66,4 +85,8 @@ impl ImageStorageRepository for GcsImageStorageRepositoryImpl {
         let path = format!("performance_image/{}/{}", profile_id, filename);
         Ok(format!("https://storage.googleapis.com/{}/{}", self.gcs.bucket_name(), path))
     }
+
+    async fn list_images(&self, profile_id: &str) -> Result<Vec<StoredObject>, RepositoryError> {
+        self.gcs.list_objects(&format!("performance_image/{}/", profile_id)).await
+    }
 }
@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use google_cloud_storage::http::Error as GcsError;
+use google_cloud_storage::http::objects::delete::DeleteObjectRequest;
+use google_cloud_storage::http::objects::download::Range;
+use google_cloud_storage::http::objects::get::GetObjectRequest;
+use google_cloud_storage::http::objects::list::ListObjectsRequest;
+use google_cloud_storage::http::objects::upload::{Media, UploadObjectRequest, UploadType};
+use crate::infrastructure::cloud_storage::gcs::common::gcs_repository::GcsRepository;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+use crate::interface_adapters::gateways::repositories::profile::image::image_transform_cache_repository::ImageTransformCacheRepository;
+
+#[derive(Clone)]
+pub struct GcsImageTransformCacheRepositoryImpl {
+    gcs: GcsRepository,
+}
+
+impl GcsImageTransformCacheRepositoryImpl {
+    pub fn new(gcs: GcsRepository) -> Self {
+        Self { gcs }
+    }
+
+    fn prefix(profile_id: &str, image_id: &str) -> String {
+        format!("image_transform/{}/{}/", profile_id, image_id)
+    }
+}
+
+#[async_trait]
+impl ImageTransformCacheRepository for GcsImageTransformCacheRepositoryImpl {
+    async fn find_transform(
+        &self,
+        profile_id: &str,
+        image_id: &str,
+        key: &str,
+    ) -> Result<Option<Vec<u8>>, RepositoryError> {
+        let path = format!("{}{}", Self::prefix(profile_id, image_id), key);
+
+        match self.gcs.client().download_object(&GetObjectRequest {
+            bucket: self.gcs.bucket_name().to_string(),
+            object: path,
+            ..Default::default()
+        }, &Range::default()).await {
+            Ok(data) => Ok(Some(data)),
+            Err(GcsError::Response(e)) if e.code == 404 => Ok(None),
+            Err(e) => Err(RepositoryError::InternalError(format!("GCS Download Error: {}", e))),
+        }
+    }
+
+    async fn save_transform(
+        &self,
+        profile_id: &str,
+        image_id: &str,
+        key: &str,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<(), RepositoryError> {
+        let path = format!("{}{}", Self::prefix(profile_id, image_id), key);
+
+        let mut media = Media::new(path);
+        media.content_type = content_type.to_string().into();
+        let upload_request = UploadObjectRequest {
+            bucket: self.gcs.bucket_name().to_string(),
+            ..Default::default()
+        };
+        self.gcs.client().upload_object(&upload_request, data, &UploadType::Simple(media))
+            .await
+            .map_err(|e| RepositoryError::InternalError(format!("GCS Upload Error: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn delete_transforms(&self, profile_id: &str, image_id: &str) -> Result<(), RepositoryError> {
+        let objects = self.gcs.client().list_objects(&ListObjectsRequest {
+            bucket: self.gcs.bucket_name().to_string(),
+            prefix: Some(Self::prefix(profile_id, image_id)),
+            ..Default::default()
+        })
+        .await
+        .map_err(|e| RepositoryError::InternalError(format!("GCS List Error: {}", e)))?
+        .items
+        .unwrap_or_default();
+
+        for object in objects {
+            self.gcs.client().delete_object(&DeleteObjectRequest {
+                bucket: self.gcs.bucket_name().to_string(),
+                object: object.name,
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| RepositoryError::InternalError(format!("GCS Delete Error: {}", e)))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use async_trait::async_trait;
+use crate::domain::entities::profile::image::image_upload_session::ImageUploadSession;
+use crate::infrastructure::db::mysql::common::mysql_repository::MySqlRepository;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+use crate::interface_adapters::gateways::repositories::profile::image::image_upload_session_repository::ImageUploadSessionRepository;
+
+#[derive(sqlx::FromRow)]
+struct ImageUploadSessionRecord {
+    id: String,
+    profile_id: String,
+    original_filename: String,
+    total_size: i64,
+    received_bytes: i64,
+    alt_text: Option<String>,
+    caption: Option<String>,
+    on_duplicate: String,
+    created_at: sqlx::types::chrono::NaiveDateTime,
+    expires_at: sqlx::types::chrono::NaiveDateTime,
+}
+
+fn record_to_session(r: ImageUploadSessionRecord) -> ImageUploadSession {
+    ImageUploadSession {
+        id: r.id,
+        profile_id: r.profile_id,
+        original_filename: r.original_filename,
+        total_size: r.total_size,
+        received_bytes: r.received_bytes,
+        alt_text: r.alt_text,
+        caption: r.caption,
+        on_duplicate: r.on_duplicate,
+        created_at: r.created_at.to_string(),
+        expires_at: r.expires_at.to_string(),
+    }
+}
+
+#[derive(Clone)]
+pub struct ImageUploadSessionRepositoryImpl {
+    mysql: MySqlRepository,
+}
+
+impl ImageUploadSessionRepositoryImpl {
+    pub fn new(mysql: MySqlRepository) -> Self {
+        Self { mysql }
+    }
+}
+
+#[async_trait]
+impl ImageUploadSessionRepository for ImageUploadSessionRepositoryImpl {
+    async fn create(&self, session: ImageUploadSession) -> Result<ImageUploadSession, RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO image_upload_session
+                (id, profile_id, original_filename, total_size, received_bytes, alt_text, caption, on_duplicate, created_at, expires_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#
+        )
+        .bind(&session.id)
+        .bind(&session.profile_id)
+        .bind(&session.original_filename)
+        .bind(session.total_size)
+        .bind(session.received_bytes)
+        .bind(&session.alt_text)
+        .bind(&session.caption)
+        .bind(&session.on_duplicate)
+        .bind(&session.created_at)
+        .bind(&session.expires_at)
+        .execute(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(session)
+    }
+
+    async fn find_by_id(&self, id: &str, profile_id: &str) -> Result<Option<ImageUploadSession>, RepositoryError> {
+        let row = sqlx::query_as::<_, ImageUploadSessionRecord>(
+            r#"
+            SELECT id, profile_id, original_filename, total_size, received_bytes, alt_text, caption,
+                   on_duplicate, created_at, expires_at
+            FROM image_upload_session
+            WHERE id = ? AND profile_id = ?
+            "#
+        )
+        .bind(id)
+        .bind(profile_id)
+        .fetch_optional(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(record_to_session))
+    }
+
+    async fn append_chunk(&self, id: &str, offset: i64, data: &[u8], expires_at: &str) -> Result<bool, RepositoryError> {
+        let mut tx = self.mysql.pool().begin().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        // Only moves when nobody else appended at this offset first, two racing retries can't both land
+        let moved = sqlx::query(
+            r#"
+            UPDATE image_upload_session
+            SET received_bytes = received_bytes + ?, expires_at = ?
+            WHERE id = ? AND received_bytes = ?
+            "#
+        )
+        .bind(data.len() as i64)
+        .bind(expires_at)
+        .bind(id)
+        .bind(offset)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+        .rows_affected() > 0;
+
+        if !moved {
+            tx.rollback().await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            return Ok(false);
+        }
+
+        sqlx::query("INSERT INTO image_upload_chunk (session_id, byte_offset, data) VALUES (?, ?, ?)")
+            .bind(id)
+            .bind(offset)
+            .bind(data)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        tx.commit().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(true)
+    }
+
+    async fn read_bytes(&self, id: &str) -> Result<Vec<u8>, RepositoryError> {
+        let chunks: Vec<(Vec<u8>,)> = sqlx::query_as(
+            "SELECT data FROM image_upload_chunk WHERE session_id = ? ORDER BY byte_offset"
+        )
+        .bind(id)
+        .fetch_all(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(chunks.into_iter().flat_map(|(data,)| data).collect())
+    }
+
+    async fn delete(&self, id: &str, profile_id: &str) -> Result<(), RepositoryError> {
+        // Chunks go with it (ON DELETE CASCADE)
+        sqlx::query("DELETE FROM image_upload_session WHERE id = ? AND profile_id = ?")
+            .bind(id)
+            .bind(profile_id)
+            .execute(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete_expired(&self, now: &str) -> Result<u64, RepositoryError> {
+        let result = sqlx::query("DELETE FROM image_upload_session WHERE expires_at < ?")
+            .bind(now)
+            .execute(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -1,8 +1,15 @@
 pub mod life_status;
 pub mod announce;
 pub mod image;
+pub mod album;
 pub mod performance;
+pub mod performance_category;
+pub mod visibility;
+pub mod tag;
+pub mod share_link;
+pub mod archive;
 pub mod performance_content;
+pub mod og_card;
 pub mod data;
 pub mod repository;
 pub mod skill;
@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+use google_cloud_storage::http::Error as GcsError;
+use google_cloud_storage::http::objects::delete::DeleteObjectRequest;
+use google_cloud_storage::http::objects::download::Range;
+use google_cloud_storage::http::objects::get::GetObjectRequest;
+use google_cloud_storage::http::objects::list::ListObjectsRequest;
+use google_cloud_storage::http::objects::upload::{Media, UploadObjectRequest, UploadType};
+use crate::infrastructure::cloud_storage::gcs::common::gcs_repository::GcsRepository;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+use crate::interface_adapters::gateways::repositories::profile::og_card::og_card_repository::OgCardRepository;
+
+#[derive(Clone)]
+pub struct GcsOgCardRepositoryImpl {
+    gcs: GcsRepository,
+}
+
+impl GcsOgCardRepositoryImpl {
+    pub fn new(gcs: GcsRepository) -> Self {
+        Self { gcs }
+    }
+
+    fn prefix(profile_id: &str, performance_id: &str) -> String {
+        format!("performance_og_card/{}/{}/", profile_id, performance_id)
+    }
+}
+
+#[async_trait]
+impl OgCardRepository for GcsOgCardRepositoryImpl {
+    async fn find_card(
+        &self,
+        profile_id: &str,
+        performance_id: &str,
+        key: &str,
+    ) -> Result<Option<Vec<u8>>, RepositoryError> {
+        let path = format!("{}{}.png", Self::prefix(profile_id, performance_id), key);
+
+        match self.gcs.client().download_object(&GetObjectRequest {
+            bucket: self.gcs.bucket_name().to_string(),
+            object: path,
+            ..Default::default()
+        }, &Range::default()).await {
+            Ok(data) => Ok(Some(data)),
+            Err(GcsError::Response(e)) if e.code == 404 => Ok(None),
+            Err(e) => Err(RepositoryError::InternalError(format!("GCS Download Error: {}", e))),
+        }
+    }
+
+    async fn save_card(
+        &self,
+        profile_id: &str,
+        performance_id: &str,
+        key: &str,
+        data: Vec<u8>,
+    ) -> Result<(), RepositoryError> {
+        let prefix = Self::prefix(profile_id, performance_id);
+        let path = format!("{}{}.png", prefix, key);
+
+        let mut media = Media::new(path.clone());
+        media.content_type = "image/png".into();
+        let upload_request = UploadObjectRequest {
+            bucket: self.gcs.bucket_name().to_string(),
+            ..Default::default()
+        };
+        self.gcs.client().upload_object(&upload_request, data, &UploadType::Simple(media))
+            .await
+            .map_err(|e| RepositoryError::InternalError(format!("GCS Upload Error: {}", e)))?;
+
+        // Stale cards are only garbage; failing to list or delete them must not fail the request
+        let listed = self.gcs.client().list_objects(&ListObjectsRequest {
+            bucket: self.gcs.bucket_name().to_string(),
+            prefix: Some(prefix),
+            ..Default::default()
+        }).await;
+        let stale = match listed {
+            Ok(response) => response.items.unwrap_or_default(),
+            Err(e) => {
+                tracing::warn!("Failed to list OpenGraph cards of {}: {}", performance_id, e);
+                Vec::new()
+            }
+        };
+        for object in stale.into_iter().filter(|o| o.name != path) {
+            if let Err(e) = self.gcs.client().delete_object(&DeleteObjectRequest {
+                bucket: self.gcs.bucket_name().to_string(),
+                object: object.name.clone(),
+                ..Default::default()
+            }).await {
+                tracing::warn!("Failed to delete stale OpenGraph card {}: {}", object.name, e);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -23,6 +23,8 @@ struct PerformanceRecord {
     close: i8,
     created_at: sqlx::types::chrono::NaiveDate,
     updated_at: Option<sqlx::types::chrono::NaiveDate>,
+    sort_order: i32,
+    pinned: i8,
 }
 
 #[derive(Clone)]
@@ -41,13 +43,14 @@ impl PerformanceRepository for PerformanceRepositoryImpl {
     async fn create(&self, perf: Performance) -> Result<Performance, RepositoryError> {
         sqlx::query!(
             r#"
-            INSERT INTO performance (id, profile_id, category_id, visibility_id, title, summary, content_url, content_type, content_preview, start_date, end_date, location, close, created_at, updated_at)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            INSERT INTO performance (id, profile_id, category_id, visibility_id, title, summary, content_url, content_type, content_preview, start_date, end_date, location, close, created_at, updated_at, sort_order, pinned)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
             perf.id, perf.profile_id, perf.category_id, perf.visibility_id,
             perf.title, perf.summary, perf.content_url, perf.content_type,
             perf.content_preview, perf.start_date, perf.end_date,
-            perf.location, perf.close as i8, perf.created_at, perf.updated_at
+            perf.location, perf.close as i8, perf.created_at, perf.updated_at,
+            perf.sort_order, perf.pinned as i8
         )
             .execute(self.mysql.pool())
             .await
@@ -62,13 +65,14 @@ impl PerformanceRepository for PerformanceRepositoryImpl {
             UPDATE performance
             SET category_id = ?, visibility_id = ?, title = ?, summary = ?, 
                 content_url = ?, content_type = ?, content_preview = ?, 
-                start_date = ?, end_date = ?, location = ?, close = ?, updated_at = ?
+                start_date = ?, end_date = ?, location = ?, close = ?, updated_at = ?,
+                pinned = ?
             WHERE id = ?
             "#,
             perf.category_id, perf.visibility_id, perf.title, perf.summary,
             perf.content_url, perf.content_type, perf.content_preview,
             perf.start_date, perf.end_date, perf.location, perf.close as i8,
-            perf.updated_at, perf.id
+            perf.updated_at, perf.pinned as i8, perf.id
         )
             .execute(self.mysql.pool())
             .await
@@ -83,7 +87,8 @@ impl PerformanceRepository for PerformanceRepositoryImpl {
             SELECT 
                 id, profile_id, category_id, visibility_id, title, summary, 
                 content_url, content_type, content_preview, start_date, 
-                end_date, location, close, created_at, updated_at
+                end_date, location, close, created_at, updated_at,
+                sort_order, pinned
             FROM performance
             WHERE id = ?
             "#
@@ -106,20 +111,22 @@ impl PerformanceRepository for PerformanceRepositoryImpl {
             SELECT 
                 id, profile_id, category_id, visibility_id, title, summary, 
                 content_url, content_type, content_preview, start_date, 
-                end_date, location, close, created_at, updated_at
+                end_date, location, close, created_at, updated_at,
+                sort_order, pinned
             FROM performance
             WHERE profile_id = ? AND visibility_id = ?
-            ORDER BY created_at DESC
+            ORDER BY pinned DESC, sort_order, created_at DESC, id
             "#
         } else {
             r#"
             SELECT 
                 id, profile_id, category_id, visibility_id, title, summary, 
                 content_url, content_type, content_preview, start_date, 
-                end_date, location, close, created_at, updated_at
+                end_date, location, close, created_at, updated_at,
+                sort_order, pinned
             FROM performance
             WHERE profile_id = ?
-            ORDER BY created_at DESC
+            ORDER BY pinned DESC, sort_order, created_at DESC, id
             "#
         };
 
@@ -139,18 +146,80 @@ impl PerformanceRepository for PerformanceRepositoryImpl {
             .collect())
     }
 
-    async fn delete(&self, id: &str) -> Result<(), RepositoryError> {
-        sqlx::query!(
+    async fn find_by_visibility(&self, visibility_id: &str) -> Result<Vec<Performance>, RepositoryError> {
+        let rows = sqlx::query_as::<_, PerformanceRecord>(
             r#"
-            DELETE FROM performance
-            WHERE id = ?
-            "#,
-            id
+            SELECT 
+                id, profile_id, category_id, visibility_id, title, summary, 
+                content_url, content_type, content_preview, start_date, 
+                end_date, location, close, created_at, updated_at,
+                sort_order, pinned
+            FROM performance
+            WHERE visibility_id = ?
+            ORDER BY profile_id, created_at DESC
+            "#
         )
-        .execute(self.mysql.pool())
+        .bind(visibility_id)
+        .fetch_all(self.mysql.pool())
         .await
         .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
+        Ok(rows
+            .into_iter()
+            .map(record_to_performance)
+            .collect())
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), RepositoryError> {
+        let mut tx = self.mysql.pool().begin().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        // image_usage has no foreign key to performance since it got other owners
+        sqlx::query("DELETE FROM image_usage WHERE owner_type = 'performance' AND owner_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        sqlx::query("DELETE FROM performance WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        tx.commit().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn first_sort_order(&self, profile_id: &str) -> Result<i32, RepositoryError> {
+        let first = sqlx::query_scalar::<_, i64>("SELECT CAST(COALESCE(MIN(sort_order) - 1, 0) AS SIGNED) FROM performance WHERE profile_id = ?")
+            .bind(profile_id)
+            .fetch_one(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(first as i32)
+    }
+
+    async fn reorder(&self, profile_id: &str, ordered_ids: &[String]) -> Result<(), RepositoryError> {
+        let mut tx = self.mysql.pool().begin().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        for (position, id) in ordered_ids.iter().enumerate() {
+            sqlx::query("UPDATE performance SET sort_order = ? WHERE id = ? AND profile_id = ?")
+                .bind(position as i32)
+                .bind(id)
+                .bind(profile_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        tx.commit().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
         Ok(())
     }
 
@@ -162,10 +231,8 @@ impl PerformanceRepository for PerformanceRepositoryImpl {
         let pool = self.mysql.pool();
 
         if current_image_ids.is_empty() {
-            sqlx::query!(
-                "DELETE FROM image_usage WHERE performance_id = ?",
-                performance_id
-            )
+            sqlx::query("DELETE FROM image_usage WHERE owner_type = 'performance' AND owner_id = ?")
+            .bind(performance_id)
             .execute(pool)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
@@ -181,25 +248,25 @@ impl PerformanceRepository for PerformanceRepositoryImpl {
         }
 
         for (image_id, count) in image_counts {
-            sqlx::query!(
+            sqlx::query(
                 r#"
-                INSERT INTO image_usage (id, image_id, performance_id, usage_count, first_used_at, last_used_at)
-                VALUES (UUID(), ?, ?, ?, NOW(), NOW())
+                INSERT INTO image_usage (id, image_id, owner_type, owner_id, usage_count, first_used_at, last_used_at)
+                VALUES (UUID(), ?, 'performance', ?, ?, NOW(), NOW())
                 ON DUPLICATE KEY UPDATE
                     usage_count = VALUES(usage_count),
                     last_used_at = NOW()
-                "#,
-                image_id,
-                performance_id,
-                count
+                "#
             )
+            .bind(image_id)
+            .bind(performance_id)
+            .bind(count)
             .execute(pool)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         }
 
         // Delete Orphans
-        let mut query_builder = sqlx::QueryBuilder::new("DELETE FROM image_usage WHERE performance_id = ");
+        let mut query_builder = sqlx::QueryBuilder::new("DELETE FROM image_usage WHERE owner_type = 'performance' AND owner_id = ");
         query_builder.push_bind(performance_id);
         query_builder.push(" AND image_id NOT IN (");
         let mut separated = query_builder.separated(", ");
@@ -216,21 +283,6 @@ impl PerformanceRepository for PerformanceRepositoryImpl {
         Ok(())
     }
 
-    async fn delete_image_usage_by_performance_id(&self, performance_id: &str) -> Result<(), RepositoryError> {
-        sqlx::query!(
-            r#"
-            DELETE FROM image_usage
-            WHERE performance_id = ?
-            "#,
-            performance_id
-        )
-        .execute(self.mysql.pool())
-        .await
-        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
-
-        Ok(())
-    }
-
     async fn find_images_by_performance_id(
         &self,
         performance_id: &str,
@@ -239,10 +291,11 @@ impl PerformanceRepository for PerformanceRepositoryImpl {
             r#"
             SELECT 
                 i.id, i.profile_id, i.filename, i.original_filename, i.storage_url,
-                i.file_size, i.width, i.height, i.mime_type, i.alt_text, i.caption, i.created_at
+                i.file_size, i.width, i.height, i.mime_type, i.alt_text, i.caption, i.created_at,
+                i.captured_at, i.camera_model, i.content_hash, i.blurhash, i.dominant_color, i.lqip
             FROM image i
             INNER JOIN image_usage iu ON i.id = iu.image_id
-            WHERE iu.performance_id = ?
+            WHERE iu.owner_type = 'performance' AND iu.owner_id = ?
             "#
         )
         .bind(performance_id)
@@ -265,6 +318,12 @@ impl PerformanceRepository for PerformanceRepositoryImpl {
                 alt_text: r.get("alt_text"),
                 caption: r.get("caption"),
                 created_at: format!("{:?}", r.get_unchecked::<sqlx::types::chrono::NaiveDateTime, _>("created_at")),
+                captured_at: r.get::<Option<sqlx::types::chrono::NaiveDateTime>, _>("captured_at").map(|d| format!("{:?}", d)),
+                camera_model: r.get("camera_model"),
+                content_hash: r.get("content_hash"),
+                blurhash: r.get("blurhash"),
+                dominant_color: r.get("dominant_color"),
+                lqip: r.get("lqip"),
             });
         }
 
@@ -289,5 +348,8 @@ fn record_to_performance(r: PerformanceRecord) -> Performance {
         close: r.close != 0,
         created_at: r.created_at.to_string(),
         updated_at: r.updated_at.map(|d| d.to_string()),
+        sort_order: r.sort_order,
+        pinned: r.pinned != 0,
+        tags: Vec::new(),
     }
 }
@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use crate::domain::entities::profile::performance_category::performance_category::PerformanceCategory;
+use crate::infrastructure::db::mysql::common::mysql_repository::MySqlRepository;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+use crate::interface_adapters::gateways::repositories::profile::performance_category::performance_category_repository::PerformanceCategoryRepository;
+
+#[derive(sqlx::FromRow)]
+struct PerformanceCategoryRecord {
+    id: String,
+    profile_id: String,
+    name: String,
+    icon: Option<String>,
+    color: Option<String>,
+    sort_order: i32,
+    created_at: sqlx::types::chrono::NaiveDateTime,
+    updated_at: Option<sqlx::types::chrono::NaiveDateTime>,
+}
+
+fn record_to_category(r: PerformanceCategoryRecord) -> PerformanceCategory {
+    PerformanceCategory {
+        id: r.id,
+        profile_id: r.profile_id,
+        name: r.name,
+        icon: r.icon,
+        color: r.color,
+        sort_order: r.sort_order,
+        created_at: r.created_at.to_string(),
+        updated_at: r.updated_at.map(|d| d.to_string()),
+    }
+}
+
+#[derive(Clone)]
+pub struct PerformanceCategoryRepositoryImpl {
+    mysql: MySqlRepository,
+}
+
+impl PerformanceCategoryRepositoryImpl {
+    pub fn new(mysql: MySqlRepository) -> Self {
+        Self { mysql }
+    }
+}
+
+#[async_trait]
+impl PerformanceCategoryRepository for PerformanceCategoryRepositoryImpl {
+    async fn find_by_profile_id(&self, profile_id: &str) -> Result<Vec<PerformanceCategory>, RepositoryError> {
+        let rows = sqlx::query_as::<_, PerformanceCategoryRecord>(
+            r#"
+            SELECT id, profile_id, name, icon, color, sort_order, created_at, updated_at
+            FROM performance_category
+            WHERE profile_id = ?
+            ORDER BY sort_order, name
+            "#
+        )
+        .bind(profile_id)
+        .fetch_all(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(record_to_category).collect())
+    }
+
+    async fn find_by_id(&self, id: &str, profile_id: &str) -> Result<Option<PerformanceCategory>, RepositoryError> {
+        let row = sqlx::query_as::<_, PerformanceCategoryRecord>(
+            r#"
+            SELECT id, profile_id, name, icon, color, sort_order, created_at, updated_at
+            FROM performance_category
+            WHERE id = ? AND profile_id = ?
+            "#
+        )
+        .bind(id)
+        .bind(profile_id)
+        .fetch_optional(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(record_to_category))
+    }
+
+    async fn create(&self, category: PerformanceCategory) -> Result<PerformanceCategory, RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO performance_category (id, profile_id, name, icon, color, sort_order, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#
+        )
+        .bind(&category.id)
+        .bind(&category.profile_id)
+        .bind(&category.name)
+        .bind(&category.icon)
+        .bind(&category.color)
+        .bind(category.sort_order)
+        .bind(&category.created_at)
+        .bind(&category.updated_at)
+        .execute(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(category)
+    }
+
+    async fn update(&self, category: PerformanceCategory) -> Result<PerformanceCategory, RepositoryError> {
+        sqlx::query(
+            r#"
+            UPDATE performance_category
+            SET name = ?, icon = ?, color = ?, sort_order = ?, updated_at = ?
+            WHERE id = ? AND profile_id = ?
+            "#
+        )
+        .bind(&category.name)
+        .bind(&category.icon)
+        .bind(&category.color)
+        .bind(category.sort_order)
+        .bind(&category.updated_at)
+        .bind(&category.id)
+        .bind(&category.profile_id)
+        .execute(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(category)
+    }
+
+    async fn delete(&self, id: &str, profile_id: &str) -> Result<(), RepositoryError> {
+        sqlx::query("DELETE FROM performance_category WHERE id = ? AND profile_id = ?")
+            .bind(id)
+            .bind(profile_id)
+            .execute(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn count_performances(&self, id: &str) -> Result<i64, RepositoryError> {
+        sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM performance WHERE category_id = ?")
+            .bind(id)
+            .fetch_one(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))
+    }
+}
@@ -5,6 +5,7 @@ use google_cloud_storage::http::objects::get::GetObjectRequest;
 use google_cloud_storage::http::objects::upload::{Media, UploadObjectRequest, UploadType};
 use crate::infrastructure::cloud_storage::gcs::common::gcs_repository::GcsRepository;
 use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+use crate::interface_adapters::gateways::common::stored_object::StoredObject;
 use crate::interface_adapters::gateways::repositories::profile::performance_content::performance_content_repository::PerformanceContentRepository;
 
 #[derive(Clone)]
@@ -87,4 +88,17 @@ impl PerformanceContentRepository for GcsPerformanceContentRepositoryImpl {
 
         Ok(())
     }
+
+    async fn list_contents(&self, profile_id: &str) -> Result<Vec<StoredObject>, RepositoryError> {
+        let objects = self.gcs.list_objects(&format!("performance_content/{}/", profile_id)).await?;
+
+        // "{performance_id}/content.md"; anything else under the prefix is not ours to judge
+        Ok(objects
+            .into_iter()
+            .filter_map(|object| {
+                let performance_id = object.name.strip_suffix("/content.md")?.to_string();
+                (!performance_id.contains('/')).then_some(StoredObject { name: performance_id, ..object })
+            })
+            .collect())
+    }
 }
@@ -1,11 +1,10 @@
 use async_trait::async_trait;
 use crate::domain::entities::profile::profile::Profile;
 use crate::infrastructure::db::mysql::common::mysql_repository::MySqlRepository;
+use crate::infrastructure::repository_impl::profile::data::repository::ProfileDataRepositoryImpl;
 use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
 use crate::interface_adapters::gateways::repositories::profile::profile_repository::ProfileRepository;
 
-use crate::domain::entities::profile::life_status::life_status::LifeStatus;
-
 #[derive(Clone)]
 pub struct ProfileRepositoryImpl {
     mysql: MySqlRepository,
@@ -15,11 +14,20 @@ impl ProfileRepositoryImpl {
     pub fn new(mysql: MySqlRepository) -> Self {
         Self { mysql }
     }
+
+    /// The profile queries live in `ProfileDataRepositoryImpl`; this one only forwards to it.
+    fn data(&self) -> ProfileDataRepositoryImpl {
+        ProfileDataRepositoryImpl::new(self.mysql.clone())
+    }
 }
 
 #[async_trait]
 impl ProfileRepository for ProfileRepositoryImpl {
-    async fn find_by_id(&self, _id: &str) -> Result<Option<Profile>, RepositoryError> {
-        unimplemented!("Use ProfileDataRepositoryImpl instead")
+    async fn find_by_id(&self, id: &str) -> Result<Option<Profile>, RepositoryError> {
+        self.data().find_by_id(id).await
+    }
+
+    async fn find_all(&self) -> Result<Vec<Profile>, RepositoryError> {
+        self.data().find_all().await
     }
 }
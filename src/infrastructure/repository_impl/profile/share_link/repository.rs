@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use crate::domain::entities::profile::share_link::share_link::ShareLink;
+use crate::infrastructure::db::mysql::common::mysql_repository::MySqlRepository;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+use crate::interface_adapters::gateways::repositories::profile::share_link::share_link_repository::ShareLinkRepository;
+
+#[derive(sqlx::FromRow)]
+struct ShareLinkRecord {
+    id: String,
+    profile_id: String,
+    performance_id: String,
+    token_hash: String,
+    password_hash: Option<String>,
+    label: Option<String>,
+    expires_at: NaiveDateTime,
+    access_count: i64,
+    last_accessed_at: Option<NaiveDateTime>,
+    revoked_at: Option<NaiveDateTime>,
+    created_at: NaiveDateTime,
+}
+
+fn utc(date: NaiveDateTime) -> DateTime<Utc> {
+    DateTime::<Utc>::from_naive_utc_and_offset(date, Utc)
+}
+
+fn record_to_share_link(r: ShareLinkRecord) -> ShareLink {
+    ShareLink {
+        id: r.id,
+        profile_id: r.profile_id,
+        performance_id: r.performance_id,
+        token_hash: r.token_hash,
+        password_hash: r.password_hash,
+        label: r.label,
+        expires_at: utc(r.expires_at),
+        access_count: r.access_count,
+        last_accessed_at: r.last_accessed_at.map(utc),
+        revoked_at: r.revoked_at.map(utc),
+        created_at: utc(r.created_at),
+    }
+}
+
+const SELECT_COLUMNS: &str = r#"
+    SELECT id, profile_id, performance_id, token_hash, password_hash, label,
+           expires_at, access_count, last_accessed_at, revoked_at, created_at
+    FROM performance_share_link
+"#;
+
+#[derive(Clone)]
+pub struct ShareLinkRepositoryImpl {
+    mysql: MySqlRepository,
+}
+
+impl ShareLinkRepositoryImpl {
+    pub fn new(mysql: MySqlRepository) -> Self {
+        Self { mysql }
+    }
+}
+
+#[async_trait]
+impl ShareLinkRepository for ShareLinkRepositoryImpl {
+    async fn create(&self, link: ShareLink) -> Result<ShareLink, RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO performance_share_link
+                (id, profile_id, performance_id, token_hash, password_hash, label,
+                 expires_at, access_count, last_accessed_at, revoked_at, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#
+        )
+        .bind(&link.id)
+        .bind(&link.profile_id)
+        .bind(&link.performance_id)
+        .bind(&link.token_hash)
+        .bind(&link.password_hash)
+        .bind(&link.label)
+        .bind(link.expires_at.naive_utc())
+        .bind(link.access_count)
+        .bind(link.last_accessed_at.map(|d| d.naive_utc()))
+        .bind(link.revoked_at.map(|d| d.naive_utc()))
+        .bind(link.created_at.naive_utc())
+        .execute(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(link)
+    }
+
+    async fn find_by_id(&self, id: &str, profile_id: &str) -> Result<Option<ShareLink>, RepositoryError> {
+        let row = sqlx::query_as::<_, ShareLinkRecord>(&format!("{} WHERE id = ? AND profile_id = ?", SELECT_COLUMNS))
+            .bind(id)
+            .bind(profile_id)
+            .fetch_optional(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(record_to_share_link))
+    }
+
+    async fn find_by_performance_id(&self, performance_id: &str) -> Result<Vec<ShareLink>, RepositoryError> {
+        let rows = sqlx::query_as::<_, ShareLinkRecord>(&format!(
+            "{} WHERE performance_id = ? ORDER BY created_at DESC",
+            SELECT_COLUMNS
+        ))
+            .bind(performance_id)
+            .fetch_all(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(record_to_share_link).collect())
+    }
+
+    async fn find_by_token_hash(&self, token_hash: &str) -> Result<Option<ShareLink>, RepositoryError> {
+        let row = sqlx::query_as::<_, ShareLinkRecord>(&format!("{} WHERE token_hash = ?", SELECT_COLUMNS))
+            .bind(token_hash)
+            .fetch_optional(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(record_to_share_link))
+    }
+
+    async fn revoke(&self, id: &str, profile_id: &str, revoked_at: DateTime<Utc>) -> Result<(), RepositoryError> {
+        // Keeps the first revocation time if called twice
+        sqlx::query(
+            r#"
+            UPDATE performance_share_link
+            SET revoked_at = COALESCE(revoked_at, ?)
+            WHERE id = ? AND profile_id = ?
+            "#
+        )
+        .bind(revoked_at.naive_utc())
+        .bind(id)
+        .bind(profile_id)
+        .execute(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn record_access(&self, id: &str, accessed_at: DateTime<Utc>) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            UPDATE performance_share_link
+            SET access_count = access_count + 1, last_accessed_at = ?
+            WHERE id = ?
+            "#
+        )
+        .bind(accessed_at.naive_utc())
+        .bind(id)
+        .execute(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
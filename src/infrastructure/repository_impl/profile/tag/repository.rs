@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use crate::domain::entities::profile::tag::tag::{Tag, TagCount};
+use crate::infrastructure::db::mysql::common::mysql_repository::MySqlRepository;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+use crate::interface_adapters::gateways::repositories::profile::tag::tag_repository::TagRepository;
+
+#[derive(sqlx::FromRow)]
+struct TagRecord {
+    id: String,
+    profile_id: String,
+    name: String,
+    slug: String,
+    created_at: sqlx::types::chrono::NaiveDateTime,
+}
+
+#[derive(sqlx::FromRow)]
+struct TagCountRecord {
+    id: String,
+    profile_id: String,
+    name: String,
+    slug: String,
+    created_at: sqlx::types::chrono::NaiveDateTime,
+    performance_count: i64,
+}
+
+#[derive(sqlx::FromRow)]
+struct PerformanceTagRecord {
+    performance_id: String,
+    id: String,
+    profile_id: String,
+    name: String,
+    slug: String,
+    created_at: sqlx::types::chrono::NaiveDateTime,
+}
+
+fn record_to_tag(r: TagRecord) -> Tag {
+    Tag {
+        id: r.id,
+        profile_id: r.profile_id,
+        name: r.name,
+        slug: r.slug,
+        created_at: r.created_at.to_string(),
+    }
+}
+
+#[derive(Clone)]
+pub struct TagRepositoryImpl {
+    mysql: MySqlRepository,
+}
+
+impl TagRepositoryImpl {
+    pub fn new(mysql: MySqlRepository) -> Self {
+        Self { mysql }
+    }
+}
+
+#[async_trait]
+impl TagRepository for TagRepositoryImpl {
+    async fn find_by_profile_id(
+        &self,
+        profile_id: &str,
+        prefix: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<TagCount>, RepositoryError> {
+        let mut query_builder = sqlx::QueryBuilder::new(
+            r#"
+            SELECT t.id, t.profile_id, t.name, t.slug, t.created_at, COUNT(p.id) AS performance_count
+            FROM tag t
+            LEFT JOIN performance_tag pt ON pt.tag_id = t.id
+            LEFT JOIN performance p ON p.id = pt.performance_id
+            "#
+        );
+        query_builder.push(" WHERE t.profile_id = ");
+        query_builder.push_bind(profile_id);
+        if let Some(prefix) = prefix {
+            let pattern = format!("{}%", prefix.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_"));
+            query_builder.push(" AND (t.name LIKE ");
+            query_builder.push_bind(pattern.clone());
+            query_builder.push(" OR t.slug LIKE ");
+            query_builder.push_bind(pattern);
+            query_builder.push(")");
+        }
+        query_builder.push(" GROUP BY t.id, t.profile_id, t.name, t.slug, t.created_at");
+        query_builder.push(" ORDER BY performance_count DESC, t.name");
+        if let Some(limit) = limit {
+            query_builder.push(" LIMIT ");
+            query_builder.push_bind(limit);
+        }
+
+        let rows = query_builder
+            .build_query_as::<TagCountRecord>()
+            .fetch_all(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| TagCount {
+                tag: Tag {
+                    id: r.id,
+                    profile_id: r.profile_id,
+                    name: r.name,
+                    slug: r.slug,
+                    created_at: r.created_at.to_string(),
+                },
+                performance_count: r.performance_count,
+            })
+            .collect())
+    }
+
+    async fn find_by_id(&self, id: &str, profile_id: &str) -> Result<Option<Tag>, RepositoryError> {
+        let row = sqlx::query_as::<_, TagRecord>(
+            r#"
+            SELECT id, profile_id, name, slug, created_at
+            FROM tag
+            WHERE id = ? AND profile_id = ?
+            "#
+        )
+        .bind(id)
+        .bind(profile_id)
+        .fetch_optional(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(record_to_tag))
+    }
+
+    async fn find_by_slug(&self, profile_id: &str, slug: &str) -> Result<Option<Tag>, RepositoryError> {
+        let row = sqlx::query_as::<_, TagRecord>(
+            r#"
+            SELECT id, profile_id, name, slug, created_at
+            FROM tag
+            WHERE profile_id = ? AND slug = ?
+            "#
+        )
+        .bind(profile_id)
+        .bind(slug)
+        .fetch_optional(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(record_to_tag))
+    }
+
+    async fn create(&self, tag: Tag) -> Result<Tag, RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO tag (id, profile_id, name, slug, created_at)
+            VALUES (?, ?, ?, ?, ?)
+            "#
+        )
+        .bind(&tag.id)
+        .bind(&tag.profile_id)
+        .bind(&tag.name)
+        .bind(&tag.slug)
+        .bind(&tag.created_at)
+        .execute(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(tag)
+    }
+
+    async fn update(&self, tag: Tag) -> Result<Tag, RepositoryError> {
+        sqlx::query("UPDATE tag SET name = ?, slug = ? WHERE id = ? AND profile_id = ?")
+            .bind(&tag.name)
+            .bind(&tag.slug)
+            .bind(&tag.id)
+            .bind(&tag.profile_id)
+            .execute(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(tag)
+    }
+
+    async fn delete(&self, id: &str, profile_id: &str) -> Result<(), RepositoryError> {
+        // performance_tag rows go with it (ON DELETE CASCADE)
+        sqlx::query("DELETE FROM tag WHERE id = ? AND profile_id = ?")
+            .bind(id)
+            .bind(profile_id)
+            .execute(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_performance_ids(
+        &self,
+        performance_ids: &[String],
+    ) -> Result<HashMap<String, Vec<Tag>>, RepositoryError> {
+        let mut tags: HashMap<String, Vec<Tag>> = HashMap::new();
+        if performance_ids.is_empty() {
+            return Ok(tags);
+        }
+
+        let mut query_builder = sqlx::QueryBuilder::new(
+            r#"
+            SELECT pt.performance_id, t.id, t.profile_id, t.name, t.slug, t.created_at
+            FROM performance_tag pt
+            INNER JOIN tag t ON t.id = pt.tag_id
+            WHERE pt.performance_id IN (
+            "#
+        );
+        let mut separated = query_builder.separated(", ");
+        for id in performance_ids {
+            separated.push_bind(id);
+        }
+        separated.push_unseparated(") ORDER BY t.name");
+
+        let rows = query_builder
+            .build_query_as::<PerformanceTagRecord>()
+            .fetch_all(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        for r in rows {
+            tags.entry(r.performance_id).or_default().push(Tag {
+                id: r.id,
+                profile_id: r.profile_id,
+                name: r.name,
+                slug: r.slug,
+                created_at: r.created_at.to_string(),
+            });
+        }
+
+        Ok(tags)
+    }
+
+    async fn set_performance_tags(&self, performance_id: &str, tag_ids: &[String]) -> Result<(), RepositoryError> {
+        let mut tx = self.mysql.pool().begin().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        sqlx::query("DELETE FROM performance_tag WHERE performance_id = ?")
+            .bind(performance_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if !tag_ids.is_empty() {
+            let mut query_builder = sqlx::QueryBuilder::new("INSERT INTO performance_tag (performance_id, tag_id) ");
+            query_builder.push_values(tag_ids, |mut row, tag_id| {
+                row.push_bind(performance_id).push_bind(tag_id);
+            });
+            query_builder.build()
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        }
+
+        tx.commit().await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use crate::domain::entities::profile::visibility::visibility::Visibility;
+use crate::infrastructure::db::mysql::common::mysql_repository::MySqlRepository;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+use crate::interface_adapters::gateways::repositories::profile::visibility::visibility_repository::VisibilityRepository;
+
+#[derive(sqlx::FromRow)]
+struct VisibilityRecord {
+    id: String,
+    name: String,
+    description: Option<String>,
+    sort_order: i32,
+}
+
+fn record_to_visibility(r: VisibilityRecord) -> Visibility {
+    Visibility {
+        id: r.id,
+        name: r.name,
+        description: r.description,
+        sort_order: r.sort_order,
+    }
+}
+
+#[derive(Clone)]
+pub struct VisibilityRepositoryImpl {
+    mysql: MySqlRepository,
+}
+
+impl VisibilityRepositoryImpl {
+    pub fn new(mysql: MySqlRepository) -> Self {
+        Self { mysql }
+    }
+}
+
+#[async_trait]
+impl VisibilityRepository for VisibilityRepositoryImpl {
+    async fn find_all(&self) -> Result<Vec<Visibility>, RepositoryError> {
+        let rows = sqlx::query_as::<_, VisibilityRecord>(
+            "SELECT id, name, description, sort_order FROM visibility ORDER BY sort_order, id"
+        )
+        .fetch_all(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(record_to_visibility).collect())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<Visibility>, RepositoryError> {
+        let row = sqlx::query_as::<_, VisibilityRecord>(
+            "SELECT id, name, description, sort_order FROM visibility WHERE id = ?"
+        )
+        .bind(id)
+        .fetch_optional(self.mysql.pool())
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(record_to_visibility))
+    }
+
+    async fn update(&self, visibility: Visibility) -> Result<Visibility, RepositoryError> {
+        sqlx::query("UPDATE visibility SET name = ?, description = ?, sort_order = ? WHERE id = ?")
+            .bind(&visibility.name)
+            .bind(&visibility.description)
+            .bind(visibility.sort_order)
+            .bind(&visibility.id)
+            .execute(self.mysql.pool())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(visibility)
+    }
+}
@@ -1 +1,2 @@
-pub(crate) mod repository_error;
\ No newline at end of file
+pub(crate) mod repository_error;
+pub(crate) mod stored_object;
@@ -0,0 +1,9 @@
+use chrono::{DateTime, Utc};
+
+/// An object found in cloud storage by listing, named relative to the listed prefix.
+#[derive(Debug, Clone)]
+pub struct StoredObject {
+    pub name: String,
+    pub size: i64,
+    pub created_at: Option<DateTime<Utc>>,
+}
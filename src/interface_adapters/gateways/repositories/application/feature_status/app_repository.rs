@@ -11,4 +11,6 @@ pub trait AppRepository: Send + Sync {
         &self,
         website_id: &str,
     ) -> Result<Vec<AppFeatureStatus>, RepositoryError>;
+    /// Custom robots.txt configured for the website, if any
+    async fn find_robots_txt(&self, website_id: &str) -> Result<Option<String>, RepositoryError>;
 }
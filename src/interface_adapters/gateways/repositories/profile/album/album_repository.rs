@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use crate::domain::entities::profile::album::album::{Album, AlbumSummary};
+use crate::domain::entities::profile::image::image::Image;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+
+#[async_trait]
+pub trait AlbumRepository: Send + Sync {
+    /// Albums of a profile, newest first; with a visibility, only albums of that visibility.
+    async fn find_by_profile_id(
+        &self,
+        profile_id: &str,
+        visibility_id: Option<&str>,
+    ) -> Result<Vec<AlbumSummary>, RepositoryError>;
+    async fn find_by_id(&self, id: &str, profile_id: &str) -> Result<Option<Album>, RepositoryError>;
+    async fn find_by_slug(&self, profile_id: &str, slug: &str) -> Result<Option<Album>, RepositoryError>;
+    async fn create(&self, album: Album) -> Result<Album, RepositoryError>;
+    async fn update(&self, album: Album) -> Result<Album, RepositoryError>;
+    async fn delete(&self, id: &str, profile_id: &str) -> Result<(), RepositoryError>;
+
+    // Album <-> image links
+    /// Images of an album in album order
+    async fn find_images(&self, album_id: &str) -> Result<Vec<Image>, RepositoryError>;
+    /// Replaces the images of an album; positions follow the order of `image_ids`
+    async fn set_images(&self, album_id: &str, image_ids: &[String]) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1 @@
+pub(crate) mod album_repository;
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use crate::domain::entities::profile::archive::archive::{ProfileArchiveTarget, ProfileSnapshot};
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+
+#[async_trait]
+pub trait ProfileArchiveRepository: Send + Sync {
+    /// Every database row belonging to the profile; `None` when the profile doesn't exist
+    async fn load_snapshot(&self, profile_id: &str) -> Result<Option<ProfileSnapshot>, RepositoryError>;
+    async fn find_target(&self, profile_id: &str) -> Result<ProfileArchiveTarget, RepositoryError>;
+    /// Writes a snapshot whose ids are already final, in one transaction.
+    /// Creates the profile row (owned by `user_id`) when missing, otherwise updates it.
+    async fn restore_snapshot(&self, user_id: &str, snapshot: &ProfileSnapshot) -> Result<(), RepositoryError>;
+}
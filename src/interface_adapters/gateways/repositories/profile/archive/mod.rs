@@ -0,0 +1 @@
+pub(crate) mod archive_repository;
@@ -1,14 +1,20 @@
+use std::collections::HashMap;
 use async_trait::async_trait;
 use crate::domain::entities::profile::image::image::Image;
-use crate::domain::entities::profile::image::image_usage::ImageUsageInfo;
+use crate::domain::entities::profile::image::image_settings::ImageSettings;
+use crate::domain::entities::profile::image::image_tag::{ImageTag, ImageTagCount};
+use crate::domain::entities::profile::image::image_usage::{ImageOwnerReference, ImageOwnerSource, ImageUsageInfo};
+use crate::domain::entities::profile::image::image_variant::ImageVariant;
 use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
 
 #[async_trait]
 pub trait ImageRepository: Send + Sync {
+    /// With `tags`, only images carrying every one of these tag names.
     async fn find_all_by_profile_id(
         &self,
         profile_id: &str,
         search: Option<String>,
+        tags: &[String],
         limit: i32,
         offset: i32,
     ) -> Result<(Vec<(Image, i32, Vec<ImageUsageInfo>)>, usize), RepositoryError>;
@@ -19,6 +25,9 @@ pub trait ImageRepository: Send + Sync {
         profile_id: &str,
     ) -> Result<Option<(Image, i32, Vec<ImageUsageInfo>)>, RepositoryError>;
 
+    /// Looks an image up without knowing its profile, for public endpoints keyed by image id only.
+    async fn find_by_id(&self, id: &str) -> Result<Option<Image>, RepositoryError>;
+
     async fn find_usage_by_image_id(
         &self,
         image_id: &str,
@@ -38,6 +47,18 @@ pub trait ImageRepository: Send + Sync {
         image_id: &str,
         performance_id: &str,
     ) -> Result<(), RepositoryError>;
+
+    /// Avatar, social and skill logos, and announces of the profile, the rows `ImageUsageOwner::SYNCED` covers.
+    async fn find_owner_sources(&self, profile_id: &str) -> Result<Vec<ImageOwnerSource>, RepositoryError>;
+
+    /// Replaces the synced-owner usage of the profile's images with `references`, in one transaction.
+    /// References to images of other profiles are ignored. Performance usage is only touched to drop
+    /// rows of performances that no longer exist.
+    async fn sync_owner_usage(
+        &self,
+        profile_id: &str,
+        references: &[ImageOwnerReference],
+    ) -> Result<(), RepositoryError>;
     
     async fn update_metadata(
         &self,
@@ -62,4 +83,71 @@ pub trait ImageRepository: Send + Sync {
         profile_id: &str,
         days_old: i32,
     ) -> Result<(i64, i64), RepositoryError>;
+
+    async fn create_variants(&self, variants: Vec<ImageVariant>) -> Result<(), RepositoryError>;
+
+    /// Variants of all given images, in no particular order.
+    async fn find_variants_by_image_ids(
+        &self,
+        image_ids: &[String],
+    ) -> Result<Vec<ImageVariant>, RepositoryError>;
+
+    /// `None` when the profile never changed its image settings.
+    async fn find_settings(&self, profile_id: &str) -> Result<Option<ImageSettings>, RepositoryError>;
+
+    async fn save_settings(&self, settings: ImageSettings) -> Result<(), RepositoryError>;
+
+    /// Drops stored capture date and camera of every image of the profile.
+    async fn clear_capture_metadata(&self, profile_id: &str) -> Result<(), RepositoryError>;
+
+    /// Oldest image of the profile with this content hash.
+    async fn find_by_content_hash(
+        &self,
+        profile_id: &str,
+        content_hash: &str,
+    ) -> Result<Option<Image>, RepositoryError>;
+
+    /// Every image of the profile, oldest first.
+    async fn find_by_profile_id(&self, profile_id: &str) -> Result<Vec<Image>, RepositoryError>;
+
+    async fn set_content_hash(&self, id: &str, content_hash: &str) -> Result<(), RepositoryError>;
+
+    /// Images of the profile without placeholders that were never found undecodable, oldest first.
+    async fn find_missing_placeholders(&self, profile_id: &str) -> Result<Vec<Image>, RepositoryError>;
+
+    async fn set_placeholder(
+        &self,
+        id: &str,
+        blurhash: &str,
+        dominant_color: &str,
+        lqip: &str,
+    ) -> Result<(), RepositoryError>;
+
+    /// Keeps the placeholder backfill from retrying an image whose original can't be decoded.
+    async fn mark_placeholder_failed(&self, id: &str) -> Result<(), RepositoryError>;
+
+    /// Moves the usage of `duplicate_id` onto `keep_id` and deletes the duplicate row, in one transaction.
+    async fn merge_duplicate(
+        &self,
+        profile_id: &str,
+        keep_id: &str,
+        duplicate_id: &str,
+    ) -> Result<(), RepositoryError>;
+
+    // Image tags
+    /// Tags of a profile by name, with the number of images carrying each.
+    async fn find_tags(&self, profile_id: &str) -> Result<Vec<ImageTagCount>, RepositoryError>;
+    async fn find_tag_by_id(&self, id: &str, profile_id: &str) -> Result<Option<ImageTag>, RepositoryError>;
+    /// Case-insensitive match
+    async fn find_tag_by_name(&self, profile_id: &str, name: &str) -> Result<Option<ImageTag>, RepositoryError>;
+    async fn create_tag(&self, tag: ImageTag) -> Result<ImageTag, RepositoryError>;
+    async fn update_tag(&self, tag: ImageTag) -> Result<ImageTag, RepositoryError>;
+    async fn delete_tag(&self, id: &str, profile_id: &str) -> Result<(), RepositoryError>;
+    /// Tags of each given image, keyed by image id
+    async fn find_tags_by_image_ids(
+        &self,
+        image_ids: &[String],
+    ) -> Result<HashMap<String, Vec<ImageTag>>, RepositoryError>;
+    /// Replaces the tags of an image
+    async fn set_image_tags(&self, image_id: &str, tag_ids: &[String]) -> Result<(), RepositoryError>;
 }
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+use crate::interface_adapters::gateways::common::stored_object::StoredObject;
 
 #[async_trait]
 pub trait ImageStorageRepository: Send + Sync {
@@ -17,9 +18,18 @@ pub trait ImageStorageRepository: Send + Sync {
         filename: &str,
     ) -> Result<(), RepositoryError>;
 
+    async fn download_image(
+        &self,
+        profile_id: &str,
+        filename: &str,
+    ) -> Result<Vec<u8>, RepositoryError>;
+
     async fn get_image_url(
         &self,
         profile_id: &str,
         filename: &str,
     ) -> Result<String, RepositoryError>;
+
+    /// Every stored file of the profile, originals and variants, named by filename.
+    async fn list_images(&self, profile_id: &str) -> Result<Vec<StoredObject>, RepositoryError>;
 }
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+
+/// Results of `/images/{id}/transform`, one object per parameter key.
+#[async_trait]
+pub trait ImageTransformCacheRepository: Send + Sync {
+    /// `None` when this transform wasn't rendered yet.
+    async fn find_transform(
+        &self,
+        profile_id: &str,
+        image_id: &str,
+        key: &str,
+    ) -> Result<Option<Vec<u8>>, RepositoryError>;
+
+    async fn save_transform(
+        &self,
+        profile_id: &str,
+        image_id: &str,
+        key: &str,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<(), RepositoryError>;
+
+    /// Drops every cached transform of the image.
+    async fn delete_transforms(&self, profile_id: &str, image_id: &str) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use crate::domain::entities::profile::image::image_upload_session::ImageUploadSession;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+
+#[async_trait]
+pub trait ImageUploadSessionRepository: Send + Sync {
+    async fn create(&self, session: ImageUploadSession) -> Result<ImageUploadSession, RepositoryError>;
+
+    async fn find_by_id(&self, id: &str, profile_id: &str) -> Result<Option<ImageUploadSession>, RepositoryError>;
+
+    /// Stores a chunk starting at `offset` and moves the session past it.
+    /// Returns false without storing anything when the session is no longer at `offset`.
+    async fn append_chunk(&self, id: &str, offset: i64, data: &[u8], expires_at: &str) -> Result<bool, RepositoryError>;
+
+    /// All chunks of a session joined in order.
+    async fn read_bytes(&self, id: &str) -> Result<Vec<u8>, RepositoryError>;
+
+    async fn delete(&self, id: &str, profile_id: &str) -> Result<(), RepositoryError>;
+
+    /// Removes every session that expired before `now`, chunks included. Returns how many went.
+    async fn delete_expired(&self, now: &str) -> Result<u64, RepositoryError>;
+}
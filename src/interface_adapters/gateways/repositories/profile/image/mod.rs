@@ -1,2 +1,4 @@
 pub mod image_repository;
 pub mod image_storage_repository;
+pub mod image_transform_cache_repository;
+pub mod image_upload_session_repository;
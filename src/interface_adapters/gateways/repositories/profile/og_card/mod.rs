@@ -0,0 +1 @@
+pub mod og_card_repository;
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+
+/// Rendered OpenGraph cards of performances, one object per card key.
+#[async_trait]
+pub trait OgCardRepository: Send + Sync {
+    /// `None` when no card was stored under this key yet.
+    async fn find_card(
+        &self,
+        profile_id: &str,
+        performance_id: &str,
+        key: &str,
+    ) -> Result<Option<Vec<u8>>, RepositoryError>;
+
+    /// Stores the card and drops the performance's cards rendered from older inputs.
+    async fn save_card(
+        &self,
+        profile_id: &str,
+        performance_id: &str,
+        key: &str,
+        data: Vec<u8>,
+    ) -> Result<(), RepositoryError>;
+}
@@ -12,7 +12,14 @@ pub trait PerformanceRepository: Send + Sync {
         profile_id: &str,
         visibility_id: Option<&str>,
     ) -> Result<Vec<Performance>, RepositoryError>;
+    /// Performances of every profile with the given visibility
+    async fn find_by_visibility(&self, visibility_id: &str) -> Result<Vec<Performance>, RepositoryError>;
+    /// Deletes the performance together with its image usage, in one transaction
     async fn delete(&self, id: &str) -> Result<(), RepositoryError>;
+    /// Position before the first performance of the profile, so a new one lists first; 0 for the first one
+    async fn first_sort_order(&self, profile_id: &str) -> Result<i32, RepositoryError>;
+    /// Sets `sort_order` to each id's position in `ordered_ids`, in one transaction
+    async fn reorder(&self, profile_id: &str, ordered_ids: &[String]) -> Result<(), RepositoryError>;
     
     // Image usage tracking
     async fn sync_image_usage(
@@ -20,7 +27,6 @@ pub trait PerformanceRepository: Send + Sync {
         performance_id: &str,
         current_image_ids: &[String],
     ) -> Result<(), RepositoryError>;
-    async fn delete_image_usage_by_performance_id(&self, performance_id: &str) -> Result<(), RepositoryError>;
     async fn find_images_by_performance_id(
         &self,
         performance_id: &str,
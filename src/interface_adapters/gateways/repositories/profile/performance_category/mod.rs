@@ -0,0 +1 @@
+pub(crate) mod performance_category_repository;
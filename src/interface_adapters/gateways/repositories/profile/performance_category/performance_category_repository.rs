@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use crate::domain::entities::profile::performance_category::performance_category::PerformanceCategory;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+
+#[async_trait]
+pub trait PerformanceCategoryRepository: Send + Sync {
+    async fn find_by_profile_id(&self, profile_id: &str) -> Result<Vec<PerformanceCategory>, RepositoryError>;
+    async fn find_by_id(&self, id: &str, profile_id: &str) -> Result<Option<PerformanceCategory>, RepositoryError>;
+    async fn create(&self, category: PerformanceCategory) -> Result<PerformanceCategory, RepositoryError>;
+    async fn update(&self, category: PerformanceCategory) -> Result<PerformanceCategory, RepositoryError>;
+    async fn delete(&self, id: &str, profile_id: &str) -> Result<(), RepositoryError>;
+    /// Number of performances still filed under the category
+    async fn count_performances(&self, id: &str) -> Result<i64, RepositoryError>;
+}
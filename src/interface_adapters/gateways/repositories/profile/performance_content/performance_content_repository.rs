@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+use crate::interface_adapters::gateways::common::stored_object::StoredObject;
 
 #[async_trait]
 pub trait PerformanceContentRepository: Send + Sync {
@@ -28,4 +29,7 @@ pub trait PerformanceContentRepository: Send + Sync {
         profile_id: &str,
         performance_id: &str,
     ) -> Result<(), RepositoryError>;
+
+    /// Every stored content file of the profile, named by its performance id.
+    async fn list_contents(&self, profile_id: &str) -> Result<Vec<StoredObject>, RepositoryError>;
 }
@@ -5,4 +5,5 @@ use crate::interface_adapters::gateways::common::repository_error::RepositoryErr
 #[async_trait]
 pub trait ProfileRepository: Send + Sync {
     async fn find_by_id(&self, id: &str) -> Result<Option<Profile>, RepositoryError>;
+    async fn find_all(&self) -> Result<Vec<Profile>, RepositoryError>;
 }
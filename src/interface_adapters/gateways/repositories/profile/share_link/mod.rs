@@ -0,0 +1 @@
+pub(crate) mod share_link_repository;
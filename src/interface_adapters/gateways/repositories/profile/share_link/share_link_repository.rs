@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::entities::profile::share_link::share_link::ShareLink;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+
+#[async_trait]
+pub trait ShareLinkRepository: Send + Sync {
+    async fn create(&self, link: ShareLink) -> Result<ShareLink, RepositoryError>;
+    async fn find_by_id(&self, id: &str, profile_id: &str) -> Result<Option<ShareLink>, RepositoryError>;
+    /// Newest first, revoked and expired links included
+    async fn find_by_performance_id(&self, performance_id: &str) -> Result<Vec<ShareLink>, RepositoryError>;
+    async fn find_by_token_hash(&self, token_hash: &str) -> Result<Option<ShareLink>, RepositoryError>;
+    async fn revoke(&self, id: &str, profile_id: &str, revoked_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+    /// Bumps the access counter
+    async fn record_access(&self, id: &str, accessed_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use crate::domain::entities::profile::tag::tag::{Tag, TagCount};
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    /// Tags of a profile ordered by usage, optionally narrowed to a name/slug prefix.
+    async fn find_by_profile_id(
+        &self,
+        profile_id: &str,
+        prefix: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<Vec<TagCount>, RepositoryError>;
+    async fn find_by_id(&self, id: &str, profile_id: &str) -> Result<Option<Tag>, RepositoryError>;
+    async fn find_by_slug(&self, profile_id: &str, slug: &str) -> Result<Option<Tag>, RepositoryError>;
+    async fn create(&self, tag: Tag) -> Result<Tag, RepositoryError>;
+    async fn update(&self, tag: Tag) -> Result<Tag, RepositoryError>;
+    async fn delete(&self, id: &str, profile_id: &str) -> Result<(), RepositoryError>;
+
+    // Performance <-> tag links
+    /// Tags of each given performance, keyed by performance id
+    async fn find_by_performance_ids(
+        &self,
+        performance_ids: &[String],
+    ) -> Result<HashMap<String, Vec<Tag>>, RepositoryError>;
+    /// Replaces the tags of a performance
+    async fn set_performance_tags(&self, performance_id: &str, tag_ids: &[String]) -> Result<(), RepositoryError>;
+}
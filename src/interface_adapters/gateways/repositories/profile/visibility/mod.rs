@@ -0,0 +1 @@
+pub(crate) mod visibility_repository;
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use crate::domain::entities::profile::visibility::visibility::Visibility;
+use crate::interface_adapters::gateways::common::repository_error::RepositoryError;
+
+#[async_trait]
+pub trait VisibilityRepository: Send + Sync {
+    async fn find_all(&self) -> Result<Vec<Visibility>, RepositoryError>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<Visibility>, RepositoryError>;
+    async fn update(&self, visibility: Visibility) -> Result<Visibility, RepositoryError>;
+}
@@ -0,0 +1,125 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use crate::application::use_cases::profile::album::dto::input::{
+    ListAlbumsInput, GetAlbumInput, CreateAlbumInput, UpdateAlbumInput, DeleteAlbumInput,
+    SetAlbumImagesInput, GetPublicAlbumsInput, GetPublicAlbumInput
+};
+use crate::application::use_cases::use_case::UseCase;
+use crate::delivery::http::server::state::AppState;
+
+#[derive(Deserialize)]
+pub struct CreateAlbumRequest {
+    pub title: String,
+    pub description: Option<String>,
+    pub visibility_id: Option<String>,
+    pub cover_image_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateAlbumRequest {
+    pub title: String,
+    pub description: Option<String>,
+    pub visibility_id: String,
+    pub cover_image_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct SetAlbumImagesRequest {
+    pub image_ids: Vec<String>,
+}
+
+pub async fn get_albums_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+) -> impl IntoResponse {
+    let input = ListAlbumsInput { profile_id };
+
+    state.profile.album.get_all.execute(input).await.into_response()
+}
+
+pub async fn get_album_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, album_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let input = GetAlbumInput { id: album_id, profile_id };
+
+    state.profile.album.get_one.execute(input).await.into_response()
+}
+
+pub async fn create_album_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    Json(payload): Json<CreateAlbumRequest>,
+) -> impl IntoResponse {
+    let input = CreateAlbumInput {
+        profile_id,
+        title: payload.title,
+        description: payload.description,
+        visibility_id: payload.visibility_id,
+        cover_image_id: payload.cover_image_id,
+    };
+
+    state.profile.album.create.execute(input).await.into_response()
+}
+
+pub async fn update_album_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, album_id)): Path<(String, String)>,
+    Json(payload): Json<UpdateAlbumRequest>,
+) -> impl IntoResponse {
+    let input = UpdateAlbumInput {
+        id: album_id,
+        profile_id,
+        title: payload.title,
+        description: payload.description,
+        visibility_id: payload.visibility_id,
+        cover_image_id: payload.cover_image_id,
+    };
+
+    state.profile.album.update.execute(input).await.into_response()
+}
+
+pub async fn delete_album_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, album_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let input = DeleteAlbumInput { id: album_id, profile_id };
+
+    state.profile.album.delete.execute(input).await.into_response()
+}
+
+pub async fn set_album_images_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, album_id)): Path<(String, String)>,
+    Json(payload): Json<SetAlbumImagesRequest>,
+) -> impl IntoResponse {
+    let input = SetAlbumImagesInput {
+        id: album_id,
+        profile_id,
+        image_ids: payload.image_ids,
+    };
+
+    state.profile.album.set_images.execute(input).await.into_response()
+}
+
+pub async fn get_public_albums_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+) -> impl IntoResponse {
+    let input = GetPublicAlbumsInput { profile_id };
+
+    state.profile.album.get_public_all.execute(input).await.into_response()
+}
+
+pub async fn get_public_album_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, slug_or_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let input = GetPublicAlbumInput { profile_id, slug_or_id };
+
+    state.profile.album.get_public.execute(input).await.into_response()
+}
@@ -0,0 +1,53 @@
+use axum::{
+    extract::{Path, State, Multipart},
+    response::IntoResponse,
+    Extension,
+};
+use crate::application::use_cases::profile::archive::dto::input::{
+    ExportProfileArchiveInput, RestoreProfileArchiveInput
+};
+use crate::application::use_cases::use_case::UseCase;
+use crate::application::errors::ApplicationError;
+use crate::delivery::http::server::state::AppState;
+use crate::domain::entities::auth::claims::Claims;
+
+pub async fn export_profile_archive_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+) -> impl IntoResponse {
+    let input = ExportProfileArchiveInput { profile_id };
+
+    state.profile.archive.export.execute(input).await.into_response()
+}
+
+pub async fn restore_profile_archive_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    Extension(claims): Extension<Claims>,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    let mut archive = Vec::new();
+
+    loop {
+        let field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => break,
+            Err(e) => return ApplicationError::ValidationError { message: e.body_text() }.into_response(),
+        };
+        if field.name() != Some("file") {
+            continue;
+        }
+        match field.bytes().await {
+            Ok(bytes) => archive = bytes.to_vec(),
+            Err(e) => return ApplicationError::ValidationError { message: e.body_text() }.into_response(),
+        }
+    }
+
+    let input = RestoreProfileArchiveInput {
+        profile_id,
+        user_id: claims.sub,
+        archive,
+    };
+
+    state.profile.archive.restore.execute(input).await.into_response()
+}
@@ -1,6 +1,9 @@
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::response::IntoResponse;
-use crate::application::use_cases::profile::profile::dto::input::GetProfileInput;
+use serde::Deserialize;
+use crate::application::use_cases::profile::profile::dto::input::{
+    GetProfileInput, GetProfileResumeInput, GetProfileVCardInput
+};
 use crate::application::use_cases::use_case::UseCase;
 use crate::delivery::http::server::state::AppState;
 
@@ -12,3 +15,33 @@ pub async fn get_profile_ctrl(
 
     state.profile.profile.get_one.execute(input).await.into_response()
 }
+
+#[derive(Deserialize)]
+pub struct ResumeQuery {
+    /// Comma-separated category ids or names to list as work experience
+    pub work_categories: Option<String>,
+}
+
+pub async fn get_profile_resume_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    Query(query): Query<ResumeQuery>,
+) -> impl IntoResponse {
+    let input = GetProfileResumeInput {
+        profile_id,
+        work_categories: query.work_categories
+            .map(|c| c.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default(),
+    };
+
+    state.profile.profile.resume.execute(input).await.into_response()
+}
+
+pub async fn get_profile_vcard_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+) -> impl IntoResponse {
+    let input = GetProfileVCardInput { profile_id };
+
+    state.profile.profile.vcard.execute(input).await.into_response()
+}
@@ -1,23 +1,61 @@
 use axum::{
-    extract::{Path, Query, State, Multipart},
+    body::{to_bytes, Body},
+    extract::{Path, Query, State, Multipart, multipart::Field},
+    http::{header, HeaderMap},
     response::IntoResponse,
     Json,
 };
 use serde::Deserialize;
 use crate::application::use_cases::profile::image::dto::input::{
     GetImagesInput, GetImageInput, CreateImageInput, UpdateImageMetadataInput,
-    DeleteImageInput, ForceDeleteImageInput, GetUnusedImagesInput, DeleteUnusedImagesInput, TrackImageUsageInput
+    DeleteImageInput, ForceDeleteImageInput, GetUnusedImagesInput, DeleteUnusedImagesInput, TrackImageUsageInput, TransformImageInput,
+    GetImageSettingsInput, UpdateImageSettingsInput, DuplicateImagePolicy, MergeDuplicateImagesInput,
+    ListImageTagsInput, CreateImageTagInput, UpdateImageTagInput, DeleteImageTagInput, SetImageTagsInput,
+    BackfillImagePlaceholdersInput, CreateImageUploadInput, AppendImageUploadChunkInput, ImageUploadInput
 };
+use crate::application::errors::ApplicationError;
 use crate::application::use_cases::use_case::UseCase;
 use crate::delivery::http::server::state::AppState;
 
 #[derive(Deserialize)]
 pub struct GetImagesQuery {
     pub search: Option<String>,
+    /// Comma separated tag names, an image must carry all of them
+    pub tag: Option<String>,
     pub limit: Option<i32>,
     pub offset: Option<i32>,
 }
 
+impl GetImagesQuery {
+    fn tags(&self) -> Vec<String> {
+        self.tag
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct UploadImageQuery {
+    /// "existing" (default) or "conflict"
+    pub on_duplicate: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateImageUploadRequest {
+    pub filename: String,
+    /// Size of the whole file in bytes
+    pub total_size: i64,
+    pub alt_text: Option<String>,
+    pub caption: Option<String>,
+    /// "existing" (default) or "conflict"
+    pub on_duplicate: Option<String>,
+}
+
 #[derive(Deserialize)]
 pub struct UpdateImageMetadataRequest {
     pub alt_text: Option<String>,
@@ -35,12 +73,43 @@ pub struct DeleteUnusedImagesQuery {
     pub days_old: Option<i32>,
 }
 
+#[derive(Deserialize)]
+pub struct UpdateImageSettingsRequest {
+    pub keep_capture_metadata: bool,
+}
+
+#[derive(Deserialize)]
+pub struct BackfillPlaceholdersQuery {
+    pub limit: Option<i32>,
+}
+
+#[derive(Deserialize)]
+pub struct ImageTagRequest {
+    pub name: String,
+    pub color: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct SetImageTagsRequest {
+    pub tags: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub struct TransformImageQuery {
+    pub w: Option<u32>,
+    pub h: Option<u32>,
+    pub fit: Option<String>,
+    pub format: Option<String>,
+    pub q: Option<u32>,
+}
+
 pub async fn get_images_ctrl(
     State(state): State<AppState>,
     Path(profile_id): Path<String>,
     Query(query): Query<GetImagesQuery>,
 ) -> impl IntoResponse {
-    let input = GetImagesInput::new(profile_id, query.search, query.limit, query.offset);
+    let tags = query.tags();
+    let input = GetImagesInput::new(profile_id, query.search, tags, query.limit, query.offset);
     
     let res: Result<crate::application::services::profile::image::result::ImageListResult, crate::application::errors::ApplicationError> = state.profile.image.get_all.execute(input).await;
     res.into_response()
@@ -65,58 +134,168 @@ pub async fn get_image_usage_ctrl(
     let res: Result<crate::application::services::profile::image::result::ImageUsageResult, crate::application::errors::ApplicationError> = state.profile.image.get_usage.execute(input).await;
     res.into_response()
 }
+/// Multipart text fields are small; anything bigger is not a caption.
+const MAX_TEXT_FIELD_BYTES: usize = 64 * 1024;
+
+/// Reads a multipart field chunk by chunk and gives up as soon as it grows past `max_bytes`,
+/// so an oversized upload is never buffered whole.
+async fn read_field_capped(field: &mut Field<'_>, max_bytes: usize, what: &str) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    while let Some(chunk) = field.chunk().await.map_err(|e| e.body_text())? {
+        if bytes.len() + chunk.len() > max_bytes {
+            return Err(format!("{} cannot be larger than {} bytes", what, max_bytes));
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+    Ok(bytes)
+}
+
+async fn read_text_field(field: &mut Field<'_>, name: &str) -> Result<String, String> {
+    let bytes = read_field_capped(field, MAX_TEXT_FIELD_BYTES, name).await?;
+    String::from_utf8(bytes).map_err(|_| format!("{} must be valid UTF-8", name))
+}
+
 pub async fn upload_image_ctrl(
     State(state): State<AppState>,
     Path(profile_id): Path<String>,
+    Query(query): Query<UploadImageQuery>,
     mut multipart: Multipart,
 ) -> impl IntoResponse {
+    let on_duplicate = match DuplicateImagePolicy::parse(query.on_duplicate.as_deref()) {
+        Ok(policy) => policy,
+        Err(message) => return ApplicationError::ValidationError { message }.into_response(),
+    };
+
+    let max_bytes = state.profile.image.create.max_upload_bytes();
     let mut original_filename = String::new();
-    let mut mime_type = String::new();
     let mut image_bytes = Vec::new();
     let mut alt_text = None;
     let mut caption = None;
 
-    while let Ok(Some(field)) = multipart.next_field().await {
+    loop {
+        let mut field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => break,
+            Err(e) => return ApplicationError::ValidationError { message: e.body_text() }.into_response(),
+        };
         let name = field.name().unwrap_or_default().to_string();
-        
-        match name.as_str() {
+
+        let result = match name.as_str() {
             "file" => {
-                original_filename = field.file_name().unwrap_or("image.jpg").to_string();
-                mime_type = field.content_type().unwrap_or("image/jpeg").to_string();
-                image_bytes = field.bytes().await.unwrap_or_default().to_vec();
-            },
-            "alt_text" => {
-                alt_text = Some(field.text().await.unwrap_or_default());
+                original_filename = field.file_name().unwrap_or("image").to_string();
+                read_field_capped(&mut field, max_bytes, "Image").await.map(|bytes| image_bytes = bytes)
             },
-            "caption" => {
-                caption = Some(field.text().await.unwrap_or_default());
-            },
-            _ => {}
+            "alt_text" => read_text_field(&mut field, "alt_text").await.map(|text| alt_text = Some(text)),
+            "caption" => read_text_field(&mut field, "caption").await.map(|text| caption = Some(text)),
+            _ => Ok(()),
+        };
+        if let Err(message) = result {
+            return ApplicationError::ValidationError { message }.into_response();
         }
     }
 
     if image_bytes.is_empty() {
-        return crate::application::errors::ApplicationError::ValidationError { 
-            message: "No image file provided".to_string() 
+        return ApplicationError::ValidationError {
+            message: "No image file provided".to_string()
         }.into_response();
     }
 
     let input = CreateImageInput {
         profile_id,
         original_filename,
-        mime_type,
-        file_size: image_bytes.len() as i32,
-        width: None,
-        height: None,
         alt_text,
         caption,
         image_bytes,
+        on_duplicate,
     };
 
     let res: Result<crate::application::services::profile::image::result::ImageResult, crate::application::errors::ApplicationError> = state.profile.image.create.execute(input).await;
     res.into_response()
 }
 
+/// Where a chunk starts in the whole file, as in the tus protocol.
+const UPLOAD_OFFSET_HEADER: &str = "Upload-Offset";
+
+pub async fn create_image_upload_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    Json(payload): Json<CreateImageUploadRequest>,
+) -> impl IntoResponse {
+    let on_duplicate = match DuplicateImagePolicy::parse(payload.on_duplicate.as_deref()) {
+        Ok(policy) => policy,
+        Err(message) => return ApplicationError::ValidationError { message }.into_response(),
+    };
+
+    let input = CreateImageUploadInput {
+        profile_id,
+        original_filename: payload.filename,
+        total_size: payload.total_size,
+        alt_text: payload.alt_text,
+        caption: payload.caption,
+        on_duplicate,
+    };
+
+    state.profile.image.upload_create.execute(input).await.into_response()
+}
+
+pub async fn get_image_upload_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, upload_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let input = ImageUploadInput::new(profile_id, upload_id);
+
+    state.profile.image.upload_get.execute(input).await.into_response()
+}
+
+/// Appends the raw request body at the offset given in the `Upload-Offset` header.
+pub async fn append_image_upload_chunk_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, upload_id)): Path<(String, String)>,
+    headers: HeaderMap,
+    body: Body,
+) -> impl IntoResponse {
+    let offset = headers
+        .get(UPLOAD_OFFSET_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<i64>().ok());
+    let Some(offset) = offset else {
+        return ApplicationError::ValidationError {
+            message: format!("{} header with the chunk's byte offset is required", UPLOAD_OFFSET_HEADER),
+        }.into_response();
+    };
+
+    // No chunk can be bigger than a whole upload; the session narrows it down to what is left
+    let max_bytes = state.profile.image.create.max_upload_bytes();
+    let bytes = match to_bytes(body, max_bytes).await {
+        Ok(bytes) => bytes.to_vec(),
+        Err(_) => return ApplicationError::ValidationError {
+            message: format!("Chunk cannot be larger than {} bytes", max_bytes),
+        }.into_response(),
+    };
+
+    let input = AppendImageUploadChunkInput { profile_id, upload_id, offset, bytes };
+
+    state.profile.image.upload_append.execute(input).await.into_response()
+}
+
+pub async fn complete_image_upload_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, upload_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let input = ImageUploadInput::new(profile_id, upload_id);
+
+    state.profile.image.upload_complete.execute(input).await.into_response()
+}
+
+pub async fn cancel_image_upload_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, upload_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let input = ImageUploadInput::new(profile_id, upload_id);
+
+    state.profile.image.upload_cancel.execute(input).await.into_response()
+}
+
 pub async fn update_image_metadata_ctrl(
     State(state): State<AppState>,
     Path((profile_id, image_id)): Path<(String, String)>,
@@ -223,3 +402,144 @@ pub async fn untrack_image_usage_ctrl(
     let res: Result<crate::application::services::profile::image::result::MessageResult, crate::application::errors::ApplicationError> = state.profile.image.untrack_usage.execute(input).await;
     res.into_response()
 }
+
+pub async fn get_image_settings_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+) -> impl IntoResponse {
+    let input = GetImageSettingsInput { profile_id };
+
+    let res: Result<crate::application::services::profile::image::result::ImageSettingsResult, crate::application::errors::ApplicationError> = state.profile.image.get_settings.execute(input).await;
+    res.into_response()
+}
+
+pub async fn update_image_settings_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    Json(payload): Json<UpdateImageSettingsRequest>,
+) -> impl IntoResponse {
+    let input = UpdateImageSettingsInput {
+        profile_id,
+        keep_capture_metadata: payload.keep_capture_metadata,
+    };
+
+    let res: Result<crate::application::services::profile::image::result::ImageSettingsResult, crate::application::errors::ApplicationError> = state.profile.image.update_settings.execute(input).await;
+    res.into_response()
+}
+
+pub async fn get_duplicate_images_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+) -> impl IntoResponse {
+    let input = MergeDuplicateImagesInput { profile_id, dry_run: true };
+
+    let res: Result<crate::application::services::profile::image::result::DuplicateImagesResult, crate::application::errors::ApplicationError> = state.profile.image.merge_duplicates.execute(input).await;
+    res.into_response()
+}
+
+pub async fn merge_duplicate_images_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+) -> impl IntoResponse {
+    let input = MergeDuplicateImagesInput { profile_id, dry_run: false };
+
+    let res: Result<crate::application::services::profile::image::result::DuplicateImagesResult, crate::application::errors::ApplicationError> = state.profile.image.merge_duplicates.execute(input).await;
+    res.into_response()
+}
+
+pub async fn backfill_image_placeholders_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    Query(query): Query<BackfillPlaceholdersQuery>,
+) -> impl IntoResponse {
+    let input = BackfillImagePlaceholdersInput { profile_id, limit: query.limit };
+
+    let res: Result<crate::application::services::profile::image::result::ImagePlaceholderBackfillResult, crate::application::errors::ApplicationError> = state.profile.image.backfill_placeholders.execute(input).await;
+    res.into_response()
+}
+
+pub async fn get_image_tags_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+) -> impl IntoResponse {
+    let input = ListImageTagsInput { profile_id };
+
+    let res: Result<crate::application::services::profile::image::result::ImageTagListResult, crate::application::errors::ApplicationError> = state.profile.image.list_tags.execute(input).await;
+    res.into_response()
+}
+
+pub async fn create_image_tag_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    Json(payload): Json<ImageTagRequest>,
+) -> impl IntoResponse {
+    let input = CreateImageTagInput {
+        profile_id,
+        name: payload.name,
+        color: payload.color,
+    };
+
+    let res: Result<crate::application::services::profile::image::result::ImageTagResult, crate::application::errors::ApplicationError> = state.profile.image.create_tag.execute(input).await;
+    res.into_response()
+}
+
+pub async fn update_image_tag_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, tag_id)): Path<(String, String)>,
+    Json(payload): Json<ImageTagRequest>,
+) -> impl IntoResponse {
+    let input = UpdateImageTagInput {
+        id: tag_id,
+        profile_id,
+        name: payload.name,
+        color: payload.color,
+    };
+
+    let res: Result<crate::application::services::profile::image::result::ImageTagResult, crate::application::errors::ApplicationError> = state.profile.image.update_tag.execute(input).await;
+    res.into_response()
+}
+
+pub async fn delete_image_tag_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, tag_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let input = DeleteImageTagInput { id: tag_id, profile_id };
+
+    let res: Result<crate::application::services::profile::image::result::MessageResult, crate::application::errors::ApplicationError> = state.profile.image.delete_tag.execute(input).await;
+    res.into_response()
+}
+
+pub async fn set_image_tags_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, image_id)): Path<(String, String)>,
+    Json(payload): Json<SetImageTagsRequest>,
+) -> impl IntoResponse {
+    let input = SetImageTagsInput {
+        profile_id,
+        image_id,
+        tags: payload.tags,
+    };
+
+    let res: Result<crate::application::services::profile::image::result::ImageTagsResult, crate::application::errors::ApplicationError> = state.profile.image.set_tags.execute(input).await;
+    res.into_response()
+}
+
+pub async fn transform_image_ctrl(
+    State(state): State<AppState>,
+    Path(image_id): Path<String>,
+    Query(query): Query<TransformImageQuery>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let input = TransformImageInput {
+        image_id,
+        width: query.w,
+        height: query.h,
+        fit: query.fit,
+        format: query.format,
+        quality: query.q,
+        if_none_match: headers.get(header::IF_NONE_MATCH).and_then(|v| v.to_str().ok()).map(str::to_string),
+    };
+
+    let res: Result<crate::application::services::profile::image::result::TransformedImageResult, crate::application::errors::ApplicationError> = state.profile.image.transform.execute(input).await;
+    res.into_response()
+}
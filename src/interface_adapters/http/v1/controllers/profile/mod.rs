@@ -2,4 +2,11 @@ pub mod life_status;
 pub mod announce;
 pub mod image;
 pub mod performance;
+pub mod performance_category;
+pub mod visibility;
+pub mod tag;
+pub mod share_link;
+pub mod archive;
+pub mod album;
+pub mod storage;
 pub mod controller;
@@ -1,15 +1,21 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State, Multipart},
+    http::{header, HeaderMap},
     response::IntoResponse,
     Json,
 };
 use serde::Deserialize;
 use crate::application::use_cases::profile::performance::dto::input::{
-    CreatePerformanceInput, UpdatePerformanceInput, DeletePerformanceInput,
+    CreatePerformanceInput, UpdatePerformanceInput, DeletePerformanceInput, DuplicatePerformanceInput,
+    ReorderPerformancesInput, ImportPerformancesInput,
     GetPerformanceContentInput, UpdatePerformanceContentInput, ListPerformancesInput,
-    GetPerformanceImagesInput
+    GetPerformanceImagesInput, RenderPerformanceContentInput, GetPublicPerformanceInput,
+    GetPerformanceFeedInput
 };
+use crate::domain::entities::profile::visibility::visibility::VISIBILITY_PUBLIC;
 use crate::application::use_cases::use_case::UseCase;
+use crate::application::errors::ApplicationError;
+use crate::shared::utils::feed::FeedFormat;
 use crate::delivery::http::server::state::AppState;
 
 #[derive(Deserialize)]
@@ -21,6 +27,8 @@ pub struct CreatePerformanceRequest {
     pub start_date: Option<String>,
     pub end_date: Option<String>,
     pub location: Option<String>,
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 #[derive(Deserialize)]
@@ -33,6 +41,55 @@ pub struct UpdatePerformanceRequest {
     pub end_date: Option<String>,
     pub location: Option<String>,
     pub close: bool,
+    pub pinned: Option<bool>,
+}
+
+#[derive(Deserialize)]
+pub struct ReorderPerformancesRequest {
+    pub performance_ids: Vec<String>,
+}
+
+/// Password of a protected share link; sent as a header so it stays out of URLs and access logs
+const SHARE_PASSWORD_HEADER: &str = "x-share-password";
+
+#[derive(Deserialize)]
+pub struct PublicPerformanceQuery {
+    pub format: Option<String>,
+    /// Share link token
+    pub share: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ShareQuery {
+    /// Share link token
+    pub share: Option<String>,
+}
+
+fn share_password(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(SHARE_PASSWORD_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+#[derive(Deserialize)]
+pub struct PerformancesQuery {
+    pub group_by: Option<String>,
+    /// Comma-separated tag slugs, e.g. `?tag=rust,axum`
+    pub tag: Option<String>,
+}
+
+impl PerformancesQuery {
+    fn tags(&self) -> Vec<String> {
+        self.tag
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
 }
 
 #[derive(Deserialize)]
@@ -54,6 +111,7 @@ pub async fn create_performance_ctrl(
         start_date: payload.start_date,
         end_date: payload.end_date,
         location: payload.location,
+        pinned: payload.pinned,
     };
 
     state.profile.performance.create.execute(input).await.into_response()
@@ -75,6 +133,7 @@ pub async fn update_performance_ctrl(
         end_date: payload.end_date,
         location: payload.location,
         close: payload.close,
+        pinned: payload.pinned,
     };
 
     state.profile.performance.update.execute(input).await.into_response()
@@ -92,6 +151,69 @@ pub async fn delete_performance_ctrl(
     state.profile.performance.delete.execute(input).await.into_response()
 }
 
+pub async fn import_performances_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    let mut archive = Vec::new();
+    let mut category_id = None;
+    let mut visibility_id = None;
+
+    loop {
+        let field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => break,
+            Err(e) => return ApplicationError::ValidationError { message: e.body_text() }.into_response(),
+        };
+        let name = field.name().unwrap_or_default().to_string();
+
+        let result = match name.as_str() {
+            "file" => field.bytes().await.map(|bytes| archive = bytes.to_vec()),
+            "category_id" => field.text().await.map(|text| category_id = Some(text).filter(|t| !t.trim().is_empty())),
+            "visibility_id" => field.text().await.map(|text| visibility_id = Some(text).filter(|t| !t.trim().is_empty())),
+            _ => Ok(()),
+        };
+        if let Err(e) = result {
+            return ApplicationError::ValidationError { message: e.body_text() }.into_response();
+        }
+    }
+
+    let input = ImportPerformancesInput {
+        profile_id,
+        archive,
+        category_id,
+        visibility_id,
+    };
+
+    state.profile.performance.import.execute(input).await.into_response()
+}
+
+pub async fn reorder_performances_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    Json(payload): Json<ReorderPerformancesRequest>,
+) -> impl IntoResponse {
+    let input = ReorderPerformancesInput {
+        profile_id,
+        performance_ids: payload.performance_ids,
+    };
+
+    state.profile.performance.reorder.execute(input).await.into_response()
+}
+
+pub async fn duplicate_performance_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, performance_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let input = DuplicatePerformanceInput {
+        id: performance_id,
+        profile_id,
+    };
+
+    state.profile.performance.duplicate.execute(input).await.into_response()
+}
+
 pub async fn get_performance_content_ctrl(
     State(state): State<AppState>,
     Path((profile_id, performance_id)): Path<(String, String)>,
@@ -121,10 +243,13 @@ pub async fn update_performance_content_ctrl(
 pub async fn get_performances_ctrl(
     State(state): State<AppState>,
     Path(profile_id): Path<String>,
+    Query(query): Query<PerformancesQuery>,
 ) -> impl IntoResponse {
     let input = ListPerformancesInput {
         profile_id,
         visibility_id: None,
+        tags: query.tags(),
+        group_by: query.group_by,
     };
 
     state.profile.performance.list.execute(input).await.into_response()
@@ -145,11 +270,89 @@ pub async fn get_performance_images_ctrl(
 pub async fn get_public_performances_ctrl(
     State(state): State<AppState>,
     Path(profile_id): Path<String>,
+    Query(query): Query<PerformancesQuery>,
 ) -> impl IntoResponse {
     let input = ListPerformancesInput {
         profile_id,
-        visibility_id: Some("visibility_public".to_string()),
+        visibility_id: Some(VISIBILITY_PUBLIC.to_string()),
+        tags: query.tags(),
+        group_by: query.group_by,
     };
 
     state.profile.performance.list.execute(input).await.into_response()
 }
+
+pub async fn get_public_performance_rendered_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, performance_id)): Path<(String, String)>,
+    Query(query): Query<ShareQuery>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let input = RenderPerformanceContentInput {
+        performance_id,
+        profile_id,
+        share_token: query.share,
+        share_password: share_password(&headers),
+    };
+
+    state.profile.performance.render_content.execute(input).await.into_response()
+}
+
+pub async fn get_public_performance_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, slug_or_id)): Path<(String, String)>,
+    Query(query): Query<PublicPerformanceQuery>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let input = GetPublicPerformanceInput {
+        profile_id,
+        slug_or_id,
+        format: query.format,
+        share_token: query.share,
+        share_password: share_password(&headers),
+    };
+
+    state.profile.performance.get_public.execute(input).await.into_response()
+}
+
+async fn performance_feed(
+    state: AppState,
+    profile_id: String,
+    headers: HeaderMap,
+    format: FeedFormat,
+) -> axum::response::Response {
+    let header_value = |name| headers.get(name).and_then(|v| v.to_str().ok()).map(str::to_string);
+
+    let input = GetPerformanceFeedInput {
+        profile_id,
+        format,
+        if_none_match: header_value(header::IF_NONE_MATCH),
+        if_modified_since: header_value(header::IF_MODIFIED_SINCE),
+    };
+
+    state.profile.performance.feed.execute(input).await.into_response()
+}
+
+pub async fn get_performance_atom_feed_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    performance_feed(state, profile_id, headers, FeedFormat::Atom).await
+}
+
+pub async fn get_performance_rss_feed_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    performance_feed(state, profile_id, headers, FeedFormat::Rss).await
+}
+
+pub async fn get_performance_json_feed_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    performance_feed(state, profile_id, headers, FeedFormat::Json).await
+}
@@ -0,0 +1,82 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use crate::application::use_cases::profile::performance_category::dto::input::{
+    ListPerformanceCategoriesInput, CreatePerformanceCategoryInput,
+    UpdatePerformanceCategoryInput, DeletePerformanceCategoryInput
+};
+use crate::application::use_cases::use_case::UseCase;
+use crate::delivery::http::server::state::AppState;
+
+#[derive(Deserialize)]
+pub struct CreatePerformanceCategoryRequest {
+    pub name: String,
+    pub icon: Option<String>,
+    pub color: Option<String>,
+    pub sort_order: Option<i32>,
+}
+
+#[derive(Deserialize)]
+pub struct UpdatePerformanceCategoryRequest {
+    pub name: String,
+    pub icon: Option<String>,
+    pub color: Option<String>,
+    pub sort_order: i32,
+}
+
+pub async fn get_performance_categories_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+) -> impl IntoResponse {
+    let input = ListPerformanceCategoriesInput { profile_id };
+
+    state.profile.performance_category.list.execute(input).await.into_response()
+}
+
+pub async fn create_performance_category_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    Json(payload): Json<CreatePerformanceCategoryRequest>,
+) -> impl IntoResponse {
+    let input = CreatePerformanceCategoryInput {
+        profile_id,
+        name: payload.name,
+        icon: payload.icon,
+        color: payload.color,
+        sort_order: payload.sort_order,
+    };
+
+    state.profile.performance_category.create.execute(input).await.into_response()
+}
+
+pub async fn update_performance_category_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, category_id)): Path<(String, String)>,
+    Json(payload): Json<UpdatePerformanceCategoryRequest>,
+) -> impl IntoResponse {
+    let input = UpdatePerformanceCategoryInput {
+        id: category_id,
+        profile_id,
+        name: payload.name,
+        icon: payload.icon,
+        color: payload.color,
+        sort_order: payload.sort_order,
+    };
+
+    state.profile.performance_category.update.execute(input).await.into_response()
+}
+
+pub async fn delete_performance_category_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, category_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let input = DeletePerformanceCategoryInput {
+        id: category_id,
+        profile_id,
+    };
+
+    state.profile.performance_category.delete.execute(input).await.into_response()
+}
@@ -0,0 +1,59 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use crate::application::use_cases::profile::share_link::dto::input::{
+    CreateShareLinkInput, ListShareLinksInput, RevokeShareLinkInput
+};
+use crate::application::use_cases::use_case::UseCase;
+use crate::delivery::http::server::state::AppState;
+
+#[derive(Deserialize)]
+pub struct CreateShareLinkRequest {
+    pub label: Option<String>,
+    pub password: Option<String>,
+    pub expires_in_hours: Option<i64>,
+}
+
+pub async fn create_share_link_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, performance_id)): Path<(String, String)>,
+    Json(payload): Json<CreateShareLinkRequest>,
+) -> impl IntoResponse {
+    let input = CreateShareLinkInput {
+        profile_id,
+        performance_id,
+        label: payload.label,
+        password: payload.password,
+        expires_in_hours: payload.expires_in_hours,
+    };
+
+    state.profile.share_link.create.execute(input).await.into_response()
+}
+
+pub async fn get_share_links_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, performance_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let input = ListShareLinksInput {
+        profile_id,
+        performance_id,
+    };
+
+    state.profile.share_link.list.execute(input).await.into_response()
+}
+
+pub async fn revoke_share_link_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, performance_id, share_link_id)): Path<(String, String, String)>,
+) -> impl IntoResponse {
+    let input = RevokeShareLinkInput {
+        id: share_link_id,
+        profile_id,
+        performance_id,
+    };
+
+    state.profile.share_link.revoke.execute(input).await.into_response()
+}
@@ -0,0 +1,25 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use crate::application::use_cases::profile::storage::dto::input::ReconcileStorageInput;
+use crate::application::use_cases::use_case::UseCase;
+use crate::delivery::http::server::state::AppState;
+
+pub async fn get_storage_reconciliation_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+) -> impl IntoResponse {
+    let input = ReconcileStorageInput { profile_id, dry_run: true };
+
+    state.profile.storage.reconcile.execute(input).await.into_response()
+}
+
+pub async fn apply_storage_reconciliation_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+) -> impl IntoResponse {
+    let input = ReconcileStorageInput { profile_id, dry_run: false };
+
+    state.profile.storage.reconcile.execute(input).await.into_response()
+}
@@ -0,0 +1,105 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use crate::application::use_cases::profile::tag::dto::input::{
+    ListTagsInput, CreateTagInput, UpdateTagInput, DeleteTagInput,
+    SetPerformanceTagsInput, GetTagPerformancesInput
+};
+use crate::application::use_cases::use_case::UseCase;
+use crate::delivery::http::server::state::AppState;
+
+#[derive(Deserialize)]
+pub struct TagsQuery {
+    /// Autocomplete prefix
+    pub q: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct TagRequest {
+    pub name: String,
+}
+
+#[derive(Deserialize)]
+pub struct SetPerformanceTagsRequest {
+    pub tags: Vec<String>,
+}
+
+pub async fn get_tags_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    Query(query): Query<TagsQuery>,
+) -> impl IntoResponse {
+    let input = ListTagsInput {
+        profile_id,
+        prefix: query.q,
+        limit: query.limit,
+    };
+
+    state.profile.tag.list.execute(input).await.into_response()
+}
+
+pub async fn create_tag_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    Json(payload): Json<TagRequest>,
+) -> impl IntoResponse {
+    let input = CreateTagInput {
+        profile_id,
+        name: payload.name,
+    };
+
+    state.profile.tag.create.execute(input).await.into_response()
+}
+
+pub async fn update_tag_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, tag_id)): Path<(String, String)>,
+    Json(payload): Json<TagRequest>,
+) -> impl IntoResponse {
+    let input = UpdateTagInput {
+        id: tag_id,
+        profile_id,
+        name: payload.name,
+    };
+
+    state.profile.tag.update.execute(input).await.into_response()
+}
+
+pub async fn delete_tag_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, tag_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let input = DeleteTagInput {
+        id: tag_id,
+        profile_id,
+    };
+
+    state.profile.tag.delete.execute(input).await.into_response()
+}
+
+pub async fn set_performance_tags_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, performance_id)): Path<(String, String)>,
+    Json(payload): Json<SetPerformanceTagsRequest>,
+) -> impl IntoResponse {
+    let input = SetPerformanceTagsInput {
+        profile_id,
+        performance_id,
+        tags: payload.tags,
+    };
+
+    state.profile.tag.set_performance_tags.execute(input).await.into_response()
+}
+
+pub async fn get_tag_performances_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, tag)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let input = GetTagPerformancesInput { profile_id, tag };
+
+    state.profile.tag.get_performances.execute(input).await.into_response()
+}
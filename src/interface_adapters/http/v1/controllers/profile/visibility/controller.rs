@@ -0,0 +1,37 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use crate::application::use_cases::profile::visibility::dto::input::{ListVisibilitiesInput, UpdateVisibilityInput};
+use crate::application::use_cases::use_case::UseCase;
+use crate::delivery::http::server::state::AppState;
+
+#[derive(Deserialize)]
+pub struct UpdateVisibilityRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub sort_order: i32,
+}
+
+pub async fn get_visibilities_ctrl(
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    state.profile.visibility.list.execute(ListVisibilitiesInput).await.into_response()
+}
+
+pub async fn update_visibility_ctrl(
+    State(state): State<AppState>,
+    Path(visibility_id): Path<String>,
+    Json(payload): Json<UpdateVisibilityRequest>,
+) -> impl IntoResponse {
+    let input = UpdateVisibilityInput {
+        id: visibility_id,
+        name: payload.name,
+        description: payload.description,
+        sort_order: payload.sort_order,
+    };
+
+    state.profile.visibility.update.execute(input).await.into_response()
+}
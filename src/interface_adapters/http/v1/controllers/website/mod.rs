@@ -1,2 +1,3 @@
 pub mod feature_status;
 pub mod config;
+pub mod seo;
@@ -0,0 +1,120 @@
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap};
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+use crate::application::errors::ApplicationError;
+use crate::application::use_cases::application::seo::dto::input::{
+    GetPerformanceMetadataInput, GetPerformanceOgCardInput, GetProfileMetadataInput, GetRobotsTxtInput, GetSitemapInput, PageMetadataFormat
+};
+use crate::application::use_cases::use_case::UseCase;
+use crate::delivery::http::server::state::AppState;
+use crate::interface_adapters::http::v1::presenters::website::feature_status::presenter::WebsitePresenter;
+
+async fn sitemap(state: AppState, page: Option<usize>) -> Response {
+    let input = GetSitemapInput::new(page);
+
+    match state
+        .website
+        .seo
+        .get_sitemap
+        .execute(input)
+        .await
+    {
+        Ok(result) => WebsitePresenter::success(result).into_response(),
+        Err(error) => WebsitePresenter::error(error).into_response(),
+    }
+}
+
+pub async fn get_sitemap_ctrl(State(state): State<AppState>) -> Response {
+    sitemap(state, None).await
+}
+
+pub async fn get_sitemap_page_ctrl(
+    State(state): State<AppState>,
+    Path(page): Path<usize>,
+) -> Response {
+    sitemap(state, Some(page)).await
+}
+
+async fn robots_txt(state: AppState, app_id: Option<String>) -> Response {
+    let input = GetRobotsTxtInput::new(app_id);
+
+    match state
+        .website
+        .seo
+        .get_robots_txt
+        .execute(input)
+        .await
+    {
+        Ok(result) => WebsitePresenter::success(result).into_response(),
+        Err(error) => WebsitePresenter::error(error).into_response(),
+    }
+}
+
+pub async fn get_robots_txt_ctrl(State(state): State<AppState>) -> Response {
+    robots_txt(state, None).await
+}
+
+pub async fn get_app_robots_txt_ctrl(
+    State(state): State<AppState>,
+    Path(app_id): Path<String>,
+) -> Response {
+    robots_txt(state, Some(app_id)).await
+}
+
+#[derive(Deserialize)]
+pub struct PageMetadataQuery {
+    /// `json` (default) or `html`
+    pub format: Option<String>,
+}
+
+pub async fn get_profile_metadata_ctrl(
+    State(state): State<AppState>,
+    Path(profile_id): Path<String>,
+    Query(query): Query<PageMetadataQuery>,
+) -> Response {
+    let format = match PageMetadataFormat::parse(query.format.as_deref()) {
+        Ok(format) => format,
+        Err(message) => return WebsitePresenter::error(ApplicationError::ValidationError { message }).into_response(),
+    };
+    let input = GetProfileMetadataInput { profile_id, format };
+
+    match state.website.seo.get_profile_metadata.execute(input).await {
+        Ok(result) => WebsitePresenter::success(result).into_response(),
+        Err(error) => WebsitePresenter::error(error).into_response(),
+    }
+}
+
+pub async fn get_performance_metadata_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, slug_or_id)): Path<(String, String)>,
+    Query(query): Query<PageMetadataQuery>,
+) -> Response {
+    let format = match PageMetadataFormat::parse(query.format.as_deref()) {
+        Ok(format) => format,
+        Err(message) => return WebsitePresenter::error(ApplicationError::ValidationError { message }).into_response(),
+    };
+    let input = GetPerformanceMetadataInput { profile_id, slug_or_id, format };
+
+    match state.website.seo.get_performance_metadata.execute(input).await {
+        Ok(result) => WebsitePresenter::success(result).into_response(),
+        Err(error) => WebsitePresenter::error(error).into_response(),
+    }
+}
+
+pub async fn get_performance_og_card_ctrl(
+    State(state): State<AppState>,
+    Path((profile_id, slug_or_id)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> Response {
+    let input = GetPerformanceOgCardInput {
+        profile_id,
+        slug_or_id,
+        if_none_match: headers.get(header::IF_NONE_MATCH).and_then(|v| v.to_str().ok()).map(str::to_string),
+    };
+
+    match state.website.seo.get_performance_og_card.execute(input).await {
+        Ok(result) => WebsitePresenter::success(result).into_response(),
+        Err(error) => WebsitePresenter::error(error).into_response(),
+    }
+}
@@ -11,6 +11,9 @@ pub struct ApiResponse<T> {
 pub struct ApiError {
     pub code: String,
     pub message: String,
+    /// Machine-readable context, e.g. the id of the record a conflict refers to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
 }
 
 impl<T> ApiResponse<T> {
@@ -29,6 +32,19 @@ impl<T> ApiResponse<T> {
             error: Some(ApiError {
                 code: code.to_string(),
                 message: message.to_string(),
+                details: None,
+            }),
+        }
+    }
+
+    pub fn error_with_details(code: &str, message: &str, details: serde_json::Value) -> Self {
+        Self {
+            success: false,
+            data: None,
+            error: Some(ApiError {
+                code: code.to_string(),
+                message: message.to_string(),
+                details: Some(details),
             }),
         }
     }
@@ -24,6 +24,15 @@ impl ErrorPresenter {
             ApplicationError::Conflict { message } => {
                 (StatusCode::CONFLICT, "CONFLICT", message)
             }
+            ApplicationError::AlreadyExists { resource, existing_id } => {
+                let message = format!("{} already exists: {}", resource, existing_id);
+                let response: ApiResponse<()> = ApiResponse::error_with_details(
+                    "ALREADY_EXISTS",
+                    &message,
+                    serde_json::json!({ "existing_id": existing_id }),
+                );
+                return (StatusCode::CONFLICT, Json(response));
+            }
             ApplicationError::Internal { message } => {
                 (StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL_ERROR", message)
             }
@@ -0,0 +1,375 @@
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use serde::Serialize;
+use crate::shared::utils::markdown_renderer::{markdown_options, AnchorIds};
+
+/// Maximum length, in characters, of `Performance::content_preview`.
+pub const PREVIEW_MAX_CHARS: usize = 500;
+
+/// Storage folder our uploaded images live in:
+/// `.../performance_image/{profile_id}/{image_id}.{ext}`
+const IMAGE_FOLDER: &str = "performance_image";
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ContentLink {
+    pub url: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ContentHeading {
+    pub level: u8,
+    /// Same anchor id the renderer assigns to this heading.
+    pub id: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ContentAnalysis {
+    /// Ids of our own uploaded images, one entry per reference (duplicates are kept for usage counts).
+    pub image_ids: Vec<String>,
+    pub links: Vec<ContentLink>,
+    pub headings: Vec<ContentHeading>,
+    /// Readable text with all markup removed, one block per line.
+    pub plain_text: String,
+}
+
+/// Walks the markdown AST once and collects everything the rest of the app needs to know about a document.
+/// Inline, reference-style and raw HTML `<img>` images are all recognised.
+pub fn analyze_markdown(content: &str) -> ContentAnalysis {
+    let mut analysis = ContentAnalysis::default();
+    let mut anchors = AnchorIds::default();
+
+    let mut text = String::with_capacity(content.len());
+    let mut html_buffer = String::new();
+    let mut image_depth = 0usize;
+    let mut open_link: Option<(String, String)> = None;
+    let mut open_heading: Option<(u8, String)> = None;
+
+    for event in Parser::new_ext(content, markdown_options()) {
+        // Raw HTML arrives line by line; scan it as a whole once the HTML run ends
+        if !matches!(event, Event::Html(_) | Event::InlineHtml(_)) && !html_buffer.is_empty() {
+            collect_html_images(&html_buffer, &mut analysis.image_ids);
+            html_buffer.clear();
+        }
+
+        match event {
+            Event::Html(html) | Event::InlineHtml(html) => html_buffer.push_str(&html),
+            Event::Start(Tag::Image { dest_url, .. }) => {
+                image_depth += 1;
+                if let Some(id) = own_image_id(&dest_url) {
+                    analysis.image_ids.push(id);
+                }
+            }
+            Event::End(TagEnd::Image) => image_depth = image_depth.saturating_sub(1),
+            // Alt text is not part of the readable text
+            _ if image_depth > 0 => {}
+            Event::Start(Tag::Link { dest_url, .. }) => {
+                open_link = Some((dest_url.to_string(), String::new()));
+            }
+            Event::End(TagEnd::Link) => {
+                if let Some((url, link_text)) = open_link.take() {
+                    analysis.links.push(ContentLink { url, text: link_text.trim().to_string() });
+                }
+            }
+            Event::Start(Tag::Heading { level, .. }) => {
+                open_heading = Some((level as u8, String::new()));
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((level, heading_text)) = open_heading.take() {
+                    analysis.headings.push(ContentHeading {
+                        level,
+                        id: anchors.next(&heading_text),
+                        text: heading_text.trim().to_string(),
+                    });
+                }
+                end_block(&mut text);
+            }
+            Event::Text(value) | Event::Code(value) => {
+                text.push_str(&value);
+                if let Some((_, link_text)) = open_link.as_mut() {
+                    link_text.push_str(&value);
+                }
+                if let Some((_, heading_text)) = open_heading.as_mut() {
+                    heading_text.push_str(&value);
+                }
+            }
+            Event::SoftBreak | Event::HardBreak => text.push(' '),
+            Event::TaskListMarker(_) | Event::Rule | Event::FootnoteReference(_) => {}
+            Event::End(TagEnd::TableCell) => text.push(' '),
+            Event::End(
+                TagEnd::Paragraph
+                | TagEnd::Item
+                | TagEnd::CodeBlock
+                | TagEnd::TableRow
+                | TagEnd::TableHead
+                | TagEnd::BlockQuote(_),
+            ) => end_block(&mut text),
+            _ => {}
+        }
+    }
+
+    if !html_buffer.is_empty() {
+        collect_html_images(&html_buffer, &mut analysis.image_ids);
+    }
+
+    analysis.plain_text = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    analysis
+}
+
+fn end_block(text: &mut String) {
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+}
+
+/// Returns the image id when `url` points at one of our own uploads, `None` for anything else.
+/// The id is the UUID file stem directly under `performance_image/{profile_id}/`.
+pub fn own_image_id(url: &str) -> Option<String> {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let segments: Vec<&str> = path.split('/').collect();
+
+    let folder_idx = segments.iter().rposition(|s| *s == IMAGE_FOLDER)?;
+    let [profile_id, filename] = segments.get(folder_idx + 1..)? else {
+        return None;
+    };
+    if profile_id.is_empty() {
+        return None;
+    }
+
+    let (stem, extension) = filename.rsplit_once('.')?;
+    if extension.is_empty() {
+        return None;
+    }
+
+    uuid::Uuid::parse_str(stem).ok()?;
+    Some(stem.to_string())
+}
+
+/// Finds `<img ... src="...">` tags in a raw HTML fragment and records the ones that point at our storage.
+fn collect_html_images(html: &str, image_ids: &mut Vec<String>) {
+    let lower = html.to_ascii_lowercase();
+    let mut cursor = 0;
+
+    while let Some(found) = lower[cursor..].find("<img") {
+        let tag_start = cursor + found + "<img".len();
+        let tag_end = lower[tag_start..].find('>').map_or(html.len(), |i| tag_start + i);
+
+        if let Some(id) = attribute_value(&html[tag_start..tag_end], "src").and_then(own_image_id) {
+            image_ids.push(id);
+        }
+        cursor = tag_end;
+    }
+}
+
+/// Reads one attribute value out of the inside of an HTML tag, handling quoted and unquoted forms.
+pub(crate) fn attribute_value<'a>(tag_body: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = tag_body;
+
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+        if rest.is_empty() {
+            return None;
+        }
+
+        let name_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        let attr_name = &rest[..name_end];
+        rest = rest[name_end..].trim_start();
+
+        let value = if let Some(after_eq) = rest.strip_prefix('=') {
+            let after_eq = after_eq.trim_start();
+            match after_eq.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let body = &after_eq[1..];
+                    let end = body.find(quote).unwrap_or(body.len());
+                    rest = body.get(end + 1..).unwrap_or_default();
+                    &body[..end]
+                }
+                _ => {
+                    let end = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                    rest = &after_eq[end..];
+                    &after_eq[..end]
+                }
+            }
+        } else {
+            ""
+        };
+
+        if attr_name.eq_ignore_ascii_case(name) {
+            return Some(value);
+        }
+    }
+}
+
+/// Builds a single-line excerpt of at most `max_chars` characters.
+/// Counts characters rather than bytes so multi-byte text (e.g. Thai) is never split mid-character,
+/// and prefers to break at a word boundary when one is close to the limit.
+pub fn excerpt(text: &str, max_chars: usize) -> String {
+    let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if normalized.chars().count() <= max_chars {
+        return normalized;
+    }
+    if max_chars == 0 {
+        return String::new();
+    }
+
+    // Leave room for the ellipsis
+    let budget = max_chars - 1;
+    let cut = normalized
+        .char_indices()
+        .nth(budget)
+        .map_or(normalized.len(), |(idx, _)| idx);
+    let mut head = &normalized[..cut];
+
+    if let Some(space) = head.rfind(' ') {
+        // Only back off to the word boundary if that keeps most of the budget
+        if head[..space].chars().count() >= budget * 4 / 5 {
+            head = &head[..space];
+        }
+    }
+
+    format!("{}…", head.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IMG_A: &str = "https://storage.googleapis.com/personal-website_storage/performance_image/profile_001/4c8e1e80-5970-475a-b170-6fabffaa4a4c.jpg";
+    const IMG_B: &str = "https://storage.googleapis.com/personal-website_storage/performance_image/profile_001/0f1d5c2e-8a6b-4d7e-9c3a-2b1e4f6a8d90.png";
+    const ID_A: &str = "4c8e1e80-5970-475a-b170-6fabffaa4a4c";
+    const ID_B: &str = "0f1d5c2e-8a6b-4d7e-9c3a-2b1e4f6a8d90";
+
+    #[test]
+    fn test_inline_image_from_own_storage_is_extracted() {
+        let analysis = analyze_markdown(&format!("![image]({})", IMG_A));
+        assert_eq!(analysis.image_ids, vec![ID_A]);
+    }
+
+    #[test]
+    fn test_foreign_images_are_ignored() {
+        let content = format!(
+            "![ours]({}) some text ![another](https://example.com/images/img2.png)",
+            IMG_A
+        );
+        let analysis = analyze_markdown(&content);
+        assert_eq!(analysis.image_ids, vec![ID_A]);
+    }
+
+    #[test]
+    fn test_non_uuid_file_in_image_folder_is_ignored() {
+        let analysis = analyze_markdown("![x](https://cdn.example.com/performance_image/p1/banner.png)");
+        assert!(analysis.image_ids.is_empty());
+    }
+
+    #[test]
+    fn test_reference_style_image_is_extracted() {
+        let content = format!("Intro\n\n![diagram][arch]\n\n[arch]: {}\n", IMG_B);
+        let analysis = analyze_markdown(&content);
+        assert_eq!(analysis.image_ids, vec![ID_B]);
+    }
+
+    #[test]
+    fn test_html_img_tags_are_extracted() {
+        let content = format!(
+            "<div align=\"center\">\n  <img width=\"300\"\n       src=\"{}\" alt=\"a\">\n</div>\n\nText <img src='{}'> inline",
+            IMG_A, IMG_B
+        );
+        let analysis = analyze_markdown(&content);
+        assert_eq!(analysis.image_ids, vec![ID_A, ID_B]);
+    }
+
+    #[test]
+    fn test_repeated_image_is_reported_once_per_reference() {
+        let content = format!("![a]({0})\n\n![b]({0}?v=2)", IMG_A);
+        let analysis = analyze_markdown(&content);
+        assert_eq!(analysis.image_ids, vec![ID_A, ID_A]);
+    }
+
+    #[test]
+    fn test_images_inside_code_are_not_extracted() {
+        let content = format!("`![a]({0})`\n\n```md\n![b]({0})\n```", IMG_A);
+        let analysis = analyze_markdown(&content);
+        assert!(analysis.image_ids.is_empty());
+    }
+
+    #[test]
+    fn test_own_image_id_rejects_malformed_urls() {
+        assert_eq!(own_image_id(IMG_A).as_deref(), Some(ID_A));
+        assert_eq!(own_image_id(&format!("{}#frag", IMG_B)).as_deref(), Some(ID_B));
+        assert_eq!(own_image_id("performance_image/4c8e1e80-5970-475a-b170-6fabffaa4a4c.jpg"), None);
+        assert_eq!(own_image_id("https://x/performance_image/p1/4c8e1e80-5970-475a-b170-6fabffaa4a4c"), None);
+        assert_eq!(own_image_id("https://x/performance_image/p1/extra/4c8e1e80-5970-475a-b170-6fabffaa4a4c.jpg"), None);
+    }
+
+    #[test]
+    fn test_links_are_collected_with_their_text() {
+        let analysis = analyze_markdown("See [the **docs**](https://example.com/docs) and <https://rust-lang.org>.");
+        assert_eq!(
+            analysis.links,
+            vec![
+                ContentLink { url: "https://example.com/docs".to_string(), text: "the docs".to_string() },
+                ContentLink { url: "https://rust-lang.org".to_string(), text: "https://rust-lang.org".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_headings_match_renderer_anchor_ids() {
+        let analysis = analyze_markdown("# Overview\n\n## Tech `stack`\n\n## Overview\n");
+        let ids: Vec<&str> = analysis.headings.iter().map(|h| h.id.as_str()).collect();
+        assert_eq!(ids, vec!["overview", "tech-stack", "overview-1"]);
+        assert_eq!(analysis.headings[1].level, 2);
+        assert_eq!(analysis.headings[1].text, "Tech stack");
+    }
+
+    #[test]
+    fn test_plain_text_strips_markup_but_keeps_identifiers() {
+        let content = format!(
+            "# Title\n\nUse **bold**, _emphasis_ and `snake_case_name`.\n\n![alt text]({})\n\n- [link](https://example.com)\n- item two",
+            IMG_A
+        );
+        let analysis = analyze_markdown(&content);
+        assert_eq!(
+            analysis.plain_text,
+            "Title\nUse bold, emphasis and snake_case_name.\nlink\nitem two"
+        );
+    }
+
+    #[test]
+    fn test_plain_text_skips_raw_html() {
+        let analysis = analyze_markdown("<div class=\"note\">\n\nHello\n\n</div>");
+        assert_eq!(analysis.plain_text, "Hello");
+    }
+
+    #[test]
+    fn test_excerpt_leaves_short_text_untouched() {
+        assert_eq!(excerpt("short\ntext", 500), "short text");
+    }
+
+    #[test]
+    fn test_excerpt_breaks_at_word_boundary() {
+        let text = "alpha beta gamma delta epsilon";
+        assert_eq!(excerpt(text, 20), "alpha beta gamma…");
+    }
+
+    #[test]
+    fn test_excerpt_is_utf8_safe_for_thai() {
+        let thai = "ภาษาไทยไม่มีการเว้นวรรคระหว่างคำ".repeat(40);
+        let result = excerpt(&thai, 500);
+        assert_eq!(result.chars().count(), 500);
+        assert!(result.ends_with('…'));
+    }
+
+    #[test]
+    fn test_excerpt_with_zero_budget_is_empty() {
+        assert_eq!(excerpt("anything", 0), "");
+    }
+}
@@ -0,0 +1,243 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::json;
+use crate::shared::utils::xml::xml_escape;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    Atom,
+    Rss,
+    Json,
+}
+
+impl FeedFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+            FeedFormat::Json => "application/feed+json; charset=utf-8",
+        }
+    }
+
+    /// File name the feed is served under, relative to the profile.
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            FeedFormat::Atom => "feed.xml",
+            FeedFormat::Rss => "rss.xml",
+            FeedFormat::Json => "feed.json",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FeedMeta {
+    /// Stable, globally unique id of the feed (a URL is fine)
+    pub id: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub author: String,
+    /// Human-facing page the feed belongs to
+    pub home_url: String,
+    /// URL of this very feed document
+    pub self_url: String,
+    pub updated: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FeedItem {
+    /// Stable, globally unique id of the entry; never changes when the title does
+    pub id: String,
+    pub title: String,
+    pub url: String,
+    pub summary: Option<String>,
+    pub content_html: Option<String>,
+    pub published: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+    /// Tag names, emitted as categories
+    pub tags: Vec<String>,
+}
+
+pub fn render_feed(format: FeedFormat, meta: &FeedMeta, items: &[FeedItem]) -> String {
+    match format {
+        FeedFormat::Atom => atom(meta, items),
+        FeedFormat::Rss => rss(meta, items),
+        FeedFormat::Json => json_feed(meta, items),
+    }
+}
+
+fn rfc3339(date: &DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Atom 1.0 (RFC 4287)
+pub fn atom(meta: &FeedMeta, items: &[FeedItem]) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    xml.push_str(&format!("  <id>{}</id>\n", xml_escape(&meta.id)));
+    xml.push_str(&format!("  <title>{}</title>\n", xml_escape(&meta.title)));
+    if let Some(subtitle) = &meta.subtitle {
+        xml.push_str(&format!("  <subtitle>{}</subtitle>\n", xml_escape(subtitle)));
+    }
+    xml.push_str(&format!("  <updated>{}</updated>\n", rfc3339(&meta.updated)));
+    xml.push_str(&format!("  <author><name>{}</name></author>\n", xml_escape(&meta.author)));
+    xml.push_str(&format!("  <link rel=\"alternate\" type=\"text/html\" href=\"{}\"/>\n", xml_escape(&meta.home_url)));
+    xml.push_str(&format!("  <link rel=\"self\" type=\"application/atom+xml\" href=\"{}\"/>\n", xml_escape(&meta.self_url)));
+
+    for item in items {
+        xml.push_str("  <entry>\n");
+        xml.push_str(&format!("    <id>{}</id>\n", xml_escape(&item.id)));
+        xml.push_str(&format!("    <title>{}</title>\n", xml_escape(&item.title)));
+        xml.push_str(&format!("    <link rel=\"alternate\" type=\"text/html\" href=\"{}\"/>\n", xml_escape(&item.url)));
+        xml.push_str(&format!("    <published>{}</published>\n", rfc3339(&item.published)));
+        xml.push_str(&format!("    <updated>{}</updated>\n", rfc3339(&item.updated)));
+        if let Some(summary) = &item.summary {
+            xml.push_str(&format!("    <summary type=\"text\">{}</summary>\n", xml_escape(summary)));
+        }
+        if let Some(content) = &item.content_html {
+            xml.push_str(&format!("    <content type=\"html\">{}</content>\n", xml_escape(content)));
+        }
+        for tag in &item.tags {
+            xml.push_str(&format!("    <category term=\"{}\"/>\n", xml_escape(tag)));
+        }
+        xml.push_str("  </entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+/// RSS 2.0, with an Atom self link and the full HTML in `content:encoded`
+pub fn rss(meta: &FeedMeta, items: &[FeedItem]) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\" xmlns:content=\"http://purl.org/rss/1.0/modules/content/\">\n");
+    xml.push_str("  <channel>\n");
+    xml.push_str(&format!("    <title>{}</title>\n", xml_escape(&meta.title)));
+    xml.push_str(&format!("    <link>{}</link>\n", xml_escape(&meta.home_url)));
+    xml.push_str(&format!(
+        "    <description>{}</description>\n",
+        xml_escape(meta.subtitle.as_deref().unwrap_or(&meta.title))
+    ));
+    xml.push_str(&format!("    <lastBuildDate>{}</lastBuildDate>\n", meta.updated.to_rfc2822()));
+    xml.push_str(&format!(
+        "    <atom:link rel=\"self\" type=\"application/rss+xml\" href=\"{}\"/>\n",
+        xml_escape(&meta.self_url)
+    ));
+
+    for item in items {
+        xml.push_str("    <item>\n");
+        xml.push_str(&format!("      <guid isPermaLink=\"false\">{}</guid>\n", xml_escape(&item.id)));
+        xml.push_str(&format!("      <title>{}</title>\n", xml_escape(&item.title)));
+        xml.push_str(&format!("      <link>{}</link>\n", xml_escape(&item.url)));
+        xml.push_str(&format!("      <pubDate>{}</pubDate>\n", item.published.to_rfc2822()));
+        if let Some(summary) = &item.summary {
+            xml.push_str(&format!("      <description>{}</description>\n", xml_escape(summary)));
+        }
+        if let Some(content) = &item.content_html {
+            xml.push_str(&format!("      <content:encoded>{}</content:encoded>\n", xml_escape(content)));
+        }
+        for tag in &item.tags {
+            xml.push_str(&format!("      <category>{}</category>\n", xml_escape(tag)));
+        }
+        xml.push_str("    </item>\n");
+    }
+
+    xml.push_str("  </channel>\n</rss>\n");
+    xml
+}
+
+/// JSON Feed 1.1
+pub fn json_feed(meta: &FeedMeta, items: &[FeedItem]) -> String {
+    let items: Vec<_> = items
+        .iter()
+        .map(|item| {
+            json!({
+                "id": item.id,
+                "url": item.url,
+                "title": item.title,
+                "summary": item.summary,
+                "content_html": item.content_html,
+                "date_published": rfc3339(&item.published),
+                "date_modified": rfc3339(&item.updated),
+                "tags": item.tags,
+            })
+        })
+        .collect();
+
+    let feed = json!({
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": meta.title,
+        "description": meta.subtitle,
+        "home_page_url": meta.home_url,
+        "feed_url": meta.self_url,
+        "authors": [{ "name": meta.author }],
+        "items": items,
+    });
+
+    feed.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn sample() -> (FeedMeta, Vec<FeedItem>) {
+        let updated = Utc.with_ymd_and_hms(2025, 3, 2, 0, 0, 0).unwrap();
+        let meta = FeedMeta {
+            id: "https://example.com/profiles/p1".to_string(),
+            title: "Tom & Jerry's work".to_string(),
+            subtitle: None,
+            author: "Tom".to_string(),
+            home_url: "https://example.com/profiles/p1".to_string(),
+            self_url: "https://api.example.com/profiles/p1/feed.xml".to_string(),
+            updated,
+        };
+        let items = vec![FeedItem {
+            id: "urn:uuid:7d1c3a1e-9a1b-4c55-8a40-0d5f6e2b9c11".to_string(),
+            title: "<Hello>".to_string(),
+            url: "https://example.com/profiles/p1/performances/hello".to_string(),
+            summary: Some("Short preview".to_string()),
+            content_html: Some("<p>Body</p>".to_string()),
+            published: Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+            updated,
+            tags: vec!["Rust".to_string(), "C#".to_string()],
+        }];
+        (meta, items)
+    }
+
+    #[test]
+    fn test_atom_escapes_text_and_uses_rfc3339() {
+        let (meta, items) = sample();
+        let xml = atom(&meta, &items);
+
+        assert!(xml.contains("<title>Tom &amp; Jerry&apos;s work</title>"));
+        assert!(xml.contains("<title>&lt;Hello&gt;</title>"));
+        assert!(xml.contains("<content type=\"html\">&lt;p&gt;Body&lt;/p&gt;</content>"));
+        assert!(xml.contains("<updated>2025-03-02T00:00:00Z</updated>"));
+        assert!(xml.contains("<published>2025-03-01T00:00:00Z</published>"));
+        assert!(xml.contains("<category term=\"C#\"/>"));
+    }
+
+    #[test]
+    fn test_rss_uses_rfc2822_dates_and_stable_guid() {
+        let (meta, items) = sample();
+        let xml = rss(&meta, &items);
+
+        assert!(xml.contains("<pubDate>Sat, 1 Mar 2025 00:00:00 +0000</pubDate>"));
+        assert!(xml.contains("<guid isPermaLink=\"false\">urn:uuid:7d1c3a1e-9a1b-4c55-8a40-0d5f6e2b9c11</guid>"));
+        assert!(xml.contains("<content:encoded>&lt;p&gt;Body&lt;/p&gt;</content:encoded>"));
+        assert!(xml.contains("<category>Rust</category>"));
+    }
+
+    #[test]
+    fn test_json_feed_is_valid_json() {
+        let (meta, items) = sample();
+        let value: serde_json::Value = serde_json::from_str(&json_feed(&meta, &items)).unwrap();
+
+        assert_eq!(value["version"], "https://jsonfeed.org/version/1.1");
+        assert_eq!(value["items"][0]["title"], "<Hello>");
+        assert_eq!(value["items"][0]["date_modified"], "2025-03-02T00:00:00Z");
+        assert_eq!(value["items"][0]["tags"][1], "C#");
+    }
+}
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Deserializer};
+
+/// Metadata block at the top of an imported markdown file.
+/// Unknown keys (`layout`, `draft`, ...) left behind by static site generators are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct FrontMatter {
+    pub title: Option<String>,
+    #[serde(alias = "description")]
+    pub summary: Option<String>,
+    #[serde(alias = "date")]
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub location: Option<String>,
+    /// Category id or name
+    pub category: Option<String>,
+    /// Visibility id or name
+    pub visibility: Option<String>,
+    /// Either a YAML list or a comma-separated string
+    #[serde(default, deserialize_with = "string_or_list")]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrList {
+    String(String),
+    List(Vec<String>),
+}
+
+fn string_or_list<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let values = match Option::<StringOrList>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(StringOrList::String(s)) => s.split(',').map(str::to_string).collect(),
+        Some(StringOrList::List(list)) => list,
+    };
+
+    Ok(values
+        .into_iter()
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .collect())
+}
+
+/// Splits a `---` delimited YAML block off the start of `source`.
+/// Files without one get an empty `FrontMatter` and are returned unchanged.
+pub fn split_front_matter(source: &str) -> Result<(FrontMatter, &str), String> {
+    let source = source.strip_prefix('\u{feff}').unwrap_or(source);
+
+    let mut lines = source.split_inclusive('\n');
+    match lines.next() {
+        Some(first) if first.trim_end() == "---" => {}
+        _ => return Ok((FrontMatter::default(), source)),
+    }
+
+    let yaml_start = source.find('\n').map_or(source.len(), |i| i + 1);
+    let mut offset = yaml_start;
+    for line in lines {
+        let line_end = offset + line.len();
+        if matches!(line.trim_end(), "---" | "...") {
+            let yaml = &source[yaml_start..offset];
+            let front_matter = if yaml.trim().is_empty() {
+                FrontMatter::default()
+            } else {
+                serde_yaml::from_str(yaml).map_err(|e| format!("Invalid front matter: {}", e))?
+            };
+            return Ok((front_matter, &source[line_end..]));
+        }
+        offset = line_end;
+    }
+
+    Err("Front matter is not closed with '---'".to_string())
+}
+
+/// Reduces a front matter date (`2021-03-04`, `2021-03-04T10:00:00Z`, `2021-03-04 10:00`) to `YYYY-MM-DD`.
+pub fn normalize_date(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    let date = value.get(..10).unwrap_or(value);
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .map_err(|_| format!("Invalid date '{}', expected YYYY-MM-DD", value))
+}
+
+/// Title for a file without one in its front matter: `notes/my-first_talk.md` -> "my first talk".
+pub fn title_from_path(path: &str) -> String {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    let stem = file_name.rsplit_once('.').map_or(file_name, |(stem, _)| stem);
+    stem.replace(['-', '_'], " ").trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_front_matter_is_parsed_and_stripped() {
+        let source = "---\ntitle: My Talk\ndate: 2021-03-04\ntags: [rust, axum]\nlayout: post\n---\n# Body\n";
+        let (front_matter, body) = split_front_matter(source).unwrap();
+
+        assert_eq!(front_matter.title.as_deref(), Some("My Talk"));
+        assert_eq!(front_matter.start_date.as_deref(), Some("2021-03-04"));
+        assert_eq!(front_matter.tags, vec!["rust", "axum"]);
+        assert_eq!(body, "# Body\n");
+    }
+
+    #[test]
+    fn test_comma_separated_tags() {
+        let (front_matter, _) = split_front_matter("---\ntags: \"rust, web ,\"\n---\n").unwrap();
+        assert_eq!(front_matter.tags, vec!["rust", "web"]);
+    }
+
+    #[test]
+    fn test_file_without_front_matter_is_unchanged() {
+        let (front_matter, body) = split_front_matter("# Title\n---\ntext").unwrap();
+        assert_eq!(front_matter, FrontMatter::default());
+        assert_eq!(body, "# Title\n---\ntext");
+    }
+
+    #[test]
+    fn test_crlf_and_bom_are_handled() {
+        let (front_matter, body) = split_front_matter("\u{feff}---\r\ntitle: Hi\r\n---\r\nBody").unwrap();
+        assert_eq!(front_matter.title.as_deref(), Some("Hi"));
+        assert_eq!(body, "Body");
+    }
+
+    #[test]
+    fn test_unclosed_or_invalid_front_matter_is_an_error() {
+        assert!(split_front_matter("---\ntitle: Hi\n").is_err());
+        assert!(split_front_matter("---\ntitle: [unclosed\n---\n").is_err());
+    }
+
+    #[test]
+    fn test_normalize_date() {
+        assert_eq!(normalize_date("2021-03-04").unwrap(), "2021-03-04");
+        assert_eq!(normalize_date("2021-03-04T10:00:00Z").unwrap(), "2021-03-04");
+        assert!(normalize_date("04/03/2021").is_err());
+    }
+
+    #[test]
+    fn test_title_from_path() {
+        assert_eq!(title_from_path("notes/my-first_talk.md"), "my first talk");
+    }
+}
@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use crate::shared::utils::hash::sha256_hex;
+
+/// Strong ETag (quoted) derived from the response body.
+pub fn etag_for(body: &[u8]) -> String {
+    format!("\"{}\"", sha256_hex(body))
+}
+
+/// Formats a timestamp as an HTTP-date (RFC 9110 IMF-fixdate), e.g. `Sun, 02 Mar 2025 00:00:00 GMT`.
+pub fn http_date(date: &DateTime<Utc>) -> String {
+    date.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// Evaluates `If-None-Match` / `If-Modified-Since` the way RFC 9110 orders them:
+/// when `If-None-Match` is present it alone decides, otherwise the date comparison does.
+pub fn is_not_modified(
+    if_none_match: Option<&str>,
+    if_modified_since: Option<&str>,
+    etag: &str,
+    last_modified: Option<&DateTime<Utc>>,
+) -> bool {
+    if let Some(header) = if_none_match {
+        let current = etag.trim_start_matches("W/");
+        return header
+            .split(',')
+            .map(str::trim)
+            .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == current);
+    }
+
+    match (if_modified_since, last_modified) {
+        (Some(header), Some(last_modified)) => DateTime::parse_from_rfc2822(header)
+            .map(|since| last_modified.timestamp() <= since.timestamp())
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_http_date_uses_imf_fixdate() {
+        let date = Utc.with_ymd_and_hms(2025, 3, 2, 0, 0, 0).unwrap();
+        assert_eq!(http_date(&date), "Sun, 02 Mar 2025 00:00:00 GMT");
+    }
+
+    #[test]
+    fn test_if_none_match_takes_precedence_over_date() {
+        let date = Utc.with_ymd_and_hms(2025, 3, 2, 0, 0, 0).unwrap();
+        let since = http_date(&date);
+
+        assert!(is_not_modified(Some("\"a\", W/\"b\""), None, "\"b\"", Some(&date)));
+        assert!(is_not_modified(Some("*"), None, "\"b\"", None));
+        assert!(!is_not_modified(Some("\"a\""), Some(&since), "\"b\"", Some(&date)));
+    }
+
+    #[test]
+    fn test_if_modified_since_compares_seconds() {
+        let date = Utc.with_ymd_and_hms(2025, 3, 2, 0, 0, 0).unwrap();
+        let newer = Utc.with_ymd_and_hms(2025, 3, 3, 0, 0, 0).unwrap();
+
+        assert!(is_not_modified(None, Some(&http_date(&date)), "\"x\"", Some(&date)));
+        assert!(!is_not_modified(None, Some(&http_date(&date)), "\"x\"", Some(&newer)));
+        assert!(!is_not_modified(None, Some("not a date"), "\"x\"", Some(&date)));
+    }
+}
@@ -0,0 +1,342 @@
+use std::io::Cursor;
+use chrono::NaiveDateTime;
+use exif::{In, Tag, Value};
+use image::metadata::Orientation;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader};
+use crate::shared::utils::image_variants::{encode_image_with_icc, VariantFormat};
+
+/// Quality of re-encoded JPEG uploads; high enough that the extra generation isn't visible.
+const SANITIZED_JPEG_QUALITY: u8 = 90;
+
+const MAX_CAMERA_MODEL_LEN: usize = 100;
+
+/// `VP8X` feature flags, see the WebP container specification.
+const WEBP_FLAG_EXIF: u8 = 0x08;
+const WEBP_FLAG_XMP: u8 = 0x04;
+const WEBP_FLAG_ANIMATION: u8 = 0x02;
+
+/// The only EXIF facts kept from an upload, and only when the profile opted in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CaptureMetadata {
+    /// `DateTimeOriginal` as written by the camera, in its local time
+    pub captured_at: Option<NaiveDateTime>,
+    /// `Make` and `Model`, e.g. "SONY ILCE-7M3"
+    pub camera_model: Option<String>,
+}
+
+/// An upload decoded, turned upright and stripped of its metadata.
+pub struct SanitizedUpload {
+    /// Pixels with the EXIF orientation applied
+    pub image: DynamicImage,
+    pub format: ImageFormat,
+    /// File without EXIF, XMP or text chunks; `None` when the original bytes are kept
+    /// because the format can't be re-encoded losslessly in kind (GIF animations)
+    pub bytes: Option<Vec<u8>>,
+    pub capture: CaptureMetadata,
+}
+
+/// Decodes `bytes`, applies the EXIF orientation and re-encodes JPEG and PNG without metadata.
+/// The ICC profile is carried over, it describes colors rather than the photographer.
+///
+/// WebP only has a lossless encoder here, so its metadata chunks are cut from the container
+/// instead and the lossy data is kept as uploaded. A still WebP is re-encoded only when it has
+/// to be turned upright, which can make it larger than the upload, so callers check the size
+/// again; an animation never is, and its orientation is dropped with its EXIF.
+pub fn sanitize_upload(bytes: &[u8]) -> Result<SanitizedUpload, String> {
+    let reader = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|e| format!("Failed to read image: {}", e))?;
+    let format = reader.format().ok_or_else(|| "Unknown image format".to_string())?;
+
+    let mut decoder = reader.into_decoder().map_err(|e| format!("Failed to decode image: {}", e))?;
+    let exif = decoder.exif_metadata().ok().flatten();
+    let icc_profile = decoder.icc_profile().ok().flatten();
+    let orientation = decoder.orientation().map_err(|e| format!("Failed to read image orientation: {}", e))?;
+
+    let mut image = DynamicImage::from_decoder(decoder).map_err(|e| format!("Failed to decode image: {}", e))?;
+
+    let webp = match format {
+        ImageFormat::WebP => Some(strip_webp_metadata(bytes)?),
+        _ => None,
+    };
+    let orientation = match &webp {
+        Some(webp) if webp.animated => Orientation::NoTransforms,
+        _ => orientation,
+    };
+    image.apply_orientation(orientation);
+
+    let target = match format {
+        ImageFormat::Jpeg => Some(VariantFormat::Jpeg),
+        ImageFormat::Png => Some(VariantFormat::Png),
+        ImageFormat::WebP if orientation != Orientation::NoTransforms => Some(VariantFormat::WebP),
+        _ => None,
+    };
+    let bytes = match target {
+        Some(target) => {
+            let icc_profile = icc_profile.filter(|icc| icc_matches(icc, target, &image));
+            Some(encode_image_with_icc(&image, target, SANITIZED_JPEG_QUALITY, icc_profile)?)
+        }
+        None => webp.map(|webp| webp.bytes),
+    };
+
+    Ok(SanitizedUpload {
+        image,
+        format,
+        bytes,
+        capture: exif.as_deref().map(capture_metadata).unwrap_or_default(),
+    })
+}
+
+/// A WebP file with its `EXIF` and `XMP ` chunks removed.
+struct StrippedWebp {
+    bytes: Vec<u8>,
+    animated: bool,
+}
+
+/// Copies the RIFF container chunk by chunk, leaving out `EXIF` and `XMP ` and clearing their
+/// `VP8X` flags. Image data and the ICC profile are copied unchanged.
+fn strip_webp_metadata(bytes: &[u8]) -> Result<StrippedWebp, String> {
+    let invalid = || "Invalid WebP container".to_string();
+    if bytes.len() < 12 || &bytes[..4] != b"RIFF" || &bytes[8..12] != b"WEBP" {
+        return Err(invalid());
+    }
+
+    let mut out = bytes[..12].to_vec();
+    let mut animated = false;
+    let mut rest = &bytes[12..];
+    while !rest.is_empty() {
+        let header = rest.get(..8).ok_or_else(invalid)?;
+        let fourcc = &header[..4];
+        let size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        // Chunks are padded to an even length
+        let padded = size.checked_add(size % 2).and_then(|n| n.checked_add(8)).ok_or_else(invalid)?;
+        let chunk = rest.get(..padded).or_else(|| rest.get(..8 + size)).ok_or_else(invalid)?;
+        rest = &rest[chunk.len()..];
+
+        match fourcc {
+            b"EXIF" | b"XMP " => continue,
+            b"VP8X" if size >= 1 => {
+                animated = chunk[8] & WEBP_FLAG_ANIMATION != 0;
+                out.extend(&chunk[..8]);
+                out.push(chunk[8] & !(WEBP_FLAG_EXIF | WEBP_FLAG_XMP));
+                out.extend(&chunk[9..]);
+            }
+            _ => out.extend(chunk),
+        }
+    }
+
+    let riff_size = u32::try_from(out.len() - 8).map_err(|_| invalid())?;
+    out[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    Ok(StrippedWebp { bytes: out, animated })
+}
+
+/// Reads capture date and camera from a raw EXIF block. Anything else, GPS and serials included, is ignored.
+pub fn capture_metadata(exif: &[u8]) -> CaptureMetadata {
+    let tiff = exif.strip_prefix(b"Exif\0\0").unwrap_or(exif);
+    let Ok(exif) = exif::Reader::new().read_raw(tiff.to_vec()) else {
+        return CaptureMetadata::default();
+    };
+
+    let ascii = |tag: Tag| -> Option<String> {
+        match &exif.get_field(tag, In::PRIMARY)?.value {
+            Value::Ascii(values) => values.first().map(|v| String::from_utf8_lossy(v).into_owned()),
+            _ => None,
+        }
+    };
+
+    let captured_at = ascii(Tag::DateTimeOriginal)
+        .and_then(|v| NaiveDateTime::parse_from_str(v.trim(), "%Y:%m:%d %H:%M:%S").ok());
+
+    let make = ascii(Tag::Make).map(|v| sanitize_text(&v)).filter(|v| !v.is_empty());
+    let model = ascii(Tag::Model).map(|v| sanitize_text(&v)).filter(|v| !v.is_empty());
+    let camera_model = match (make, model) {
+        (Some(make), Some(model)) if model.to_lowercase().starts_with(&make.to_lowercase()) => Some(model),
+        (Some(make), Some(model)) => Some(format!("{} {}", make, model)),
+        (None, model) => model,
+        (make, None) => make,
+    }
+    .map(|v| v.chars().take(MAX_CAMERA_MODEL_LEN).collect());
+
+    CaptureMetadata { captured_at, camera_model }
+}
+
+/// Printable characters only, whitespace collapsed.
+fn sanitize_text(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Keeps a profile only when its color space matches the pixels written, e.g. not a CMYK
+/// profile on a JPEG the decoder already converted to RGB.
+fn icc_matches(icc: &[u8], target: VariantFormat, image: &DynamicImage) -> bool {
+    let Some(color_space) = icc.get(16..20) else {
+        return false;
+    };
+    let grayscale = target != VariantFormat::Jpeg && !image.color().has_color();
+    color_space == if grayscale { b"GRAY" } else { b"RGB " }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exif::experimental::Writer;
+    use exif::Field;
+    use image::{Rgb, RgbImage};
+    use crate::shared::utils::image_variants::encode_image;
+
+    fn exif_block(orientation: u16) -> Vec<u8> {
+        let fields = [
+            Field { tag: Tag::Orientation, ifd_num: In::PRIMARY, value: Value::Short(vec![orientation]) },
+            Field { tag: Tag::Make, ifd_num: In::PRIMARY, value: Value::Ascii(vec![b"SONY".to_vec()]) },
+            Field { tag: Tag::Model, ifd_num: In::PRIMARY, value: Value::Ascii(vec![b"ILCE-7M3\n".to_vec()]) },
+            Field { tag: Tag::BodySerialNumber, ifd_num: In::PRIMARY, value: Value::Ascii(vec![b"SN-1234567".to_vec()]) },
+            Field { tag: Tag::DateTimeOriginal, ifd_num: In::PRIMARY, value: Value::Ascii(vec![b"2024:05:01 18:30:05".to_vec()]) },
+            Field { tag: Tag::GPSLatitudeRef, ifd_num: In::PRIMARY, value: Value::Ascii(vec![b"N".to_vec()]) },
+        ];
+        let mut writer = Writer::new();
+        for field in &fields {
+            writer.push_field(field);
+        }
+        let mut tiff = Cursor::new(Vec::new());
+        writer.write(&mut tiff, false).unwrap();
+        tiff.into_inner()
+    }
+
+    /// A 40x20 JPEG with an APP1 EXIF segment right after SOI.
+    fn jpeg_with_exif(orientation: u16) -> Vec<u8> {
+        let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(40, 20, Rgb([30, 120, 200])));
+        let jpeg = encode_image(&image, VariantFormat::Jpeg, 90).unwrap();
+
+        let mut app1 = b"Exif\0\0".to_vec();
+        app1.extend(exif_block(orientation));
+        let mut out = jpeg[..2].to_vec();
+        out.extend([0xFF, 0xE1]);
+        out.extend(((app1.len() + 2) as u16).to_be_bytes());
+        out.extend(app1);
+        out.extend(&jpeg[2..]);
+        out
+    }
+
+    #[test]
+    fn test_sanitize_upload_rotates_and_strips_exif() {
+        let original = jpeg_with_exif(6);
+        assert!(original.windows(10).any(|w| w == b"SN-1234567"));
+
+        let upload = sanitize_upload(&original).unwrap();
+        assert_eq!(upload.format, ImageFormat::Jpeg);
+        assert_eq!((upload.image.width(), upload.image.height()), (20, 40));
+
+        let bytes = upload.bytes.unwrap();
+        assert!(!bytes.windows(4).any(|w| w == b"Exif"));
+        assert!(!bytes.windows(10).any(|w| w == b"SN-1234567"));
+        let decoded = image::load_from_memory(&bytes).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (20, 40));
+    }
+
+    #[test]
+    fn test_capture_metadata_keeps_date_and_camera_only() {
+        let capture = capture_metadata(&exif_block(1));
+        assert_eq!(capture.camera_model.as_deref(), Some("SONY ILCE-7M3"));
+        assert_eq!(
+            capture.captured_at,
+            NaiveDateTime::parse_from_str("2024-05-01 18:30:05", "%Y-%m-%d %H:%M:%S").ok()
+        );
+
+        assert_eq!(capture_metadata(b"not exif"), CaptureMetadata::default());
+    }
+
+    fn riff_chunk(fourcc: &[u8], payload: &[u8]) -> Vec<u8> {
+        let mut chunk = fourcc.to_vec();
+        chunk.extend((payload.len() as u32).to_le_bytes());
+        chunk.extend(payload);
+        if payload.len() % 2 == 1 {
+            chunk.push(0);
+        }
+        chunk
+    }
+
+    /// A 40x20 extended WebP: `VP8X` with the given flags, the image data, then `EXIF` and `XMP `.
+    fn webp_with_exif(orientation: u16, flags: u8) -> (Vec<u8>, Vec<u8>) {
+        let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(40, 20, Rgb([30, 120, 200])));
+        let simple = encode_image(&image, VariantFormat::WebP, 90).unwrap();
+        let image_chunk = simple[12..].to_vec();
+
+        let mut vp8x = vec![flags | WEBP_FLAG_EXIF | WEBP_FLAG_XMP, 0, 0, 0];
+        vp8x.extend(&39u32.to_le_bytes()[..3]);
+        vp8x.extend(&19u32.to_le_bytes()[..3]);
+
+        let mut body = b"WEBP".to_vec();
+        body.extend(riff_chunk(b"VP8X", &vp8x));
+        body.extend(&image_chunk);
+        body.extend(riff_chunk(b"EXIF", &exif_block(orientation)));
+        body.extend(riff_chunk(b"XMP ", b"<x:xmpmeta>SN-1234567</x:xmpmeta>"));
+
+        let mut out = b"RIFF".to_vec();
+        out.extend((body.len() as u32).to_le_bytes());
+        out.extend(body);
+        (out, image_chunk)
+    }
+
+    #[test]
+    fn test_sanitize_upload_strips_webp_without_reencoding() {
+        let (original, image_chunk) = webp_with_exif(1, 0);
+        assert!(original.windows(10).any(|w| w == b"SN-1234567"));
+
+        let upload = sanitize_upload(&original).unwrap();
+        assert_eq!(upload.format, ImageFormat::WebP);
+        assert_eq!(upload.capture.camera_model.as_deref(), Some("SONY ILCE-7M3"));
+
+        let bytes = upload.bytes.unwrap();
+        assert!(bytes.windows(image_chunk.len()).any(|w| w == image_chunk));
+        assert!(!bytes.windows(4).any(|w| w == b"EXIF" || w == b"XMP "));
+        assert!(!bytes.windows(10).any(|w| w == b"SN-1234567"));
+        assert_eq!(bytes[20] & (WEBP_FLAG_EXIF | WEBP_FLAG_XMP), 0);
+        assert_eq!(u32::from_le_bytes(bytes[4..8].try_into().unwrap()) as usize, bytes.len() - 8);
+        let decoded = image::load_from_memory(&bytes).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (40, 20));
+    }
+
+    #[test]
+    fn test_sanitize_upload_rotates_webp() {
+        let (original, _) = webp_with_exif(6, 0);
+
+        let upload = sanitize_upload(&original).unwrap();
+        assert_eq!((upload.image.width(), upload.image.height()), (20, 40));
+
+        let bytes = upload.bytes.unwrap();
+        assert!(!bytes.windows(10).any(|w| w == b"SN-1234567"));
+        let decoded = image::load_from_memory(&bytes).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (20, 40));
+    }
+
+    #[test]
+    fn test_strip_webp_metadata_keeps_animations() {
+        let (original, _) = webp_with_exif(6, WEBP_FLAG_ANIMATION);
+
+        let stripped = strip_webp_metadata(&original).unwrap();
+        assert!(stripped.animated);
+        assert_eq!(stripped.bytes[20], WEBP_FLAG_ANIMATION);
+        assert!(!stripped.bytes.windows(4).any(|w| w == b"EXIF" || w == b"XMP "));
+
+        assert!(strip_webp_metadata(b"RIFF\x04\0\0\0WEBP").is_ok());
+        assert!(strip_webp_metadata(b"RIFF\x10\0\0\0WEBPVP8 \xff\0\0\0").is_err());
+    }
+
+    #[test]
+    fn test_sanitize_upload_without_exif() {
+        let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(8, 4, Rgb([0, 0, 0])));
+        let png = encode_image(&image, VariantFormat::Png, 90).unwrap();
+
+        let upload = sanitize_upload(&png).unwrap();
+        assert_eq!(upload.format, ImageFormat::Png);
+        assert_eq!(upload.capture, CaptureMetadata::default());
+        assert!(upload.bytes.is_some());
+
+        assert!(sanitize_upload(b"plain text").is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use image::imageops::FilterType;
+use image::{DynamicImage, GenericImageView};
+use crate::shared::utils::image_variants::{encode_image, VariantFormat};
+
+/// Longest side the BlurHash and color are computed on; more pixels don't change the result.
+const SAMPLE_SIZE: u32 = 64;
+
+/// Longest side of the inline preview, small enough to embed in every listing.
+const LQIP_SIZE: u32 = 16;
+
+/// Components along the longer side; the shorter side gets proportionally fewer, at least 3.
+const BLURHASH_COMPONENTS: u32 = 4;
+
+/// What a client shows while the real image loads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImagePlaceholder {
+    pub blurhash: String,
+    /// Average color as "#rrggbb", transparent pixels weigh nothing
+    pub dominant_color: String,
+    /// Tiny WebP preview as a `data:` URI
+    pub lqip: String,
+}
+
+/// Computes all placeholders from a decoded image.
+pub fn compute_placeholder(image: &DynamicImage) -> Result<ImagePlaceholder, String> {
+    if image.width() == 0 || image.height() == 0 {
+        return Err("Image has no pixels".to_string());
+    }
+
+    let sample = image.thumbnail(SAMPLE_SIZE, SAMPLE_SIZE).to_rgba8();
+    let (components_x, components_y) = blurhash_components(sample.width(), sample.height());
+    let blurhash = blurhash::encode(components_x, components_y, sample.width(), sample.height(), sample.as_raw())
+        .map_err(|e| format!("Failed to compute BlurHash: {}", e))?;
+
+    let dominant_color = average_color(&DynamicImage::ImageRgba8(sample));
+
+    let tiny = image.resize(LQIP_SIZE, LQIP_SIZE, FilterType::Triangle);
+    let lqip = format!("data:image/webp;base64,{}", STANDARD.encode(encode_image(&tiny, VariantFormat::WebP, 0)?));
+
+    Ok(ImagePlaceholder { blurhash, dominant_color, lqip })
+}
+
+/// Keeps the BlurHash cells roughly square.
+fn blurhash_components(width: u32, height: u32) -> (u32, u32) {
+    let short = |long: u32, short: u32| {
+        ((BLURHASH_COMPONENTS as f64 * short as f64 / long.max(1) as f64).round() as u32).clamp(3, BLURHASH_COMPONENTS)
+    };
+    if width >= height {
+        (BLURHASH_COMPONENTS, short(width, height))
+    } else {
+        (short(height, width), BLURHASH_COMPONENTS)
+    }
+}
+
+fn average_color(image: &DynamicImage) -> String {
+    let (mut r, mut g, mut b, mut weight) = (0u64, 0u64, 0u64, 0u64);
+    for (_, _, pixel) in image.pixels() {
+        let [pr, pg, pb, pa] = pixel.0;
+        let a = pa as u64;
+        r += pr as u64 * a;
+        g += pg as u64 * a;
+        b += pb as u64 * a;
+        weight += a;
+    }
+    if weight == 0 {
+        return "#000000".to_string();
+    }
+    format!("#{:02x}{:02x}{:02x}", r / weight, g / weight, b / weight)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage, Rgba, RgbaImage};
+
+    #[test]
+    fn test_compute_placeholder_for_solid_image() {
+        let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(300, 200, Rgb([200, 40, 10])));
+        let placeholder = compute_placeholder(&image).unwrap();
+
+        assert_eq!(placeholder.dominant_color, "#c8280a");
+        // 4x3 components encode to 4 + 2 * 12 characters
+        assert_eq!(placeholder.blurhash.len(), 28);
+        assert!(blurhash::decode(&placeholder.blurhash, 4, 3, 1.0).is_ok());
+
+        let encoded = placeholder.lqip.strip_prefix("data:image/webp;base64,").unwrap();
+        let preview = image::load_from_memory(&STANDARD.decode(encoded).unwrap()).unwrap();
+        assert_eq!((preview.width(), preview.height()), (16, 11));
+    }
+
+    #[test]
+    fn test_dominant_color_ignores_transparent_pixels() {
+        let mut image = RgbaImage::from_pixel(10, 10, Rgba([255, 255, 255, 0]));
+        for x in 0..5 {
+            image.put_pixel(x, 0, Rgba([0, 0, 255, 255]));
+        }
+        assert_eq!(average_color(&DynamicImage::ImageRgba8(image)), "#0000ff");
+        assert_eq!(average_color(&DynamicImage::ImageRgba8(RgbaImage::new(2, 2))), "#000000");
+    }
+
+    #[test]
+    fn test_blurhash_components_follow_aspect_ratio() {
+        assert_eq!(blurhash_components(64, 64), (4, 4));
+        assert_eq!(blurhash_components(64, 32), (4, 3));
+        assert_eq!(blurhash_components(20, 64), (3, 4));
+    }
+}
@@ -0,0 +1,262 @@
+use std::io::Cursor;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageReader, Limits};
+use crate::shared::utils::image_variants::{encode_image, VariantFormat, JPEG_QUALITY};
+
+/// Largest requested output width or height.
+pub const MAX_TRANSFORM_DIMENSION: u32 = 2048;
+
+/// Requested sizes are rounded up to a multiple of this, so a client can't fill the
+/// transform cache with one entry per pixel.
+pub const TRANSFORM_DIMENSION_STEP: u32 = 50;
+
+/// JPEG qualities a transform can use; `q` snaps to the nearest one.
+pub const TRANSFORM_QUALITIES: [u8; 4] = [50, 70, JPEG_QUALITY, 95];
+
+/// Largest original width or height the endpoint is willing to decode.
+pub const MAX_SOURCE_DIMENSION: u32 = 12_000;
+
+/// Decoder allocation cap, keeps a crafted header from reserving gigabytes.
+const MAX_DECODE_ALLOC: u64 = 512 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fit {
+    /// Scales down to fit inside the box, keeping the aspect ratio. Never upscales.
+    Contain,
+    /// Scales and center-crops to fill the box exactly.
+    Cover,
+    /// Stretches to the box, ignoring the aspect ratio.
+    Fill,
+}
+
+impl Fit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Fit::Contain => "contain",
+            Fit::Cover => "cover",
+            Fit::Fill => "fill",
+        }
+    }
+}
+
+/// Validated `?w=&h=&fit=&format=&q=` of a transform request, with sizes and quality
+/// snapped to [`TRANSFORM_DIMENSION_STEP`] and [`TRANSFORM_QUALITIES`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransformParams {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub fit: Fit,
+    /// `None` picks a format from the original, see [`TransformParams::output_format`].
+    pub format: Option<VariantFormat>,
+    /// JPEG quality; ignored for WebP (lossless) and PNG.
+    pub quality: u8,
+}
+
+impl TransformParams {
+    pub fn parse(
+        width: Option<u32>,
+        height: Option<u32>,
+        fit: Option<&str>,
+        format: Option<&str>,
+        quality: Option<u32>,
+    ) -> Result<Self, String> {
+        if width.is_none() && height.is_none() {
+            return Err("At least one of w or h is required".to_string());
+        }
+        for dimension in [width, height].into_iter().flatten() {
+            if dimension == 0 || dimension > MAX_TRANSFORM_DIMENSION {
+                return Err(format!("w and h must be between 1 and {}", MAX_TRANSFORM_DIMENSION));
+            }
+        }
+
+        let fit = match fit.map(|f| f.trim().to_ascii_lowercase()).as_deref() {
+            None | Some("") | Some("contain") => Fit::Contain,
+            Some("cover") => Fit::Cover,
+            Some("fill") => Fit::Fill,
+            Some(other) => return Err(format!("Unsupported fit '{}', expected contain, cover or fill", other)),
+        };
+        if fit != Fit::Contain && (width.is_none() || height.is_none()) {
+            return Err(format!("fit={} requires both w and h", fit.as_str()));
+        }
+
+        let format = match format.map(|f| f.trim().to_ascii_lowercase()).as_deref() {
+            None | Some("") => None,
+            Some("webp") => Some(VariantFormat::WebP),
+            Some("jpeg") | Some("jpg") => Some(VariantFormat::Jpeg),
+            Some("png") => Some(VariantFormat::Png),
+            Some(other) => return Err(format!("Unsupported format '{}', expected webp, jpeg or png", other)),
+        };
+
+        let quality = quality.unwrap_or(JPEG_QUALITY as u32);
+        if !(1..=100).contains(&quality) {
+            return Err("q must be between 1 and 100".to_string());
+        }
+        let quality = TRANSFORM_QUALITIES
+            .into_iter()
+            .min_by_key(|&level| (level as i32 - quality as i32).abs())
+            .unwrap_or(JPEG_QUALITY);
+
+        let snap = |d: Option<u32>| d.map(|d| d.div_ceil(TRANSFORM_DIMENSION_STEP) * TRANSFORM_DIMENSION_STEP)
+            .map(|d| d.min(MAX_TRANSFORM_DIMENSION));
+
+        Ok(Self { width: snap(width), height: snap(height), fit, format, quality })
+    }
+
+    /// The requested format, otherwise JPEG and PNG originals keep theirs and the rest become WebP.
+    pub fn output_format(&self, original_mime: &str) -> VariantFormat {
+        self.format.unwrap_or(match original_mime {
+            "image/jpeg" => VariantFormat::Jpeg,
+            "image/png" => VariantFormat::Png,
+            _ => VariantFormat::WebP,
+        })
+    }
+
+    /// Storage key of the result, e.g. `w320-h320-cover-q82.jpg`.
+    /// Quality only takes part for JPEG so `q` can't multiply identical lossless outputs.
+    pub fn cache_key(&self, original_mime: &str) -> String {
+        let dimension = |d: Option<u32>| d.map(|d| d.to_string()).unwrap_or_else(|| "auto".to_string());
+        let format = self.output_format(original_mime);
+        let quality = match format {
+            VariantFormat::Jpeg => format!("-q{}", self.quality),
+            _ => String::new(),
+        };
+        format!(
+            "w{}-h{}-{}{}.{}",
+            dimension(self.width),
+            dimension(self.height),
+            self.fit.as_str(),
+            quality,
+            format.extension()
+        )
+    }
+
+    /// Output size for an original of `width` x `height`.
+    pub fn target_size(&self, width: u32, height: u32) -> (u32, u32) {
+        match (self.fit, self.width, self.height) {
+            (Fit::Cover | Fit::Fill, Some(w), Some(h)) => (w, h),
+            _ => {
+                let max_w = self.width.unwrap_or(u32::MAX).min(width);
+                let max_h = self.height.unwrap_or(u32::MAX).min(height);
+                let scale = (max_w as f64 / width.max(1) as f64).min(max_h as f64 / height.max(1) as f64);
+                (
+                    ((width as f64 * scale).round() as u32).max(1),
+                    ((height as f64 * scale).round() as u32).max(1),
+                )
+            }
+        }
+    }
+}
+
+/// Decodes with dimension and allocation limits so oversized originals fail fast.
+pub fn decode_bounded(bytes: &[u8]) -> Result<DynamicImage, String> {
+    let mut reader = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|e| format!("Failed to read image: {}", e))?;
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_SOURCE_DIMENSION);
+    limits.max_image_height = Some(MAX_SOURCE_DIMENSION);
+    limits.max_alloc = Some(MAX_DECODE_ALLOC);
+    reader.limits(limits);
+
+    reader.decode().map_err(|e| format!("Failed to decode image: {}", e))
+}
+
+/// Decodes the original, applies `params` and encodes the result.
+pub fn transform_image(
+    original: &[u8],
+    original_mime: &str,
+    params: &TransformParams,
+) -> Result<(Vec<u8>, VariantFormat), String> {
+    let image = decode_bounded(original)?;
+    let (width, height) = params.target_size(image.width(), image.height());
+
+    let transformed = match params.fit {
+        _ if (width, height) == (image.width(), image.height()) => image,
+        Fit::Contain | Fit::Fill => image.resize_exact(width, height, FilterType::Lanczos3),
+        Fit::Cover => image.resize_to_fill(width, height, FilterType::Lanczos3),
+    };
+
+    let format = params.output_format(original_mime);
+    let bytes = encode_image(&transformed, format, params.quality)?;
+    Ok((bytes, format))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(width, height, Rgba([200, 10, 10, 255])));
+        encode_image(&image, VariantFormat::Png, JPEG_QUALITY).unwrap()
+    }
+
+    #[test]
+    fn test_parse_bounds_parameters() {
+        assert!(TransformParams::parse(None, None, None, None, None).is_err());
+        assert!(TransformParams::parse(Some(0), None, None, None, None).is_err());
+        assert!(TransformParams::parse(Some(MAX_TRANSFORM_DIMENSION + 1), None, None, None, None).is_err());
+        assert!(TransformParams::parse(Some(100), None, Some("cover"), None, None).is_err());
+        assert!(TransformParams::parse(Some(100), None, Some("zoom"), None, None).is_err());
+        assert!(TransformParams::parse(Some(100), None, None, Some("gif"), None).is_err());
+        assert!(TransformParams::parse(Some(100), None, None, None, Some(0)).is_err());
+        assert!(TransformParams::parse(Some(100), None, None, None, Some(101)).is_err());
+
+        let params = TransformParams::parse(Some(320), Some(200), Some("Cover"), Some("jpg"), None).unwrap();
+        assert_eq!(params.fit, Fit::Cover);
+        assert_eq!(params.format, Some(VariantFormat::Jpeg));
+        assert_eq!(params.quality, JPEG_QUALITY);
+    }
+
+    #[test]
+    fn test_cache_key_only_includes_quality_for_jpeg() {
+        let jpeg = TransformParams::parse(Some(300), Some(300), Some("cover"), None, Some(70)).unwrap();
+        assert_eq!(jpeg.cache_key("image/jpeg"), "w300-h300-cover-q70.jpg");
+        assert_eq!(jpeg.cache_key("image/gif"), "w300-h300-cover.webp");
+
+        let png = TransformParams::parse(None, Some(100), None, None, Some(10)).unwrap();
+        assert_eq!(png.cache_key("image/png"), "wauto-h100-contain.png");
+    }
+
+    #[test]
+    fn test_parse_snaps_sizes_and_quality() {
+        let params = TransformParams::parse(Some(301), Some(2040), None, None, Some(73)).unwrap();
+        assert_eq!((params.width, params.height), (Some(350), Some(MAX_TRANSFORM_DIMENSION)));
+        assert_eq!(params.quality, 70);
+
+        let params = TransformParams::parse(Some(1), None, None, None, Some(100)).unwrap();
+        assert_eq!(params.width, Some(TRANSFORM_DIMENSION_STEP));
+        assert_eq!(params.quality, 95);
+        assert_eq!(TransformParams::parse(Some(1), None, None, None, Some(1)).unwrap().quality, 50);
+    }
+
+    #[test]
+    fn test_contain_never_upscales() {
+        let params = TransformParams::parse(Some(500), Some(500), None, None, None).unwrap();
+        assert_eq!(params.target_size(1000, 500), (500, 250));
+        assert_eq!(params.target_size(200, 100), (200, 100));
+
+        let by_height = TransformParams::parse(None, Some(50), None, None, None).unwrap();
+        assert_eq!(by_height.target_size(1000, 500), (100, 50));
+    }
+
+    #[test]
+    fn test_transform_image_output_sizes() {
+        let original = png(400, 200);
+
+        let cover = TransformParams::parse(Some(100), Some(100), Some("cover"), Some("webp"), None).unwrap();
+        let (bytes, format) = transform_image(&original, "image/png", &cover).unwrap();
+        let decoded = image::load_from_memory(&bytes).unwrap();
+        assert_eq!(format, VariantFormat::WebP);
+        assert_eq!((decoded.width(), decoded.height()), (100, 100));
+
+        let contain = TransformParams::parse(Some(100), Some(100), None, None, None).unwrap();
+        let (bytes, format) = transform_image(&original, "image/png", &contain).unwrap();
+        let decoded = image::load_from_memory(&bytes).unwrap();
+        assert_eq!(format, VariantFormat::Png);
+        assert_eq!((decoded.width(), decoded.height()), (100, 50));
+
+        assert!(transform_image(b"not an image", "image/png", &contain).is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use std::io::Cursor;
+use image::{ImageFormat, ImageReader};
+
+pub const DEFAULT_MAX_UPLOAD_BYTES: usize = 20 * 1024 * 1024;
+
+/// Stays below the transform endpoint's decode limit so every upload can be transformed.
+pub const DEFAULT_MAX_UPLOAD_DIMENSION: u32 = 10_000;
+
+/// 50 megapixels, about 200 MB once decoded to RGBA.
+pub const DEFAULT_MAX_UPLOAD_PIXELS: u64 = 50_000_000;
+
+const MAX_FILENAME_CHARS: usize = 255;
+
+/// Formats we decode, re-encode and serve. Everything else is rejected by its content, whatever it is called.
+const ALLOWED_FORMATS: [ImageFormat; 4] = [ImageFormat::Jpeg, ImageFormat::Png, ImageFormat::WebP, ImageFormat::Gif];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadLimits {
+    pub max_bytes: usize,
+    /// Largest width or height
+    pub max_dimension: u32,
+    /// Largest width times height
+    pub max_pixels: u64,
+}
+
+impl Default for UploadLimits {
+    fn default() -> Self {
+        Self {
+            max_bytes: DEFAULT_MAX_UPLOAD_BYTES,
+            max_dimension: DEFAULT_MAX_UPLOAD_DIMENSION,
+            max_pixels: DEFAULT_MAX_UPLOAD_PIXELS,
+        }
+    }
+}
+
+/// An upload whose content passed [`validate_upload`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidatedUpload {
+    pub format: ImageFormat,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Detects the format from the leading bytes. The client's filename and content type are never consulted.
+pub fn sniff_format(bytes: &[u8]) -> Result<ImageFormat, String> {
+    if looks_like_svg(bytes) {
+        return Err("SVG images are not accepted, upload a PNG or WebP export instead".to_string());
+    }
+    match image::guess_format(bytes) {
+        Ok(format) if ALLOWED_FORMATS.contains(&format) => Ok(format),
+        Ok(format) => Err(format!(
+            "{} images are not accepted, expected JPEG, PNG, WebP or GIF",
+            format.extensions_str().first().copied().unwrap_or("these").to_ascii_uppercase()
+        )),
+        Err(_) => Err("File is not a recognized image, expected JPEG, PNG, WebP or GIF".to_string()),
+    }
+}
+
+/// Checks size, format and the dimensions in the header before anything is decoded.
+pub fn validate_upload(bytes: &[u8], limits: &UploadLimits) -> Result<ValidatedUpload, String> {
+    if bytes.is_empty() {
+        return Err("Image file is empty".to_string());
+    }
+    if bytes.len() > limits.max_bytes {
+        return Err(format!("Image cannot be larger than {} bytes", limits.max_bytes));
+    }
+
+    let format = sniff_format(bytes)?;
+    let (width, height) = ImageReader::with_format(Cursor::new(bytes), format)
+        .into_dimensions()
+        .map_err(|e| format!("Image could not be read: {}", e))?;
+
+    if width == 0 || height == 0 {
+        return Err("Image has no pixels".to_string());
+    }
+    if width > limits.max_dimension || height > limits.max_dimension {
+        return Err(format!(
+            "Image is {}x{}, width and height cannot exceed {} pixels",
+            width, height, limits.max_dimension
+        ));
+    }
+    if width as u64 * height as u64 > limits.max_pixels {
+        return Err(format!("Image is {}x{}, cannot exceed {} pixels in total", width, height, limits.max_pixels));
+    }
+
+    Ok(ValidatedUpload { format, width, height })
+}
+
+/// The client's filename reduced to a display name: no directories, no control characters, at most 255 chars.
+pub fn sanitize_filename(filename: &str) -> String {
+    let name = filename.rsplit(['/', '\\']).next().unwrap_or_default();
+    let name: String = name
+        .chars()
+        .filter(|c| !c.is_control())
+        .take(MAX_FILENAME_CHARS)
+        .collect();
+    let name = name.trim().trim_start_matches('.').trim();
+    if name.is_empty() { "image".to_string() } else { name.to_string() }
+}
+
+/// Storage extension of a sniffed format.
+pub fn extension_for(format: ImageFormat) -> &'static str {
+    match format {
+        ImageFormat::Jpeg => "jpg",
+        format => format.extensions_str().first().copied().unwrap_or("bin"),
+    }
+}
+
+fn looks_like_svg(bytes: &[u8]) -> bool {
+    let head = &bytes[..bytes.len().min(1024)];
+    let head = head.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(head);
+    let text = String::from_utf8_lossy(head).to_ascii_lowercase();
+    let text = text.trim_start();
+    text.starts_with('<') && (text.contains("<svg") || text.starts_with("<?xml") || text.starts_with("<!doctype svg"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{DynamicImage, Rgb, RgbImage};
+    use crate::shared::utils::image_variants::{encode_image, VariantFormat};
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(width, height, Rgb([1, 2, 3])));
+        encode_image(&image, VariantFormat::Png, 90).unwrap()
+    }
+
+    #[test]
+    fn test_sniff_format_ignores_names_and_rejects_svg() {
+        assert_eq!(sniff_format(&png(2, 2)), Ok(ImageFormat::Png));
+        assert!(sniff_format(b"<?xml version=\"1.0\"?><svg xmlns=\"http://www.w3.org/2000/svg\"/>")
+            .unwrap_err()
+            .contains("SVG"));
+        assert!(sniff_format(b"\xEF\xBB\xBF  <svg onload=\"alert(1)\"></svg>").unwrap_err().contains("SVG"));
+        assert!(sniff_format(b"BM\x00\x00\x00\x00").unwrap_err().contains("BMP"));
+        assert!(sniff_format(b"#!/bin/sh\necho hi").is_err());
+    }
+
+    #[test]
+    fn test_validate_upload_enforces_limits() {
+        let limits = UploadLimits { max_bytes: 10_000, max_dimension: 100, max_pixels: 5_000 };
+
+        let upload = validate_upload(&png(60, 50), &limits).unwrap();
+        assert_eq!((upload.format, upload.width, upload.height), (ImageFormat::Png, 60, 50));
+
+        assert!(validate_upload(&png(101, 10), &limits).unwrap_err().contains("cannot exceed 100"));
+        assert!(validate_upload(&png(100, 100), &limits).unwrap_err().contains("5000 pixels"));
+        assert!(validate_upload(&[], &limits).is_err());
+
+        let small = UploadLimits { max_bytes: 16, ..limits };
+        assert!(validate_upload(&png(60, 50), &small).unwrap_err().contains("larger than 16 bytes"));
+
+        let mut truncated = png(60, 50);
+        truncated.truncate(12);
+        assert!(validate_upload(&truncated, &limits).is_err());
+    }
+
+    #[test]
+    fn test_sanitize_filename() {
+        assert_eq!(sanitize_filename("../../etc/passwd"), "passwd");
+        assert_eq!(sanitize_filename("C:\\Users\\me\\holiday.JPG"), "holiday.JPG");
+        assert_eq!(sanitize_filename("bad\u{0}\nname.png"), "badname.png");
+        assert_eq!(sanitize_filename(".htaccess"), "htaccess");
+        assert_eq!(sanitize_filename("   "), "image");
+        assert_eq!(sanitize_filename(&"a".repeat(300)).len(), 255);
+        assert_eq!(extension_for(ImageFormat::Jpeg), "jpg");
+        assert_eq!(extension_for(ImageFormat::WebP), "webp");
+    }
+}
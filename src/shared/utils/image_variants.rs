@@ -0,0 +1,205 @@
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageEncoder};
+
+/// Widths generated when `IMAGE_VARIANT_WIDTHS` isn't set.
+pub const DEFAULT_VARIANT_WIDTHS: [u32; 3] = [320, 768, 1600];
+
+/// Upper bound for configured widths; anything larger is an original, not a variant.
+pub const MAX_VARIANT_WIDTH: u32 = 4096;
+
+/// JPEG quality used for variants and as the transform default.
+pub const JPEG_QUALITY: u8 = 82;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariantFormat {
+    WebP,
+    Jpeg,
+    Png,
+}
+
+impl VariantFormat {
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            VariantFormat::WebP => "image/webp",
+            VariantFormat::Jpeg => "image/jpeg",
+            VariantFormat::Png => "image/png",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            VariantFormat::WebP => "webp",
+            VariantFormat::Jpeg => "jpg",
+            VariantFormat::Png => "png",
+        }
+    }
+}
+
+/// A resized copy ready to be stored.
+#[derive(Debug, Clone)]
+pub struct EncodedVariant {
+    pub width: u32,
+    pub height: u32,
+    pub format: VariantFormat,
+    pub bytes: Vec<u8>,
+}
+
+/// Parses a comma separated width list ("320, 768,1600").
+pub fn parse_variant_widths(value: &str) -> Result<Vec<u32>, String> {
+    let mut widths = Vec::new();
+    for part in value.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let width: u32 = part.parse().map_err(|_| format!("Invalid image variant width '{}'", part))?;
+        if width == 0 || width > MAX_VARIANT_WIDTH {
+            return Err(format!("Image variant widths must be between 1 and {}", MAX_VARIANT_WIDTH));
+        }
+        widths.push(width);
+    }
+    widths.sort_unstable();
+    widths.dedup();
+    Ok(widths)
+}
+
+/// Configured widths narrower than the original; images are never upscaled.
+pub fn variant_widths(original_width: u32, configured: &[u32]) -> Vec<u32> {
+    let mut widths: Vec<u32> = configured.iter().copied().filter(|w| *w > 0 && *w < original_width).collect();
+    widths.sort_unstable();
+    widths.dedup();
+    widths
+}
+
+/// WebP for every image, plus the original format when it's one browsers fall back to.
+/// GIFs and other formats only get WebP, their originals stay the fallback.
+pub fn variant_formats(original_mime: &str) -> Vec<VariantFormat> {
+    match original_mime {
+        "image/jpeg" => vec![VariantFormat::WebP, VariantFormat::Jpeg],
+        "image/png" => vec![VariantFormat::WebP, VariantFormat::Png],
+        _ => vec![VariantFormat::WebP],
+    }
+}
+
+/// Storage filename of a variant, next to the original `{image_id}.{ext}`.
+pub fn variant_filename(image_id: &str, width: u32, format: VariantFormat) -> String {
+    format!("{}_w{}.{}", image_id, width, format.extension())
+}
+
+/// Resizes to `width` keeping the aspect ratio and encodes in `format`.
+pub fn encode_variant(image: &DynamicImage, width: u32, format: VariantFormat) -> Result<EncodedVariant, String> {
+    let height = ((image.height() as f64 * width as f64 / image.width().max(1) as f64).round() as u32).max(1);
+    let resized = image.resize_exact(width, height, FilterType::Lanczos3);
+    let bytes = encode_image(&resized, format, JPEG_QUALITY)?;
+
+    Ok(EncodedVariant { width, height, format, bytes })
+}
+
+/// Encodes as is; `jpeg_quality` (1-100) only applies to JPEG, WebP is always lossless.
+pub fn encode_image(image: &DynamicImage, format: VariantFormat, jpeg_quality: u8) -> Result<Vec<u8>, String> {
+    encode_image_with_icc(image, format, jpeg_quality, None)
+}
+
+/// Like [`encode_image`], embedding `icc_profile` so colors survive re-encoding.
+pub fn encode_image_with_icc(
+    image: &DynamicImage,
+    format: VariantFormat,
+    jpeg_quality: u8,
+    icc_profile: Option<Vec<u8>>,
+) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    let result = match format {
+        VariantFormat::Jpeg => {
+            let mut encoder = JpegEncoder::new_with_quality(&mut bytes, jpeg_quality.clamp(1, 100));
+            if let Some(icc) = icc_profile {
+                let _ = encoder.set_icc_profile(icc);
+            }
+            DynamicImage::ImageRgb8(image.to_rgb8()).write_with_encoder(encoder)
+        }
+        VariantFormat::WebP => {
+            let image = if image.color().has_alpha() {
+                DynamicImage::ImageRgba8(image.to_rgba8())
+            } else {
+                DynamicImage::ImageRgb8(image.to_rgb8())
+            };
+            let mut encoder = WebPEncoder::new_lossless(&mut bytes);
+            if let Some(icc) = icc_profile {
+                let _ = encoder.set_icc_profile(icc);
+            }
+            image.write_with_encoder(encoder)
+        }
+        VariantFormat::Png => {
+            let mut encoder = PngEncoder::new(&mut bytes);
+            if let Some(icc) = icc_profile {
+                let _ = encoder.set_icc_profile(icc);
+            }
+            image.write_with_encoder(encoder)
+        }
+    };
+    result.map_err(|e| format!("Failed to encode {}: {}", format.mime_type(), e))?;
+
+    Ok(bytes)
+}
+
+/// `srcset` attribute value from `(url, width)` candidates, narrowest first.
+pub fn srcset<'a>(candidates: impl IntoIterator<Item = (&'a str, i32)>) -> String {
+    let mut candidates: Vec<(&str, i32)> = candidates.into_iter().collect();
+    candidates.sort_by_key(|(_, width)| *width);
+    candidates
+        .iter()
+        .map(|(url, width)| format!("{} {}w", url, width))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    #[test]
+    fn test_parse_variant_widths() {
+        assert_eq!(parse_variant_widths("1600, 320,768,320"), Ok(vec![320, 768, 1600]));
+        assert_eq!(parse_variant_widths(""), Ok(vec![]));
+        assert!(parse_variant_widths("320,abc").is_err());
+        assert!(parse_variant_widths("0").is_err());
+        assert!(parse_variant_widths("10000").is_err());
+    }
+
+    #[test]
+    fn test_variant_widths_never_upscale() {
+        assert_eq!(variant_widths(1000, &[320, 768, 1600]), vec![320, 768]);
+        assert_eq!(variant_widths(768, &[320, 768]), vec![320]);
+        assert!(variant_widths(200, &DEFAULT_VARIANT_WIDTHS).is_empty());
+    }
+
+    #[test]
+    fn test_variant_formats_keep_original_fallback() {
+        assert_eq!(variant_formats("image/jpeg"), vec![VariantFormat::WebP, VariantFormat::Jpeg]);
+        assert_eq!(variant_formats("image/png"), vec![VariantFormat::WebP, VariantFormat::Png]);
+        assert_eq!(variant_formats("image/gif"), vec![VariantFormat::WebP]);
+        assert_eq!(variant_formats("image/webp"), vec![VariantFormat::WebP]);
+    }
+
+    #[test]
+    fn test_encode_variant_keeps_aspect_ratio() {
+        let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(1000, 500, Rgba([10, 20, 30, 128])));
+
+        for format in [VariantFormat::WebP, VariantFormat::Jpeg, VariantFormat::Png] {
+            let variant = encode_variant(&image, 320, format).unwrap();
+            assert_eq!((variant.width, variant.height), (320, 160));
+
+            let decoded = image::load_from_memory(&variant.bytes).unwrap();
+            assert_eq!((decoded.width(), decoded.height()), (320, 160));
+            assert_eq!(image::guess_format(&variant.bytes).unwrap().to_mime_type(), format.mime_type());
+        }
+    }
+
+    #[test]
+    fn test_srcset_orders_by_width() {
+        assert_eq!(
+            srcset([("https://cdn/a_w768.webp", 768), ("https://cdn/a_w320.webp", 320)]),
+            "https://cdn/a_w320.webp 320w, https://cdn/a_w768.webp 768w"
+        );
+        assert_eq!(variant_filename("abc", 320, VariantFormat::WebP), "abc_w320.webp");
+    }
+}
@@ -0,0 +1,173 @@
+use serde::Serialize;
+
+/// Schema the documents below conform to (https://jsonresume.org/schema).
+pub const JSON_RESUME_SCHEMA: &str = "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json";
+pub const JSON_RESUME_VERSION: &str = "v1.0.0";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Resume {
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+    pub basics: ResumeBasics,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub work: Vec<ResumeWork>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<ResumeProject>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<ResumeSkill>,
+    pub meta: ResumeMeta,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResumeBasics {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    pub profiles: Vec<ResumeProfile>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResumeProfile {
+    pub network: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeWork {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeProject {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    /// Category name, e.g. "Talks" or "Open source"
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResumeSkill {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    pub keywords: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeMeta {
+    pub canonical: String,
+    pub version: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+}
+
+/// Last path segment of a profile link, without a leading `@` ("https://github.com/ada/" -> "ada").
+pub fn social_username(url: &str) -> Option<String> {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let path = without_scheme.split(['?', '#']).next().unwrap_or_default();
+    let (_, path) = path.split_once('/')?;
+    path.rsplit('/')
+        .find(|segment| !segment.is_empty())
+        .map(|segment| segment.trim_start_matches('@').to_string())
+        .filter(|segment| !segment.is_empty())
+}
+
+/// Skill levels are stored as numbers; whole values drop their fraction ("4", "3.5").
+pub fn skill_level(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_social_username() {
+        assert_eq!(social_username("https://github.com/ada/").as_deref(), Some("ada"));
+        assert_eq!(social_username("https://www.linkedin.com/in/ada-lovelace?trk=x").as_deref(), Some("ada-lovelace"));
+        assert_eq!(social_username("https://mastodon.social/@ada").as_deref(), Some("ada"));
+        assert_eq!(social_username("https://example.com"), None);
+        assert_eq!(social_username("https://example.com/"), None);
+    }
+
+    #[test]
+    fn test_skill_level() {
+        assert_eq!(skill_level(4.0), "4");
+        assert_eq!(skill_level(3.5), "3.5");
+    }
+
+    #[test]
+    fn test_resume_serializes_with_json_resume_field_names() {
+        let resume = Resume {
+            schema: JSON_RESUME_SCHEMA,
+            basics: ResumeBasics {
+                name: "Ada".to_string(),
+                label: None,
+                image: None,
+                email: None,
+                url: "https://example.com/profiles/p1".to_string(),
+                summary: None,
+                profiles: vec![],
+            },
+            work: vec![],
+            projects: vec![ResumeProject {
+                name: "Engine".to_string(),
+                description: None,
+                url: "https://example.com/profiles/p1/performances/engine".to_string(),
+                start_date: Some("1843-01-01".to_string()),
+                end_date: None,
+                keywords: vec![],
+                kind: "Papers".to_string(),
+            }],
+            skills: vec![],
+            meta: ResumeMeta {
+                canonical: "https://api.example.com/profiles/p1/resume.json".to_string(),
+                version: JSON_RESUME_VERSION,
+                last_modified: None,
+            },
+        };
+
+        let value = serde_json::to_value(&resume).unwrap();
+        assert_eq!(value["$schema"], JSON_RESUME_SCHEMA);
+        assert_eq!(value["projects"][0]["startDate"], "1843-01-01");
+        assert_eq!(value["projects"][0]["type"], "Papers");
+        assert!(value.get("work").is_none());
+        assert!(value["basics"].get("label").is_none());
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use pulldown_cmark::{Event, Parser, Tag};
+use crate::shared::utils::content_analysis::attribute_value;
+use crate::shared::utils::markdown_renderer::markdown_options;
+use crate::shared::utils::zip_archive::{read_entries, ArchiveLimits};
+
+#[derive(Debug, Clone)]
+pub struct ArchiveDocument {
+    /// Path inside the archive, `/` separated
+    pub path: String,
+    pub source: String,
+}
+
+/// Markdown documents and every other file of an archive, keyed by their path inside it.
+#[derive(Debug, Clone, Default)]
+pub struct MarkdownArchive {
+    pub documents: Vec<ArchiveDocument>,
+    pub files: HashMap<String, Vec<u8>>,
+}
+
+fn is_markdown(path: &str) -> bool {
+    let lower = path.to_ascii_lowercase();
+    lower.ends_with(".md") || lower.ends_with(".markdown")
+}
+
+/// Unpacks a zip of markdown documents and the files they reference.
+pub fn read_archive(bytes: &[u8], limits: ArchiveLimits) -> Result<MarkdownArchive, String> {
+    let mut archive = MarkdownArchive::default();
+
+    for (path, data) in read_entries(bytes, limits)? {
+        if is_markdown(&path) {
+            archive.documents.push(ArchiveDocument {
+                source: String::from_utf8_lossy(&data).into_owned(),
+                path,
+            });
+        } else {
+            archive.files.insert(path, data);
+        }
+    }
+
+    archive.documents.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(archive)
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = (bytes[i] == b'%')
+            .then(|| value.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match hex {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Resolves a link found in `document_path` to a path inside the archive.
+/// Returns `None` for external URLs, anchors and links that climb above the archive root.
+pub fn resolve_local_link(document_path: &str, link: &str) -> Option<String> {
+    let link = link.trim();
+    let has_scheme = link
+        .split_once(':')
+        .is_some_and(|(scheme, _)| !scheme.is_empty() && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c)));
+    if link.is_empty() || has_scheme || link.starts_with("//") || link.starts_with('#') {
+        return None;
+    }
+
+    let link = percent_decode(link.split(['?', '#']).next().unwrap_or_default());
+    let mut segments: Vec<&str> = match link.strip_prefix('/') {
+        Some(_) => Vec::new(),
+        None => {
+            let mut base: Vec<&str> = document_path.split('/').collect();
+            base.pop();
+            base
+        }
+    };
+    for segment in link.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            _ => segments.push(segment),
+        }
+    }
+
+    (!segments.is_empty()).then(|| segments.join("/"))
+}
+
+/// Byte range of every image destination in `markdown`, paired with the destination text.
+/// Covers inline images, reference definitions and `<img src>` in raw HTML.
+fn image_destinations(markdown: &str) -> Vec<(Range<usize>, String)> {
+    let mut destinations = Vec::new();
+    let parser = Parser::new_ext(markdown, markdown_options()).into_offset_iter();
+
+    for (_, def) in parser.reference_definitions().iter() {
+        let span = &markdown[def.span.clone()];
+        let after_label = span.find("]:").map_or(0, |i| i + 2);
+        if let Some(found) = span[after_label..].find(def.dest.as_ref()) {
+            let start = def.span.start + after_label + found;
+            destinations.push((start..start + def.dest.len(), def.dest.to_string()));
+        }
+    }
+
+    for (event, range) in parser {
+        match event {
+            Event::Start(Tag::Image { dest_url, .. }) => {
+                // Reference-style images have no "](" and were covered by their definition above
+                let source = &markdown[range.clone()];
+                let Some(open) = source.find("](").map(|i| i + 2) else {
+                    continue;
+                };
+                if let Some(found) = source[open..].find(dest_url.as_ref()) {
+                    let start = range.start + open + found;
+                    destinations.push((start..start + dest_url.len(), dest_url.to_string()));
+                }
+            }
+            Event::Html(_) | Event::InlineHtml(_) => {
+                let html = &markdown[range.clone()];
+                let lower = html.to_ascii_lowercase();
+                let mut cursor = 0;
+                while let Some(found) = lower[cursor..].find("<img") {
+                    let tag_start = cursor + found + "<img".len();
+                    let tag_end = lower[tag_start..].find('>').map_or(html.len(), |i| tag_start + i);
+                    if let Some(src) = attribute_value(&html[tag_start..tag_end], "src").filter(|s| !s.is_empty()) {
+                        let start = range.start + (src.as_ptr() as usize - html.as_ptr() as usize);
+                        destinations.push((start..start + src.len(), src.to_string()));
+                    }
+                    cursor = tag_end;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    destinations.sort_by_key(|(range, _)| range.start);
+    destinations.dedup_by_key(|(range, _)| range.start);
+    destinations
+}
+
+/// Distinct image destinations in document order.
+pub fn image_links(markdown: &str) -> Vec<String> {
+    let mut links: Vec<String> = Vec::new();
+    for (_, link) in image_destinations(markdown) {
+        if !links.contains(&link) {
+            links.push(link);
+        }
+    }
+    links
+}
+
+/// Replaces image destinations found in `replacements`, leaving the rest of the document byte-for-byte intact.
+pub fn rewrite_image_links(markdown: &str, replacements: &HashMap<String, String>) -> String {
+    let mut rewritten = String::with_capacity(markdown.len());
+    let mut cursor = 0;
+
+    for (range, link) in image_destinations(markdown) {
+        if let Some(replacement) = replacements.get(&link) {
+            rewritten.push_str(&markdown[cursor..range.start]);
+            rewritten.push_str(replacement);
+            cursor = range.end;
+        }
+    }
+    rewritten.push_str(&markdown[cursor..]);
+    rewritten
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::zip_archive::write_entries;
+
+    const LIMITS: ArchiveLimits = ArchiveLimits { max_entries: 10, max_total_bytes: 1024 };
+
+    #[test]
+    fn test_read_archive_splits_documents_and_files() {
+        let bytes = write_entries([
+            ("posts/b.md", b"# B".as_slice()),
+            ("posts/a.markdown", b"# A".as_slice()),
+            ("posts/img/cat.png", b"png".as_slice()),
+        ])
+        .unwrap();
+        let archive = read_archive(&bytes, LIMITS).unwrap();
+
+        let paths: Vec<&str> = archive.documents.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(paths, vec!["posts/a.markdown", "posts/b.md"]);
+        assert_eq!(archive.files.keys().collect::<Vec<_>>(), vec!["posts/img/cat.png"]);
+    }
+
+    #[test]
+    fn test_resolve_local_link() {
+        assert_eq!(resolve_local_link("posts/a.md", "img/cat.png").as_deref(), Some("posts/img/cat.png"));
+        assert_eq!(resolve_local_link("posts/a.md", "./../shared/My%20Cat.png?x=1").as_deref(), Some("shared/My Cat.png"));
+        assert_eq!(resolve_local_link("posts/a.md", "/img/cat.png").as_deref(), Some("img/cat.png"));
+        assert_eq!(resolve_local_link("a.md", "../cat.png"), None);
+        assert_eq!(resolve_local_link("a.md", "https://example.com/cat.png"), None);
+        assert_eq!(resolve_local_link("a.md", "data:image/png;base64,AAAA"), None);
+    }
+
+    #[test]
+    fn test_image_links_cover_inline_reference_and_html() {
+        let markdown = "![a](img/a.png \"A\")\n\n![b][logo]\n\n<img src=\"img/c.png\" alt=\"c\">\n\n[logo]: img/b.png\n\n![a again](img/a.png)";
+        assert_eq!(image_links(markdown), vec!["img/a.png", "img/c.png", "img/b.png"]);
+    }
+
+    #[test]
+    fn test_rewrite_image_links_only_touches_destinations() {
+        let markdown = "Text about img/a.png.\n\n![img/a.png](img/a.png)\n\n<img src='img/a.png'>\n\n![ext](https://example.com/x.png)\n";
+        let replacements = HashMap::from([("img/a.png".to_string(), "https://cdn/a.png".to_string())]);
+
+        assert_eq!(
+            rewrite_image_links(markdown, &replacements),
+            "Text about img/a.png.\n\n![img/a.png](https://cdn/a.png)\n\n<img src='https://cdn/a.png'>\n\n![ext](https://example.com/x.png)\n"
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag, TagEnd};
+use serde::Serialize;
+
+/// Average silent reading speed used to estimate `reading_time_minutes`.
+const WORDS_PER_MINUTE: usize = 200;
+
+/// Allowlist sanitizer applied to every rendered document.
+/// Starts from ammonia's defaults and only adds what the renderer itself emits:
+/// heading anchors, fenced-code language classes and GFM task-list checkboxes.
+static SANITIZER: LazyLock<ammonia::Builder<'static>> = LazyLock::new(|| {
+    let mut builder = ammonia::Builder::default();
+    builder
+        .add_tags(&["input"])
+        .add_tag_attributes("h1", &["id"])
+        .add_tag_attributes("h2", &["id"])
+        .add_tag_attributes("h3", &["id"])
+        .add_tag_attributes("h4", &["id"])
+        .add_tag_attributes("h5", &["id"])
+        .add_tag_attributes("h6", &["id"])
+        .add_tag_attributes("code", &["class"])
+        .add_tag_attributes("input", &["type", "checked", "disabled"])
+        .attribute_filter(|element, attribute, value| {
+            if element == "input" && attribute == "type" && value != "checkbox" {
+                return None;
+            }
+            if element == "code" && attribute == "class" && !value.starts_with("language-") {
+                return None;
+            }
+            Some(value.into())
+        });
+    builder
+});
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TocEntry {
+    pub level: u8,
+    pub id: String,
+    pub text: String,
+    pub children: Vec<TocEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RenderedMarkdown {
+    pub html: String,
+    pub toc: Vec<TocEntry>,
+    pub word_count: usize,
+    pub reading_time_minutes: usize,
+}
+
+/// CommonMark plus the GitHub Flavored Markdown extensions we support.
+pub fn markdown_options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_GFM
+}
+
+/// Converts heading text into a URL fragment, GitHub style:
+/// lowercase, whitespace becomes `-`, ASCII punctuation is dropped and
+/// non-ASCII letters (e.g. Thai) are kept as-is.
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    let mut pending_dash = false;
+
+    for c in text.trim().chars().flat_map(char::to_lowercase) {
+        if c.is_whitespace() || c == '-' || c == '_' {
+            pending_dash = !slug.is_empty();
+        } else if c.is_ascii_punctuation() || c.is_control() {
+            continue;
+        } else {
+            if pending_dash {
+                slug.push('-');
+                pending_dash = false;
+            }
+            slug.push(c);
+        }
+    }
+
+    slug
+}
+
+/// Hands out unique anchor ids within one document ("intro", "intro-1", ...).
+#[derive(Default)]
+pub struct AnchorIds {
+    seen: HashMap<String, usize>,
+}
+
+impl AnchorIds {
+    pub fn next(&mut self, text: &str) -> String {
+        let mut base = slugify(text);
+        if base.is_empty() {
+            base = "section".to_string();
+        }
+
+        match self.seen.get_mut(&base) {
+            Some(count) => {
+                *count += 1;
+                format!("{}-{}", base, count)
+            }
+            None => {
+                self.seen.insert(base.clone(), 0);
+                base
+            }
+        }
+    }
+}
+
+/// Renders markdown to sanitized HTML and extracts the heading tree,
+/// word count and estimated reading time in the same pass.
+pub fn render_markdown(content: &str) -> RenderedMarkdown {
+    let mut events: Vec<Event> = Parser::new_ext(content, markdown_options()).collect();
+
+    let mut anchors = AnchorIds::default();
+    let mut flat_toc = Vec::new();
+    let mut word_count = 0;
+    let mut open_heading: Option<(usize, String)> = None;
+
+    for idx in 0..events.len() {
+        match &events[idx] {
+            Event::Start(Tag::Heading { .. }) => {
+                open_heading = Some((idx, String::new()));
+            }
+            Event::Text(text) | Event::Code(text) => {
+                word_count += text.split_whitespace().count();
+                if let Some((_, heading_text)) = open_heading.as_mut() {
+                    heading_text.push_str(text);
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((start_idx, text)) = open_heading.take() {
+                    let id = anchors.next(&text);
+                    if let Event::Start(Tag::Heading { level, .. }) = events[start_idx] {
+                        events[start_idx] = Event::Start(Tag::Heading {
+                            level,
+                            id: Some(CowStr::from(id.clone())),
+                            classes: vec![],
+                            attrs: vec![],
+                        });
+                        flat_toc.push(TocEntry {
+                            level: level as u8,
+                            id,
+                            text: text.trim().to_string(),
+                            children: vec![],
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut raw_html = String::with_capacity(content.len() * 3 / 2);
+    html::push_html(&mut raw_html, events.into_iter());
+
+    RenderedMarkdown {
+        html: SANITIZER.clean(&raw_html).to_string(),
+        toc: build_toc(flat_toc),
+        word_count,
+        reading_time_minutes: word_count.div_ceil(WORDS_PER_MINUTE),
+    }
+}
+
+fn build_toc(flat: Vec<TocEntry>) -> Vec<TocEntry> {
+    let mut root = Vec::new();
+    for entry in flat {
+        insert_toc_entry(&mut root, entry);
+    }
+    root
+}
+
+fn insert_toc_entry(siblings: &mut Vec<TocEntry>, entry: TocEntry) {
+    match siblings.last_mut() {
+        Some(last) if last.level < entry.level => insert_toc_entry(&mut last.children, entry),
+        _ => siblings.push(entry),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_markdown_builds_nested_toc_with_unique_ids() {
+        let rendered = render_markdown("# Intro\n\n## Setup\n\n## Setup\n\n# Result\n");
+
+        assert_eq!(rendered.toc.len(), 2);
+        assert_eq!(rendered.toc[0].id, "intro");
+        assert_eq!(rendered.toc[0].children[0].id, "setup");
+        assert_eq!(rendered.toc[0].children[1].id, "setup-1");
+        assert!(rendered.html.contains("<h2 id=\"setup-1\">"));
+    }
+
+    #[test]
+    fn test_render_markdown_strips_scripts_and_event_handlers() {
+        let rendered = render_markdown("<script>alert(1)</script>\n\n<img src=\"x.png\" onerror=\"alert(1)\">\n\n[x](javascript:alert(1))");
+
+        assert!(!rendered.html.contains("<script"));
+        assert!(!rendered.html.contains("onerror"));
+        assert!(!rendered.html.contains("javascript:"));
+    }
+
+    #[test]
+    fn test_render_markdown_supports_gfm() {
+        let rendered = render_markdown("- [x] done\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\n~~old~~\n\n```rust\nfn main() {}\n```");
+
+        assert!(rendered.html.contains("<table>"));
+        assert!(rendered.html.contains("type=\"checkbox\""));
+        assert!(rendered.html.contains("<del>"));
+        assert!(rendered.html.contains("class=\"language-rust\""));
+    }
+
+    #[test]
+    fn test_slugify_keeps_non_ascii_letters() {
+        assert_eq!(slugify("Hello, World!"), "hello-world");
+        assert_eq!(slugify("  ผลงาน ล่าสุด "), "ผลงาน-ล่าสุด");
+    }
+
+    #[test]
+    fn test_reading_time_rounds_up() {
+        let words = "word ".repeat(201);
+        let rendered = render_markdown(&words);
+        assert_eq!(rendered.word_count, 201);
+        assert_eq!(rendered.reading_time_minutes, 2);
+    }
+}
@@ -1 +1,20 @@
-pub mod markdown;
+pub mod content_analysis;
+pub mod front_matter;
+pub mod markdown_archive;
+pub mod markdown_renderer;
+pub mod hash;
+pub mod image_metadata;
+pub mod image_placeholder;
+pub mod image_upload;
+pub mod image_transform;
+pub mod image_variants;
+pub mod json_resume;
+pub mod og_card;
+pub mod feed;
+pub mod http_cache;
+pub mod public_url;
+pub mod seo_meta;
+pub mod sitemap;
+pub mod vcard;
+pub mod xml;
+pub mod zip_archive;
@@ -0,0 +1,296 @@
+use std::io::Cursor;
+use ab_glyph::{point, Font, FontRef, PxScale, ScaleFont};
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageFormat, Rgb, RgbImage};
+use crate::shared::utils::hash::sha256_hex;
+
+/// Size link unfurlers expect for `summary_large_image` / `og:image`.
+pub const OG_CARD_WIDTH: u32 = 1200;
+pub const OG_CARD_HEIGHT: u32 = 630;
+
+/// Bumped whenever the drawing below changes, so cached cards get regenerated.
+const LAYOUT_VERSION: &str = "1";
+
+const FONT_BOLD: &[u8] = include_bytes!("../../../resource/fonts/DejaVuSans-Bold.ttf");
+const FONT_REGULAR: &[u8] = include_bytes!("../../../resource/fonts/DejaVuSans.ttf");
+
+const MARGIN: f32 = 80.0;
+const ACCENT_WIDTH: u32 = 16;
+const TITLE_SIZE: f32 = 64.0;
+const TITLE_LINE_HEIGHT: f32 = 78.0;
+const TITLE_MAX_LINES: usize = 4;
+const LABEL_SIZE: f32 = 30.0;
+const NAME_SIZE: f32 = 36.0;
+
+const BACKGROUND: [u8; 3] = [0x11, 0x18, 0x27];
+const DEFAULT_ACCENT: [u8; 3] = [0x1e, 0x88, 0xe5];
+const TEXT: [u8; 3] = [0xff, 0xff, 0xff];
+const MUTED_TEXT: [u8; 3] = [0xd1, 0xd5, 0xdb];
+/// Share of the cover that stays visible under the background tint, keeping the text readable
+const COVER_OPACITY: f32 = 0.35;
+
+/// Everything drawn on a card. `cover_id` identifies the cover image so the cache key
+/// changes with it without the image bytes having to be fetched.
+#[derive(Debug, Clone, Default)]
+pub struct OgCardSpec {
+    pub title: String,
+    pub profile_name: String,
+    pub category_name: Option<String>,
+    pub category_color: Option<String>,
+    pub cover_id: Option<String>,
+}
+
+/// Stable digest of the card inputs, used as storage key and ETag.
+pub fn og_card_key(spec: &OgCardSpec) -> String {
+    let fields = [
+        LAYOUT_VERSION,
+        spec.title.trim(),
+        spec.profile_name.trim(),
+        spec.category_name.as_deref().unwrap_or_default(),
+        spec.category_color.as_deref().unwrap_or_default(),
+        spec.cover_id.as_deref().unwrap_or_default(),
+    ];
+    sha256_hex(fields.join("\0").as_bytes())
+}
+
+/// Parses `#rgb` / `#rrggbb` (with or without `#`). Design-token names yield `None`.
+pub fn parse_hex_color(value: &str) -> Option<[u8; 3]> {
+    let hex = value.trim().trim_start_matches('#');
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+    match hex.len() {
+        3 => {
+            let mut rgb = [0u8; 3];
+            for (i, c) in hex.chars().enumerate() {
+                rgb[i] = channel(&c.to_string())? * 17;
+            }
+            Some(rgb)
+        }
+        6 => Some([channel(&hex[0..2])?, channel(&hex[2..4])?, channel(&hex[4..6])?]),
+        _ => None,
+    }
+}
+
+fn text_width<F: Font>(font: &F, scale: PxScale, text: &str) -> f32 {
+    let scaled = font.as_scaled(scale);
+    let mut width = 0.0;
+    let mut previous = None;
+    for c in text.chars() {
+        let id = scaled.glyph_id(c);
+        if let Some(prev) = previous {
+            width += scaled.kern(prev, id);
+        }
+        width += scaled.h_advance(id);
+        previous = Some(id);
+    }
+    width
+}
+
+/// `text` itself when it fits, otherwise `text…` with characters dropped from the end until it does.
+fn fit_line<F: Font>(font: &F, scale: PxScale, text: &str, max_width: f32) -> String {
+    if text_width(font, scale, text) <= max_width {
+        return text.to_string();
+    }
+    with_ellipsis(font, scale, text, max_width)
+}
+
+/// Drops characters from the end until `text…` fits.
+fn with_ellipsis<F: Font>(font: &F, scale: PxScale, text: &str, max_width: f32) -> String {
+    let mut chars: Vec<char> = text.trim_end().chars().collect();
+    loop {
+        let candidate = format!("{}…", chars.iter().collect::<String>().trim_end());
+        if chars.is_empty() || text_width(font, scale, &candidate) <= max_width {
+            return candidate;
+        }
+        chars.pop();
+    }
+}
+
+/// Greedy word wrap. Words wider than a line are broken between characters and the
+/// last line gets an ellipsis when the text doesn't fit in `max_lines`.
+fn wrap_text<F: Font>(font: &F, scale: PxScale, text: &str, max_width: f32, max_lines: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() { word.to_string() } else { format!("{} {}", current, word) };
+        if text_width(font, scale, &candidate) <= max_width {
+            current = candidate;
+            continue;
+        }
+        if !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        for c in word.chars() {
+            current.push(c);
+            if text_width(font, scale, &current) > max_width {
+                current.pop();
+                lines.push(std::mem::replace(&mut current, c.to_string()));
+            }
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    if lines.len() > max_lines {
+        lines.truncate(max_lines);
+        let last = lines.pop().unwrap_or_default();
+        lines.push(with_ellipsis(font, scale, &last, max_width));
+    }
+    lines
+}
+
+fn blend(canvas: &mut RgbImage, x: u32, y: u32, color: [u8; 3], coverage: f32) {
+    if x >= canvas.width() || y >= canvas.height() {
+        return;
+    }
+    let coverage = coverage.clamp(0.0, 1.0);
+    let pixel = canvas.get_pixel_mut(x, y);
+    for i in 0..3 {
+        pixel[i] = (pixel[i] as f32 * (1.0 - coverage) + color[i] as f32 * coverage).round() as u8;
+    }
+}
+
+/// Draws a single line with its baseline at `baseline`.
+fn draw_text<F: Font>(canvas: &mut RgbImage, font: &F, scale: PxScale, x: f32, baseline: f32, text: &str, color: [u8; 3]) {
+    let scaled = font.as_scaled(scale);
+    let mut caret = x;
+    let mut previous = None;
+    for c in text.chars() {
+        let id = scaled.glyph_id(c);
+        if let Some(prev) = previous {
+            caret += scaled.kern(prev, id);
+        }
+        let glyph = id.with_scale_and_position(scale, point(caret, baseline));
+        caret += scaled.h_advance(id);
+        previous = Some(id);
+
+        if let Some(outlined) = font.outline_glyph(glyph) {
+            let bounds = outlined.px_bounds();
+            outlined.draw(|gx, gy, coverage| {
+                let px = bounds.min.x + gx as f32;
+                let py = bounds.min.y + gy as f32;
+                if px >= 0.0 && py >= 0.0 {
+                    blend(canvas, px as u32, py as u32, color, coverage);
+                }
+            });
+        }
+    }
+}
+
+fn fill_rect(canvas: &mut RgbImage, x: u32, y: u32, width: u32, height: u32, color: [u8; 3]) {
+    for py in y..(y + height).min(canvas.height()) {
+        for px in x..(x + width).min(canvas.width()) {
+            canvas.put_pixel(px, py, Rgb(color));
+        }
+    }
+}
+
+/// Renders the card as PNG: optional tinted cover, category accent bar and label,
+/// the wrapped title and the profile name at the bottom.
+pub fn render_og_card(spec: &OgCardSpec, cover: Option<&DynamicImage>) -> Result<Vec<u8>, String> {
+    let bold = FontRef::try_from_slice(FONT_BOLD).map_err(|e| format!("Invalid bundled font: {}", e))?;
+    let regular = FontRef::try_from_slice(FONT_REGULAR).map_err(|e| format!("Invalid bundled font: {}", e))?;
+    let accent = spec.category_color.as_deref().and_then(parse_hex_color).unwrap_or(DEFAULT_ACCENT);
+
+    let mut canvas = match cover {
+        Some(cover) => {
+            let mut canvas = cover.resize_to_fill(OG_CARD_WIDTH, OG_CARD_HEIGHT, FilterType::Triangle).to_rgb8();
+            for pixel in canvas.pixels_mut() {
+                for i in 0..3 {
+                    pixel[i] = (pixel[i] as f32 * COVER_OPACITY + BACKGROUND[i] as f32 * (1.0 - COVER_OPACITY)).round() as u8;
+                }
+            }
+            canvas
+        }
+        None => RgbImage::from_pixel(OG_CARD_WIDTH, OG_CARD_HEIGHT, Rgb(BACKGROUND)),
+    };
+
+    fill_rect(&mut canvas, 0, 0, ACCENT_WIDTH, OG_CARD_HEIGHT, accent);
+
+    let max_width = OG_CARD_WIDTH as f32 - 2.0 * MARGIN;
+    let mut top = MARGIN;
+    if let Some(category) = spec.category_name.as_deref().map(str::trim).filter(|c| !c.is_empty()) {
+        let scale = PxScale::from(LABEL_SIZE);
+        let label = fit_line(&bold, scale, &category.to_uppercase(), max_width);
+        top += LABEL_SIZE;
+        draw_text(&mut canvas, &bold, scale, MARGIN, top, &label, accent);
+        top += 40.0;
+    }
+
+    let title_scale = PxScale::from(TITLE_SIZE);
+    for line in wrap_text(&bold, title_scale, spec.title.trim(), max_width, TITLE_MAX_LINES) {
+        top += TITLE_LINE_HEIGHT;
+        draw_text(&mut canvas, &bold, title_scale, MARGIN, top, &line, TEXT);
+    }
+
+    let name_scale = PxScale::from(NAME_SIZE);
+    let name = fit_line(&regular, name_scale, spec.profile_name.trim(), max_width);
+    draw_text(&mut canvas, &regular, name_scale, MARGIN, OG_CARD_HEIGHT as f32 - MARGIN, &name, MUTED_TEXT);
+
+    let mut png = Vec::new();
+    DynamicImage::ImageRgb8(canvas)
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|e| format!("Failed to encode card: {}", e))?;
+    Ok(png)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec() -> OgCardSpec {
+        OgCardSpec {
+            title: "Building an analytical engine".to_string(),
+            profile_name: "Ada Lovelace".to_string(),
+            category_name: Some("Talks".to_string()),
+            category_color: Some("#e53935".to_string()),
+            cover_id: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_hex_color() {
+        assert_eq!(parse_hex_color("#1e88e5"), Some([0x1e, 0x88, 0xe5]));
+        assert_eq!(parse_hex_color("FFF"), Some([255, 255, 255]));
+        assert_eq!(parse_hex_color("primary-500"), None);
+        assert_eq!(parse_hex_color("#12345"), None);
+    }
+
+    #[test]
+    fn test_key_changes_with_title_and_cover() {
+        let base = og_card_key(&spec());
+        assert_eq!(base, og_card_key(&spec()));
+        assert_ne!(base, og_card_key(&OgCardSpec { title: "Other".to_string(), ..spec() }));
+        assert_ne!(base, og_card_key(&OgCardSpec { cover_id: Some("img-1".to_string()), ..spec() }));
+    }
+
+    #[test]
+    fn test_wrap_text_truncates_with_ellipsis() {
+        let font = FontRef::try_from_slice(FONT_BOLD).unwrap();
+        let scale = PxScale::from(TITLE_SIZE);
+        let text = "word ".repeat(100);
+
+        let lines = wrap_text(&font, scale, &text, 500.0, 3);
+        assert_eq!(lines.len(), 3);
+        assert!(lines[2].ends_with('…'));
+        assert!(lines.iter().all(|l| text_width(&font, scale, l) <= 500.0));
+
+        let long_word = wrap_text(&font, scale, &"x".repeat(60), 500.0, 10);
+        assert!(long_word.len() > 1);
+        assert_eq!(long_word.concat(), "x".repeat(60));
+    }
+
+    #[test]
+    fn test_render_og_card_produces_png_of_card_size() {
+        let cover = DynamicImage::ImageRgb8(RgbImage::from_pixel(300, 200, Rgb([200, 100, 50])));
+        for cover in [None, Some(&cover)] {
+            let png = render_og_card(&spec(), cover).unwrap();
+            let decoded = image::load_from_memory_with_format(&png, ImageFormat::Png).unwrap();
+            assert_eq!((decoded.width(), decoded.height()), (OG_CARD_WIDTH, OG_CARD_HEIGHT));
+        }
+    }
+}
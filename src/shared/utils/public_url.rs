@@ -0,0 +1,35 @@
+/// Builds absolute URLs for links we hand out to the outside world (feeds, sitemaps, metadata).
+/// `site_url` is the public website, `api_url` is this service as reachable from the internet.
+#[derive(Debug, Clone)]
+pub struct PublicUrls {
+    site_url: String,
+    api_url: String,
+}
+
+impl PublicUrls {
+    pub fn new(site_url: &str, api_url: &str) -> Self {
+        Self {
+            site_url: site_url.trim_end_matches('/').to_string(),
+            api_url: api_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn site_url(&self) -> &str {
+        &self.site_url
+    }
+
+    /// Website page of a profile
+    pub fn profile_page(&self, profile_id: &str) -> String {
+        format!("{}/profiles/{}", self.site_url, profile_id)
+    }
+
+    /// Website page of a single performance
+    pub fn performance_page(&self, profile_id: &str, slug: &str) -> String {
+        format!("{}/profiles/{}/performances/{}", self.site_url, profile_id, slug)
+    }
+
+    /// Absolute URL of an endpoint of this API, `path` starting with `/`
+    pub fn api(&self, path: &str) -> String {
+        format!("{}{}", self.api_url, path)
+    }
+}
@@ -0,0 +1,107 @@
+use serde::Serialize;
+use serde_json::Value;
+use crate::shared::utils::xml::xml_escape;
+
+/// Longest description handed to link unfurlers; most cut off well before this.
+pub const META_DESCRIPTION_MAX_CHARS: usize = 200;
+
+/// One `<meta>` tag. OpenGraph uses `property`, Twitter cards and robots use `name`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetaTag {
+    pub attribute: &'static str,
+    pub key: String,
+    pub content: String,
+}
+
+impl MetaTag {
+    pub fn property(key: &str, content: impl Into<String>) -> Self {
+        Self { attribute: "property", key: key.to_string(), content: content.into() }
+    }
+
+    pub fn name(key: &str, content: impl Into<String>) -> Self {
+        Self { attribute: "name", key: key.to_string(), content: content.into() }
+    }
+}
+
+/// Page metadata ready to be placed in a document `<head>`.
+#[derive(Debug, Clone)]
+pub struct PageMeta {
+    pub title: String,
+    pub canonical_url: String,
+    pub tags: Vec<MetaTag>,
+    pub json_ld: Value,
+}
+
+/// Serializes JSON-LD for a `<script>` element; `<` is escaped so the data can't close the tag.
+pub fn json_ld_script(json_ld: &Value) -> String {
+    let json = json_ld.to_string().replace('<', "\\u003c");
+    format!("<script type=\"application/ld+json\">{}</script>", json)
+}
+
+/// `<title>`, canonical link, meta tags and JSON-LD, one element per line.
+pub fn render_head(meta: &PageMeta) -> String {
+    let mut lines = vec![
+        format!("<title>{}</title>", xml_escape(&meta.title)),
+        format!("<link rel=\"canonical\" href=\"{}\">", xml_escape(&meta.canonical_url)),
+    ];
+    if let Some(description) = meta.tags.iter().find(|t| t.key == "og:description") {
+        lines.push(format!("<meta name=\"description\" content=\"{}\">", xml_escape(&description.content)));
+    }
+    lines.extend(meta.tags.iter().map(|tag| {
+        format!("<meta {}=\"{}\" content=\"{}\">", tag.attribute, xml_escape(&tag.key), xml_escape(&tag.content))
+    }));
+    lines.push(json_ld_script(&meta.json_ld));
+    lines.join("\n")
+}
+
+/// Minimal document for crawlers that don't run JavaScript: the head plus a link to the real page.
+pub fn render_html_shell(meta: &PageMeta) -> String {
+    format!(
+        "<!doctype html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n{}\n</head>\n<body>\n<a href=\"{}\">{}</a>\n</body>\n</html>\n",
+        render_head(meta),
+        xml_escape(&meta.canonical_url),
+        xml_escape(&meta.title),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn meta() -> PageMeta {
+        PageMeta {
+            title: "Ada & the Engine".to_string(),
+            canonical_url: "https://example.com/p?a=1&b=2".to_string(),
+            tags: vec![
+                MetaTag::property("og:title", "Ada & the Engine"),
+                MetaTag::property("og:description", "Says \"hi\""),
+                MetaTag::name("twitter:card", "summary"),
+            ],
+            json_ld: json!({ "@type": "Article", "headline": "</script><b>" }),
+        }
+    }
+
+    #[test]
+    fn test_render_head_escapes_attributes_and_script() {
+        let head = render_head(&meta());
+
+        assert_eq!(
+            head,
+            "<title>Ada &amp; the Engine</title>\n\
+             <link rel=\"canonical\" href=\"https://example.com/p?a=1&amp;b=2\">\n\
+             <meta name=\"description\" content=\"Says &quot;hi&quot;\">\n\
+             <meta property=\"og:title\" content=\"Ada &amp; the Engine\">\n\
+             <meta property=\"og:description\" content=\"Says &quot;hi&quot;\">\n\
+             <meta name=\"twitter:card\" content=\"summary\">\n\
+             <script type=\"application/ld+json\">{\"@type\":\"Article\",\"headline\":\"\\u003c/script>\\u003cb>\"}</script>"
+        );
+    }
+
+    #[test]
+    fn test_html_shell_links_to_canonical_page() {
+        let html = render_html_shell(&meta());
+        assert!(html.starts_with("<!doctype html>"));
+        assert!(html.contains("<a href=\"https://example.com/p?a=1&amp;b=2\">Ada &amp; the Engine</a>"));
+    }
+}
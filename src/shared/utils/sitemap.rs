@@ -0,0 +1,64 @@
+use crate::shared::utils::xml::xml_escape;
+
+/// Protocol limit of URLs per sitemap file (sitemaps.org).
+pub const SITEMAP_URL_LIMIT: usize = 50_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitemapUrl {
+    pub loc: String,
+    /// W3C date, e.g. `2025-03-02`
+    pub lastmod: Option<String>,
+}
+
+fn write_entries(xml: &mut String, tag: &str, entries: &[SitemapUrl]) {
+    for entry in entries {
+        xml.push_str(&format!("  <{}>\n    <loc>{}</loc>\n", tag, xml_escape(&entry.loc)));
+        if let Some(lastmod) = &entry.lastmod {
+            xml.push_str(&format!("    <lastmod>{}</lastmod>\n", xml_escape(lastmod)));
+        }
+        xml.push_str(&format!("  </{}>\n", tag));
+    }
+}
+
+/// A single sitemap file listing page URLs.
+pub fn urlset(urls: &[SitemapUrl]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+    write_entries(&mut xml, "url", urls);
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+/// A sitemap index pointing at other sitemap files.
+pub fn sitemap_index(sitemaps: &[SitemapUrl]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+    write_entries(&mut xml, "sitemap", sitemaps);
+    xml.push_str("</sitemapindex>\n");
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_urlset_escapes_locations() {
+        let xml = urlset(&[
+            SitemapUrl { loc: "https://example.com/a?x=1&y=2".to_string(), lastmod: Some("2025-03-02".to_string()) },
+            SitemapUrl { loc: "https://example.com/b".to_string(), lastmod: None },
+        ]);
+
+        assert!(xml.contains("<loc>https://example.com/a?x=1&amp;y=2</loc>"));
+        assert!(xml.contains("<lastmod>2025-03-02</lastmod>"));
+        assert_eq!(xml.matches("<lastmod>").count(), 1);
+    }
+
+    #[test]
+    fn test_sitemap_index_uses_sitemap_elements() {
+        let xml = sitemap_index(&[SitemapUrl { loc: "https://api.example.com/sitemaps/1".to_string(), lastmod: None }]);
+
+        assert!(xml.contains("<sitemapindex"));
+        assert!(xml.contains("<sitemap>\n    <loc>https://api.example.com/sitemaps/1</loc>\n  </sitemap>"));
+    }
+}
@@ -0,0 +1,160 @@
+/// Contact details rendered as a vCard 3.0, the version every address book imports.
+#[derive(Debug, Clone, Default)]
+pub struct VCard {
+    pub full_name: String,
+    pub title: Option<String>,
+    pub email: Option<String>,
+    pub photo_url: Option<String>,
+    pub url: Option<String>,
+    pub note: Option<String>,
+    /// `(network, url)` pairs
+    pub social_links: Vec<(String, String)>,
+    /// `YYYY-MM-DD` or a full timestamp
+    pub revision: Option<String>,
+}
+
+/// Escapes a property value per RFC 2426 (backslash, comma, semicolon, newline).
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ',' => escaped.push_str("\\,"),
+            ';' => escaped.push_str("\\;"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// URI values are not escaped, so control characters are dropped: a CR/LF in a stored link
+/// would otherwise start a property of its own.
+fn uri_value(value: &str) -> String {
+    value.trim().chars().filter(|c| !c.is_control()).collect()
+}
+
+/// Parameter values can't hold separators; anything unusual becomes `-`.
+fn param_value(value: &str) -> String {
+    value
+        .trim()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c.to_ascii_lowercase() } else { '-' })
+        .collect()
+}
+
+/// Folds a content line to 75 octets, continuation lines starting with a space, without splitting a character.
+fn fold(line: &str, out: &mut String) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+/// `N` wants family and given names; the display name is split at its last space.
+fn structured_name(full_name: &str) -> String {
+    match full_name.trim().rsplit_once(' ') {
+        Some((given, family)) => format!("{};{};;;", escape(family), escape(given.trim())),
+        None => format!(";{};;;", escape(full_name.trim())),
+    }
+}
+
+pub fn render_vcard(card: &VCard) -> String {
+    let mut lines = vec![
+        "BEGIN:VCARD".to_string(),
+        "VERSION:3.0".to_string(),
+        format!("FN:{}", escape(&card.full_name)),
+        format!("N:{}", structured_name(&card.full_name)),
+    ];
+    if let Some(title) = card.title.as_deref().filter(|t| !t.trim().is_empty()) {
+        lines.push(format!("TITLE:{}", escape(title)));
+    }
+    if let Some(email) = card.email.as_deref().filter(|e| !e.trim().is_empty()) {
+        lines.push(format!("EMAIL;TYPE=INTERNET:{}", escape(email.trim())));
+    }
+    if let Some(photo) = card.photo_url.as_deref().filter(|p| !p.trim().is_empty()) {
+        lines.push(format!("PHOTO;VALUE=uri:{}", uri_value(photo)));
+    }
+    if let Some(url) = card.url.as_deref() {
+        lines.push(format!("URL:{}", uri_value(url)));
+    }
+    for (network, link) in &card.social_links {
+        lines.push(format!("URL;TYPE={}:{}", param_value(network), uri_value(link)));
+    }
+    if let Some(note) = card.note.as_deref().filter(|n| !n.trim().is_empty()) {
+        lines.push(format!("NOTE:{}", escape(note.trim())));
+    }
+    if let Some(revision) = &card.revision {
+        lines.push(format!("REV:{}", revision));
+    }
+    lines.push("END:VCARD".to_string());
+
+    let mut out = String::new();
+    for line in &lines {
+        fold(line, &mut out);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_vcard() {
+        let card = VCard {
+            full_name: "Ada King Lovelace".to_string(),
+            title: Some("Engineer, writer; analyst".to_string()),
+            email: Some("ada@example.com".to_string()),
+            photo_url: Some("https://cdn.example.com/ada.png".to_string()),
+            url: Some("https://example.com/profiles/p1".to_string()),
+            note: Some("Line one\nLine two".to_string()),
+            social_links: vec![("GitHub".to_string(), "https://github.com/ada".to_string())],
+            revision: Some("2024-05-01".to_string()),
+        };
+
+        assert_eq!(
+            render_vcard(&card),
+            "BEGIN:VCARD\r\nVERSION:3.0\r\nFN:Ada King Lovelace\r\nN:Lovelace;Ada King;;;\r\n\
+             TITLE:Engineer\\, writer\\; analyst\r\nEMAIL;TYPE=INTERNET:ada@example.com\r\n\
+             PHOTO;VALUE=uri:https://cdn.example.com/ada.png\r\nURL:https://example.com/profiles/p1\r\n\
+             URL;TYPE=github:https://github.com/ada\r\n\
+             NOTE:Line one\\nLine two\r\nREV:2024-05-01\r\nEND:VCARD\r\n"
+        );
+    }
+
+    #[test]
+    fn test_uri_values_cannot_inject_properties() {
+        let card = VCard {
+            full_name: "Ada".to_string(),
+            photo_url: Some("https://cdn.example.com/a.png\r\nEMAIL:evil@example.com".to_string()),
+            url: Some("https://example.com/\nNOTE:injected".to_string()),
+            social_links: vec![("GitHub".to_string(), "https://github.com/ada\r\nTEL:123\t".to_string())],
+            ..VCard::default()
+        };
+        let rendered = render_vcard(&card);
+
+        assert!(rendered.contains("PHOTO;VALUE=uri:https://cdn.example.com/a.pngEMAIL:evil@example.com\r\n"));
+        assert!(rendered.contains("URL:https://example.com/NOTE:injected\r\n"));
+        assert!(rendered.contains("URL;TYPE=github:https://github.com/adaTEL:123\r\n"));
+        assert!(!rendered.split("\r\n").any(|line| line.starts_with("EMAIL") || line.starts_with("NOTE") || line.starts_with("TEL")));
+    }
+
+    #[test]
+    fn test_long_lines_are_folded_on_char_boundaries() {
+        let card = VCard { full_name: "é".repeat(60), ..VCard::default() };
+        let rendered = render_vcard(&card);
+        let fn_line: Vec<&str> = rendered.split("\r\n").skip(2).take(2).collect();
+
+        assert!(fn_line.iter().all(|l| l.len() <= 75));
+        assert!(fn_line[1].starts_with(' '));
+        assert_eq!(format!("{}{}", fn_line[0], &fn_line[1][1..]), format!("FN:{}", "é".repeat(60)));
+    }
+}
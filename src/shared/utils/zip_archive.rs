@@ -0,0 +1,115 @@
+use std::io::{Cursor, Read, Write};
+
+/// Limits applied while unpacking an uploaded archive, so a small zip can't expand into gigabytes.
+#[derive(Debug, Clone, Copy)]
+pub struct ArchiveLimits {
+    pub max_entries: usize,
+    pub max_total_bytes: u64,
+}
+
+/// Unpacks the files of a zip held in memory, as `/` separated paths with their contents.
+/// Directories, hidden files and `__MACOSX` metadata are skipped;
+/// entries whose names escape the archive root are rejected by the zip reader and skipped too.
+pub fn read_entries(bytes: &[u8], limits: ArchiveLimits) -> Result<Vec<(String, Vec<u8>)>, String> {
+    let mut zip = zip::ZipArchive::new(Cursor::new(bytes)).map_err(|e| format!("Invalid zip archive: {}", e))?;
+    if zip.len() > limits.max_entries {
+        return Err(format!("Archive has more than {} entries", limits.max_entries));
+    }
+
+    let mut entries = Vec::with_capacity(zip.len());
+    let mut total_bytes: u64 = 0;
+
+    for index in 0..zip.len() {
+        let entry = zip.by_index(index).map_err(|e| format!("Invalid zip entry: {}", e))?;
+        if entry.is_dir() {
+            continue;
+        }
+        let Some(path) = entry.enclosed_name() else {
+            continue;
+        };
+        let path = path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+            .join("/");
+        if path.split('/').any(|segment| segment.starts_with('.') || segment == "__MACOSX") {
+            continue;
+        }
+
+        // The declared size can lie, so cap what is actually read
+        let remaining = limits.max_total_bytes - total_bytes;
+        let mut data = Vec::new();
+        entry
+            .take(remaining + 1)
+            .read_to_end(&mut data)
+            .map_err(|e| format!("Failed to read '{}': {}", path, e))?;
+        total_bytes += data.len() as u64;
+        if total_bytes > limits.max_total_bytes {
+            return Err(format!("Archive expands to more than {} bytes", limits.max_total_bytes));
+        }
+
+        entries.push((path, data));
+    }
+
+    Ok(entries)
+}
+
+/// Packs files into a deflate-compressed zip, in the given order.
+pub fn write_entries<'a, I>(entries: I) -> Result<Vec<u8>, String>
+where
+    I: IntoIterator<Item = (&'a str, &'a [u8])>,
+{
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .large_file(true);
+
+    for (path, data) in entries {
+        writer.start_file(path, options).map_err(|e| format!("Failed to add '{}': {}", path, e))?;
+        writer.write_all(data).map_err(|e| format!("Failed to write '{}': {}", path, e))?;
+    }
+
+    writer
+        .finish()
+        .map(Cursor::into_inner)
+        .map_err(|e| format!("Failed to finish zip archive: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMITS: ArchiveLimits = ArchiveLimits { max_entries: 10, max_total_bytes: 1024 };
+
+    #[test]
+    fn test_entries_round_trip_without_junk() {
+        let bytes = write_entries([
+            ("posts/a.md", b"# A".as_slice()),
+            ("__MACOSX/posts/._a.md", b"junk".as_slice()),
+            ("posts/.DS_Store", b"junk".as_slice()),
+            ("img/cat.png", b"png".as_slice()),
+        ])
+        .unwrap();
+
+        let entries = read_entries(&bytes, LIMITS).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("posts/a.md".to_string(), b"# A".to_vec()),
+                ("img/cat.png".to_string(), b"png".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_entries_enforces_limits() {
+        let big = vec![b'x'; 2048];
+        assert!(read_entries(&write_entries([("a.md", big.as_slice())]).unwrap(), LIMITS).is_err());
+
+        let names: Vec<String> = (0..11).map(|i| format!("{}.md", i)).collect();
+        let many = write_entries(names.iter().map(|n| (n.as_str(), b"x".as_slice()))).unwrap();
+        assert!(read_entries(&many, LIMITS).is_err());
+
+        assert!(read_entries(b"not a zip", LIMITS).is_err());
+    }
+}